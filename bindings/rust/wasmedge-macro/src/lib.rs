@@ -0,0 +1,147 @@
+//! # WasmEdge procedural macros
+//! This crate provides the procedural macros for [wasmedge-sys](https://crates.io/crates/wasmedge-sys). It is not
+//! intended to be used directly; use the macros re-exported by `wasmedge-sys` instead.
+
+#![deny(rust_2018_idioms, unreachable_pub)]
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::{format_ident, quote};
+use syn::{parse_macro_input, spanned::Spanned, Data, DeriveInput, Error, Fields, Index};
+
+/// Derives `wasmedge_sys::WasmPod` for a `#[repr(C)]` struct of which all the fields implement `WasmPod`.
+///
+/// The fields are laid out in the linear memory in the declaration order, and each field is aligned to the alignment
+/// of its type, the same as what a C compiler targeting `wasm32` does. The padding bytes are written as zeros.
+#[proc_macro_derive(WasmPod)]
+pub fn derive_wasm_pod(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand_wasm_pod(input)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+fn expand_wasm_pod(input: DeriveInput) -> syn::Result<TokenStream2> {
+    if !is_repr_c(&input)? {
+        return Err(Error::new(
+            input.ident.span(),
+            "WasmPod can only be derived for the structs with #[repr(C)]",
+        ));
+    }
+
+    let fields = match &input.data {
+        Data::Struct(data) => &data.fields,
+        _ => {
+            return Err(Error::new(
+                input.ident.span(),
+                "WasmPod can only be derived for structs",
+            ))
+        }
+    };
+    // C has no empty structs
+    if fields.is_empty() {
+        return Err(Error::new(
+            input.ident.span(),
+            "WasmPod can not be derived for the structs without fields",
+        ));
+    }
+
+    let tys = fields.iter().map(|field| &field.ty).collect::<Vec<_>>();
+    let vars = (0..fields.len())
+        .map(|i| format_ident!("field_{}", i))
+        .collect::<Vec<_>>();
+    let members = fields
+        .iter()
+        .enumerate()
+        .map(|(i, field)| match &field.ident {
+            Some(ident) => quote!(#ident),
+            None => {
+                let index = Index::from(i);
+                quote!(#index)
+            }
+        })
+        .collect::<Vec<_>>();
+    let construct = match fields {
+        Fields::Named(_) => quote!(Self { #(#members: #vars),* }),
+        Fields::Unnamed(_) => quote!(Self(#(#vars),*)),
+        Fields::Unit => quote!(Self),
+    };
+
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let pod = quote!(::wasmedge_sys::WasmPod);
+    let align_up = quote!(::wasmedge_sys::ptr::align_up);
+
+    Ok(quote! {
+        impl #impl_generics #pod for #name #ty_generics #where_clause {
+            const ALIGN: usize = {
+                let mut align = 1;
+                #(
+                    if <#tys as #pod>::ALIGN > align {
+                        align = <#tys as #pod>::ALIGN;
+                    }
+                )*
+                align
+            };
+
+            const SIZE: usize = {
+                let mut offset = 0;
+                #(
+                    offset = #align_up(offset, <#tys as #pod>::ALIGN) + <#tys as #pod>::SIZE;
+                )*
+                #align_up(offset, <Self as #pod>::ALIGN)
+            };
+
+            #[allow(unused_variables, unused_assignments)]
+            fn from_le_bytes(bytes: &[u8]) -> Self {
+                let mut offset = 0;
+                #(
+                    offset = #align_up(offset, <#tys as #pod>::ALIGN);
+                    let #vars = <#tys as #pod>::from_le_bytes(
+                        &bytes[offset..offset + <#tys as #pod>::SIZE],
+                    );
+                    offset += <#tys as #pod>::SIZE;
+                )*
+                #construct
+            }
+
+            #[allow(unused_variables, unused_assignments)]
+            fn write_le_bytes(&self, bytes: &mut [u8]) {
+                bytes[..<Self as #pod>::SIZE].fill(0);
+                let mut offset = 0;
+                #(
+                    offset = #align_up(offset, <#tys as #pod>::ALIGN);
+                    <#tys as #pod>::write_le_bytes(
+                        &self.#members,
+                        &mut bytes[offset..offset + <#tys as #pod>::SIZE],
+                    );
+                    offset += <#tys as #pod>::SIZE;
+                )*
+            }
+        }
+    })
+}
+
+fn is_repr_c(input: &DeriveInput) -> syn::Result<bool> {
+    let mut repr_c = false;
+    for attr in input
+        .attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("repr"))
+    {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("C") {
+                repr_c = true;
+                Ok(())
+            } else if meta.path.is_ident("packed") || meta.path.is_ident("align") {
+                Err(Error::new(
+                    meta.path.span(),
+                    "WasmPod can not be derived for the structs with a custom alignment",
+                ))
+            } else {
+                Ok(())
+            }
+        })?;
+    }
+    Ok(repr_c)
+}
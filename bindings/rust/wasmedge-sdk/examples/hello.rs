@@ -2,35 +2,27 @@
 
 #[cfg_attr(test, test)]
 fn main() -> Result<(), Box<dyn std::error::Error>> {
-    // NOTICE: The following code is commented out temporarily because the wasmedge-sys crate is in the process of refactoring, which would bring some changes to the current wasmedge-sdk crate.
+    if std::env::args().len() <= 1 {
+        println!("Rust: No input args.");
+    }
+    let module_path =
+        std::path::PathBuf::from(env!("WASMEDGE_DIR")).join("tools/wasmedge/examples/hello.wasm");
 
-    // let mut args: Vec<String> = std::env::args().collect();
-    // let args: Vec<&str> = args.iter_mut().map(|arg| arg.as_str()).collect();
-    // if args.len() <= 1 {
-    //     println!("Rust: No input args.");
-    // }
-    // let module_path =
-    //     std::path::PathBuf::from(env!("WASMEDGE_DIR")).join("tools/wasmedge/examples/hello.wasm");
+    let config = wasmedge_sdk::Config::with_wasi()?;
+    let module = wasmedge_sdk::Module::new(&config, &module_path)?;
 
-    // let config = wasmedge_sdk::Config::create().expect("fail to create Config instance");
-    // let mut module = wasmedge_sdk::Module::new(&config, &module_path)?;
+    let mut vm = wasmedge_sdk::Vm::load(&module)?
+        .with_config(&config)?
+        .create()?;
 
-    // let vm = wasmedge_sdk::Vm::load(&mut module)?
-    //     .with_config(&config)?
-    //     .create()?;
+    // pass the commandline arguments of this example to the guest
+    let ctx = wasmedge_sdk::WasiCtxBuilder::new().inherit_args().build()?;
+    vm.init_wasi(&ctx)?;
 
-    // vm.init_wasi_obj().with_args(args).build();
-    // // or
-    // // vm.init_wasi_obj().with_args(args).with_envs(envs).build();
+    let results = vm.run("_start", &[])?;
 
-    // let results = vm.run("_start", &[])?;
-
-    // assert_eq!(results.len(), 0);
-
-    // for r in results.iter() {
-    //     let res = r.as_i32().unwrap();
-    //     println!("hello : {}", res);
-    // }
+    assert_eq!(results.len(), 0);
+    assert_eq!(vm.wasi_exit_code()?, 0);
 
     Ok(())
 }
@@ -1,5 +1,7 @@
-use super::wasmedge;
-use crate::error::ConfigError;
+use super::wasmedge::{self, CompilerOptimizationLevel, CompilerOutputFormat};
+use crate::{error::ConfigError, WasmEdgeResult};
+use serde::{Deserialize, Serialize};
+use std::path::Path;
 
 #[derive(Debug)]
 pub struct Config {
@@ -13,16 +15,584 @@ impl Config {
             Err(e) => Err(ConfigError::Creation(e)),
         }
     }
+
+    /// Returns a [ConfigBuilder] with the default options of WasmEdge.
+    pub fn builder() -> ConfigBuilder {
+        ConfigBuilder::new()
+    }
+
+    // the WasmEdge contexts take the ownership of the config, so each of them is given a copy
+    pub(crate) fn copy_inner(&self) -> WasmEdgeResult<wasmedge::Config> {
+        wasmedge::Config::copy_from(&self.inner)
+    }
 }
 impl Config {
     pub fn with_wasi() -> Result<Self, ConfigError> {
         let result = Self::create();
         match result {
-            Ok(config) => {
-                let inner: wasmedge::Config = config.inner.enable_wasi();
-                Ok(Self { inner })
+            Ok(mut config) => {
+                config.inner.wasi(true);
+                Ok(config)
             }
             Err(e) => Err(e),
         }
     }
 }
+
+/// The maximum number of the pages of a 32-bit memory.
+const MAX_MEMORY_PAGES: u32 = 65536;
+
+/// Builds a [Config] from the options of the proposals, the host registrations, the runtime, the AOT compiler and
+/// the statistics.
+///
+/// A [ConfigBuilder] can also be (de)serialized, in which the options are grouped into the `proposals`, `host`,
+/// `runtime`, `compiler` and `statistics` tables, and the omitted options take the defaults of WasmEdge.
+///
+/// # Example
+///
+/// ```
+/// use wasmedge_sdk::config::ConfigBuilder;
+///
+/// # fn main() -> Result<(), wasmedge_sdk::error::ConfigError> {
+/// let builder = ConfigBuilder::from_toml_str(
+///     r#"
+///     [proposals]
+///     threads = true
+///
+///     [runtime]
+///     max_memory_pages = 1024
+///     "#,
+/// )?;
+/// let config = builder.wasi(true).build()?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct ConfigBuilder {
+    proposals: ProposalOptions,
+    host: HostOptions,
+    runtime: RuntimeOptions,
+    compiler: CompilerOptions,
+    statistics: StatisticsOptions,
+}
+impl ConfigBuilder {
+    /// Creates a new [ConfigBuilder] with the default options of WasmEdge.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a new [ConfigBuilder] from the given TOML string.
+    ///
+    /// # Error
+    ///
+    /// If fail to parse the TOML string, then an error is returned.
+    pub fn from_toml_str(s: &str) -> Result<Self, ConfigError> {
+        toml::from_str(s).map_err(ConfigError::Parse)
+    }
+
+    /// Creates a new [ConfigBuilder] from the given TOML file.
+    ///
+    /// # Error
+    ///
+    /// If fail to read or parse the TOML file, then an error is returned.
+    pub fn from_toml_file(path: impl AsRef<Path>) -> Result<Self, ConfigError> {
+        let s = std::fs::read_to_string(path).map_err(ConfigError::Read)?;
+        Self::from_toml_str(&s)
+    }
+
+    /// Serializes the options into a TOML string.
+    ///
+    /// # Error
+    ///
+    /// If fail to serialize the options, then an error is returned.
+    pub fn to_toml_string(&self) -> Result<String, ConfigError> {
+        toml::to_string(self).map_err(ConfigError::Serialize)
+    }
+
+    /// Enables or disables the ImportExportMutGlobals proposal, which is enabled by default.
+    pub fn mutable_globals(mut self, enable: bool) -> Self {
+        self.proposals.mutable_globals = enable;
+        self
+    }
+
+    /// Enables or disables the NonTrapFloatToIntConversions proposal, which is enabled by default.
+    pub fn non_trap_conversions(mut self, enable: bool) -> Self {
+        self.proposals.non_trap_conversions = enable;
+        self
+    }
+
+    /// Enables or disables the SignExtensionOperators proposal, which is enabled by default.
+    pub fn sign_extension_operators(mut self, enable: bool) -> Self {
+        self.proposals.sign_extension_operators = enable;
+        self
+    }
+
+    /// Enables or disables the MultiValue proposal, which is enabled by default.
+    pub fn multi_value(mut self, enable: bool) -> Self {
+        self.proposals.multi_value = enable;
+        self
+    }
+
+    /// Enables or disables the BulkMemoryOperations proposal, which is enabled by default.
+    pub fn bulk_memory_operations(mut self, enable: bool) -> Self {
+        self.proposals.bulk_memory_operations = enable;
+        self
+    }
+
+    /// Enables or disables the ReferenceTypes proposal, which is enabled by default and requires the
+    /// BulkMemoryOperations proposal.
+    pub fn reference_types(mut self, enable: bool) -> Self {
+        self.proposals.reference_types = enable;
+        self
+    }
+
+    /// Enables or disables the SIMD proposal, which is enabled by default.
+    pub fn simd(mut self, enable: bool) -> Self {
+        self.proposals.simd = enable;
+        self
+    }
+
+    /// Enables or disables the TailCall proposal.
+    pub fn tail_call(mut self, enable: bool) -> Self {
+        self.proposals.tail_call = enable;
+        self
+    }
+
+    /// Enables or disables the Annotations proposal.
+    pub fn annotations(mut self, enable: bool) -> Self {
+        self.proposals.annotations = enable;
+        self
+    }
+
+    /// Enables or disables the Memory64 proposal.
+    pub fn memory64(mut self, enable: bool) -> Self {
+        self.proposals.memory64 = enable;
+        self
+    }
+
+    /// Enables or disables the Threads proposal.
+    pub fn threads(mut self, enable: bool) -> Self {
+        self.proposals.threads = enable;
+        self
+    }
+
+    /// Enables or disables the ExceptionHandling proposal.
+    pub fn exception_handling(mut self, enable: bool) -> Self {
+        self.proposals.exception_handling = enable;
+        self
+    }
+
+    /// Enables or disables the FunctionReferences proposal, which requires the ReferenceTypes proposal.
+    pub fn function_references(mut self, enable: bool) -> Self {
+        self.proposals.function_references = enable;
+        self
+    }
+
+    /// Enables or disables the registration of the WASI host module.
+    pub fn wasi(mut self, enable: bool) -> Self {
+        self.host.wasi = enable;
+        self
+    }
+
+    /// Enables or disables the registration of the wasmedge_process host module.
+    pub fn wasmedge_process(mut self, enable: bool) -> Self {
+        self.host.wasmedge_process = enable;
+        self
+    }
+
+    /// Sets the maximum number of the memory pages (64KiB per page), which is in the range of `1..=65536`.
+    pub fn max_memory_pages(mut self, count: u32) -> Self {
+        self.runtime.max_memory_pages = count;
+        self
+    }
+
+    /// Sets the optimization level of the AOT compiler, which is `O3` by default.
+    pub fn aot_optimization_level(mut self, level: CompilerOptimizationLevel) -> Self {
+        self.compiler.optimization_level = level;
+        self
+    }
+
+    /// Sets the output format of the AOT compiler, which is `Wasm` by default.
+    pub fn aot_output_format(mut self, format: CompilerOutputFormat) -> Self {
+        self.compiler.output_format = format;
+        self
+    }
+
+    /// Enables or disables dumping the LLVM IR in the AOT compilation.
+    pub fn dump_ir(mut self, enable: bool) -> Self {
+        self.compiler.dump_ir = enable;
+        self
+    }
+
+    /// Enables or disables generating the generic binary, which is not optimized for the host CPU, in the AOT
+    /// compilation.
+    pub fn generic_binary(mut self, enable: bool) -> Self {
+        self.compiler.generic_binary = enable;
+        self
+    }
+
+    /// Enables or disables generating the interruptible binary in the AOT compilation.
+    pub fn interruptible(mut self, enable: bool) -> Self {
+        self.compiler.interruptible = enable;
+        self
+    }
+
+    /// Enables or disables counting the instructions in execution.
+    pub fn count_instructions(mut self, enable: bool) -> Self {
+        self.statistics.count_instructions = enable;
+        self
+    }
+
+    /// Enables or disables measuring the cost in execution.
+    pub fn measure_cost(mut self, enable: bool) -> Self {
+        self.statistics.measure_cost = enable;
+        self
+    }
+
+    /// Enables or disables measuring the time in execution.
+    pub fn measure_time(mut self, enable: bool) -> Self {
+        self.statistics.measure_time = enable;
+        self
+    }
+
+    /// Checks the options, and builds a [Config] from them.
+    ///
+    /// # Error
+    ///
+    /// If the options are incompatible, or fail to create a [Config], then an error is returned.
+    pub fn build(&self) -> Result<Config, ConfigError> {
+        self.validate()?;
+
+        let mut config = Config::create()?;
+        let inner = &mut config.inner;
+
+        let proposals = &self.proposals;
+        inner.mutable_globals(proposals.mutable_globals);
+        inner.non_trap_conversions(proposals.non_trap_conversions);
+        inner.sign_extension_operators(proposals.sign_extension_operators);
+        inner.multi_value(proposals.multi_value);
+        inner.bulk_memory_operations(proposals.bulk_memory_operations);
+        inner.reference_types(proposals.reference_types);
+        inner.simd(proposals.simd);
+        inner.tail_call(proposals.tail_call);
+        inner.annotations(proposals.annotations);
+        inner.memory64(proposals.memory64);
+        inner.threads(proposals.threads);
+        inner.exception_handling(proposals.exception_handling);
+        inner.function_references(proposals.function_references);
+
+        inner.wasi(self.host.wasi);
+        inner.wasmedge_process(self.host.wasmedge_process);
+
+        inner.set_max_memory_pages(self.runtime.max_memory_pages);
+
+        let compiler = &self.compiler;
+        inner.set_aot_optimization_level(compiler.optimization_level);
+        inner.set_aot_compiler_output_format(compiler.output_format);
+        inner.dump_ir(compiler.dump_ir);
+        inner.generic_binary(compiler.generic_binary);
+        inner.interruptible(compiler.interruptible);
+
+        let statistics = &self.statistics;
+        inner.count_instructions(statistics.count_instructions);
+        inner.measure_cost(statistics.measure_cost);
+        inner.measure_time(statistics.measure_time);
+
+        Ok(config)
+    }
+
+    fn validate(&self) -> Result<(), ConfigError> {
+        let proposals = &self.proposals;
+        if proposals.reference_types && !proposals.bulk_memory_operations {
+            return Err(ConfigError::Incompatible {
+                option: "reference_types",
+                requires: "bulk_memory_operations",
+            });
+        }
+        if proposals.function_references && !proposals.reference_types {
+            return Err(ConfigError::Incompatible {
+                option: "function_references",
+                requires: "reference_types",
+            });
+        }
+
+        let pages = self.runtime.max_memory_pages;
+        if pages == 0 || pages > MAX_MEMORY_PAGES {
+            return Err(ConfigError::MaxMemoryPages(pages));
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+struct ProposalOptions {
+    mutable_globals: bool,
+    non_trap_conversions: bool,
+    sign_extension_operators: bool,
+    multi_value: bool,
+    bulk_memory_operations: bool,
+    reference_types: bool,
+    simd: bool,
+    tail_call: bool,
+    annotations: bool,
+    memory64: bool,
+    threads: bool,
+    exception_handling: bool,
+    function_references: bool,
+}
+impl Default for ProposalOptions {
+    // the same as the proposals enabled by WasmEdge by default
+    fn default() -> Self {
+        Self {
+            mutable_globals: true,
+            non_trap_conversions: true,
+            sign_extension_operators: true,
+            multi_value: true,
+            bulk_memory_operations: true,
+            reference_types: true,
+            simd: true,
+            tail_call: false,
+            annotations: false,
+            memory64: false,
+            threads: false,
+            exception_handling: false,
+            function_references: false,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+struct HostOptions {
+    wasi: bool,
+    wasmedge_process: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+struct RuntimeOptions {
+    max_memory_pages: u32,
+}
+impl Default for RuntimeOptions {
+    fn default() -> Self {
+        Self {
+            max_memory_pages: MAX_MEMORY_PAGES,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+struct CompilerOptions {
+    #[serde(with = "CompilerOptimizationLevelDef")]
+    optimization_level: CompilerOptimizationLevel,
+    #[serde(with = "CompilerOutputFormatDef")]
+    output_format: CompilerOutputFormat,
+    dump_ir: bool,
+    generic_binary: bool,
+    interruptible: bool,
+}
+impl Default for CompilerOptions {
+    fn default() -> Self {
+        Self {
+            optimization_level: CompilerOptimizationLevel::O3,
+            output_format: CompilerOutputFormat::Wasm,
+            dump_ir: false,
+            generic_binary: false,
+            interruptible: false,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+struct StatisticsOptions {
+    count_instructions: bool,
+    measure_cost: bool,
+    measure_time: bool,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(remote = "CompilerOptimizationLevel")]
+enum CompilerOptimizationLevelDef {
+    O0,
+    O1,
+    O2,
+    O3,
+    Os,
+    Oz,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(remote = "CompilerOutputFormat")]
+enum CompilerOutputFormatDef {
+    Native,
+    Wasm,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_config_builder() {
+        // the default options are the ones of WasmEdge
+        let result = ConfigBuilder::new().build();
+        assert!(result.is_ok());
+        let config = result.unwrap();
+        let result = wasmedge::Config::create();
+        assert!(result.is_ok());
+        let default = result.unwrap();
+        assert_eq!(
+            config.inner.reference_types_enabled(),
+            default.reference_types_enabled()
+        );
+        assert_eq!(config.inner.simd_enabled(), default.simd_enabled());
+        assert_eq!(config.inner.threads_enabled(), default.threads_enabled());
+        assert_eq!(
+            config.inner.get_max_memory_pages(),
+            default.get_max_memory_pages()
+        );
+        assert_eq!(
+            config.inner.get_aot_optimization_level(),
+            default.get_aot_optimization_level()
+        );
+        assert_eq!(
+            config.inner.get_aot_compiler_output_format(),
+            default.get_aot_compiler_output_format()
+        );
+
+        let result = Config::builder()
+            .simd(false)
+            .threads(true)
+            .memory64(true)
+            .wasi(true)
+            .max_memory_pages(1024)
+            .aot_optimization_level(CompilerOptimizationLevel::Oz)
+            .aot_output_format(CompilerOutputFormat::Native)
+            .interruptible(true)
+            .count_instructions(true)
+            .measure_cost(true)
+            .build();
+        assert!(result.is_ok());
+        let config = result.unwrap();
+        assert!(!config.inner.simd_enabled());
+        assert!(config.inner.threads_enabled());
+        assert!(config.inner.memory64_enabled());
+        assert!(config.inner.wasi_enabled());
+        assert!(!config.inner.wasmedge_process_enabled());
+        assert_eq!(config.inner.get_max_memory_pages(), 1024);
+        assert_eq!(
+            config.inner.get_aot_optimization_level(),
+            CompilerOptimizationLevel::Oz
+        );
+        assert_eq!(
+            config.inner.get_aot_compiler_output_format(),
+            CompilerOutputFormat::Native
+        );
+        assert!(config.inner.interruptible_enabled());
+        assert!(config.inner.is_instruction_counting());
+        assert!(config.inner.is_cost_measuring());
+        assert!(!config.inner.is_time_measuring());
+
+        // incompatible options
+        let result = Config::builder().bulk_memory_operations(false).build();
+        assert!(matches!(
+            result,
+            Err(ConfigError::Incompatible {
+                option: "reference_types",
+                requires: "bulk_memory_operations"
+            })
+        ));
+        let result = Config::builder()
+            .bulk_memory_operations(false)
+            .reference_types(false)
+            .build();
+        assert!(result.is_ok());
+        let result = Config::builder()
+            .reference_types(false)
+            .function_references(true)
+            .build();
+        assert!(matches!(
+            result,
+            Err(ConfigError::Incompatible {
+                option: "function_references",
+                requires: "reference_types"
+            })
+        ));
+        let result = Config::builder().max_memory_pages(0).build();
+        assert!(matches!(result, Err(ConfigError::MaxMemoryPages(0))));
+        let result = Config::builder().max_memory_pages(65537).build();
+        assert!(matches!(result, Err(ConfigError::MaxMemoryPages(65537))));
+    }
+
+    #[test]
+    fn test_config_builder_toml() {
+        let result = ConfigBuilder::from_toml_str(
+            r#"
+            [proposals]
+            threads = true
+            simd = false
+
+            [host]
+            wasi = true
+
+            [runtime]
+            max_memory_pages = 1024
+
+            [compiler]
+            optimization_level = "Os"
+
+            [statistics]
+            measure_time = true
+            "#,
+        );
+        assert!(result.is_ok());
+        let builder = result.unwrap();
+        assert_eq!(
+            builder,
+            ConfigBuilder::new()
+                .threads(true)
+                .simd(false)
+                .wasi(true)
+                .max_memory_pages(1024)
+                .aot_optimization_level(CompilerOptimizationLevel::Os)
+                .measure_time(true)
+        );
+
+        // round trip
+        let result = builder.to_toml_string();
+        assert!(result.is_ok());
+        let s = result.unwrap();
+        let result = ConfigBuilder::from_toml_str(&s);
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap(), builder);
+
+        // from a file
+        let path = std::env::temp_dir().join("wasmedge_sdk_test_config_builder.toml");
+        assert!(std::fs::write(&path, &s).is_ok());
+        let result = ConfigBuilder::from_toml_file(&path);
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap(), builder);
+        assert!(std::fs::remove_file(&path).is_ok());
+        let result = ConfigBuilder::from_toml_file(&path);
+        assert!(matches!(result, Err(ConfigError::Read(_))));
+
+        // unknown options and invalid values
+        let result = ConfigBuilder::from_toml_str("[proposals]\nsimd2 = true");
+        assert!(matches!(result, Err(ConfigError::Parse(_))));
+        let result = ConfigBuilder::from_toml_str("[compiler]\noutput_format = \"Elf\"");
+        assert!(matches!(result, Err(ConfigError::Parse(_))));
+
+        // the incompatible options are checked when built
+        let result = ConfigBuilder::from_toml_str("[proposals]\nbulk_memory_operations = false");
+        assert!(result.is_ok());
+        assert!(matches!(
+            result.unwrap().build(),
+            Err(ConfigError::Incompatible { .. })
+        ));
+    }
+}
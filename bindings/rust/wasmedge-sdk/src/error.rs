@@ -1,38 +1,92 @@
-use super::wasmedge;
+use super::wasmedge::error::WasmEdgeError;
 use thiserror::Error;
 
 #[derive(Debug, Error)]
 pub enum ModuleError {
     #[error("an unknown error occured")]
-    Unknown(wasmedge::Error),
+    Unknown(WasmEdgeError),
 
     #[error("`{0}` is not a valid path: {1}")]
     Path(String, Box<dyn std::error::Error + 'static + Send + Sync>),
 
     #[error("loader error: {}", _0)]
-    Load(wasmedge::Error),
+    Load(WasmEdgeError),
 }
 
 #[derive(Debug, Error)]
 pub enum VmError {
+    #[error("VM creation failed: {}", _0)]
+    Create(WasmEdgeError),
+
+    #[error("module registration failed: {}", _0)]
+    Register(WasmEdgeError),
+
     #[error("module loading failed: {}", _0)]
-    ModuleLoad(wasmedge::Error),
+    ModuleLoad(WasmEdgeError),
 
     #[error("module validation failed: {}", _0)]
-    Validate(wasmedge::Error),
+    Validate(WasmEdgeError),
 
     #[error("module instantiation failed: {}", _0)]
-    Instantiate(wasmedge::Error),
+    Instantiate(WasmEdgeError),
 
     #[error("could not find function `{0}` in module")]
     MissingFunction(String),
 
+    #[error("could not find function `{1}` in module `{0}`")]
+    MissingRegisteredFunction(String, String),
+
     #[error("module execution failed: {}", _0)]
-    Execute(wasmedge::Error),
+    Execute(WasmEdgeError),
 }
 
 #[derive(Debug, Error)]
 pub enum ConfigError {
     #[error("Config creation failed: {}", _0)]
-    Creation(wasmedge::Error),
+    Creation(WasmEdgeError),
+
+    #[error("`{option}` requires `{requires}` to be enabled")]
+    Incompatible {
+        option: &'static str,
+        requires: &'static str,
+    },
+
+    #[error("the maximum number of memory pages must be in 1..=65536, but got {0}")]
+    MaxMemoryPages(u32),
+
+    #[error("failed to read the config file: {}", _0)]
+    Read(std::io::Error),
+
+    #[error("failed to parse the config: {}", _0)]
+    Parse(toml::de::Error),
+
+    #[error("failed to serialize the config: {}", _0)]
+    Serialize(toml::ser::Error),
+}
+
+#[derive(Debug, Error)]
+pub enum WasiError {
+    #[error("invalid argument `{0}`: not valid UTF-8 or contains a NUL character")]
+    InvalidArg(String),
+
+    #[error("invalid environment variable `{0}`")]
+    InvalidEnv(String),
+
+    #[error("invalid preopened directory `{0}`: {1}")]
+    InvalidPreopen(String, &'static str),
+
+    #[error(
+        "the WASI module is not found, of which the host registration may not be enabled: {}",
+        _0
+    )]
+    NotFound(WasmEdgeError),
+
+    #[error("failed to initialize the WASI module: {}", _0)]
+    Init(WasmEdgeError),
+
+    #[error("failed to redirect the standard streams of the WASI guest: {}", _0)]
+    Redirect(WasmEdgeError),
+
+    #[error("failed to transfer the standard streams of the WASI guest: {}", _0)]
+    Stdio(std::io::Error),
 }
@@ -0,0 +1,221 @@
+//! Defines the Executor struct, which instantiates modules into stores and runs functions.
+
+use crate::{
+    wasmedge, Config, Func, ImportObject, Module, Statistics, Store, WasmEdgeResult, WasmValue,
+};
+
+/// Registers [import objects](crate::ImportObject) and [modules](crate::Module) into [stores](crate::Store), and runs
+/// the functions of the module instances in them.
+#[derive(Debug)]
+pub struct Executor {
+    // declared before `stat`, so that the executor is dropped before the statistics it refers to
+    inner: wasmedge::Executor,
+    stat: Option<Statistics>,
+}
+impl Executor {
+    /// Creates a new [Executor].
+    ///
+    /// # Arguments
+    ///
+    /// - `config` specifies the configuration of the [Executor]. If `None`, the default configuration is used.
+    ///
+    /// - `stat` specifies the [Statistics] to collect the executions into, which is owned by the [Executor].
+    ///
+    /// # Error
+    ///
+    /// If fail to create the [Executor], then an error is returned.
+    pub fn new(config: Option<&Config>, mut stat: Option<Statistics>) -> WasmEdgeResult<Self> {
+        let config = config.map(Config::copy_inner).transpose()?;
+        let inner = wasmedge::Executor::create(config, stat.as_mut().map(|stat| &mut stat.inner))?;
+        Ok(Self { inner, stat })
+    }
+
+    /// Returns the [Statistics] of the [Executor], if any.
+    pub fn statistics(&self) -> Option<&Statistics> {
+        self.stat.as_ref()
+    }
+
+    /// Returns the mutable [Statistics] of the [Executor], if any.
+    pub fn statistics_mut(&mut self) -> Option<&mut Statistics> {
+        self.stat.as_mut()
+    }
+
+    /// Registers the given [ImportObject] into the given [Store].
+    ///
+    /// # Error
+    ///
+    /// If fail to register the [ImportObject], then an error is returned.
+    pub fn register_import_object(
+        &mut self,
+        store: &mut Store,
+        import: &ImportObject,
+    ) -> WasmEdgeResult<()> {
+        self.inner
+            .register_import_object(&mut store.inner, &import.inner)
+    }
+
+    /// Instantiates the given [Module], and registers it into the given [Store] with the given name.
+    ///
+    /// # Error
+    ///
+    /// If fail to register the [Module], then an error is returned.
+    pub fn register_module(
+        &mut self,
+        store: &mut Store,
+        module: &Module,
+        name: impl AsRef<str>,
+    ) -> WasmEdgeResult<()> {
+        self.inner
+            .register_named_module(&mut store.inner, &module.inner, name)
+    }
+
+    /// Instantiates the given [Module] into the given [Store] as the active anonymous module, which replaces the old
+    /// one.
+    ///
+    /// # Error
+    ///
+    /// If fail to instantiate the [Module], then an error is returned.
+    pub fn register_active_module(
+        &mut self,
+        store: &mut Store,
+        module: &Module,
+    ) -> WasmEdgeResult<()> {
+        self.inner
+            .register_active_module(&mut store.inner, &module.inner)
+    }
+
+    /// Runs the exported function of the given name in the active module of the given [Store].
+    ///
+    /// # Error
+    ///
+    /// If fail to run the function, then an error is returned.
+    pub fn run_func(
+        &mut self,
+        store: &mut Store,
+        func_name: impl AsRef<str>,
+        params: impl IntoIterator<Item = WasmValue>,
+    ) -> WasmEdgeResult<Vec<WasmValue>> {
+        self.inner.run_func(&mut store.inner, func_name, params)
+    }
+
+    /// Runs the exported function of the given name in the registered module of the given name.
+    ///
+    /// # Error
+    ///
+    /// If fail to run the function, then an error is returned.
+    pub fn run_func_registered(
+        &mut self,
+        store: &mut Store,
+        mod_name: impl AsRef<str>,
+        func_name: impl AsRef<str>,
+        params: impl IntoIterator<Item = WasmValue>,
+    ) -> WasmEdgeResult<Vec<WasmValue>> {
+        self.inner
+            .run_func_registered(&mut store.inner, mod_name, func_name, params)
+    }
+
+    /// Runs the given [function](crate::Func), which is either a host function or owned by a module instance in the
+    /// given [Store].
+    ///
+    /// # Error
+    ///
+    /// If fail to run the function, then an error is returned.
+    pub fn run_func_ref(
+        &mut self,
+        store: &Store,
+        func: &Func,
+        params: impl IntoIterator<Item = WasmValue>,
+    ) -> WasmEdgeResult<Vec<WasmValue>> {
+        self.inner.run_func_ref(&store.inner, &func.inner, params)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{FuncType, Loader, ValType, Validator};
+
+    #[test]
+    fn test_executor_run_funcs() {
+        let path = std::path::PathBuf::from(env!("WASMEDGE_DIR"))
+            .join("bindings/rust/wasmedge-sys/tests/data/fibonacci.wasm");
+        let result = Loader::new(None);
+        assert!(result.is_ok());
+        let loader = result.unwrap();
+        let result = loader.from_file(path);
+        assert!(result.is_ok());
+        let module = result.unwrap();
+        assert_eq!(module.exports(), ["fib"]);
+
+        let result = Validator::new(None);
+        assert!(result.is_ok());
+        let validator = result.unwrap();
+        assert!(validator.validate(&module).is_ok());
+
+        let result = Statistics::new();
+        assert!(result.is_ok());
+        let stat = result.unwrap();
+        let result = Config::builder().count_instructions(true).build();
+        assert!(result.is_ok());
+        let config = result.unwrap();
+        let result = Executor::new(Some(&config), Some(stat));
+        assert!(result.is_ok());
+        let mut executor = result.unwrap();
+        let result = Store::new();
+        assert!(result.is_ok());
+        let mut store = result.unwrap();
+
+        // run the function in the active module
+        assert!(executor.register_active_module(&mut store, &module).is_ok());
+        let result = executor.run_func(&mut store, "fib", [WasmValue::from_i32(5)]);
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap()[0].to_i32(), 8);
+        assert!(executor.statistics().unwrap().instr_count() > 0);
+
+        // run the function in a registered module
+        assert!(executor
+            .register_module(&mut store, &module, "math")
+            .is_ok());
+        assert_eq!(store.module_names(), ["math"]);
+        let result =
+            executor.run_func_registered(&mut store, "math", "fib", [WasmValue::from_i32(6)]);
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap()[0].to_i32(), 13);
+
+        // run the function found in the registered module
+        let result = store.module("math");
+        assert!(result.is_ok());
+        let instance = result.unwrap();
+        assert_eq!(instance.name(), Some("math".to_string()));
+        assert_eq!(instance.func_names(), ["fib"]);
+        let result = instance.func("fib");
+        assert!(result.is_ok());
+        let fib = result.unwrap();
+        let result = fib.call(&mut executor, &store, [WasmValue::from_i32(7)]);
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap()[0].to_i32(), 21);
+
+        // run a host function registered by an import object
+        let ty = FuncType::new([ValType::I32; 2], [ValType::I32]);
+        let result = Func::new(&ty, |_caller, inputs| {
+            Ok(vec![WasmValue::from_i32(
+                inputs[0].to_i32() + inputs[1].to_i32(),
+            )])
+        });
+        assert!(result.is_ok());
+        let add = result.unwrap();
+        let result = ImportObject::new("extern");
+        assert!(result.is_ok());
+        let mut import = result.unwrap();
+        import.add_func("add", add);
+        assert!(executor.register_import_object(&mut store, &import).is_ok());
+        let result = executor.run_func_registered(
+            &mut store,
+            "extern",
+            "add",
+            [WasmValue::from_i32(2), WasmValue::from_i32(3)],
+        );
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap()[0].to_i32(), 5);
+    }
+}
@@ -0,0 +1,79 @@
+//! Defines the Func struct, which represents a host function or an exported wasm function.
+
+use crate::{
+    wasmedge::{self, io::IntoHostFunc, io::WasmFnIO},
+    Caller, Executor, FuncType, Store, Trap, WasmEdgeResult, WasmValue,
+};
+
+/// Represents a host function, or a function exported by a module [Instance](crate::Instance).
+#[derive(Debug)]
+pub struct Func {
+    pub(crate) inner: wasmedge::Function,
+}
+impl Func {
+    /// Creates a host function of the given type from a closure.
+    ///
+    /// # Arguments
+    ///
+    /// - `ty` specifies the type of the host function.
+    ///
+    /// - `real_fn` specifies the closure, which takes the [Caller] and the arguments, and returns the results.
+    ///
+    /// # Error
+    ///
+    /// If fail to create the host function, then an error is returned.
+    pub fn new(
+        ty: &FuncType,
+        real_fn: impl Fn(Caller, Vec<WasmValue>) -> Result<Vec<WasmValue>, Trap> + Send + Sync + 'static,
+    ) -> WasmEdgeResult<Self> {
+        Ok(Self {
+            inner: wasmedge::Function::create(&ty.to_raw()?, Box::new(real_fn), 0)?,
+        })
+    }
+
+    /// Creates a host function from a Rust closure or function, of which the type is inferred from its signature.
+    ///
+    /// # Error
+    ///
+    /// If fail to create the host function, then an error is returned.
+    pub fn wrap<Args, Rets>(real_fn: impl IntoHostFunc<Args, Rets>) -> WasmEdgeResult<Self>
+    where
+        Args: WasmFnIO,
+        Rets: WasmFnIO,
+    {
+        Ok(Self {
+            inner: wasmedge::Function::wrap(real_fn)?,
+        })
+    }
+
+    /// Returns the type of the function.
+    ///
+    /// # Error
+    ///
+    /// If fail to get the function type, then an error is returned.
+    pub fn ty(&self) -> WasmEdgeResult<FuncType> {
+        self.inner.ty().map(Into::into)
+    }
+
+    /// Runs the function by the given [Executor], and returns the results.
+    ///
+    /// # Arguments
+    ///
+    /// - `executor` specifies the [Executor] to run the function.
+    ///
+    /// - `store` specifies the [Store] which the module instance owning the function is instantiated in.
+    ///
+    /// - `params` specifies the argument values for the function.
+    ///
+    /// # Error
+    ///
+    /// If fail to run the function, then an error is returned.
+    pub fn call(
+        &self,
+        executor: &mut Executor,
+        store: &Store,
+        params: impl IntoIterator<Item = WasmValue>,
+    ) -> WasmEdgeResult<Vec<WasmValue>> {
+        executor.run_func_ref(store, self, params)
+    }
+}
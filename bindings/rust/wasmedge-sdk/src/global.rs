@@ -0,0 +1,45 @@
+//! Defines the Global struct, which represents a global variable.
+
+use crate::{wasmedge, GlobalType, WasmEdgeResult, WasmValue};
+
+/// Represents a global variable, which is either created on the host or exported by a module
+/// [Instance](crate::Instance).
+#[derive(Debug)]
+pub struct Global {
+    pub(crate) inner: wasmedge::Global,
+}
+impl Global {
+    /// Creates a new [Global] of the given type with the given initial value.
+    ///
+    /// # Error
+    ///
+    /// If fail to create the [Global], then an error is returned.
+    pub fn new(ty: GlobalType, value: WasmValue) -> WasmEdgeResult<Self> {
+        Ok(Self {
+            inner: wasmedge::Global::create(&ty.to_raw()?, value)?,
+        })
+    }
+
+    /// Returns the type of the [Global].
+    ///
+    /// # Error
+    ///
+    /// If fail to get the global type, then an error is returned.
+    pub fn ty(&self) -> WasmEdgeResult<GlobalType> {
+        self.inner.ty().map(Into::into)
+    }
+
+    /// Returns the value of the [Global].
+    pub fn get(&self) -> WasmValue {
+        self.inner.get_value()
+    }
+
+    /// Sets the value of the [Global].
+    ///
+    /// # Error
+    ///
+    /// If the [Global] is immutable, or the value does not match its type, then an error is returned.
+    pub fn set(&mut self, value: WasmValue) -> WasmEdgeResult<()> {
+        self.inner.set_value(value)
+    }
+}
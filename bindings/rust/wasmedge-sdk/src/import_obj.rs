@@ -0,0 +1,331 @@
+//! Defines the ImportObject struct, which collects the host instances to be imported by modules.
+
+use crate::{
+    error::WasiError, wasi::StdioRedirect, wasmedge, Func, Global, Memory, Table, WasiCtx,
+    WasiStdio, WasmEdgeResult,
+};
+
+/// Collects the host [functions](crate::Func), [memories](crate::Memory), [tables](crate::Table) and
+/// [globals](crate::Global) under a module name, which are imported by the modules after the [ImportObject] is
+/// registered by an [Executor](crate::Executor).
+#[derive(Debug)]
+pub struct ImportObject {
+    pub(crate) inner: wasmedge::ImportObject,
+    // dropped after `inner`, which closes the redirected standard streams, so that the writer threads can be joined
+    stdio: Option<StdioRedirect>,
+}
+impl ImportObject {
+    /// Creates a new [ImportObject] with the given module name.
+    ///
+    /// # Error
+    ///
+    /// If fail to create the [ImportObject], then an error is returned.
+    pub fn new(name: impl AsRef<str>) -> WasmEdgeResult<Self> {
+        Ok(Self {
+            inner: wasmedge::ImportObject::create(name)?,
+            stdio: None,
+        })
+    }
+
+    /// Creates a new WASI [ImportObject] with the given [WasiCtx].
+    ///
+    /// # Error
+    ///
+    /// If fail to create the [ImportObject], then an error is returned.
+    pub fn wasi(ctx: &WasiCtx) -> WasmEdgeResult<Self> {
+        let (args, envs, preopens) = ctx.to_raw();
+        Ok(Self {
+            inner: wasmedge::ImportObject::create_wasi(Some(args), Some(envs), Some(preopens))?,
+            stdio: None,
+        })
+    }
+
+    /// Re-initializes the WASI [ImportObject] with the given [WasiCtx].
+    ///
+    /// The standard streams redirected by [ImportObject::redirect_stdio] are restored.
+    ///
+    /// # Error
+    ///
+    /// If the [ImportObject] is not a WASI one, fail to initialize the WASI module, or fail to write the output of the guest into the sinks of the
+    /// restored [WasiStdio], then an error is returned.
+    pub fn init_wasi(&mut self, ctx: &WasiCtx) -> Result<(), WasiError> {
+        let (args, envs, preopens) = ctx.to_raw();
+        self.inner
+            .init_wasi(Some(args), Some(envs), Some(preopens))
+            .map_err(WasiError::Init)?;
+        match self.stdio.take() {
+            Some(stdio) => stdio.finish(),
+            None => Ok(()),
+        }
+    }
+
+    /// Redirects the standard streams of the WASI guest with the given [WasiStdio]. The previous redirection is
+    /// restored first.
+    ///
+    /// # Error
+    ///
+    /// If fail to redirect the standard streams, for example, the [ImportObject] is not a WASI one, then an error is
+    /// returned.
+    pub fn redirect_stdio(&mut self, stdio: WasiStdio) -> Result<(), WasiError> {
+        self.restore_stdio()?;
+        self.stdio = Some(StdioRedirect::new(&mut self.inner, stdio)?);
+        Ok(())
+    }
+
+    /// Restores the standard streams of the host process, and waits until all the output of the WASI guest is
+    /// written into the sinks of the [WasiStdio]. Nothing happens if the standard streams are not redirected.
+    ///
+    /// # Error
+    ///
+    /// If fail to restore the standard streams, or fail to write the output into the sinks, then an error is
+    /// returned. If the output is still pending 1 second after the restoration, for example, the guest kept a
+    /// duplicate of a standard stream, then the rest of the output is discarded, and an error is returned.
+    pub fn restore_stdio(&mut self) -> Result<(), WasiError> {
+        match self.stdio.take() {
+            Some(stdio) => {
+                self.inner
+                    .redirect_wasi_stdio(None, None, None)
+                    .map_err(WasiError::Redirect)?;
+                stdio.finish()
+            }
+            None => Ok(()),
+        }
+    }
+
+    // splits the ImportObject into the inner one and the redirection of its standard streams, which must be dropped
+    // after the inner one
+    pub(crate) fn into_parts(self) -> (wasmedge::ImportObject, Option<StdioRedirect>) {
+        (self.inner, self.stdio)
+    }
+
+    /// Returns the exit code of the WASI guest, which is available after its `_start` function runs. The exit code
+    /// is always `1` if the [ImportObject] is not a WASI one.
+    pub fn exit_code(&self) -> u32 {
+        self.inner.exit_code()
+    }
+
+    /// Returns the module name of the [ImportObject].
+    pub fn name(&self) -> String {
+        self.inner.name()
+    }
+
+    /// Adds a host [function](crate::Func) with the given export name, and takes the ownership of it.
+    pub fn add_func(&mut self, name: impl AsRef<str>, func: Func) {
+        self.inner.add_func(name, func.inner)
+    }
+
+    /// Adds a [memory](crate::Memory) with the given export name, and takes the ownership of it.
+    pub fn add_memory(&mut self, name: impl AsRef<str>, memory: Memory) {
+        self.inner.add_memory(name, memory.inner)
+    }
+
+    /// Adds a [table](crate::Table) with the given export name, and takes the ownership of it.
+    pub fn add_table(&mut self, name: impl AsRef<str>, table: Table) {
+        self.inner.add_table(name, table.inner)
+    }
+
+    /// Adds a [global](crate::Global) with the given export name, and takes the ownership of it.
+    pub fn add_global(&mut self, name: impl AsRef<str>, global: Global) {
+        self.inner.add_global(name, global.inner)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        Executor, GlobalType, Loader, MemoryType, Mutability, OutputBuffer, RefType, Store,
+        TableType, ValType, Validator, WasiCtxBuilder, WasmValue,
+    };
+
+    #[test]
+    fn test_import_obj_instances() {
+        // memory
+        let result = Memory::new(MemoryType::new(1, Some(2)));
+        assert!(result.is_ok());
+        let mut memory = result.unwrap();
+        assert_eq!(memory.ty().unwrap(), MemoryType::new(1, Some(2)));
+        assert!(memory.write_string("hello", 10).is_ok());
+        let result = memory.read_string(10, 5);
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap(), "hello");
+        assert!(memory.grow(1).is_ok());
+        assert_eq!(memory.size(), 2);
+        assert!(memory.grow(1).is_err());
+
+        // table
+        let result = Table::new(TableType::new(RefType::FuncRef, 2, Some(4)));
+        assert!(result.is_ok());
+        let mut table = result.unwrap();
+        assert_eq!(table.size(), 2);
+        assert!(table.grow(2).is_ok());
+        assert_eq!(table.size(), 4);
+        let result = Func::wrap(|a: i32| a * 2);
+        assert!(result.is_ok());
+        let mut double = result.unwrap();
+        assert!(table
+            .set(3, WasmValue::from_func_ref(&mut double.inner))
+            .is_ok());
+        let result = table.get(3);
+        assert!(result.is_ok());
+        assert!(!result.unwrap().is_null_ref());
+        assert!(table.get(4).is_err());
+
+        // global
+        let ty = GlobalType::new(ValType::I64, Mutability::Var);
+        let result = Global::new(ty, WasmValue::from_i64(1));
+        assert!(result.is_ok());
+        let mut global = result.unwrap();
+        assert_eq!(global.ty().unwrap(), ty);
+        assert!(global.set(WasmValue::from_i64(2)).is_ok());
+        assert_eq!(global.get().to_i64(), 2);
+        assert!(global.set(WasmValue::from_i32(3)).is_err());
+
+        // register the instances, and find them in the store
+        let result = ImportObject::new("host");
+        assert!(result.is_ok());
+        let mut import = result.unwrap();
+        assert_eq!(import.name(), "host");
+        import.add_memory("mem", memory);
+        import.add_table("tab", table);
+        import.add_global("glob", global);
+        import.add_func("double", double);
+
+        let mut executor = Executor::new(None, None).unwrap();
+        let mut store = Store::new().unwrap();
+        assert!(executor.register_import_object(&mut store, &import).is_ok());
+        let result = store.module("host");
+        assert!(result.is_ok());
+        let instance = result.unwrap();
+        assert_eq!(instance.memory_names(), ["mem"]);
+        assert_eq!(instance.table_names(), ["tab"]);
+        assert_eq!(instance.global_names(), ["glob"]);
+        let result = instance.memory("mem");
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap().read_string(10, 5).unwrap(), "hello");
+        let result = instance.global("glob");
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap().get().to_i64(), 2);
+        assert_eq!(import.exit_code(), 1);
+    }
+
+    #[test]
+    fn test_import_obj_wasi() {
+        let result = WasiCtxBuilder::new()
+            .args(["wasi.wasm", "a", "b"])
+            .env("K", "V")
+            .build();
+        assert!(result.is_ok());
+        let ctx = result.unwrap();
+        let result = ImportObject::wasi(&ctx);
+        assert!(result.is_ok());
+        let mut wasi = result.unwrap();
+        assert_eq!(wasi.name(), "wasi_snapshot_preview1");
+
+        let path = std::path::PathBuf::from(env!("WASMEDGE_DIR"))
+            .join("bindings/rust/wasmedge-sdk/tests/data/wasi.wasm");
+        let result = Loader::new(None).and_then(|loader| loader.from_file(path));
+        assert!(result.is_ok());
+        let module = result.unwrap();
+        let result = Validator::new(None).and_then(|validator| validator.validate(&module));
+        assert!(result.is_ok());
+
+        let mut executor = Executor::new(None, None).unwrap();
+        let mut store = Store::new().unwrap();
+        assert!(executor.register_import_object(&mut store, &wasi).is_ok());
+        assert!(executor.register_active_module(&mut store, &module).is_ok());
+
+        // the exit code is the number of the arguments plus 10 times the number of the environment variables
+        let result = executor.run_func(&mut store, "_start", []);
+        assert!(result.is_ok());
+        assert_eq!(wasi.exit_code(), 13);
+
+        // re-initialize the WASI import object
+        let result = WasiCtxBuilder::new().arg("wasi.wasm").build();
+        assert!(result.is_ok());
+        assert!(wasi.init_wasi(&result.unwrap()).is_ok());
+        let result = executor.run_func(&mut store, "_start", []);
+        assert!(result.is_ok());
+        assert_eq!(wasi.exit_code(), 1);
+    }
+
+    #[test]
+    fn test_import_obj_wasi_stdio() {
+        let path = std::path::PathBuf::from(env!("WASMEDGE_DIR"))
+            .join("bindings/rust/wasmedge-sdk/tests/data/wasi.wasm");
+        let result = Loader::new(None).and_then(|loader| loader.from_file(path));
+        assert!(result.is_ok());
+        let module = result.unwrap();
+        let result = Validator::new(None).and_then(|validator| validator.validate(&module));
+        assert!(result.is_ok());
+
+        // the logs of the tenants are collected separately
+        for tenant in ["tenant-1", "tenant-2"] {
+            let result = WasiCtxBuilder::new().build();
+            assert!(result.is_ok());
+            let result = ImportObject::wasi(&result.unwrap());
+            assert!(result.is_ok());
+            let mut wasi = result.unwrap();
+
+            let stdout = OutputBuffer::new();
+            let stderr = OutputBuffer::new();
+            let result = wasi.redirect_stdio(
+                WasiStdio::new()
+                    .stdin(tenant.as_bytes())
+                    .stdout(stdout.clone())
+                    .stderr(stderr.clone()),
+            );
+            assert!(result.is_ok());
+
+            let mut executor = Executor::new(None, None).unwrap();
+            let mut store = Store::new().unwrap();
+            assert!(executor.register_import_object(&mut store, &wasi).is_ok());
+            assert!(executor.register_active_module(&mut store, &module).is_ok());
+
+            // the guest copies the standard input to the standard output and the standard error
+            let result = executor.run_func(&mut store, "echo", []);
+            assert!(result.is_ok());
+            assert_eq!(result.unwrap()[0].to_i32(), 0);
+
+            assert!(wasi.restore_stdio().is_ok());
+            assert_eq!(stdout.contents(), tenant.as_bytes());
+            assert_eq!(stderr.take(), tenant.as_bytes());
+            assert!(stderr.contents().is_empty());
+
+            // nothing to restore
+            assert!(wasi.restore_stdio().is_ok());
+        }
+
+        // the standard streams are restored when the import object is dropped
+        let result = WasiCtxBuilder::new().build();
+        assert!(result.is_ok());
+        let result = ImportObject::wasi(&result.unwrap());
+        assert!(result.is_ok());
+        let mut wasi = result.unwrap();
+        let stdout = OutputBuffer::new();
+        let result = wasi.redirect_stdio(
+            WasiStdio::new()
+                .stdin(&b"dropped"[..])
+                .stdout(stdout.clone())
+                .stderr(std::io::sink()),
+        );
+        assert!(result.is_ok());
+        let mut executor = Executor::new(None, None).unwrap();
+        let mut store = Store::new().unwrap();
+        assert!(executor.register_import_object(&mut store, &wasi).is_ok());
+        assert!(executor.register_active_module(&mut store, &module).is_ok());
+        let result = executor.run_func(&mut store, "echo", []);
+        assert!(result.is_ok());
+        drop(store);
+        drop(executor);
+        drop(wasi);
+        assert_eq!(stdout.contents(), b"dropped");
+
+        // not a WASI import object
+        let result = ImportObject::new("extern");
+        assert!(result.is_ok());
+        let mut import = result.unwrap();
+        let result = import.redirect_stdio(WasiStdio::new().stdout(OutputBuffer::new()));
+        assert!(matches!(result, Err(WasiError::Redirect(_))));
+    }
+}
@@ -0,0 +1,113 @@
+//! Defines the Instance struct, which represents a module instance in a store.
+
+use crate::{wasmedge, Func, Global, Memory, Table, WasmEdgeResult};
+use std::{
+    marker::PhantomData,
+    ops::{Deref, DerefMut},
+};
+
+/// Represents a module instance in a [Store](crate::Store), which gives the access to its exported
+/// [functions](crate::Func), [memories](crate::Memory), [tables](crate::Table) and [globals](crate::Global).
+#[derive(Debug)]
+pub struct Instance<'store> {
+    pub(crate) inner: wasmedge::Instance<'store>,
+}
+impl<'store> Instance<'store> {
+    /// Returns the name of the module instance, or `None` if it is the active anonymous module instance.
+    pub fn name(&self) -> Option<String> {
+        self.inner.name()
+    }
+
+    /// Returns the exported [function](crate::Func) of the given name.
+    ///
+    /// # Error
+    ///
+    /// If the function is not found, then an error is returned.
+    pub fn func(&self, name: impl AsRef<str>) -> WasmEdgeResult<InstanceRef<'store, Func>> {
+        Ok(InstanceRef::new(Func {
+            inner: self.inner.find_func(name)?,
+        }))
+    }
+
+    /// Returns the exported [memory](crate::Memory) of the given name.
+    ///
+    /// # Error
+    ///
+    /// If the memory is not found, then an error is returned.
+    pub fn memory(&self, name: impl AsRef<str>) -> WasmEdgeResult<InstanceRef<'store, Memory>> {
+        Ok(InstanceRef::new(Memory {
+            inner: self.inner.find_memory(name)?,
+        }))
+    }
+
+    /// Returns the exported [table](crate::Table) of the given name.
+    ///
+    /// # Error
+    ///
+    /// If the table is not found, then an error is returned.
+    pub fn table(&self, name: impl AsRef<str>) -> WasmEdgeResult<InstanceRef<'store, Table>> {
+        Ok(InstanceRef::new(Table {
+            inner: self.inner.find_table(name)?,
+        }))
+    }
+
+    /// Returns the exported [global](crate::Global) of the given name.
+    ///
+    /// # Error
+    ///
+    /// If the global is not found, then an error is returned.
+    pub fn global(&self, name: impl AsRef<str>) -> WasmEdgeResult<InstanceRef<'store, Global>> {
+        Ok(InstanceRef::new(Global {
+            inner: self.inner.find_global(name)?,
+        }))
+    }
+
+    /// Returns the names of the exported functions.
+    pub fn func_names(&self) -> Vec<String> {
+        self.inner.func_names().unwrap_or_default()
+    }
+
+    /// Returns the names of the exported memories.
+    pub fn memory_names(&self) -> Vec<String> {
+        self.inner.mem_names().unwrap_or_default()
+    }
+
+    /// Returns the names of the exported tables.
+    pub fn table_names(&self) -> Vec<String> {
+        self.inner.table_names().unwrap_or_default()
+    }
+
+    /// Returns the names of the exported globals.
+    pub fn global_names(&self) -> Vec<String> {
+        self.inner.global_names().unwrap_or_default()
+    }
+}
+
+/// A [function](crate::Func), [memory](crate::Memory), [table](crate::Table) or [global](crate::Global) borrowed
+/// from a module [Instance]. It dereferences to the wrapped item, and can not outlive the [Store](crate::Store) owning
+/// the module instance.
+#[derive(Debug)]
+pub struct InstanceRef<'store, T> {
+    item: T,
+    _marker: PhantomData<&'store ()>,
+}
+impl<'store, T> InstanceRef<'store, T> {
+    fn new(item: T) -> Self {
+        Self {
+            item,
+            _marker: PhantomData,
+        }
+    }
+}
+impl<'store, T> Deref for InstanceRef<'store, T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        &self.item
+    }
+}
+impl<'store, T> DerefMut for InstanceRef<'store, T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.item
+    }
+}
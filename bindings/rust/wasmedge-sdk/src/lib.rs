@@ -1,13 +1,53 @@
+//! # Overview
+//! The wasmedge-sdk crate defines a group of high-level Rust APIs on top of [wasmedge-sys](wasmedge_sys), which own
+//! the underlying WasmEdge contexts and release them when dropped.
+//!
+//! - A [Config] sets up the proposals and the host registrations enabled.
+//! - A [Loader] loads a wasm binary into a [Module], and a [Validator] validates it.
+//! - An [Executor] registers [ImportObject]s and [Module]s into a [Store], and runs the exported functions.
+//! - The module [Instance]s in a [Store] give the access to the exported [Func]s, [Memory]s, [Table]s and
+//!   [Global]s.
+//! - A [Vm] puts all of them together.
+
 #![deny(rust_2018_idioms, unreachable_pub)]
 
 pub use wasmedge_sys as wasmedge;
 
 pub mod config;
 pub mod error;
+pub mod executor;
+pub mod func;
+pub mod global;
+pub mod import_obj;
+pub mod instance;
+pub mod loader;
+pub mod memory;
 pub mod module;
+pub mod statistics;
+pub mod store;
+pub mod table;
+pub mod types;
+pub mod validator;
 pub mod vm;
-pub mod wasi_conf;
+pub mod wasi;
 
 pub use config::Config;
+pub use executor::Executor;
+pub use func::Func;
+pub use global::Global;
+pub use import_obj::ImportObject;
+pub use instance::{Instance, InstanceRef};
+pub use loader::Loader;
+pub use memory::Memory;
 pub use module::Module;
+pub use statistics::Statistics;
+pub use store::Store;
+pub use table::Table;
+pub use types::{FuncType, GlobalType, MemoryType, TableType};
+pub use validator::Validator;
 pub use vm::Vm;
+pub use wasi::{OutputBuffer, WasiCtx, WasiCtxBuilder, WasiStdio};
+pub use wasmedge::{
+    error::{Trap, WasmEdgeError},
+    Caller, Mutability, RefType, ValType, WasmEdgeResult, WasmValue,
+};
@@ -0,0 +1,50 @@
+//! Defines the Loader struct, which loads wasm binaries into modules.
+
+use crate::{wasmedge, Config, Module, WasmEdgeResult};
+use std::path::Path;
+
+/// Loads a wasm binary from a file or a buffer into a [Module].
+#[derive(Debug)]
+pub struct Loader {
+    inner: wasmedge::Loader,
+}
+impl Loader {
+    /// Creates a new [Loader].
+    ///
+    /// # Argument
+    ///
+    /// - `config` specifies the configuration which determines the proposals enabled. If `None`, the default
+    ///   configuration is used.
+    ///
+    /// # Error
+    ///
+    /// If fail to create a [Loader], then an error is returned.
+    pub fn new(config: Option<&Config>) -> WasmEdgeResult<Self> {
+        let config = config.map(Config::copy_inner).transpose()?;
+        Ok(Self {
+            inner: wasmedge::Loader::create(config)?,
+        })
+    }
+
+    /// Loads a wasm binary from the given file into a [Module].
+    ///
+    /// # Error
+    ///
+    /// If fail to load the file, then an error is returned.
+    pub fn from_file(&self, file: impl AsRef<Path>) -> WasmEdgeResult<Module> {
+        Ok(Module {
+            inner: self.inner.from_file(file)?,
+        })
+    }
+
+    /// Loads a wasm binary from the given buffer into a [Module].
+    ///
+    /// # Error
+    ///
+    /// If fail to load the buffer, then an error is returned.
+    pub fn from_buffer(&self, buffer: impl AsRef<[u8]>) -> WasmEdgeResult<Module> {
+        Ok(Module {
+            inner: self.inner.from_buffer(buffer)?,
+        })
+    }
+}
@@ -0,0 +1,82 @@
+//! Defines the Memory struct, which represents a linear memory.
+
+use crate::{wasmedge, MemoryType, WasmEdgeResult};
+use std::convert::TryFrom;
+
+/// Represents a linear memory, which is either created on the host or exported by a module
+/// [Instance](crate::Instance).
+#[derive(Debug)]
+pub struct Memory {
+    pub(crate) inner: wasmedge::Memory,
+}
+impl Memory {
+    /// Creates a new [Memory] of the given type.
+    ///
+    /// # Error
+    ///
+    /// If fail to create the [Memory], then an error is returned.
+    pub fn new(ty: MemoryType) -> WasmEdgeResult<Self> {
+        Ok(Self {
+            inner: wasmedge::Memory::create(&ty.to_raw()?)?,
+        })
+    }
+
+    /// Returns the type of the [Memory].
+    ///
+    /// # Error
+    ///
+    /// If fail to get the memory type, or the [Memory] is a 64-bit one, then an error is returned.
+    pub fn ty(&self) -> WasmEdgeResult<MemoryType> {
+        MemoryType::try_from(self.inner.ty()?)
+    }
+
+    /// Returns the size of the [Memory] in pages.
+    pub fn size(&self) -> u64 {
+        self.inner.size()
+    }
+
+    /// Grows the [Memory] by the given number of pages.
+    ///
+    /// # Error
+    ///
+    /// If fail to grow the [Memory], then an error is returned.
+    pub fn grow(&mut self, count: u64) -> WasmEdgeResult<()> {
+        self.inner.grow(count)
+    }
+
+    /// Reads the data of the given length from the given offset.
+    ///
+    /// # Error
+    ///
+    /// If the range is out of bounds, then an error is returned.
+    pub fn read(&self, offset: u64, len: u32) -> WasmEdgeResult<Vec<u8>> {
+        self.inner.get_data(offset, len)
+    }
+
+    /// Writes the given data at the given offset.
+    ///
+    /// # Error
+    ///
+    /// If the range is out of bounds, then an error is returned.
+    pub fn write(&mut self, data: impl IntoIterator<Item = u8>, offset: u64) -> WasmEdgeResult<()> {
+        self.inner.set_data(data, offset)
+    }
+
+    /// Reads a UTF-8 string of the given length from the given offset.
+    ///
+    /// # Error
+    ///
+    /// If the range is out of bounds, or the data is not valid UTF-8, then an error is returned.
+    pub fn read_string(&self, offset: u64, len: u32) -> WasmEdgeResult<String> {
+        self.inner.read_string(offset, len)
+    }
+
+    /// Writes the given string at the given offset.
+    ///
+    /// # Error
+    ///
+    /// If the range is out of bounds, then an error is returned.
+    pub fn write_string(&mut self, s: impl AsRef<str>, offset: u64) -> WasmEdgeResult<()> {
+        self.inner.write_string(s, offset)
+    }
+}
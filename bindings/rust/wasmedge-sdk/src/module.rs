@@ -2,19 +2,51 @@ use super::wasmedge;
 
 use std::path::Path;
 
-use crate::{error::ModuleError, Config};
+use crate::{error::ModuleError, Config, Loader};
 use anyhow::Result;
 
 #[derive(Debug)]
 pub struct Module {
-    pub inner: wasmedge::Module,
+    pub(crate) inner: wasmedge::Module,
 }
 
 impl Module {
     pub fn new(config: &Config, module_path: &Path) -> Result<Self, anyhow::Error> {
-        let module = wasmedge::Module::load_from_file(&config.inner, module_path)
+        let module = Loader::new(Some(config))
+            .and_then(|loader| loader.from_file(module_path))
             .map_err(ModuleError::Load)?;
 
-        Ok(Self { inner: module })
+        Ok(module)
+    }
+
+    pub fn from_buffer(config: &Config, buffer: impl AsRef<[u8]>) -> Result<Self, anyhow::Error> {
+        let module = Loader::new(Some(config))
+            .and_then(|loader| loader.from_buffer(buffer))
+            .map_err(ModuleError::Load)?;
+
+        Ok(module)
+    }
+
+    /// Returns the names of the imports, each of which is paired with the name of the module it is imported from.
+    pub fn imports(&self) -> Vec<(String, String)> {
+        self.inner
+            .imports()
+            .iter()
+            .map(|import| {
+                (
+                    import.module_name().into_owned(),
+                    import.name().into_owned(),
+                )
+            })
+            .collect()
+    }
+
+    /// Returns the names of the exports.
+    pub fn exports(&self) -> Vec<String> {
+        self.inner
+            .exports()
+            .iter()
+            .map(|export| export.name().into_owned())
+            .collect()
     }
 }
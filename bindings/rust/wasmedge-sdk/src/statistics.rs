@@ -0,0 +1,54 @@
+//! Defines the Statistics struct, which collects the instruction counts and the costs in execution.
+
+use crate::{wasmedge, WasmEdgeResult};
+
+/// Collects the instruction counts and the costs of the executions by an [Executor](crate::Executor).
+#[derive(Debug)]
+pub struct Statistics {
+    pub(crate) inner: wasmedge::Statistics,
+}
+impl Statistics {
+    /// Creates a new [Statistics].
+    ///
+    /// # Error
+    ///
+    /// If fail to create a [Statistics], then an error is returned.
+    pub fn new() -> WasmEdgeResult<Self> {
+        Ok(Self {
+            inner: wasmedge::Statistics::create()?,
+        })
+    }
+
+    /// Returns the instruction count in execution.
+    pub fn instr_count(&self) -> u64 {
+        self.inner.instr_count()
+    }
+
+    /// Returns the instruction count per second in execution, which is `NaN` if nothing has been executed.
+    pub fn instr_per_sec(&self) -> f64 {
+        self.inner.instr_per_sec()
+    }
+
+    /// Returns the total cost in execution.
+    pub fn cost_in_total(&self) -> u64 {
+        self.inner.cost_in_total()
+    }
+
+    /// Sets the cost of instructions.
+    ///
+    /// # Argument
+    ///
+    /// - `cost_table` specifies the costs of the instructions, indexed by their opcodes.
+    pub fn set_cost_table(&mut self, cost_table: impl AsRef<[u64]>) {
+        self.inner.set_cost_table(cost_table)
+    }
+
+    /// Sets the cost limit in execution.
+    ///
+    /// # Argument
+    ///
+    /// - `limit` specifies the cost limit.
+    pub fn set_cost_limit(&mut self, limit: u64) {
+        self.inner.set_cost_limit(limit)
+    }
+}
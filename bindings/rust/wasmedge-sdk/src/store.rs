@@ -0,0 +1,48 @@
+//! Defines the Store struct, which holds the module instances.
+
+use crate::{wasmedge, Instance, WasmEdgeResult};
+
+/// Holds the module [instances](crate::Instance) registered or instantiated by an [Executor](crate::Executor).
+#[derive(Debug)]
+pub struct Store {
+    pub(crate) inner: wasmedge::Store,
+}
+impl Store {
+    /// Creates a new [Store].
+    ///
+    /// # Error
+    ///
+    /// If fail to create the [Store], then an error is returned.
+    pub fn new() -> WasmEdgeResult<Self> {
+        Ok(Self {
+            inner: wasmedge::Store::create()?,
+        })
+    }
+
+    /// Returns the active anonymous module [instance](crate::Instance).
+    ///
+    /// # Error
+    ///
+    /// If no module is instantiated as the active module, then an error is returned.
+    pub fn active_module(&self) -> WasmEdgeResult<Instance<'_>> {
+        Ok(Instance {
+            inner: self.inner.active_module()?,
+        })
+    }
+
+    /// Returns the registered module [instance](crate::Instance) of the given name.
+    ///
+    /// # Error
+    ///
+    /// If the module is not found, then an error is returned.
+    pub fn module(&self, name: impl AsRef<str>) -> WasmEdgeResult<Instance<'_>> {
+        Ok(Instance {
+            inner: self.inner.named_module(name)?,
+        })
+    }
+
+    /// Returns the names of the registered modules.
+    pub fn module_names(&self) -> Vec<String> {
+        self.inner.reg_module_names().unwrap_or_default()
+    }
+}
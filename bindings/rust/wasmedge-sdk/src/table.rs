@@ -0,0 +1,63 @@
+//! Defines the Table struct, which represents a table of references.
+
+use crate::{wasmedge, TableType, WasmEdgeResult, WasmValue};
+
+/// Represents a table of references, which is either created on the host or exported by a module
+/// [Instance](crate::Instance).
+#[derive(Debug)]
+pub struct Table {
+    pub(crate) inner: wasmedge::Table,
+}
+impl Table {
+    /// Creates a new [Table] of the given type.
+    ///
+    /// # Error
+    ///
+    /// If fail to create the [Table], then an error is returned.
+    pub fn new(ty: TableType) -> WasmEdgeResult<Self> {
+        Ok(Self {
+            inner: wasmedge::Table::create(&ty.to_raw()?)?,
+        })
+    }
+
+    /// Returns the type of the [Table].
+    ///
+    /// # Error
+    ///
+    /// If fail to get the table type, then an error is returned.
+    pub fn ty(&self) -> WasmEdgeResult<TableType> {
+        self.inner.ty().map(Into::into)
+    }
+
+    /// Returns the number of the elements of the [Table].
+    pub fn size(&self) -> usize {
+        self.inner.capacity()
+    }
+
+    /// Grows the [Table] by the given number of elements.
+    ///
+    /// # Error
+    ///
+    /// If fail to grow the [Table], then an error is returned.
+    pub fn grow(&mut self, delta: u32) -> WasmEdgeResult<()> {
+        self.inner.grow(delta)
+    }
+
+    /// Returns the element at the given index.
+    ///
+    /// # Error
+    ///
+    /// If the index is out of bounds, then an error is returned.
+    pub fn get(&self, idx: u32) -> WasmEdgeResult<WasmValue> {
+        self.inner.get_data(idx)
+    }
+
+    /// Sets the element at the given index.
+    ///
+    /// # Error
+    ///
+    /// If the index is out of bounds, or the value does not match the element type, then an error is returned.
+    pub fn set(&mut self, idx: u32, value: WasmValue) -> WasmEdgeResult<()> {
+        self.inner.set_data(value, idx)
+    }
+}
@@ -0,0 +1,204 @@
+//! Defines the types of the functions, memories, tables and globals.
+//!
+//! Unlike their counterparts in wasmedge-sys, these types are plain data, so they can be freely cloned, compared and
+//! built without touching the runtime.
+
+use crate::{
+    wasmedge::{self, error::MemError},
+    Mutability, RefType, ValType, WasmEdgeError, WasmEdgeResult,
+};
+use std::convert::TryFrom;
+
+/// Defines the type of a [function](crate::Func) by its argument types and return types.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct FuncType {
+    args: Vec<ValType>,
+    returns: Vec<ValType>,
+}
+impl FuncType {
+    /// Creates a new [FuncType] with the given argument types and return types.
+    pub fn new(
+        args: impl IntoIterator<Item = ValType>,
+        returns: impl IntoIterator<Item = ValType>,
+    ) -> Self {
+        Self {
+            args: args.into_iter().collect(),
+            returns: returns.into_iter().collect(),
+        }
+    }
+
+    /// Returns the argument types.
+    pub fn args(&self) -> &[ValType] {
+        &self.args
+    }
+
+    /// Returns the return types.
+    pub fn returns(&self) -> &[ValType] {
+        &self.returns
+    }
+
+    pub(crate) fn to_raw(&self) -> WasmEdgeResult<wasmedge::FuncType> {
+        wasmedge::FuncType::create(self.args.iter().copied(), self.returns.iter().copied())
+    }
+}
+impl From<wasmedge::FuncType> for FuncType {
+    fn from(ty: wasmedge::FuncType) -> Self {
+        Self::new(ty.params_type_iter(), ty.returns_type_iter())
+    }
+}
+
+/// Defines the type of a [memory](crate::Memory) by the limits of its size in pages.
+///
+/// A maximum equal to the minimum is treated as no maximum by WasmEdge.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MemoryType {
+    min: u32,
+    max: Option<u32>,
+}
+impl MemoryType {
+    /// Creates a new [MemoryType] with the given minimum and maximum sizes in pages.
+    pub fn new(min: u32, max: Option<u32>) -> Self {
+        Self { min, max }
+    }
+
+    /// Returns the minimum size in pages.
+    pub fn min(&self) -> u32 {
+        self.min
+    }
+
+    /// Returns the maximum size in pages, if any.
+    pub fn max(&self) -> Option<u32> {
+        self.max
+    }
+
+    pub(crate) fn to_raw(self) -> WasmEdgeResult<wasmedge::MemType> {
+        wasmedge::MemType::create(self.min..=self.max.unwrap_or(self.min))
+    }
+}
+impl TryFrom<wasmedge::MemType> for MemoryType {
+    type Error = WasmEdgeError;
+
+    fn try_from(ty: wasmedge::MemType) -> Result<Self, Self::Error> {
+        let (min, max) = ty.limit().into_inner();
+        match (ty.is_64(), u32::try_from(min), u32::try_from(max)) {
+            (false, Ok(min), Ok(max)) => Ok(Self::new(min, (max != min).then_some(max))),
+            _ => Err(WasmEdgeError::Mem(MemError::Not32Bit)),
+        }
+    }
+}
+
+/// Defines the type of a [table](crate::Table) by its element type and the limits of its size.
+///
+/// A maximum equal to the minimum is treated as no maximum by WasmEdge.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TableType {
+    elem_ty: RefType,
+    min: u32,
+    max: Option<u32>,
+}
+impl TableType {
+    /// Creates a new [TableType] with the given element type, and minimum and maximum sizes.
+    pub fn new(elem_ty: RefType, min: u32, max: Option<u32>) -> Self {
+        Self { elem_ty, min, max }
+    }
+
+    /// Returns the element type.
+    pub fn elem_ty(&self) -> RefType {
+        self.elem_ty
+    }
+
+    /// Returns the minimum size.
+    pub fn min(&self) -> u32 {
+        self.min
+    }
+
+    /// Returns the maximum size, if any.
+    pub fn max(&self) -> Option<u32> {
+        self.max
+    }
+
+    pub(crate) fn to_raw(self) -> WasmEdgeResult<wasmedge::TableType> {
+        wasmedge::TableType::create(self.elem_ty, self.min..=self.max.unwrap_or(self.min))
+    }
+}
+impl From<wasmedge::TableType> for TableType {
+    fn from(ty: wasmedge::TableType) -> Self {
+        let (min, max) = ty.limit().into_inner();
+        Self::new(ty.elem_ty(), min, (max != min).then_some(max))
+    }
+}
+
+/// Defines the type of a [global](crate::Global) by its value type and mutability.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GlobalType {
+    ty: ValType,
+    mutability: Mutability,
+}
+impl GlobalType {
+    /// Creates a new [GlobalType] with the given value type and mutability.
+    pub fn new(ty: ValType, mutability: Mutability) -> Self {
+        Self { ty, mutability }
+    }
+
+    /// Returns the value type.
+    pub fn value_ty(&self) -> ValType {
+        self.ty
+    }
+
+    /// Returns the mutability.
+    pub fn mutability(&self) -> Mutability {
+        self.mutability
+    }
+
+    pub(crate) fn to_raw(self) -> WasmEdgeResult<wasmedge::GlobalType> {
+        wasmedge::GlobalType::create(self.ty, self.mutability)
+    }
+}
+impl From<wasmedge::GlobalType> for GlobalType {
+    fn from(ty: wasmedge::GlobalType) -> Self {
+        Self::new(ty.value_type(), ty.mutability())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_types_round_trip() {
+        let ty = FuncType::new([ValType::I32, ValType::I64], [ValType::F32]);
+        let result = ty.to_raw();
+        assert!(result.is_ok());
+        assert_eq!(FuncType::from(result.unwrap()), ty);
+
+        let ty = MemoryType::new(1, Some(10));
+        let result = ty.to_raw();
+        assert!(result.is_ok());
+        let result = MemoryType::try_from(result.unwrap());
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap(), ty);
+        let ty = MemoryType::new(1, None);
+        let result = ty.to_raw();
+        assert!(result.is_ok());
+        let result = MemoryType::try_from(result.unwrap());
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap(), ty);
+
+        // the 64-bit memory types are not truncated
+        let result = wasmedge::MemType::create_64(1..=u32::MAX as u64 + 1);
+        assert!(result.is_ok());
+        let result = MemoryType::try_from(result.unwrap());
+        assert!(result.is_err());
+        assert_eq!(result.unwrap_err(), WasmEdgeError::Mem(MemError::Not32Bit));
+
+        let ty = TableType::new(RefType::FuncRef, 2, Some(4));
+        let result = ty.to_raw();
+        assert!(result.is_ok());
+        assert_eq!(TableType::from(result.unwrap()), ty);
+
+        let ty = GlobalType::new(ValType::F64, Mutability::Var);
+        let result = ty.to_raw();
+        assert!(result.is_ok());
+        assert_eq!(GlobalType::from(result.unwrap()), ty);
+    }
+}
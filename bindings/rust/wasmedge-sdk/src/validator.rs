@@ -0,0 +1,36 @@
+//! Defines the Validator struct, which validates loaded modules.
+
+use crate::{wasmedge, Config, Module, WasmEdgeResult};
+
+/// Validates a [Module] before it is instantiated.
+#[derive(Debug)]
+pub struct Validator {
+    inner: wasmedge::Validator,
+}
+impl Validator {
+    /// Creates a new [Validator].
+    ///
+    /// # Argument
+    ///
+    /// - `config` specifies the configuration which determines the proposals enabled. If `None`, the default
+    ///   configuration is used.
+    ///
+    /// # Error
+    ///
+    /// If fail to create a [Validator], then an error is returned.
+    pub fn new(config: Option<&Config>) -> WasmEdgeResult<Self> {
+        let config = config.map(Config::copy_inner).transpose()?;
+        Ok(Self {
+            inner: wasmedge::Validator::create(config)?,
+        })
+    }
+
+    /// Validates the given [Module].
+    ///
+    /// # Error
+    ///
+    /// If the [Module] is invalid, then an error is returned.
+    pub fn validate(&self, module: &Module) -> WasmEdgeResult<()> {
+        self.inner.validate(&module.inner)
+    }
+}
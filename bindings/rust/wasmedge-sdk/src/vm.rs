@@ -1,6 +1,14 @@
 use super::wasmedge;
 
-use crate::{config::Config, error::VmError, module::Module, wasi_conf::WasiConf};
+use crate::{
+    config::Config,
+    error::{VmError, WasiError},
+    import_obj::ImportObject,
+    module::Module,
+    types::FuncType,
+    wasi::{StdioRedirect, WasiCtx, WasiStdio},
+    WasmEdgeError,
+};
 
 /// # Example
 ///
@@ -9,89 +17,467 @@ use crate::{config::Config, error::VmError, module::Module, wasi_conf::WasiConf}
 ///     let module_path = std::path::PathBuf::from(env!("WASMEDGE_SRC_DIR"))
 ///         .join("tools/wasmedge/examples/fibonacci.wasm");
 ///
-///     let config = wasmedge_sdk::Config::default();
+///     let config = wasmedge_sdk::Config::create()?;
 ///     let module = wasmedge_sdk::Module::new(&config, &module_path)?;
 ///
-///     let vm = wasmedge_sdk::Vm::load(&module)?.with_config(&config)?.create()?;
+///     let mut vm = wasmedge_sdk::Vm::load(&module)?.with_config(&config)?.create()?;
 ///
-///     let results = vm.run("fib", &[5.into()])?;
-///
-///     assert_eq!(results.len(), 1);
-///     let result = results[0].as_i32().unwrap();
-///
-///     assert_eq!(result, 8);
+///     // a Vm can run functions as many times as needed
+///     for (n, fib) in [(5, 8), (10, 89)] {
+///         let results = vm.run("fib", &[wasmedge_sdk::WasmValue::from_i32(n)])?;
+///         assert_eq!(results.len(), 1);
+///         assert_eq!(results[0].to_i32(), fib);
+///     }
 /// ```
 ///
 #[derive(Debug)]
-pub struct Vm<'a> {
-    config: Option<&'a wasmedge::Config>,
-    module: &'a mut Module,
-    pub(crate) inner: Option<wasmedge::Vm>,
+pub struct Vm {
+    pub(crate) inner: wasmedge::Vm,
+    // dropped after `inner`, which closes the redirected standard streams, so that the writer threads can be joined
+    wasi_stdio: Option<StdioRedirect>,
+    _import_stdio: Vec<StdioRedirect>,
 }
 
-impl<'a> Vm<'a> {
-    pub fn load(module: &'a mut Module) -> Result<VmBuilder<'a>, anyhow::Error> {
+impl Vm {
+    pub fn load(module: &Module) -> Result<VmBuilder<'_>, anyhow::Error> {
         VmBuilder::new(module)
     }
 
-    pub fn init_wasi_obj(&self) -> WasiConf<'_> {
-        WasiConf::new(self)
+    /// Initializes the WASI module of the [Vm] with the given [WasiCtx]. The WASI host registration must be
+    /// enabled in the config of the [Vm].
+    ///
+    /// The standard streams redirected by [Vm::redirect_wasi_stdio] are restored.
+    pub fn init_wasi(&mut self, ctx: &WasiCtx) -> Result<(), anyhow::Error> {
+        let mut wasi = self
+            .inner
+            .wasi_import_module_mut()
+            .map_err(WasiError::NotFound)?;
+        let (args, envs, preopens) = ctx.to_raw();
+        wasi.init_wasi(Some(args), Some(envs), Some(preopens))
+            .map_err(WasiError::Init)?;
+        if let Some(stdio) = self.wasi_stdio.take() {
+            stdio.finish()?;
+        }
+        Ok(())
+    }
+
+    /// Redirects the standard streams of the WASI guest with the given [WasiStdio]. The previous redirection is
+    /// restored first. The WASI host registration must be enabled in the config of the [Vm].
+    pub fn redirect_wasi_stdio(&mut self, stdio: WasiStdio) -> Result<(), anyhow::Error> {
+        self.restore_wasi_stdio()?;
+        let mut wasi = self
+            .inner
+            .wasi_import_module_mut()
+            .map_err(WasiError::NotFound)?;
+        self.wasi_stdio = Some(StdioRedirect::new(&mut wasi, stdio)?);
+        Ok(())
     }
 
+    /// Restores the standard streams of the host process, and waits until all the output of the WASI guest is
+    /// written into the sinks of the [WasiStdio]. Nothing happens if the standard streams are not redirected.
+    pub fn restore_wasi_stdio(&mut self) -> Result<(), anyhow::Error> {
+        if let Some(stdio) = self.wasi_stdio.take() {
+            let mut wasi = self
+                .inner
+                .wasi_import_module_mut()
+                .map_err(WasiError::NotFound)?;
+            wasi.redirect_wasi_stdio(None, None, None)
+                .map_err(WasiError::Redirect)?;
+            stdio.finish()?;
+        }
+        Ok(())
+    }
+
+    /// Returns the exit code of the WASI guest, which is available after its `_start` function runs.
+    pub fn wasi_exit_code(&mut self) -> Result<u32, anyhow::Error> {
+        let wasi = self
+            .inner
+            .wasi_import_module_mut()
+            .map_err(WasiError::NotFound)?;
+        Ok(wasi.exit_code())
+    }
+
+    /// Runs an exported function of the loaded module, and returns the results.
     pub fn run(
-        mut self,
+        &mut self,
         func_name: &str,
-        params: &[wasmedge::Value],
-    ) -> Result<Vec<wasmedge::Value>, anyhow::Error> {
-        match self.inner {
-            Some(ref mut vm) => {
-                let returns = vm.run(func_name, params).map_err(VmError::Execute)?;
-                Ok(returns)
-            }
-            None => panic!("WasmEdge Vm can't run!"),
-        }
+        params: &[wasmedge::WasmValue],
+    ) -> Result<Vec<wasmedge::WasmValue>, anyhow::Error> {
+        let returns = self
+            .inner
+            .run_function(func_name, params.iter().copied())
+            .map_err(|err| match is_func_not_found(&err) {
+                true => VmError::MissingFunction(func_name.to_string()),
+                false => VmError::Execute(err),
+            })?;
+        Ok(returns)
+    }
+
+    /// Runs an exported function of the module registered with the given name by
+    /// [VmBuilder::with_module] or [VmBuilder::with_import_object], and returns the results.
+    pub fn run_registered(
+        &mut self,
+        mod_name: &str,
+        func_name: &str,
+        params: &[wasmedge::WasmValue],
+    ) -> Result<Vec<wasmedge::WasmValue>, anyhow::Error> {
+        let returns = self
+            .inner
+            .run_registered_function(mod_name, func_name, params.iter().copied())
+            .map_err(|err| match is_func_not_found(&err) {
+                true => {
+                    VmError::MissingRegisteredFunction(mod_name.to_string(), func_name.to_string())
+                }
+                false => VmError::Execute(err),
+            })?;
+        Ok(returns)
+    }
+
+    /// Runs an exported function asynchronously. The returned [AsyncResult](wasmedge::AsyncResult) implements
+    /// [Future](std::future::Future), so it can be `.await`ed without blocking the calling thread.
+    pub fn run_async(
+        &self,
+        func_name: &str,
+        params: impl IntoIterator<Item = wasmedge::WasmValue>,
+    ) -> Result<wasmedge::AsyncResult<'_>, anyhow::Error> {
+        let async_result = self
+            .inner
+            .run_function_async(func_name, params)
+            .map_err(VmError::Execute)?;
+        Ok(async_result)
+    }
+
+    /// Returns the names and the types of the exported functions of the loaded module.
+    pub fn functions(&self) -> Vec<(String, FuncType)> {
+        self.inner
+            .function_iter()
+            .filter_map(|(name, ty)| Some((name?, ty?.into())))
+            .collect()
+    }
+
+    /// Returns the type of the exported function of the loaded module with the given name.
+    pub fn func_type(&self, func_name: &str) -> Result<FuncType, anyhow::Error> {
+        let ty = self
+            .inner
+            .get_function_type(func_name)
+            .map_err(|_| VmError::MissingFunction(func_name.to_string()))?;
+        Ok(ty.into())
+    }
+}
+impl Drop for Vm {
+    fn drop(&mut self) {
+        // the writer threads end after the standard streams are restored
+        let _ = self.restore_wasi_stdio();
     }
 }
 
 #[derive(Debug)]
 pub struct VmBuilder<'a> {
-    pub inner: Vm<'a>,
+    config: Option<&'a Config>,
+    module: &'a Module,
+    modules: Vec<(String, Module)>,
+    import_objects: Vec<ImportObject>,
 }
 
 impl<'a> VmBuilder<'a> {
-    pub fn new(module: &'a mut Module) -> Result<Self, anyhow::Error> {
-        let vm = Vm {
+    pub fn new(module: &'a Module) -> Result<Self, anyhow::Error> {
+        Ok(Self {
             config: None,
             module,
-            inner: None,
-        };
-        Ok(Self { inner: vm })
+            modules: Vec::new(),
+            import_objects: Vec::new(),
+        })
     }
 
     pub fn with_config(self, config: &'a Config) -> Result<Self, anyhow::Error> {
-        let mut vm = self.inner;
-        vm.config = Some(&config.inner);
-        Ok(Self { inner: vm })
-    }
-
-    pub fn create(self) -> Result<Vm<'a>, anyhow::Error> {
-        let vm = self.inner;
-        if let Some(cfg) = vm.config {
-            let vm_instance =
-                wasmedge::Vm::create(Some(cfg), None).expect("fail to create VM instance");
-            let vm_instance = vm_instance
-                .load_wasm_from_ast_module(&mut vm.module.inner)
-                .map_err(VmError::ModuleLoad)?;
-            let vm_instance = vm_instance.validate().map_err(VmError::Validate)?;
-            let vm_instance = vm_instance.instantiate().map_err(VmError::Instantiate)?;
-
-            Ok(Vm {
-                config: vm.config,
-                module: vm.module,
-                inner: Some(vm_instance),
-            })
-        } else {
-            anyhow::bail!("no config provided to VM");
+        Ok(Self {
+            config: Some(config),
+            ..self
+        })
+    }
+
+    /// Registers the given module with the given name, so that the loaded module can import from it, and its
+    /// exported functions can be run by [Vm::run_registered].
+    pub fn with_module(mut self, mod_name: &str, module: Module) -> Result<Self, anyhow::Error> {
+        self.modules.push((mod_name.to_string(), module));
+        Ok(self)
+    }
+
+    /// Registers the given import object, so that the loaded module can import from it, and its host functions can
+    /// be run by [Vm::run_registered].
+    pub fn with_import_object(mut self, import: ImportObject) -> Result<Self, anyhow::Error> {
+        self.import_objects.push(import);
+        Ok(self)
+    }
+
+    /// Creates the [Vm], in which the registrations are done before the module is loaded, validated and
+    /// instantiated. If no config is given, the default one is used.
+    pub fn create(self) -> Result<Vm, anyhow::Error> {
+        let config = self
+            .config
+            .map(Config::copy_inner)
+            .transpose()
+            .map_err(VmError::Create)?;
+        // declared before `inner`, so that it is dropped after `inner` if fail to create the Vm
+        let mut import_stdio = Vec::new();
+        let mut inner = wasmedge::Vm::create(config, None).map_err(VmError::Create)?;
+
+        for import in self.import_objects {
+            let (import, stdio) = import.into_parts();
+            import_stdio.extend(stdio);
+            inner
+                .register_wasm_from_import(import)
+                .map_err(VmError::Register)?;
+        }
+        for (mod_name, module) in self.modules {
+            inner
+                .register_wasm_from_module(mod_name, module.inner)
+                .map_err(VmError::Register)?;
         }
+
+        inner
+            .load_wasm_from_module(&self.module.inner)
+            .map_err(VmError::ModuleLoad)?;
+        inner.validate().map_err(VmError::Validate)?;
+        inner.instantiate().map_err(VmError::Instantiate)?;
+
+        Ok(Vm {
+            inner,
+            wasi_stdio: None,
+            _import_stdio: import_stdio,
+        })
+    }
+}
+
+// the missing functions are reported when looking up their types before running
+fn is_func_not_found(err: &WasmEdgeError) -> bool {
+    use wasmedge::error::{CoreCommonError, CoreError};
+    matches!(
+        err,
+        WasmEdgeError::Vm(wasmedge::error::VmError::NotFoundFuncType(_))
+            | WasmEdgeError::Core(CoreError::Common(CoreCommonError::FuncNotFound))
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{wasi::OutputBuffer, Func, ValType, WasiCtxBuilder, WasmValue};
+
+    fn fibonacci(config: &Config) -> Module {
+        let path = std::path::PathBuf::from(env!("WASMEDGE_DIR"))
+            .join("bindings/rust/wasmedge-sys/tests/data/fibonacci.wasm");
+        let result = Module::new(config, &path);
+        assert!(result.is_ok());
+        result.unwrap()
+    }
+
+    #[test]
+    fn test_vm_run() {
+        let result = Config::create();
+        assert!(result.is_ok());
+        let config = result.unwrap();
+        let module = fibonacci(&config);
+
+        let result = Vm::load(&module).and_then(|builder| builder.with_config(&config));
+        assert!(result.is_ok());
+        let result = result.unwrap().create();
+        assert!(result.is_ok());
+        let mut vm = result.unwrap();
+
+        // run the function multiple times
+        for (n, fib) in [(5, 8), (10, 89)] {
+            let result = vm.run("fib", &[WasmValue::from_i32(n)]);
+            assert!(result.is_ok());
+            assert_eq!(result.unwrap()[0].to_i32(), fib);
+        }
+
+        // list the functions
+        let fib_ty = FuncType::new([ValType::I32], [ValType::I32]);
+        assert_eq!(vm.functions(), [("fib".to_string(), fib_ty.clone())]);
+        let result = vm.func_type("fib");
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap(), fib_ty);
+
+        // run a missing function
+        let result = vm.run("fib2", &[WasmValue::from_i32(5)]);
+        assert!(result.is_err());
+        assert!(matches!(
+            result.unwrap_err().downcast_ref::<VmError>(),
+            Some(VmError::MissingFunction(name)) if name == "fib2"
+        ));
+        let result = vm.func_type("fib2");
+        assert!(result.is_err());
+
+        // run with the mismatched arguments
+        let result = vm.run("fib", &[]);
+        assert!(matches!(
+            result.unwrap_err().downcast_ref::<VmError>(),
+            Some(VmError::Execute(_))
+        ));
+    }
+
+    #[test]
+    fn test_vm_run_registered() {
+        let result = Config::create();
+        assert!(result.is_ok());
+        let config = result.unwrap();
+        let module = fibonacci(&config);
+
+        let result = ImportObject::new("host");
+        assert!(result.is_ok());
+        let mut import = result.unwrap();
+        let result = Func::wrap(|a: i32, b: i32| a + b);
+        assert!(result.is_ok());
+        import.add_func("add", result.unwrap());
+
+        let result = Vm::load(&module)
+            .and_then(|builder| builder.with_module("math", fibonacci(&config)))
+            .and_then(|builder| builder.with_import_object(import))
+            .and_then(|builder| builder.create());
+        assert!(result.is_ok());
+        let mut vm = result.unwrap();
+
+        let result = vm.run_registered("math", "fib", &[WasmValue::from_i32(6)]);
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap()[0].to_i32(), 13);
+        let result = vm.run_registered(
+            "host",
+            "add",
+            &[WasmValue::from_i32(1), WasmValue::from_i32(2)],
+        );
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap()[0].to_i32(), 3);
+
+        // the loaded module still runs after the registrations
+        let result = vm.run("fib", &[WasmValue::from_i32(7)]);
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap()[0].to_i32(), 21);
+
+        let result = vm.run_registered("math", "add", &[]);
+        assert!(matches!(
+            result.unwrap_err().downcast_ref::<VmError>(),
+            Some(VmError::MissingRegisteredFunction(mod_name, func_name))
+                if mod_name == "math" && func_name == "add"
+        ));
+
+        // the registered names must be unique
+        let result = Vm::load(&module)
+            .and_then(|builder| builder.with_module("math", fibonacci(&config)))
+            .and_then(|builder| builder.with_module("math", fibonacci(&config)))
+            .and_then(|builder| builder.create());
+        assert!(matches!(
+            result.unwrap_err().downcast_ref::<VmError>(),
+            Some(VmError::Register(_))
+        ));
+    }
+
+    #[test]
+    fn test_vm_wasi() {
+        let result = Config::builder().wasi(true).build();
+        assert!(result.is_ok());
+        let config = result.unwrap();
+        let path = std::path::PathBuf::from(env!("WASMEDGE_DIR"))
+            .join("bindings/rust/wasmedge-sdk/tests/data/wasi.wasm");
+        let result = Module::new(&config, &path);
+        assert!(result.is_ok());
+        let module = result.unwrap();
+        let result = Vm::load(&module)
+            .and_then(|builder| builder.with_config(&config))
+            .and_then(|builder| builder.create());
+        assert!(result.is_ok());
+        let mut vm = result.unwrap();
+
+        let dir = std::env::temp_dir().join("wasmedge_sdk_test_vm_wasi");
+        assert!(std::fs::create_dir_all(&dir).is_ok());
+
+        // the exit code is the number of the arguments plus 10 times the number of the environment variables
+        let result = WasiCtxBuilder::new()
+            .args(["wasi.wasm", "a"])
+            .env("K1", "V1")
+            .env("K2", "V2")
+            .preopen_dir(&dir, "/")
+            .build();
+        assert!(result.is_ok());
+        assert!(vm.init_wasi(&result.unwrap()).is_ok());
+        let result = vm.run("_start", &[]);
+        assert!(result.is_ok());
+        let result = vm.wasi_exit_code();
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap(), 22);
+
+        // the guest can create a directory in the preopened directory
+        let result = vm.run("mkdir", &[]);
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap()[0].to_i32(), 0);
+
+        // but not in the read-only one
+        let result = WasiCtxBuilder::new()
+            .preopen_dir_readonly(&dir, "/")
+            .build();
+        assert!(result.is_ok());
+        assert!(vm.init_wasi(&result.unwrap()).is_ok());
+        let result = vm.run("mkdir", &[]);
+        assert!(result.is_ok());
+        // __WASI_ERRNO_NOTCAPABLE
+        assert_eq!(result.unwrap()[0].to_i32(), 76);
+        assert!(!dir.join("wasmedge_sdk_test").exists());
+
+        // redirect the standard streams, with more data than the capacity of the pipes
+        let input: Vec<u8> = (0..200_000u32).map(|i| (i % 251) as u8).collect();
+        let stdout = OutputBuffer::new();
+        let result = vm.redirect_wasi_stdio(
+            WasiStdio::new()
+                .stdin(std::io::Cursor::new(input.clone()))
+                .stdout(stdout.clone())
+                .stderr(std::io::sink()),
+        );
+        assert!(result.is_ok());
+        let result = vm.run("echo", &[]);
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap()[0].to_i32(), 0);
+        assert!(vm.restore_wasi_stdio().is_ok());
+        assert_eq!(stdout.take(), input);
+
+        // the redirection is restored by the re-initialization
+        let result = vm.redirect_wasi_stdio(
+            WasiStdio::new()
+                .stdin(&b"wasi"[..])
+                .stdout(stdout.clone())
+                .stderr(stdout.clone()),
+        );
+        assert!(result.is_ok());
+        let result = vm.run("echo", &[]);
+        assert!(result.is_ok());
+        let result = WasiCtxBuilder::new().build();
+        assert!(result.is_ok());
+        assert!(vm.init_wasi(&result.unwrap()).is_ok());
+        assert_eq!(stdout.contents(), b"wasiwasi");
+
+        // the redirection is restored when the Vm is dropped
+        let result = vm.redirect_wasi_stdio(
+            WasiStdio::new()
+                .stdin(&b"drop"[..])
+                .stdout(stdout.clone())
+                .stderr(std::io::sink()),
+        );
+        assert!(result.is_ok());
+        let result = vm.run("echo", &[]);
+        assert!(result.is_ok());
+        drop(vm);
+        assert_eq!(stdout.contents(), b"wasiwasidrop");
+
+        // the WASI module is not found if the WASI host registration is disabled
+        let result = Vm::load(&fibonacci(&config)).and_then(|builder| builder.create());
+        assert!(result.is_ok());
+        let mut vm = result.unwrap();
+        let result = WasiCtxBuilder::new().build();
+        assert!(result.is_ok());
+        let result = vm.init_wasi(&result.unwrap());
+        assert!(matches!(
+            result.unwrap_err().downcast_ref::<WasiError>(),
+            Some(WasiError::NotFound(_))
+        ));
+        assert!(vm.wasi_exit_code().is_err());
     }
 }
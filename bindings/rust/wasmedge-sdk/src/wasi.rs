@@ -0,0 +1,490 @@
+//! Defines the WasiCtxBuilder struct, which builds the WASI context of the [Vm](crate::Vm) and the WASI
+//! [ImportObject](crate::ImportObject), and the WasiStdio struct, which redirects the standard streams of the WASI
+//! guest.
+
+use crate::{error::WasiError, wasmedge};
+use std::{
+    ffi::OsString,
+    fmt,
+    io::{self, Read, Write},
+    path::{Path, PathBuf},
+    sync::{Arc, Mutex},
+    thread::{self, JoinHandle},
+    time::{Duration, Instant},
+};
+
+// the max duration to wait for the output of the guest after the standard streams are restored. The writer threads
+// do not end while the guest end of a pipe is still open, for example, duplicated by the guest.
+const OUTPUT_TIMEOUT: Duration = Duration::from_secs(1);
+
+// the interval of checking whether the writer threads have ended
+const OUTPUT_POLL_INTERVAL: Duration = Duration::from_millis(10);
+
+/// Builds a [WasiCtx] from the commandline arguments, the environment variables and the preopened directories of
+/// the WASI guest.
+///
+/// The inputs are checked when the [WasiCtx] is built.
+///
+/// # Example
+///
+/// ```
+/// use wasmedge_sdk::wasi::WasiCtxBuilder;
+///
+/// # fn main() -> Result<(), wasmedge_sdk::error::WasiError> {
+/// let ctx = WasiCtxBuilder::new()
+///     .args(["app.wasm", "--verbose"])
+///     .env("LOG_LEVEL", "debug")
+///     .preopen_dir_readonly(std::env::temp_dir(), "/tmp")
+///     .build()?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct WasiCtxBuilder {
+    args: Vec<OsString>,
+    envs: Vec<(String, String)>,
+    preopens: Vec<(PathBuf, String, bool)>,
+}
+impl WasiCtxBuilder {
+    /// Creates a new [WasiCtxBuilder] without any argument, environment variable or preopened directory.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Appends a commandline argument. The first argument is the program name.
+    pub fn arg(mut self, arg: impl Into<String>) -> Self {
+        self.args.push(arg.into().into());
+        self
+    }
+
+    /// Appends the given commandline arguments. The first argument is the program name.
+    pub fn args(mut self, args: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.args
+            .extend(args.into_iter().map(|arg| arg.into().into()));
+        self
+    }
+
+    /// Appends the commandline arguments of the host process, including its program name. The arguments which are
+    /// not valid UTF-8 fail [WasiCtxBuilder::build].
+    pub fn inherit_args(mut self) -> Self {
+        self.args.extend(std::env::args_os());
+        self
+    }
+
+    /// Sets the environment variable `key` to `value`.
+    pub fn env(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.envs.push((key.into(), value.into()));
+        self
+    }
+
+    /// Sets the given environment variables.
+    pub fn envs(
+        mut self,
+        envs: impl IntoIterator<Item = (impl Into<String>, impl Into<String>)>,
+    ) -> Self {
+        self.envs.extend(
+            envs.into_iter()
+                .map(|(key, value)| (key.into(), value.into())),
+        );
+        self
+    }
+
+    /// Sets the environment variables of the host process. The variables of which the keys or the values are not
+    /// valid UTF-8 are skipped.
+    pub fn inherit_env(mut self) -> Self {
+        self.envs.extend(
+            std::env::vars_os().filter_map(|(key, value)| {
+                Some((key.into_string().ok()?, value.into_string().ok()?))
+            }),
+        );
+        self
+    }
+
+    /// Preopens the directory `host_path` on the host as `guest_path` in the guest with the read and write
+    /// permissions.
+    pub fn preopen_dir(
+        mut self,
+        host_path: impl AsRef<Path>,
+        guest_path: impl Into<String>,
+    ) -> Self {
+        self.preopens
+            .push((host_path.as_ref().to_path_buf(), guest_path.into(), false));
+        self
+    }
+
+    /// Preopens the directory `host_path` on the host as `guest_path` in the guest with the read permission only.
+    pub fn preopen_dir_readonly(
+        mut self,
+        host_path: impl AsRef<Path>,
+        guest_path: impl Into<String>,
+    ) -> Self {
+        self.preopens
+            .push((host_path.as_ref().to_path_buf(), guest_path.into(), true));
+        self
+    }
+
+    /// Checks the inputs, and builds a [WasiCtx] from them.
+    ///
+    /// # Error
+    ///
+    /// If an argument is not valid UTF-8 or an argument or an environment variable is malformed, or a preopened directory is not an existing
+    /// directory on the host, then an error is returned.
+    pub fn build(&self) -> Result<WasiCtx, WasiError> {
+        let mut args = Vec::with_capacity(self.args.len());
+        for arg in self.args.iter() {
+            match arg.to_str() {
+                Some(arg) if !arg.contains('\0') => args.push(arg.to_string()),
+                _ => return Err(WasiError::InvalidArg(arg.to_string_lossy().into_owned())),
+            }
+        }
+
+        let mut envs = Vec::with_capacity(self.envs.len());
+        for (key, value) in self.envs.iter() {
+            if key.is_empty() || key.contains(['=', '\0']) || value.contains('\0') {
+                return Err(WasiError::InvalidEnv(key.clone()));
+            }
+            envs.push(format!("{}={}", key, value));
+        }
+
+        let mut preopens = Vec::with_capacity(self.preopens.len());
+        for (host_path, guest_path, readonly) in self.preopens.iter() {
+            // WasmEdge takes the preopened directories in the format `GUEST:HOST[:readonly]`
+            if guest_path.contains([':', '\0']) {
+                return Err(WasiError::InvalidPreopen(
+                    guest_path.clone(),
+                    "the guest path contains ':' or a NUL character",
+                ));
+            }
+            let host = match host_path.to_str() {
+                Some(host) if !host.contains('\0') => host,
+                _ => {
+                    return Err(WasiError::InvalidPreopen(
+                        host_path.display().to_string(),
+                        "the host path is not valid UTF-8 or contains a NUL character",
+                    ))
+                }
+            };
+            if !host_path.is_dir() {
+                return Err(WasiError::InvalidPreopen(
+                    host.to_string(),
+                    "the host path is not a directory",
+                ));
+            }
+            let mut preopen = format!("{}:{}", guest_path, host);
+            if *readonly {
+                preopen.push_str(":readonly");
+            }
+            preopens.push(preopen);
+        }
+
+        Ok(WasiCtx {
+            args,
+            envs,
+            preopens,
+        })
+    }
+}
+
+/// The checked WASI context built by [WasiCtxBuilder], which initializes the WASI module of a [Vm](crate::Vm) by
+/// [Vm::init_wasi](crate::Vm::init_wasi), or a WASI [ImportObject](crate::ImportObject).
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct WasiCtx {
+    args: Vec<String>,
+    envs: Vec<String>,
+    preopens: Vec<String>,
+}
+impl WasiCtx {
+    /// Returns the commandline arguments.
+    pub fn args(&self) -> &[String] {
+        &self.args
+    }
+
+    /// Returns the environment variables in the format `KEY=VALUE`.
+    pub fn envs(&self) -> &[String] {
+        &self.envs
+    }
+
+    pub(crate) fn to_raw(&self) -> (Vec<&str>, Vec<&str>, Vec<&str>) {
+        fn as_strs(strs: &[String]) -> Vec<&str> {
+            strs.iter().map(String::as_str).collect()
+        }
+        (
+            as_strs(&self.args),
+            as_strs(&self.envs),
+            as_strs(&self.preopens),
+        )
+    }
+}
+
+/// Redirects the standard input, output and error of a WASI guest, so that the guests running in the same host
+/// process are fed and logged separately.
+///
+/// The standard streams not set are the ones of the host process. The data is transferred through the pipes by the
+/// background threads, and all the output of the guest is written into the sinks after the standard streams are
+/// restored by [ImportObject::restore_stdio](crate::ImportObject::restore_stdio) or
+/// [Vm::restore_wasi_stdio](crate::Vm::restore_wasi_stdio). The standard streams are also restored when the
+/// [ImportObject](crate::ImportObject) or the [Vm](crate::Vm) is dropped. The output still pending 1 second after the
+/// standard streams are restored is discarded.
+///
+/// The redirection is only supported on Unix.
+///
+/// # Example
+///
+/// ```
+/// use wasmedge_sdk::wasi::{OutputBuffer, WasiStdio};
+///
+/// let stdout = OutputBuffer::new();
+/// let stdio = WasiStdio::new()
+///     .stdin(&b"input of the guest"[..])
+///     .stdout(stdout.clone())
+///     .stderr(std::io::sink());
+/// ```
+#[derive(Default)]
+pub struct WasiStdio {
+    stdin: Option<Box<dyn Read + Send>>,
+    stdout: Option<Box<dyn Write + Send>>,
+    stderr: Option<Box<dyn Write + Send>>,
+}
+impl WasiStdio {
+    /// Creates a new [WasiStdio], which keeps all the standard streams of the host process.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Feeds the standard input of the guest from the given reader until EOF.
+    pub fn stdin(mut self, reader: impl Read + Send + 'static) -> Self {
+        self.stdin = Some(Box::new(reader));
+        self
+    }
+
+    /// Writes the standard output of the guest into the given writer.
+    pub fn stdout(mut self, writer: impl Write + Send + 'static) -> Self {
+        self.stdout = Some(Box::new(writer));
+        self
+    }
+
+    /// Writes the standard error of the guest into the given writer.
+    pub fn stderr(mut self, writer: impl Write + Send + 'static) -> Self {
+        self.stderr = Some(Box::new(writer));
+        self
+    }
+}
+impl fmt::Debug for WasiStdio {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("WasiStdio")
+            .field("stdin", &self.stdin.is_some())
+            .field("stdout", &self.stdout.is_some())
+            .field("stderr", &self.stderr.is_some())
+            .finish()
+    }
+}
+
+/// An in-memory sink, which captures the output of a WASI guest. The clones of an [OutputBuffer] share the same
+/// contents.
+#[derive(Debug, Clone, Default)]
+pub struct OutputBuffer(Arc<Mutex<Vec<u8>>>);
+impl OutputBuffer {
+    /// Creates a new empty [OutputBuffer].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns a copy of the captured bytes.
+    pub fn contents(&self) -> Vec<u8> {
+        self.lock().clone()
+    }
+
+    /// Takes the captured bytes, and leaves the [OutputBuffer] empty.
+    pub fn take(&self) -> Vec<u8> {
+        std::mem::take(&mut *self.lock())
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, Vec<u8>> {
+        // the bytes are still valid even if a writer panicked
+        self.0
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+impl Write for OutputBuffer {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.lock().extend_from_slice(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+/// The background threads transferring the redirected standard streams of a WASI host module. The writer threads
+/// are joined when the [StdioRedirect] is dropped.
+#[derive(Debug)]
+pub(crate) struct StdioRedirect {
+    outputs: Vec<JoinHandle<io::Result<()>>>,
+}
+impl StdioRedirect {
+    /// Redirects the standard streams of the given WASI host module to the pipes, of which the other ends are
+    /// transferred by the background threads.
+    #[cfg(unix)]
+    pub(crate) fn new(
+        import: &mut wasmedge::ImportObject,
+        stdio: WasiStdio,
+    ) -> Result<Self, WasiError> {
+        use std::os::unix::io::{AsRawFd, OwnedFd};
+
+        // if fail to redirect, the guest ends are closed before the writer threads are joined by the drop of
+        // `redirect`, since the locals are dropped in the reverse order
+        let mut redirect = Self {
+            outputs: Vec::new(),
+        };
+        // the guest ends are duplicated by the WASI host module, and closed after the redirection
+        let mut guest_ends: Vec<OwnedFd> = Vec::new();
+        let mut fds = [None; 3];
+
+        if let Some(mut reader) = stdio.stdin {
+            let (guest, mut host) = io::pipe().map_err(WasiError::Stdio)?;
+            fds[0] = Some(guest.as_raw_fd());
+            guest_ends.push(guest.into());
+            // not joined, since the reader may block. The thread exits once the guest end is closed.
+            thread::spawn(move || io::copy(&mut reader, &mut host));
+        }
+        for (fd, writer) in fds[1..].iter_mut().zip([stdio.stdout, stdio.stderr]) {
+            if let Some(mut writer) = writer {
+                let (mut host, guest) = io::pipe().map_err(WasiError::Stdio)?;
+                *fd = Some(guest.as_raw_fd());
+                guest_ends.push(guest.into());
+                redirect.outputs.push(thread::spawn(move || {
+                    io::copy(&mut host, &mut writer)?;
+                    writer.flush()
+                }));
+            }
+        }
+
+        import
+            .redirect_wasi_stdio(fds[0], fds[1], fds[2])
+            .map_err(WasiError::Redirect)?;
+        Ok(redirect)
+    }
+
+    #[cfg(not(unix))]
+    pub(crate) fn new(
+        _import: &mut wasmedge::ImportObject,
+        _stdio: WasiStdio,
+    ) -> Result<Self, WasiError> {
+        Err(WasiError::Stdio(io::Error::new(
+            io::ErrorKind::Unsupported,
+            "the redirection of the standard streams is only supported on Unix",
+        )))
+    }
+
+    /// Waits until all the output is written into the sinks. The redirected standard streams of the WASI host
+    /// module must have been restored or reset, otherwise the output is discarded after [OUTPUT_TIMEOUT].
+    pub(crate) fn finish(mut self) -> Result<(), WasiError> {
+        self.join()
+    }
+
+    fn join(&mut self) -> Result<(), WasiError> {
+        let deadline = Instant::now() + OUTPUT_TIMEOUT;
+        let mut result = Ok(());
+        'outputs: for output in std::mem::take(&mut self.outputs) {
+            // a thread can not be joined with a timeout, so that it is polled until it ends
+            while !output.is_finished() {
+                if Instant::now() >= deadline {
+                    // the thread is detached, and exits once the guest end is closed
+                    if result.is_ok() {
+                        result = Err(WasiError::Stdio(io::Error::new(
+                            io::ErrorKind::TimedOut,
+                            "the guest end of the standard stream is still open",
+                        )));
+                    }
+                    continue 'outputs;
+                }
+                thread::sleep(OUTPUT_POLL_INTERVAL);
+            }
+            let output = output.join().unwrap_or_else(|_| {
+                Err(io::Error::other(
+                    "the writer of the standard stream panicked",
+                ))
+            });
+            if let (Ok(()), Err(e)) = (&result, output) {
+                result = Err(WasiError::Stdio(e));
+            }
+        }
+        result
+    }
+}
+impl Drop for StdioRedirect {
+    fn drop(&mut self) {
+        let _ = self.join();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_wasi_ctx_builder() {
+        let dir = std::env::temp_dir();
+        let result = WasiCtxBuilder::new()
+            .arg("app.wasm")
+            .args(["a", "b"])
+            .env("K1", "V1")
+            .envs([("K2", "V=2"), ("K3", "")])
+            .preopen_dir(&dir, "/tmp")
+            .preopen_dir_readonly(&dir, "/data")
+            .build();
+        assert!(result.is_ok());
+        let ctx = result.unwrap();
+        assert_eq!(ctx.args(), ["app.wasm", "a", "b"]);
+        assert_eq!(ctx.envs(), ["K1=V1", "K2=V=2", "K3="]);
+        let dir = dir.to_str().unwrap();
+        assert_eq!(
+            ctx.preopens,
+            [format!("/tmp:{}", dir), format!("/data:{}:readonly", dir)]
+        );
+
+        // inherit from the host process
+        std::env::set_var("WASMEDGE_SDK_TEST_WASI_ENV", "1");
+        let result = WasiCtxBuilder::new().inherit_args().inherit_env().build();
+        assert!(result.is_ok());
+        let ctx = result.unwrap();
+        assert_eq!(ctx.args().len(), std::env::args_os().count());
+        assert!(ctx
+            .envs()
+            .iter()
+            .any(|env| env == "WASMEDGE_SDK_TEST_WASI_ENV=1"));
+
+        // malformed inputs
+        let result = WasiCtxBuilder::new().arg("a\0b").build();
+        assert!(matches!(result, Err(WasiError::InvalidArg(_))));
+        #[cfg(unix)]
+        {
+            use std::os::unix::ffi::OsStringExt;
+
+            // the non-UTF-8 arguments are not replaced
+            let mut builder = WasiCtxBuilder::new();
+            builder.args.push(OsString::from_vec(vec![b'a', 0xff]));
+            let result = builder.build();
+            assert!(matches!(result, Err(WasiError::InvalidArg(_))));
+        }
+        let result = WasiCtxBuilder::new().env("K=1", "V").build();
+        assert!(matches!(result, Err(WasiError::InvalidEnv(key)) if key == "K=1"));
+        let result = WasiCtxBuilder::new().env("", "V").build();
+        assert!(matches!(result, Err(WasiError::InvalidEnv(_))));
+        let result = WasiCtxBuilder::new().env("K", "V\0").build();
+        assert!(matches!(result, Err(WasiError::InvalidEnv(_))));
+        let result = WasiCtxBuilder::new()
+            .preopen_dir(std::env::temp_dir(), "/a:b")
+            .build();
+        assert!(matches!(result, Err(WasiError::InvalidPreopen(path, _)) if path == "/a:b"));
+        let result = WasiCtxBuilder::new()
+            .preopen_dir(
+                std::env::temp_dir().join("wasmedge_sdk_no_such_dir"),
+                "/tmp",
+            )
+            .build();
+        assert!(matches!(result, Err(WasiError::InvalidPreopen(..))));
+    }
+}
@@ -0,0 +1,57 @@
+(module
+  (import "wasi_snapshot_preview1" "proc_exit" (func $proc_exit (param i32)))
+  (import "wasi_snapshot_preview1" "args_sizes_get"
+    (func $args_sizes_get (param i32 i32) (result i32)))
+  (import "wasi_snapshot_preview1" "environ_sizes_get"
+    (func $environ_sizes_get (param i32 i32) (result i32)))
+  (import "wasi_snapshot_preview1" "path_create_directory"
+    (func $path_create_directory (param i32 i32 i32) (result i32)))
+  (import "wasi_snapshot_preview1" "path_remove_directory"
+    (func $path_remove_directory (param i32 i32 i32) (result i32)))
+  (import "wasi_snapshot_preview1" "fd_read"
+    (func $fd_read (param i32 i32 i32 i32) (result i32)))
+  (import "wasi_snapshot_preview1" "fd_write"
+    (func $fd_write (param i32 i32 i32 i32) (result i32)))
+  (memory (export "memory") 1)
+  (data (i32.const 0) "wasmedge_sdk_test")
+
+  ;; exits with the number of the arguments plus 10 times the number of the environment variables
+  (func (export "_start")
+    (drop (call $args_sizes_get (i32.const 32) (i32.const 36)))
+    (drop (call $environ_sizes_get (i32.const 40) (i32.const 44)))
+    (call $proc_exit
+      (i32.add
+        (i32.load (i32.const 32))
+        (i32.mul (i32.load (i32.const 40)) (i32.const 10)))))
+
+  ;; creates and removes the "wasmedge_sdk_test" directory in the first preopened directory, and returns the errno
+  (func (export "mkdir") (result i32)
+    (local $errno i32)
+    (local.set $errno
+      (call $path_create_directory (i32.const 3) (i32.const 0) (i32.const 17)))
+    (if (i32.eqz (local.get $errno))
+      (then
+        (drop (call $path_remove_directory (i32.const 3) (i32.const 0) (i32.const 17)))))
+    (local.get $errno))
+
+  ;; copies the standard input to both the standard output and the standard error until EOF, and returns the errno
+  (func (export "echo") (result i32)
+    (local $errno i32)
+    (block $done
+      (loop $next
+        (i32.store (i32.const 64) (i32.const 128))
+        (i32.store (i32.const 68) (i32.const 1024))
+        (local.set $errno
+          (call $fd_read (i32.const 0) (i32.const 64) (i32.const 1) (i32.const 48)))
+        (br_if $done (local.get $errno))
+        (br_if $done (i32.eqz (i32.load (i32.const 48))))
+        (i32.store (i32.const 68) (i32.load (i32.const 48)))
+        (local.set $errno
+          (call $fd_write (i32.const 1) (i32.const 64) (i32.const 1) (i32.const 52)))
+        (br_if $done (local.get $errno))
+        (local.set $errno
+          (call $fd_write (i32.const 2) (i32.const 64) (i32.const 1) (i32.const 52)))
+        (br_if $done (local.get $errno))
+        (br $next)))
+    (local.get $errno))
+)
@@ -5,31 +5,33 @@
 //! the main function call `call_add`, which do nothing just passing the extern reference and the
 //! parameters of add function to the `real_add` function.
 //!
-//! The inputs and outputs of real host function are the `Vec<Value>`, which are the primitive type
-//! for WasmEdge, and the host function for registration should be the return value from the
-//! generics of `Function::create_bindings::<I, O>`, wherein the I and O are the `WasmFnIO` traits
-//! base on the inputs and outputs of the real host function.
+//! The inputs and outputs of real host function are the `Vec<WasmValue>`, which are the primitive
+//! type for WasmEdge, and the host function is registered by `Function::create` with a `FuncType`
+//! matching its inputs and outputs. The `real_add` function takes an extern reference, so it can
+//! not be created by `Function::wrap`, which supports the numeric types only.
 //!
 
-use wasmedge_sys::{Config, FuncType, Function, ImportObject, Loader, ValType, Vm, WasmValue};
+use wasmedge_sys::{
+    error::Trap, Caller, Config, FuncType, Function, ImportObject, Loader, ValType, Vm, WasmValue,
+};
 
-fn real_add(input: Vec<WasmValue>) -> Result<Vec<WasmValue>, u8> {
+fn real_add(_caller: Caller, input: Vec<WasmValue>) -> Result<Vec<WasmValue>, Trap> {
     println!("Rust: Entering Rust function real_add");
 
     if input.len() != 3 {
-        return Err(1);
+        return Err(Trap::new("Invalid number of arguments"));
     }
 
     let a = if input[1].ty() == ValType::I32 {
         input[1].to_i32()
     } else {
-        return Err(2);
+        return Err(Trap::new("Invalid type of arguments"));
     };
 
     let b = if input[2].ty() == ValType::I32 {
         input[2].to_i32()
     } else {
-        return Err(3);
+        return Err(Trap::new("Invalid type of arguments"));
     };
 
     let c = a + b;
@@ -0,0 +1,43 @@
+//! This example is a tool to pre-initialize a WASM module.
+//!
+//! The tool instantiates the given WASM module, runs the initialization function exported by the module, and writes
+//! out the initialized module, so that the initialization is done once at build time instead of at every startup.
+//!
+//! ```bash
+//! cargo run -p wasmedge-sys --example pre_init -- <input.wasm> <output.wasm> [init_func]
+//! ```
+//!
+//! The initialization function is `wizer.initialize` by default.
+
+use wasmedge_sys::{Config, Vm};
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let args = std::env::args().collect::<Vec<_>>();
+    if args.len() < 3 {
+        eprintln!("usage: {} <input.wasm> <output.wasm> [init_func]", args[0]);
+        std::process::exit(1);
+    }
+    let init_func = args
+        .get(3)
+        .map(String::as_str)
+        .unwrap_or("wizer.initialize");
+
+    // create a Vm context with the WASI module, which is imported by the most of the modules
+    let mut config = Config::create()?;
+    config.wasi(true);
+    let mut vm = Vm::create(Some(config), None)?;
+
+    // pre-initialize the module
+    let wasm = std::fs::read(&args[1])?;
+    let initialized = vm.pre_initialize(&wasm, init_func)?;
+    std::fs::write(&args[2], &initialized)?;
+    println!(
+        "pre-initialized {} ({} bytes) into {} ({} bytes)",
+        args[1],
+        wasm.len(),
+        args[2],
+        initialized.len()
+    );
+
+    Ok(())
+}
@@ -0,0 +1,293 @@
+//! Defines WasmEdge AsyncResult struct.
+
+use crate::{
+    error::{
+        check, host_func_trap, read_backtrace, CoreCommonError, CoreError, WasmBacktrace,
+        WasmEdgeError,
+    },
+    ffi, WasmEdgeResult, WasmValue,
+};
+use std::{
+    future::Future,
+    marker::PhantomData,
+    pin::Pin,
+    sync::{Arc, Mutex},
+    task::{Context, Poll, Waker},
+    thread::JoinHandle,
+    time::Duration,
+};
+
+/// Struct of WasmEdge AsyncResult.
+///
+/// An [AsyncResult] is the handle of an asynchronous execution started by one of the `*_async` methods of
+/// [Vm](crate::Vm). The execution runs on a background thread owned by WasmEdge; the handle is used to wait for
+/// it, cancel it, and collect the returns.
+///
+/// [AsyncResult] also implements [Future], so it can be `.await`ed in any async runtime. The first poll spawns a
+/// helper thread that waits for the execution to end and then wakes the task; no particular runtime is required.
+///
+/// The lifetime `'a` ties the handle to the [Vm](crate::Vm) (and, if any, the WASM binary buffer) used by the
+/// execution. Dropping an [AsyncResult] cancels the execution if it is still running, and blocks until it stops, so
+/// a pending future can be dropped (e.g., the losing branch of a `select!`) without waiting for the guest to end.
+#[derive(Debug)]
+pub struct AsyncResult<'a> {
+    pub(crate) inner: InnerAsyncResult,
+    waker: Arc<Mutex<Option<Waker>>>,
+    waiter: Option<JoinHandle<()>>,
+    _marker: PhantomData<&'a ()>,
+}
+impl<'a> AsyncResult<'a> {
+    pub(crate) fn new(ctx: *mut ffi::WasmEdge_Async) -> Self {
+        Self {
+            inner: InnerAsyncResult(ctx),
+            waker: Arc::new(Mutex::new(None)),
+            waiter: None,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Checks if the asynchronous execution has ended, without blocking.
+    pub fn is_finished(&self) -> bool {
+        unsafe { ffi::WasmEdge_AsyncWaitFor(self.inner.0, 0) }
+    }
+
+    /// Blocks the current thread until the asynchronous execution ends.
+    pub fn wait(&self) {
+        unsafe { ffi::WasmEdge_AsyncWait(self.inner.0) }
+    }
+
+    /// Blocks the current thread until the asynchronous execution ends or the given timeout elapses.
+    ///
+    /// # Argument
+    ///
+    /// - `timeout` specifies the maximum duration to wait. The duration is truncated to milliseconds.
+    ///
+    /// Returns `true` if the execution ended, or `false` if the timeout elapsed first.
+    pub fn wait_for(&self, timeout: Duration) -> bool {
+        let millis = u64::try_from(timeout.as_millis()).unwrap_or(u64::MAX);
+        unsafe { ffi::WasmEdge_AsyncWaitFor(self.inner.0, millis) }
+    }
+
+    /// Cancels the asynchronous execution.
+    ///
+    /// The execution stops at the next interruption check of the interpreter, and [get_returns](AsyncResult::get_returns)
+    /// then returns the [Interrupted](crate::error::CoreCommonError::Interrupted) error. The other executions of the
+    /// [Vm](crate::Vm) are not interrupted.
+    pub fn cancel(&self) {
+        unsafe { ffi::WasmEdge_AsyncCancel(self.inner.0) }
+    }
+
+    /// Returns the number of the returns of the asynchronous execution.
+    ///
+    /// This function blocks until the execution ends. It returns `0` if the execution failed or was canceled.
+    pub fn returns_len(&self) -> usize {
+        unsafe { ffi::WasmEdge_AsyncGetReturnsLength(self.inner.0) as usize }
+    }
+
+    /// Returns the result of the asynchronous execution.
+    ///
+    /// This function blocks until the execution ends.
+    ///
+    /// # Error
+    ///
+    /// If the execution failed or was canceled, then an error is returned.
+    pub fn get_returns(&self) -> WasmEdgeResult<Vec<WasmValue>> {
+        let returns_len = unsafe { ffi::WasmEdge_AsyncGetReturnsLength(self.inner.0) };
+        let mut returns = Vec::with_capacity(returns_len as usize);
+        unsafe {
+            check(ffi::WasmEdge_AsyncGet(
+                self.inner.0,
+                returns.as_mut_ptr(),
+                returns_len,
+            ))
+            .map_err(|err| {
+                host_func_trap(err, ffi::WasmEdge_AsyncGetHostFailureData(self.inner.0))
+            })?;
+            returns.set_len(returns_len as usize);
+        }
+
+        Ok(returns.into_iter().map(Into::into).collect::<Vec<_>>())
+    }
+
+    /// Returns the [backtrace](crate::error::WasmBacktrace) of the asynchronous execution, if it failed.
+    ///
+    /// This function blocks until the execution ends. `None` is returned if the execution succeeded, or failed before
+    /// running any WASM function.
+    pub fn backtrace(&self) -> Option<WasmBacktrace> {
+        let backtrace = read_backtrace(|frames, len| unsafe {
+            ffi::WasmEdge_AsyncGetBacktrace(self.inner.0, frames, len)
+        });
+        (!backtrace.frames().is_empty()).then_some(backtrace)
+    }
+
+    /// Returns the result of the asynchronous execution, canceling the execution if it does not end within the
+    /// given timeout.
+    ///
+    /// # Argument
+    ///
+    /// - `timeout` specifies the maximum duration the execution is allowed to run.
+    ///
+    /// # Error
+    ///
+    /// If the timeout elapsed, then [ExecutionTimeout](crate::error::WasmEdgeError::ExecutionTimeout) is returned. If
+    /// the execution failed, then an error is returned.
+    pub(crate) fn get_returns_with_timeout(
+        &self,
+        timeout: Duration,
+    ) -> WasmEdgeResult<Vec<WasmValue>> {
+        if self.wait_for(timeout) {
+            return self.get_returns();
+        }
+
+        self.cancel();
+        match self.get_returns() {
+            Err(WasmEdgeError::Core(CoreError::Common(CoreCommonError::Interrupted))) => {
+                Err(WasmEdgeError::ExecutionTimeout)
+            }
+            result => result,
+        }
+    }
+}
+impl<'a> Future for AsyncResult<'a> {
+    type Output = WasmEdgeResult<Vec<WasmValue>>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        // register the waker before checking the state, so that a wake-up is never lost
+        {
+            let mut waker = self.waker.lock().expect("[wasmedge-sys] try lock failed.");
+            match waker.as_ref() {
+                Some(w) if w.will_wake(cx.waker()) => {}
+                _ => *waker = Some(cx.waker().clone()),
+            }
+        }
+
+        if self.is_finished() {
+            return Poll::Ready(self.get_returns());
+        }
+
+        if self.waiter.is_none() {
+            let ctx = InnerAsyncResult(self.inner.0);
+            let waker = Arc::clone(&self.waker);
+            self.waiter = Some(std::thread::spawn(move || {
+                let ctx = ctx;
+                unsafe { ffi::WasmEdge_AsyncWait(ctx.0) };
+                if let Some(waker) = waker
+                    .lock()
+                    .expect("[wasmedge-sys] try lock failed.")
+                    .take()
+                {
+                    waker.wake();
+                }
+            }));
+        }
+
+        Poll::Pending
+    }
+}
+impl<'a> Drop for AsyncResult<'a> {
+    fn drop(&mut self) {
+        if !self.inner.0.is_null() {
+            // the result is no longer needed, and the background thread keeps using the Vm until the execution
+            // ends, so stop the execution before waiting for it
+            unsafe {
+                ffi::WasmEdge_AsyncCancel(self.inner.0);
+                ffi::WasmEdge_AsyncWait(self.inner.0);
+            }
+            if let Some(waiter) = self.waiter.take() {
+                waiter.join().ok();
+            }
+            unsafe { ffi::WasmEdge_AsyncDelete(self.inner.0) };
+        }
+    }
+}
+
+#[derive(Debug)]
+pub(crate) struct InnerAsyncResult(pub(crate) *mut ffi::WasmEdge_Async);
+unsafe impl Send for InnerAsyncResult {}
+unsafe impl Sync for InnerAsyncResult {}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        error::{CoreCommonError, CoreError, WasmEdgeError},
+        Vm, WasmValue,
+    };
+    use std::{
+        future::Future,
+        pin::Pin,
+        sync::Arc,
+        task::{Context, Poll, Wake},
+        thread::{self, Thread},
+    };
+
+    #[test]
+    fn test_async_result_future() {
+        let result = Vm::create(None, None);
+        assert!(result.is_ok());
+        let mut vm = result.unwrap();
+
+        let path = std::path::PathBuf::from(env!("WASMEDGE_DIR"))
+            .join("bindings/rust/wasmedge-sys/tests/data/fibonacci.wasm");
+        let result = vm.register_wasm_from_file("fib-module", path);
+        assert!(result.is_ok());
+
+        // await an asynchronous execution
+        let result =
+            vm.run_registered_function_async("fib-module", "fib", [WasmValue::from_i32(20)]);
+        assert!(result.is_ok());
+        let async_result = result.unwrap();
+        let result = block_on(async_result);
+        assert!(result.is_ok());
+        let returns = result.unwrap();
+        assert_eq!(returns[0].to_i32(), 10946);
+
+        // await a canceled execution
+        let result =
+            vm.run_registered_function_async("fib-module", "fib", [WasmValue::from_i32(100)]);
+        assert!(result.is_ok());
+        let async_result = result.unwrap();
+        assert!(!async_result.is_finished());
+        async_result.cancel();
+        let result = block_on(async_result);
+        assert!(result.is_err());
+        assert_eq!(
+            result.unwrap_err(),
+            WasmEdgeError::Core(CoreError::Common(CoreCommonError::Interrupted))
+        );
+
+        // drop a pending execution, which is canceled instead of running to the end
+        let result =
+            vm.run_registered_function_async("fib-module", "fib", [WasmValue::from_i32(100)]);
+        assert!(result.is_ok());
+        let mut async_result = result.unwrap();
+        let waker = Arc::new(ThreadWaker(thread::current())).into();
+        let mut cx = Context::from_waker(&waker);
+        assert!(Pin::new(&mut async_result).poll(&mut cx).is_pending());
+        drop(async_result);
+
+        // the canceled execution does not interrupt the next one
+        let result = vm.run_registered_function("fib-module", "fib", [WasmValue::from_i32(20)]);
+        assert!(result.is_ok());
+        let returns = result.unwrap();
+        assert_eq!(returns[0].to_i32(), 10946);
+    }
+
+    struct ThreadWaker(Thread);
+    impl Wake for ThreadWaker {
+        fn wake(self: Arc<Self>) {
+            self.0.unpark();
+        }
+    }
+
+    fn block_on<F: Future>(fut: F) -> F::Output {
+        let mut fut = Box::pin(fut);
+        let waker = Arc::new(ThreadWaker(thread::current())).into();
+        let mut cx = Context::from_waker(&waker);
+        loop {
+            match fut.as_mut().poll(&mut cx) {
+                Poll::Ready(output) => return output,
+                Poll::Pending => thread::park(),
+            }
+        }
+    }
+}
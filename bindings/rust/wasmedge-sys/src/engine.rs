@@ -0,0 +1,45 @@
+//! Defines the Engine trait for running WasmEdge Function instances.
+
+use crate::{Executor, Function, Store, Vm, WasmEdgeResult, WasmValue};
+
+/// Defines the engines which run [functions](crate::Function) directly, rather than by their names.
+///
+/// A [Vm] runs the functions in its own [store](crate::Store), while an [Executor] needs the [store](crate::Store)
+/// which the module instances are instantiated in, so it is paired with the store as an engine.
+pub trait Engine {
+    /// Runs the given [function](crate::Function), and returns the results.
+    ///
+    /// # Arguments
+    ///
+    /// - `func` specifies the function to run.
+    ///
+    /// - `params` specifies the argument values for the function.
+    ///
+    /// # Error
+    ///
+    /// If fail to run the function, then an error is returned.
+    fn run_func_ref(
+        self,
+        func: &Function,
+        params: impl IntoIterator<Item = WasmValue>,
+    ) -> WasmEdgeResult<Vec<WasmValue>>;
+}
+impl Engine for &Vm {
+    fn run_func_ref(
+        self,
+        func: &Function,
+        params: impl IntoIterator<Item = WasmValue>,
+    ) -> WasmEdgeResult<Vec<WasmValue>> {
+        Vm::run_func_ref(self, func, params)
+    }
+}
+impl Engine for (&mut Executor, &mut Store) {
+    fn run_func_ref(
+        self,
+        func: &Function,
+        params: impl IntoIterator<Item = WasmValue>,
+    ) -> WasmEdgeResult<Vec<WasmValue>> {
+        let (executor, store) = self;
+        executor.run_func_ref(store, func, params)
+    }
+}
@@ -1,9 +1,14 @@
 //! Defines the error types.
 
 use crate::{
-    ffi::{WasmEdge_Result, WasmEdge_ResultGetCode, WasmEdge_ResultOK},
+    ffi::{
+        WasmEdge_BacktraceFrame, WasmEdge_Result, WasmEdge_ResultGetCode, WasmEdge_ResultOK,
+        WasmEdge_String, WasmEdge_StringDelete,
+    },
     ExternalType, WasmEdgeResult,
 };
+use core::ffi::c_void;
+use std::{fmt, sync::Arc};
 use thiserror::Error;
 
 /// Defines the errors raised by the wasmedge-sys crate.
@@ -16,6 +21,8 @@ pub enum WasmEdgeError {
     // context
     #[error("Fail to create ImportObj module")]
     ImportObjCreate,
+    #[error("The ImportObj module is not a WASI one")]
+    ImportObjNotWasi,
     #[error("Fail to create Executor context")]
     ExecutorCreate,
     #[error("{0}")]
@@ -34,6 +41,12 @@ pub enum WasmEdgeError {
     ValidatorCreate,
     #[error("{0}")]
     Vm(VmError),
+    #[error("Fail to create AsyncResult context")]
+    AsyncResultCreate,
+    #[error("Execution interrupted: the timeout elapsed")]
+    ExecutionTimeout,
+    #[error("{0}")]
+    Trap(Trap),
 
     // instances
     #[error("{0}")]
@@ -58,6 +71,12 @@ pub enum WasmEdgeError {
     Export(ExportError),
     #[error("{0}")]
     Instance(InstanceError),
+    #[error("{0}")]
+    GuestAlloc(GuestAllocError),
+    #[error("{0}")]
+    Snapshot(SnapshotError),
+    #[error("{0}")]
+    PreInit(PreInitError),
 
     // std
     #[error("Found an interior nul byte")]
@@ -66,6 +85,8 @@ pub enum WasmEdgeError {
     NotFoundNulByte(#[from] std::ffi::FromBytesWithNulError),
     #[error("Fail to interpret a sequence of u8 as a string")]
     Utf8(#[from] std::str::Utf8Error),
+    #[error("Fail to interpret a sequence of u16 as a string")]
+    Utf16,
 }
 
 /// Defines the errors raised from [Function](crate::Function).
@@ -77,6 +98,8 @@ pub enum FuncError {
     CreateBinding(String),
     #[error("Fail to get the function type")]
     Type,
+    #[error("The signature of the function '{0}' does not match the expected types")]
+    SignatureMismatch(String),
 }
 
 /// Defines the errors raised from [Memory](crate::Memory).
@@ -92,6 +115,12 @@ pub enum MemError {
     MutPtr,
     #[error("Fail to convert a raw pointer to a reference")]
     Ptr2Ref,
+    #[error("The length of the values does not match the length of the slice: expected {expected}, found {found}")]
+    SliceLen { expected: u32, found: usize },
+    #[error("The memory type is not shared")]
+    NotShared,
+    #[error("The memory type is 64-bit or its limits do not fit in u32")]
+    Not32Bit,
 }
 
 /// Defines the errors raised from [Global](crate::Global).
@@ -198,6 +227,39 @@ pub enum StoreError {
     NotFoundActiveModule,
 }
 
+/// Defines the errors raised from [GuestAllocator](crate::GuestAllocator).
+#[derive(Error, Clone, Debug, PartialEq)]
+pub enum GuestAllocError {
+    #[error("The guest function `{0}` is not a supported allocation function")]
+    AllocSignature(String),
+    #[error("The guest function `{0}` is not a supported deallocation function")]
+    DeallocSignature(String),
+    #[error("Fail to allocate {0} bytes in the guest")]
+    Alloc(u32),
+}
+
+/// Defines the errors raised from [Snapshot](crate::Snapshot).
+#[derive(Error, Clone, Debug, PartialEq)]
+pub enum SnapshotError {
+    #[error("Fail to snapshot the mutable global of reference type (index: {0})")]
+    RefGlobal(u32),
+    #[error("The snapshot data is malformed")]
+    Malformed,
+    #[error("The snapshot does not match the module instance: {0}")]
+    Mismatch(String),
+}
+
+/// Defines the errors raised from [Vm::pre_initialize](crate::Vm::pre_initialize).
+#[derive(Error, Clone, Debug, PartialEq)]
+pub enum PreInitError {
+    #[error("Fail to parse the WASM binary to pre-initialize")]
+    Malformed,
+    #[error("The module importing memories can not be pre-initialized")]
+    ImportedMemory,
+    #[error("The initialization changing the table {0} can not be pre-initialized")]
+    TableChanged(u32),
+}
+
 /// Defines the errors raised from [Vm](crate::Vm).
 #[derive(Error, Clone, Debug, PartialEq)]
 pub enum VmError {
@@ -257,6 +319,8 @@ pub enum CoreCommonError {
     AOTDisabled,
     #[error("execution interrupted")]
     Interrupted,
+    #[error("wasm module hasn't passed validation yet")]
+    NotValidated,
 }
 
 /// Defines the errors raised in the load phase.
@@ -361,6 +425,10 @@ pub enum CoreValidationError {
     InvalidStartFunc,
     #[error("invalid lane index")]
     InvalidLaneIdx,
+    #[error("shared memory must have maximum")]
+    SharedMemoryNoMax,
+    #[error("offset out of range")]
+    InvalidMemOffset,
 }
 
 /// Defines the errors raised in the instantiation phase.
@@ -411,6 +479,219 @@ pub enum CoreExecutionError {
     ExecutionFailed,
     #[error("reference type mismatch")]
     RefTypeMismatch,
+    #[error("unaligned atomic")]
+    UnalignedAtomicAccess,
+    #[error("expected shared memory")]
+    ExpectSharedMemory,
+}
+
+/// Defines the error returned by a [host function](crate::Function) to abort the execution.
+///
+/// A [Trap] carries a message and, optionally, the error which caused it. When the host function fails an execution,
+/// including an asynchronous one, the [Trap] is returned to the caller as [WasmEdgeError::Trap].
+///
+/// Any error type implementing [std::error::Error] can be converted into a [Trap], so the `?` operator can be used
+/// in host functions.
+#[derive(Clone)]
+pub struct Trap {
+    message: String,
+    source: Option<Arc<dyn std::error::Error + Send + Sync>>,
+    terminate: bool,
+}
+impl Trap {
+    /// Creates a [Trap] with the given message.
+    pub fn new(message: impl Into<String>) -> Self {
+        Self {
+            message: message.into(),
+            source: None,
+            terminate: false,
+        }
+    }
+
+    /// Creates a [Trap] which terminates the execution without error, for example, to implement the `exit` of a
+    /// guest program. The caller of the execution receives a success result.
+    pub fn terminate() -> Self {
+        Self {
+            message: "terminated".to_string(),
+            source: None,
+            terminate: true,
+        }
+    }
+
+    /// Returns the message of the [Trap].
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    /// Returns the error which caused the [Trap], if any.
+    pub fn source(&self) -> Option<&(dyn std::error::Error + Send + Sync + 'static)> {
+        self.source.as_deref()
+    }
+
+    /// Returns a reference to the error which caused the [Trap] if it is of type `E`.
+    pub fn downcast_ref<E: std::error::Error + 'static>(&self) -> Option<&E> {
+        self.source.as_deref().and_then(|e| e.downcast_ref::<E>())
+    }
+
+    /// Returns `true` if the [Trap] terminates the execution without error.
+    pub fn is_terminate(&self) -> bool {
+        self.terminate
+    }
+}
+impl<E: std::error::Error + Send + Sync + 'static> From<E> for Trap {
+    fn from(error: E) -> Self {
+        Self {
+            message: error.to_string(),
+            source: Some(Arc::new(error)),
+            terminate: false,
+        }
+    }
+}
+impl fmt::Display for Trap {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
+impl fmt::Debug for Trap {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Trap")
+            .field("message", &self.message)
+            .field("source", &self.source)
+            .field("terminate", &self.terminate)
+            .finish()
+    }
+}
+impl PartialEq for Trap {
+    fn eq(&self, other: &Self) -> bool {
+        self.message == other.message && self.terminate == other.terminate
+    }
+}
+
+/// Defines the frames of the WASM call stack when an execution fails, from the innermost frame to the outermost one.
+///
+/// The backtrace is recorded by the interpreter only, and kept per execution, so the concurrent executions of the same
+/// [Executor](crate::Executor) or [Vm](crate::Vm) do not overwrite each other's backtrace. It is returned by
+/// [Executor::backtrace](crate::Executor::backtrace) and [Vm::backtrace](crate::Vm::backtrace) for the last execution
+/// run by the calling thread, and by [AsyncResult::backtrace](crate::AsyncResult::backtrace) for an asynchronous
+/// execution.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct WasmBacktrace {
+    frames: Vec<FrameInfo>,
+}
+impl WasmBacktrace {
+    /// Returns the frames, from the innermost frame to the outermost one.
+    pub fn frames(&self) -> &[FrameInfo] {
+        &self.frames
+    }
+}
+impl fmt::Display for WasmBacktrace {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "wasm backtrace:")?;
+        for (idx, frame) in self.frames.iter().enumerate() {
+            write!(f, "\n  {:>2}: {}", idx, frame)?;
+        }
+        Ok(())
+    }
+}
+
+/// Defines a frame in a [WasmBacktrace].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FrameInfo {
+    module_name: Option<String>,
+    func_index: Option<u32>,
+    func_name: Option<String>,
+    offset: Option<u32>,
+}
+impl FrameInfo {
+    /// Returns the name of the [module instance](crate::Instance) which hosts the function. `None` is returned for
+    /// the anonymous module.
+    pub fn module_name(&self) -> Option<&str> {
+        self.module_name.as_deref()
+    }
+
+    /// Returns the index of the function in the [module instance](crate::Instance), if known.
+    pub fn func_index(&self) -> Option<u32> {
+        self.func_index
+    }
+
+    /// Returns the exported name of the function, if it is exported.
+    pub fn func_name(&self) -> Option<&str> {
+        self.func_name.as_deref()
+    }
+
+    /// Returns the offset of the executing instruction in the WASM binary. `None` is returned for the host functions
+    /// and the AOT compiled functions.
+    pub fn offset(&self) -> Option<u32> {
+        self.offset
+    }
+}
+impl fmt::Display for FrameInfo {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(module_name) = &self.module_name {
+            write!(f, "{}::", module_name)?;
+        }
+        match (&self.func_name, self.func_index) {
+            (Some(func_name), _) => write!(f, "{}", func_name)?,
+            (None, Some(func_index)) => write!(f, "func[{}]", func_index)?,
+            (None, None) => write!(f, "<unknown>")?,
+        }
+        match self.offset {
+            Some(offset) => write!(f, " @ {:#x}", offset),
+            None => Ok(()),
+        }
+    }
+}
+
+/// Reads the backtrace of a failed execution by `get_frames`, which fills at most `len` frames into the
+/// buffer and returns the number of the frames.
+pub(crate) fn read_backtrace(
+    get_frames: impl Fn(*mut WasmEdge_BacktraceFrame, u32) -> u32,
+) -> WasmBacktrace {
+    let len = get_frames(std::ptr::null_mut(), 0);
+    let mut raw_frames = Vec::with_capacity(len as usize);
+    let frames = unsafe {
+        let len = get_frames(raw_frames.as_mut_ptr(), len).min(len);
+        raw_frames.set_len(len as usize);
+        raw_frames
+            .into_iter()
+            .map(|raw: WasmEdge_BacktraceFrame| FrameInfo {
+                module_name: take_string(raw.ModuleName),
+                func_index: (raw.FuncIndex != u32::MAX).then_some(raw.FuncIndex),
+                func_name: take_string(raw.FuncName),
+                offset: (raw.Offset != u32::MAX).then_some(raw.Offset),
+            })
+            .collect()
+    };
+    WasmBacktrace { frames }
+}
+
+// converts the string allocated by WasmEdge into a `String`, and deletes it
+unsafe fn take_string(raw: WasmEdge_String) -> Option<String> {
+    let s = match raw.Length {
+        0 => None,
+        len => {
+            let bytes = std::slice::from_raw_parts(raw.Buf as *const u8, len as usize);
+            Some(String::from_utf8_lossy(bytes).into_owned())
+        }
+    };
+    WasmEdge_StringDelete(raw);
+    s
+}
+
+/// Replaces the `ExecutionFailed` error of an execution with the trap returned by the failed host function, if any.
+///
+/// `data` is the failure data of the execution, which is attached by the host functions created by this crate.
+pub(crate) fn host_func_trap(err: WasmEdgeError, data: *const c_void) -> WasmEdgeError {
+    match err {
+        WasmEdgeError::Core(CoreError::Execution(CoreExecutionError::ExecutionFailed)) => {
+            // the host functions created by this crate attach the trap they returned as the failure data
+            match unsafe { (data as *const Trap).as_ref() } {
+                Some(trap) => WasmEdgeError::Trap(trap.clone()),
+                None => err,
+            }
+        }
+        _ => err,
+    }
 }
 
 /// Converts WasmEdge_Result to WasmEdgeResult
@@ -445,6 +726,9 @@ pub(crate) fn check(result: WasmEdge_Result) -> WasmEdgeResult<()> {
         0x07 => Err(WasmEdgeError::Core(CoreError::Common(
             CoreCommonError::Interrupted,
         ))),
+        0x08 => Err(WasmEdgeError::Core(CoreError::Common(
+            CoreCommonError::NotValidated,
+        ))),
 
         // Load phase
         0x20 => Err(WasmEdgeError::Core(CoreError::Load(
@@ -590,6 +874,12 @@ pub(crate) fn check(result: WasmEdge_Result) -> WasmEdgeResult<()> {
         0x55 => Err(WasmEdgeError::Core(CoreError::Validation(
             CoreValidationError::InvalidLaneIdx,
         ))),
+        0x56 => Err(WasmEdgeError::Core(CoreError::Validation(
+            CoreValidationError::SharedMemoryNoMax,
+        ))),
+        0x57 => Err(WasmEdgeError::Core(CoreError::Validation(
+            CoreValidationError::InvalidMemOffset,
+        ))),
 
         // Instantiation phase
         0x60 => Err(WasmEdgeError::Core(CoreError::Instantiation(
@@ -654,6 +944,12 @@ pub(crate) fn check(result: WasmEdge_Result) -> WasmEdgeResult<()> {
         0x8E => Err(WasmEdgeError::Core(CoreError::Execution(
             CoreExecutionError::RefTypeMismatch,
         ))),
+        0x8F => Err(WasmEdgeError::Core(CoreError::Execution(
+            CoreExecutionError::UnalignedAtomicAccess,
+        ))),
+        0x90 => Err(WasmEdgeError::Core(CoreError::Execution(
+            CoreExecutionError::ExpectSharedMemory,
+        ))),
 
         _ => panic!("unknown error code: {}", code),
     }
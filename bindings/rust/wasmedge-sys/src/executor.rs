@@ -2,9 +2,17 @@
 
 use super::ffi;
 use crate::{
-    error::{check, WasmEdgeError},
+    error::{
+        check, host_func_trap, read_backtrace, CoreCommonError, CoreError, WasmBacktrace,
+        WasmEdgeError,
+    },
     types::WasmEdgeString,
-    Config, ImportObject, Module, Statistics, Store, WasmEdgeResult, WasmValue,
+    Config, Function, ImportObject, Module, Statistics, Store, WasmEdgeResult, WasmValue,
+};
+use std::{
+    sync::mpsc::{self, RecvTimeoutError},
+    thread,
+    time::Duration,
 };
 
 /// Struct of WasmEdge Executor.
@@ -193,13 +201,146 @@ impl Executor {
                 raw_params.len() as u32,
                 returns.as_mut_ptr(),
                 returns_len as u32,
-            ))?;
+            ))
+            .map_err(|err| self.execution_error(err))?;
             returns.set_len(returns_len);
         }
 
         Ok(returns.into_iter().map(Into::into).collect::<Vec<_>>())
     }
 
+    /// Invokes a WASM function in the anonymous [module](crate::Module), and interrupts it if it does not end within
+    /// the given timeout.
+    ///
+    /// The interpreter checks for the interruption at every function call and branch. For AOT compiled code, the check
+    /// is generated only if the module is compiled with [interruptible](crate::Config::interruptible) enabled;
+    /// otherwise, the execution can not be interrupted and the call returns when the function ends.
+    ///
+    /// # Arguments
+    ///
+    /// - `store` specifies the target [store](crate::Store) which owns the target function specified by `func_name`.
+    ///
+    /// - `func_name` specifies the name of the target function, which is stored in an anonymous module in `store`.
+    ///
+    /// - `params` specifies the argument values for the target function.
+    ///
+    /// - `timeout` specifies the maximum duration the target function is allowed to run.
+    ///
+    /// # Error
+    ///
+    /// If the timeout elapsed, then [ExecutionTimeout](crate::error::WasmEdgeError::ExecutionTimeout) is returned. If
+    /// fail to invoke the function specified by `func_name`, then an error is returned.
+    pub fn run_func_with_timeout(
+        &mut self,
+        store: &mut Store,
+        func_name: impl AsRef<str>,
+        params: impl IntoIterator<Item = WasmValue>,
+        timeout: Duration,
+    ) -> WasmEdgeResult<Vec<WasmValue>> {
+        self.with_timeout(timeout, |executor| {
+            executor.run_func(store, func_name, params)
+        })
+    }
+
+    /// Invokes a registered WASM function by its module name and function name, and interrupts it if it does not end
+    /// within the given timeout.
+    ///
+    /// See [run_func_with_timeout](Executor::run_func_with_timeout) for the details of the interruption.
+    ///
+    /// # Arguments
+    ///
+    /// - `store` specifies the target [store](crate::Store) which owns the module and the target function.
+    ///
+    /// - `mod_name` specifies the name of the registered module.
+    ///
+    /// - `func_name` specifies the name of the target function.
+    ///
+    /// - `params` specifies the argument values for the target function.
+    ///
+    /// - `timeout` specifies the maximum duration the target function is allowed to run.
+    ///
+    /// # Error
+    ///
+    /// If the timeout elapsed, then [ExecutionTimeout](crate::error::WasmEdgeError::ExecutionTimeout) is returned. If
+    /// fail to invoke the target registered function, then an error is returned.
+    pub fn run_func_registered_with_timeout(
+        &mut self,
+        store: &mut Store,
+        mod_name: impl AsRef<str>,
+        func_name: impl AsRef<str>,
+        params: impl IntoIterator<Item = WasmValue>,
+        timeout: Duration,
+    ) -> WasmEdgeResult<Vec<WasmValue>> {
+        self.with_timeout(timeout, |executor| {
+            executor.run_func_registered(store, mod_name, func_name, params)
+        })
+    }
+
+    /// Runs `f` while a watchdog thread stops the executor once the timeout elapses.
+    fn with_timeout(
+        &mut self,
+        timeout: Duration,
+        f: impl FnOnce(&mut Self) -> WasmEdgeResult<Vec<WasmValue>>,
+    ) -> WasmEdgeResult<Vec<WasmValue>> {
+        let executor = InnerExecutor(self.inner.0);
+        let (done_tx, done_rx) = mpsc::channel::<()>();
+        let watchdog = thread::spawn(move || {
+            let executor = executor;
+            match done_rx.recv_timeout(timeout) {
+                Err(RecvTimeoutError::Timeout) => {
+                    unsafe { ffi::WasmEdge_ExecutorStop(executor.0) };
+                    true
+                }
+                _ => false,
+            }
+        });
+
+        let result = f(self);
+
+        // the watchdog must not touch the executor after this function returns
+        drop(done_tx);
+        let stopped = watchdog.join().unwrap_or(false);
+
+        // a stop which arrives as the function ends is not consumed by any stop check, so discard it to keep it from
+        // interrupting the next invocation
+        if stopped {
+            unsafe { ffi::WasmEdge_ExecutorResetStop(self.inner.0) };
+        }
+
+        match result {
+            Err(err)
+                if stopped
+                    && err
+                        == WasmEdgeError::Core(CoreError::Common(CoreCommonError::Interrupted)) =>
+            {
+                Err(WasmEdgeError::ExecutionTimeout)
+            }
+            result => result,
+        }
+    }
+
+    // completes the error of the execution which just failed on the current thread with the trap of the failed host
+    // function
+    pub(crate) fn execution_error(&self, err: WasmEdgeError) -> WasmEdgeError {
+        host_func_trap(err, unsafe {
+            ffi::WasmEdge_ExecutorGetHostFailureData(self.inner.0)
+        })
+    }
+
+    /// Returns the [backtrace](crate::error::WasmBacktrace) of the last execution run by the calling thread, if it
+    /// failed.
+    ///
+    /// The backtrace is recorded when an execution of the interpreter fails, and cleared when the next execution on
+    /// the calling thread starts, so the executions of the [Executor] on the other threads do not affect it. Call it
+    /// right after the failed `run_*` method returns its error. `None` is returned if the last execution succeeded, or
+    /// failed before running any WASM function.
+    pub fn backtrace(&self) -> Option<WasmBacktrace> {
+        let backtrace = read_backtrace(|frames, len| unsafe {
+            ffi::WasmEdge_ExecutorGetBacktrace(self.inner.0, frames, len)
+        });
+        (!backtrace.frames().is_empty()).then_some(backtrace)
+    }
+
     /// Invokes a registered WASM function by its module name and function name, and returns the results.
     ///
     /// # Arguments
@@ -246,7 +387,54 @@ impl Executor {
                 raw_params.len() as u32,
                 returns.as_mut_ptr(),
                 returns_len as u32,
-            ))?;
+            ))
+            .map_err(|err| self.execution_error(err))?;
+            returns.set_len(returns_len);
+        }
+
+        Ok(returns.into_iter().map(Into::into).collect::<Vec<_>>())
+    }
+
+    /// Invokes the given [function](crate::Function), and returns the results.
+    ///
+    /// Unlike `run_func` and `run_func_registered`, the target function is not looked up by name, so the functions which
+    /// are not exported can be invoked as well, for example, the one obtained from an element of a
+    /// [table](crate::Table) of function references by [func_ref](crate::WasmValue::func_ref).
+    ///
+    /// # Arguments
+    ///
+    /// - `store` specifies the [store](crate::Store) which the module instance owning `func` is instantiated in.
+    ///
+    /// - `func` specifies the function to invoke.
+    ///
+    /// - `params` specifies the argument values for the target function.
+    ///
+    /// # Error
+    ///
+    /// If fail to invoke the function specified by `func`, then an error is returned.
+    pub fn run_func_ref(
+        &mut self,
+        store: &Store,
+        func: &Function,
+        params: impl IntoIterator<Item = WasmValue>,
+    ) -> WasmEdgeResult<Vec<WasmValue>> {
+        let raw_params = params.into_iter().map(|x| x.as_raw()).collect::<Vec<_>>();
+
+        // get the length of the function's returns
+        let returns_len = func.ty()?.returns_len();
+        let mut returns = Vec::with_capacity(returns_len);
+
+        unsafe {
+            check(ffi::WasmEdge_ExecutorInvokeFunction(
+                self.inner.0,
+                store.inner.0,
+                func.inner.0,
+                raw_params.as_ptr(),
+                raw_params.len() as u32,
+                returns.as_mut_ptr(),
+                returns_len as u32,
+            ))
+            .map_err(|err| self.execution_error(err))?;
             returns.set_len(returns_len);
         }
 
@@ -270,8 +458,9 @@ unsafe impl Sync for InnerExecutor {}
 mod tests {
     use super::*;
     use crate::{
-        Config, FuncType, Function, Global, GlobalType, MemType, Memory, Mutability, RefType,
-        Statistics, Table, TableType, ValType,
+        error::{CoreExecutionError, Trap},
+        Caller, Config, FuncType, Function, Global, GlobalType, Loader, MemType, Memory,
+        Mutability, RefType, Statistics, Table, TableType, ValType, Validator,
     };
     use std::{
         sync::{Arc, Mutex},
@@ -417,6 +606,207 @@ mod tests {
         handle.join().unwrap();
     }
 
+    #[test]
+    fn test_executor_run_func_with_timeout() {
+        let result = Executor::create(None, None);
+        assert!(result.is_ok());
+        let mut executor = result.unwrap();
+
+        let result = Store::create();
+        assert!(result.is_ok());
+        let mut store = result.unwrap();
+
+        // load and validate a wasm module
+        let result = Loader::create(None);
+        assert!(result.is_ok());
+        let loader = result.unwrap();
+        let path = std::path::PathBuf::from(env!("WASMEDGE_DIR"))
+            .join("bindings/rust/wasmedge-sys/tests/data/fibonacci.wasm");
+        let result = loader.from_file(path);
+        assert!(result.is_ok());
+        let module = result.unwrap();
+        let result = Validator::create(None);
+        assert!(result.is_ok());
+        let validator = result.unwrap();
+        let result = validator.validate(&module);
+        assert!(result.is_ok());
+
+        // registering a named module resets the active module, so the active one is registered last
+        let result = executor.register_named_module(&mut store, &module, "fib-module");
+        assert!(result.is_ok());
+        let result = executor.register_active_module(&mut store, &module);
+        assert!(result.is_ok());
+
+        // run a function which ends before the timeout
+        let result = executor.run_func_with_timeout(
+            &mut store,
+            "fib",
+            [WasmValue::from_i32(5)],
+            Duration::from_secs(10),
+        );
+        assert!(result.is_ok());
+        let returns = result.unwrap();
+        assert_eq!(returns[0].to_i32(), 8);
+
+        // run a long-running function
+        let result = executor.run_func_with_timeout(
+            &mut store,
+            "fib",
+            [WasmValue::from_i32(100)],
+            Duration::from_millis(100),
+        );
+        assert!(result.is_err());
+        assert_eq!(result.unwrap_err(), WasmEdgeError::ExecutionTimeout);
+
+        // the executor is still usable after the timeout
+        let result = executor.run_func(&mut store, "fib", [WasmValue::from_i32(5)]);
+        assert!(result.is_ok());
+        let returns = result.unwrap();
+        assert_eq!(returns[0].to_i32(), 8);
+
+        // run a long-running registered function
+        let result = executor.run_func_registered_with_timeout(
+            &mut store,
+            "fib-module",
+            "fib",
+            [WasmValue::from_i32(100)],
+            Duration::from_millis(100),
+        );
+        assert!(result.is_err());
+        assert_eq!(result.unwrap_err(), WasmEdgeError::ExecutionTimeout);
+    }
+
+    #[test]
+    fn test_executor_run_func_with_timeout_at_the_end() {
+        let result = Executor::create(None, None);
+        assert!(result.is_ok());
+        let mut executor = result.unwrap();
+
+        let result = Store::create();
+        assert!(result.is_ok());
+        let mut store = result.unwrap();
+
+        // a host function has no stop check, so the timeout fires while it runs and is not consumed
+        let result = ImportObject::create("extern");
+        assert!(result.is_ok());
+        let mut import_obj = result.unwrap();
+        let result = Function::wrap(|| {
+            thread::sleep(Duration::from_millis(300));
+            1i32
+        });
+        assert!(result.is_ok());
+        import_obj.add_func("slow", result.unwrap());
+        let result = Function::wrap(|| 2i32);
+        assert!(result.is_ok());
+        import_obj.add_func("fast", result.unwrap());
+        let result = executor.register_import_object(&mut store, &import_obj);
+        assert!(result.is_ok());
+
+        let result = executor.run_func_registered_with_timeout(
+            &mut store,
+            "extern",
+            "slow",
+            [],
+            Duration::from_millis(50),
+        );
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap()[0].to_i32(), 1);
+
+        // the stop requested by the timeout does not interrupt the next invocation
+        let result = executor.run_func_registered(&mut store, "extern", "fast", []);
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap()[0].to_i32(), 2);
+    }
+
+    #[test]
+    fn test_executor_run_func_ref() {
+        let result = Executor::create(None, None);
+        assert!(result.is_ok());
+        let mut executor = result.unwrap();
+
+        let result = Store::create();
+        assert!(result.is_ok());
+        let mut store = result.unwrap();
+
+        // load, validate and instantiate a wasm module with a table of callbacks
+        let result = Loader::create(None);
+        assert!(result.is_ok());
+        let loader = result.unwrap();
+        let path = std::path::PathBuf::from(env!("WASMEDGE_DIR"))
+            .join("bindings/rust/wasmedge-sys/tests/data/table.wasm");
+        let result = loader.from_file(path);
+        assert!(result.is_ok());
+        let module = result.unwrap();
+        let result = Validator::create(None);
+        assert!(result.is_ok());
+        let validator = result.unwrap();
+        let result = validator.validate(&module);
+        assert!(result.is_ok());
+        let result = executor.register_active_module(&mut store, &module);
+        assert!(result.is_ok());
+
+        let result = store.find_table("callbacks");
+        assert!(result.is_ok());
+        let mut table = result.unwrap();
+
+        // invoke the callback which is not exported
+        let result = table.get_data(1);
+        assert!(result.is_ok());
+        let result = result.unwrap().func_ref();
+        assert!(result.is_some());
+        let square = result.unwrap();
+        let result = executor.run_func_ref(&store, &square, [WasmValue::from_i32(5)]);
+        assert!(result.is_ok());
+        let returns = result.unwrap();
+        assert_eq!(returns[0].to_i32(), 25);
+
+        // invoke the callback with the wrong arguments
+        let result = executor.run_func_ref(&store, &square, []);
+        assert!(result.is_err());
+        assert_eq!(
+            result.unwrap_err(),
+            WasmEdgeError::Core(CoreError::Execution(CoreExecutionError::FuncTypeMismatch))
+        );
+
+        // install a host function into the table, and invoke it from both the host and the guest
+        let result = FuncType::create([ValType::I32], [ValType::I32]);
+        assert!(result.is_ok());
+        let func_ty = result.unwrap();
+        let result = Function::create(
+            &func_ty,
+            Box::new(|_caller, inputs| Ok(vec![WasmValue::from_i32(inputs[0].to_i32() + 1)])),
+            0,
+        );
+        assert!(result.is_ok());
+        let mut increase = result.unwrap();
+        let result = table.init_from_iter(2, [WasmValue::from_func_ref(&mut increase)]);
+        assert!(result.is_ok());
+        let result = executor.run_func_ref(&store, &increase, [WasmValue::from_i32(5)]);
+        assert!(result.is_ok());
+        let returns = result.unwrap();
+        assert_eq!(returns[0].to_i32(), 6);
+        let result = executor.run_func(
+            &mut store,
+            "dispatch",
+            [WasmValue::from_i32(2), WasmValue::from_i32(5)],
+        );
+        assert!(result.is_ok());
+        let returns = result.unwrap();
+        assert_eq!(returns[0].to_i32(), 6);
+
+        // the guest calls the copied callback
+        let result = table.copy_within(0..2, 2);
+        assert!(result.is_ok());
+        let result = executor.run_func(
+            &mut store,
+            "dispatch",
+            [WasmValue::from_i32(3), WasmValue::from_i32(5)],
+        );
+        assert!(result.is_ok());
+        let returns = result.unwrap();
+        assert_eq!(returns[0].to_i32(), 25);
+    }
+
     #[test]
     fn test_executor_send() {
         // create an Executor context with the given configuration and statistics.
@@ -468,21 +858,21 @@ mod tests {
         handle.join().unwrap();
     }
 
-    fn real_add(inputs: Vec<WasmValue>) -> Result<Vec<WasmValue>, u8> {
+    fn real_add(_caller: Caller, inputs: Vec<WasmValue>) -> Result<Vec<WasmValue>, Trap> {
         if inputs.len() != 2 {
-            return Err(1);
+            return Err(Trap::new("Invalid number of arguments"));
         }
 
         let a = if inputs[0].ty() == ValType::I32 {
             inputs[0].to_i32()
         } else {
-            return Err(2);
+            return Err(Trap::new("Invalid type of arguments"));
         };
 
         let b = if inputs[1].ty() == ValType::I32 {
             inputs[1].to_i32()
         } else {
-            return Err(3);
+            return Err(Trap::new("Invalid type of arguments"));
         };
 
         let c = a + b;
@@ -0,0 +1,525 @@
+//! Defines WasmEdge GuestAllocator and GuestBuffer structs.
+//!
+//! A WASM module usually exports a pair of allocation functions, such as `malloc`/`free` or
+//! `allocate`/`deallocate`, so that the host can pass buffers into the module. The [GuestAllocator] drives these
+//! functions to copy Rust byte slices and strings into the module, and frees them automatically.
+
+use crate::{
+    error::{GuestAllocError, MemError, WasmEdgeError},
+    Memory, ValType, Vm, WasmEdgeResult, WasmSlice, WasmValue,
+};
+
+/// Struct of WasmEdge GuestAllocator.
+///
+/// A [GuestAllocator] allocates the buffers in a WASM module hosted by a [Vm] with the allocation functions exported
+/// by the module. The allocation function must be of the type `(i32) -> i32`, which takes the size of the buffer and
+/// returns the address of the buffer. The deallocation function must be of the type `(i32) -> ()` as `free`, or
+/// `(i32, i32) -> ()` as `deallocate`, which takes the address, and the size of the buffer if required.
+#[derive(Debug)]
+pub struct GuestAllocator<'vm> {
+    vm: &'vm Vm,
+    mod_name: Option<String>,
+    mem_name: String,
+    alloc_name: String,
+    dealloc_name: String,
+    dealloc_with_len: bool,
+}
+impl<'vm> GuestAllocator<'vm> {
+    /// Creates a [GuestAllocator] with the `malloc` and `free` functions, and the memory named `memory` exported by
+    /// the active module of the [Vm].
+    ///
+    /// # Argument
+    ///
+    /// - `vm` specifies the [Vm] which hosts the active module.
+    ///
+    /// # Error
+    ///
+    /// If fail to find the functions, or the types of the functions are not supported, then an error is returned.
+    pub fn new(vm: &'vm Vm) -> WasmEdgeResult<Self> {
+        Self::create(vm, None, "memory", "malloc", "free")
+    }
+
+    /// Creates a [GuestAllocator] with the given names of the exported memory and functions.
+    ///
+    /// # Arguments
+    ///
+    /// - `vm` specifies the [Vm] which hosts the module.
+    ///
+    /// - `mod_name` specifies the name of the registered module. If `None`, then the active module is used.
+    ///
+    /// - `mem_name` specifies the name of the exported memory.
+    ///
+    /// - `alloc_name` specifies the name of the exported allocation function.
+    ///
+    /// - `dealloc_name` specifies the name of the exported deallocation function.
+    ///
+    /// # Error
+    ///
+    /// If fail to find the functions, or the types of the functions are not supported, then an error is returned.
+    pub fn create(
+        vm: &'vm Vm,
+        mod_name: Option<&str>,
+        mem_name: impl AsRef<str>,
+        alloc_name: impl AsRef<str>,
+        dealloc_name: impl AsRef<str>,
+    ) -> WasmEdgeResult<Self> {
+        let func_type = |name: &str| match mod_name {
+            Some(mod_name) => vm.get_registered_function_type(mod_name, name),
+            None => vm.get_function_type(name),
+        };
+
+        let alloc_ty = func_type(alloc_name.as_ref())?;
+        if alloc_ty.params_type_iter().ne([ValType::I32])
+            || alloc_ty.returns_type_iter().ne([ValType::I32])
+        {
+            return Err(WasmEdgeError::GuestAlloc(GuestAllocError::AllocSignature(
+                alloc_name.as_ref().to_string(),
+            )));
+        }
+
+        let dealloc_ty = func_type(dealloc_name.as_ref())?;
+        let params = dealloc_ty.params_type_iter().collect::<Vec<_>>();
+        let dealloc_with_len = match (params.as_slice(), dealloc_ty.returns_len()) {
+            ([ValType::I32], 0) => false,
+            ([ValType::I32, ValType::I32], 0) => true,
+            _ => {
+                return Err(WasmEdgeError::GuestAlloc(
+                    GuestAllocError::DeallocSignature(dealloc_name.as_ref().to_string()),
+                ))
+            }
+        };
+
+        let allocator = Self {
+            vm,
+            mod_name: mod_name.map(ToString::to_string),
+            mem_name: mem_name.as_ref().to_string(),
+            alloc_name: alloc_name.as_ref().to_string(),
+            dealloc_name: dealloc_name.as_ref().to_string(),
+            dealloc_with_len,
+        };
+        // check if the memory exists
+        allocator.memory()?;
+
+        Ok(allocator)
+    }
+
+    /// Allocates a buffer of `len` bytes in the guest.
+    ///
+    /// # Argument
+    ///
+    /// - `len` specifies the size in bytes of the buffer.
+    ///
+    /// # Error
+    ///
+    /// If fail to run the allocation function, or the allocation function returns a null address, then an error is
+    /// returned.
+    pub fn alloc(&self, len: u32) -> WasmEdgeResult<GuestBuffer<'_, 'vm>> {
+        let returns = self.run(&self.alloc_name, [WasmValue::from_i32(len as i32)])?;
+        let ptr = returns[0].to_i32() as u32;
+        if ptr == 0 && len > 0 {
+            return Err(WasmEdgeError::GuestAlloc(GuestAllocError::Alloc(len)));
+        }
+
+        Ok(GuestBuffer {
+            allocator: self,
+            ptr,
+            len,
+            freed: false,
+        })
+    }
+
+    /// Allocates a buffer in the guest, and copies the given bytes into it.
+    ///
+    /// # Argument
+    ///
+    /// - `data` specifies the bytes to copy.
+    ///
+    /// # Error
+    ///
+    /// If fail to allocate the buffer or copy the bytes, then an error is returned.
+    pub fn alloc_bytes(&self, data: impl AsRef<[u8]>) -> WasmEdgeResult<GuestBuffer<'_, 'vm>> {
+        let data = data.as_ref();
+        let len = u32::try_from(data.len())
+            .map_err(|_| WasmEdgeError::GuestAlloc(GuestAllocError::Alloc(u32::MAX)))?;
+        let buffer = self.alloc(len)?;
+        buffer.write(data)?;
+        Ok(buffer)
+    }
+
+    /// Allocates a buffer in the guest, and copies the given string in UTF-8 into it. The string is not terminated
+    /// by a nul byte.
+    ///
+    /// # Argument
+    ///
+    /// - `s` specifies the string to copy.
+    ///
+    /// # Error
+    ///
+    /// If fail to allocate the buffer or copy the string, then an error is returned.
+    pub fn alloc_str(&self, s: impl AsRef<str>) -> WasmEdgeResult<GuestBuffer<'_, 'vm>> {
+        self.alloc_bytes(s.as_ref().as_bytes())
+    }
+
+    /// Copies the given bytes into the guest, calls the given function with the address and the length of the bytes
+    /// as `(i32, i32)`, and frees the bytes after the call.
+    ///
+    /// # Arguments
+    ///
+    /// - `func_name` specifies the name of the function to call, which is exported by the same module as the
+    ///   allocation functions.
+    ///
+    /// - `data` specifies the bytes to pass.
+    ///
+    /// # Error
+    ///
+    /// If fail to allocate the buffer, or fail to run the function, then an error is returned.
+    pub fn call_with_bytes(
+        &self,
+        func_name: impl AsRef<str>,
+        data: impl AsRef<[u8]>,
+    ) -> WasmEdgeResult<Vec<WasmValue>> {
+        let buffer = self.alloc_bytes(data)?;
+        let returns = self.run(func_name.as_ref(), buffer.params())?;
+        buffer.free()?;
+        Ok(returns)
+    }
+
+    /// Copies the given string in UTF-8 into the guest, calls the given function with the address and the length of
+    /// the string as `(i32, i32)`, and frees the string after the call.
+    ///
+    /// # Arguments
+    ///
+    /// - `func_name` specifies the name of the function to call, which is exported by the same module as the
+    ///   allocation functions.
+    ///
+    /// - `s` specifies the string to pass.
+    ///
+    /// # Error
+    ///
+    /// If fail to allocate the buffer, or fail to run the function, then an error is returned.
+    pub fn call_with_str(
+        &self,
+        func_name: impl AsRef<str>,
+        s: impl AsRef<str>,
+    ) -> WasmEdgeResult<Vec<WasmValue>> {
+        self.call_with_bytes(func_name, s.as_ref().as_bytes())
+    }
+
+    /// Reads the bytes of a buffer allocated by the guest, such as the result returned by a guest function, and then
+    /// frees the buffer.
+    ///
+    /// # Arguments
+    ///
+    /// - `ptr` specifies the address of the buffer.
+    ///
+    /// - `len` specifies the size in bytes of the buffer.
+    ///
+    /// # Error
+    ///
+    /// If fail to read or free the buffer, then an error is returned.
+    pub fn take_bytes(&self, ptr: u32, len: u32) -> WasmEdgeResult<Vec<u8>> {
+        let buffer = GuestBuffer {
+            allocator: self,
+            ptr,
+            len,
+            freed: false,
+        };
+        let data = buffer.read()?;
+        buffer.free()?;
+        Ok(data)
+    }
+
+    /// Reads a UTF-8 string from a buffer allocated by the guest, such as the result returned by a guest function,
+    /// and then frees the buffer.
+    ///
+    /// # Arguments
+    ///
+    /// - `ptr` specifies the address of the buffer.
+    ///
+    /// - `len` specifies the size in bytes of the string.
+    ///
+    /// # Error
+    ///
+    /// If fail to read or free the buffer, or the bytes are not valid UTF-8, then an error is returned.
+    pub fn take_string(&self, ptr: u32, len: u32) -> WasmEdgeResult<String> {
+        let data = self.take_bytes(ptr, len)?;
+        String::from_utf8(data).map_err(|e| WasmEdgeError::Utf8(e.utf8_error()))
+    }
+
+    // returns the memory every time, since the memory may be grown by the guest functions
+    fn memory(&self) -> WasmEdgeResult<Memory> {
+        let store = self.vm.store_mut()?;
+        match &self.mod_name {
+            Some(mod_name) => store.find_memory_registered(mod_name, &self.mem_name),
+            None => store.find_memory(&self.mem_name),
+        }
+    }
+
+    fn run(
+        &self,
+        func_name: &str,
+        params: impl IntoIterator<Item = WasmValue>,
+    ) -> WasmEdgeResult<Vec<WasmValue>> {
+        match &self.mod_name {
+            Some(mod_name) => self.vm.run_registered_function(mod_name, func_name, params),
+            None => self.vm.run_function(func_name, params),
+        }
+    }
+
+    fn dealloc(&self, ptr: u32, len: u32) -> WasmEdgeResult<()> {
+        let mut params = vec![WasmValue::from_i32(ptr as i32)];
+        if self.dealloc_with_len {
+            params.push(WasmValue::from_i32(len as i32));
+        }
+        self.run(&self.dealloc_name, params)?;
+        Ok(())
+    }
+}
+
+/// Struct of WasmEdge GuestBuffer.
+///
+/// A [GuestBuffer] is a buffer allocated in a WASM module by a [GuestAllocator]. The buffer is freed when the
+/// [GuestBuffer] is dropped. To handle the error raised by the deallocation function, call [GuestBuffer::free]
+/// explicitly.
+#[derive(Debug)]
+pub struct GuestBuffer<'a, 'vm> {
+    allocator: &'a GuestAllocator<'vm>,
+    ptr: u32,
+    len: u32,
+    freed: bool,
+}
+impl<'a, 'vm> GuestBuffer<'a, 'vm> {
+    /// Returns the address of the buffer in the guest.
+    pub fn ptr(&self) -> u32 {
+        self.ptr
+    }
+
+    /// Returns the size in bytes of the buffer.
+    pub fn len(&self) -> u32 {
+        self.len
+    }
+
+    /// Returns `true` if the buffer is empty.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the address and the length of the buffer as the arguments of a WASM function.
+    pub fn params(&self) -> [WasmValue; 2] {
+        [
+            WasmValue::from_i32(self.ptr as i32),
+            WasmValue::from_i32(self.len as i32),
+        ]
+    }
+
+    /// Returns the buffer as a [WasmSlice].
+    ///
+    /// # Error
+    ///
+    /// If the buffer overflows the 32-bit address space, then an error is returned.
+    pub fn as_wasm_slice(&self) -> WasmEdgeResult<WasmSlice<u8>> {
+        WasmSlice::new(self.ptr, self.len)
+    }
+
+    /// Copies the bytes of the buffer out of the guest.
+    ///
+    /// # Error
+    ///
+    /// If the buffer is out of the bounds of the guest memory, then an error is returned.
+    pub fn read(&self) -> WasmEdgeResult<Vec<u8>> {
+        let mem = self.allocator.memory()?;
+        mem.get_data(self.ptr.into(), self.len)
+    }
+
+    /// Copies the given bytes into the beginning of the buffer.
+    ///
+    /// # Argument
+    ///
+    /// - `data` specifies the bytes to copy. Its length must not be larger than the size of the buffer.
+    ///
+    /// # Error
+    ///
+    /// If the length of `data` is larger than the size of the buffer, or the buffer is out of the bounds of the guest
+    /// memory, then an error is returned.
+    pub fn write(&self, data: impl AsRef<[u8]>) -> WasmEdgeResult<()> {
+        let data = data.as_ref();
+        if data.len() > self.len as usize {
+            return Err(WasmEdgeError::Mem(MemError::SliceLen {
+                expected: self.len,
+                found: data.len(),
+            }));
+        }
+        let mut mem = self.allocator.memory()?;
+        mem.set_data(data.iter().copied(), self.ptr.into())
+    }
+
+    /// Frees the buffer by the deallocation function.
+    ///
+    /// # Error
+    ///
+    /// If fail to run the deallocation function, then an error is returned.
+    pub fn free(mut self) -> WasmEdgeResult<()> {
+        self.freed = true;
+        self.allocator.dealloc(self.ptr, self.len)
+    }
+}
+impl<'a, 'vm> Drop for GuestBuffer<'a, 'vm> {
+    fn drop(&mut self) {
+        if !self.freed {
+            let _ = self.allocator.dealloc(self.ptr, self.len);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn create_vm() -> Vm {
+        let result = Vm::create(None, None);
+        assert!(result.is_ok());
+        let mut vm = result.unwrap();
+
+        let path = std::path::PathBuf::from(env!("WASMEDGE_DIR"))
+            .join("bindings/rust/wasmedge-sys/tests/data/alloc.wasm");
+        let result = vm.register_wasm_from_file("alloc-module", &path);
+        assert!(result.is_ok());
+        let result = vm.load_wasm_from_file(&path);
+        assert!(result.is_ok());
+        let result = vm.validate();
+        assert!(result.is_ok());
+        let result = vm.instantiate();
+        assert!(result.is_ok());
+        vm
+    }
+
+    fn frees(vm: &Vm, mod_name: Option<&str>) -> i32 {
+        let result = match mod_name {
+            Some(mod_name) => vm.run_registered_function(mod_name, "frees", []),
+            None => vm.run_function("frees", []),
+        };
+        assert!(result.is_ok());
+        result.unwrap()[0].to_i32()
+    }
+
+    #[test]
+    fn test_guest_alloc_buffer() {
+        let vm = create_vm();
+        let result = GuestAllocator::new(&vm);
+        assert!(result.is_ok());
+        let allocator = result.unwrap();
+
+        // copy bytes into the guest
+        {
+            let result = allocator.alloc_bytes([1, 2, 3]);
+            assert!(result.is_ok());
+            let buffer = result.unwrap();
+            assert_eq!(buffer.ptr(), 1024);
+            assert_eq!(buffer.len(), 3);
+            let result = buffer.read();
+            assert!(result.is_ok());
+            assert_eq!(result.unwrap(), vec![1, 2, 3]);
+
+            let result = buffer.write([4, 5]);
+            assert!(result.is_ok());
+            let result = buffer.read();
+            assert!(result.is_ok());
+            assert_eq!(result.unwrap(), vec![4, 5, 3]);
+            let result = buffer.write([1; 4]);
+            assert!(result.is_err());
+        }
+        // the buffer is freed on dropping
+        assert_eq!(frees(&vm, None), 1);
+
+        // free the buffer explicitly
+        let result = allocator.alloc_str("hello");
+        assert!(result.is_ok());
+        let buffer = result.unwrap();
+        assert_eq!(buffer.ptr(), 1027);
+        let result = buffer.as_wasm_slice();
+        assert!(result.is_ok());
+        let slice = result.unwrap();
+        let result = allocator.memory();
+        assert!(result.is_ok());
+        let result = slice.read_utf8(&result.unwrap());
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap(), "hello");
+        let result = buffer.free();
+        assert!(result.is_ok());
+        assert_eq!(frees(&vm, None), 2);
+
+        // read a buffer allocated by the guest, and free it
+        let result = vm.run_function("malloc", [WasmValue::from_i32(5)]);
+        assert!(result.is_ok());
+        let ptr = result.unwrap()[0].to_i32() as u32;
+        assert_eq!(ptr, 1032);
+        let result = allocator.memory();
+        assert!(result.is_ok());
+        let mut mem = result.unwrap();
+        let result = mem.write_string("world", ptr.into());
+        assert!(result.is_ok());
+        let result = allocator.take_string(ptr, 5);
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap(), "world");
+        assert_eq!(frees(&vm, None), 3);
+    }
+
+    #[test]
+    fn test_guest_alloc_call() {
+        let vm = create_vm();
+        let result = GuestAllocator::new(&vm);
+        assert!(result.is_ok());
+        let allocator = result.unwrap();
+
+        let result = allocator.call_with_bytes("last", [1, 2, 3]);
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap()[0].to_i32(), 3);
+        let result = allocator.call_with_str("last", "wasmedge");
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap()[0].to_i32(), b'e' as i32);
+        assert_eq!(frees(&vm, None), 2);
+
+        // the function does not exist
+        let result = allocator.call_with_str("first", "wasmedge");
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_guest_alloc_create() {
+        let vm = create_vm();
+
+        // the deallocation function takes the length
+        let result = GuestAllocator::create(
+            &vm,
+            Some("alloc-module"),
+            "memory",
+            "allocate",
+            "deallocate",
+        );
+        assert!(result.is_ok());
+        let allocator = result.unwrap();
+        let result = allocator.call_with_bytes("last", [7]);
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap()[0].to_i32(), 7);
+        assert_eq!(frees(&vm, Some("alloc-module")), 1);
+        assert_eq!(frees(&vm, None), 0);
+
+        // the types of the functions are not supported
+        let result = GuestAllocator::create(&vm, None, "memory", "last", "free");
+        assert!(result.is_err());
+        assert_eq!(
+            result.unwrap_err(),
+            WasmEdgeError::GuestAlloc(GuestAllocError::AllocSignature("last".into()))
+        );
+        let result = GuestAllocator::create(&vm, None, "memory", "malloc", "malloc");
+        assert!(result.is_err());
+        assert_eq!(
+            result.unwrap_err(),
+            WasmEdgeError::GuestAlloc(GuestAllocError::DeallocSignature("malloc".into()))
+        );
+
+        // the memory does not exist
+        let result = GuestAllocator::create(&vm, None, "mem", "malloc", "free");
+        assert!(result.is_err());
+    }
+}
@@ -1,11 +1,11 @@
 //! Defines WasmEdge ImportObject struct.
 
 use crate::{
-    error::WasmEdgeError,
+    error::{check, CoreCommonError, CoreError, WasmEdgeError},
     ffi,
-    instance::{Function, Global, Memory, Table},
+    instance::{function::InnerHostFunc, Function, Global, Memory, SharedMemory, Table},
     types::WasmEdgeString,
-    utils::string_to_c_char,
+    utils::strings_to_cstrings,
     WasmEdgeResult,
 };
 
@@ -16,6 +16,8 @@ use crate::{
 pub struct ImportObject {
     pub(crate) inner: InnerImportObject,
     pub(crate) registered: bool,
+    pub(crate) host_funcs: Vec<InnerHostFunc>,
+    pub(crate) shared_mems: Vec<SharedMemory>,
 }
 impl ImportObject {
     /// Creates a new host module with the given name.
@@ -35,6 +37,8 @@ impl ImportObject {
             false => Ok(ImportObject {
                 inner: InnerImportObject(ctx),
                 registered: false,
+                host_funcs: Vec::new(),
+                shared_mems: Vec::new(),
             }),
         }
     }
@@ -57,31 +61,23 @@ impl ImportObject {
     ///
     /// # Error
     ///
-    /// If fail to create a host module, then an error is returned.
+    /// If any of the strings contains a nul byte, then a [FoundNulByte](crate::error::WasmEdgeError::FoundNulByte) error
+    /// is returned. If fail to create a host module, then an error is returned.
     pub fn create_wasi(
         args: Option<Vec<&str>>,
         envs: Option<Vec<&str>>,
         preopens: Option<Vec<&str>>,
     ) -> WasmEdgeResult<Self> {
-        let args = match args {
-            Some(args) => args.into_iter().map(string_to_c_char).collect::<Vec<_>>(),
-            None => vec![],
-        };
+        let args_cstr = strings_to_cstrings(args)?;
+        let args = args_cstr.iter().map(|s| s.as_ptr()).collect::<Vec<_>>();
         let args_len = args.len();
 
-        let envs = match envs {
-            Some(envs) => envs.into_iter().map(string_to_c_char).collect::<Vec<_>>(),
-            None => vec![],
-        };
+        let envs_cstr = strings_to_cstrings(envs)?;
+        let envs = envs_cstr.iter().map(|s| s.as_ptr()).collect::<Vec<_>>();
         let envs_len = envs.len();
 
-        let preopens = match preopens {
-            Some(preopens) => preopens
-                .into_iter()
-                .map(string_to_c_char)
-                .collect::<Vec<_>>(),
-            None => vec![],
-        };
+        let preopens_cstr = strings_to_cstrings(preopens)?;
+        let preopens = preopens_cstr.iter().map(|s| s.as_ptr()).collect::<Vec<_>>();
         let preopens_len = preopens.len();
 
         let ctx = unsafe {
@@ -99,6 +95,8 @@ impl ImportObject {
             false => Ok(ImportObject {
                 inner: InnerImportObject(ctx),
                 registered: false,
+                host_funcs: Vec::new(),
+                shared_mems: Vec::new(),
             }),
         }
     }
@@ -112,31 +110,44 @@ impl ImportObject {
     /// - `envs` specifies the environment variables in the format `ENV_VAR_NAME=VALUE`.
     ///
     /// - `preopens` specifies the directories to pre-open. The required format is `DIR1:DIR2`.
+    ///
+    /// # Error
+    ///
+    /// If the host module is not a WASI one, then an [ImportObjNotWasi](crate::error::WasmEdgeError::ImportObjNotWasi)
+    /// error is returned. If any of the strings contains a nul byte, then a
+    /// [FoundNulByte](crate::error::WasmEdgeError::FoundNulByte) error is returned.
     pub fn init_wasi(
         &mut self,
         args: Option<Vec<&str>>,
         envs: Option<Vec<&str>>,
         preopens: Option<Vec<&str>>,
-    ) {
-        let args = match args {
-            Some(args) => args.into_iter().map(string_to_c_char).collect::<Vec<_>>(),
-            None => vec![],
+    ) -> WasmEdgeResult<()> {
+        // the initialization resets the redirection of the standard streams anyway, so restoring them first tells if
+        // this is a WASI host module without changing the result
+        let restored = unsafe {
+            check(ffi::WasmEdge_ImportObjectWASIRedirectStdio(
+                self.inner.0,
+                -1,
+                -1,
+                -1,
+            ))
         };
+        if let Err(WasmEdgeError::Core(CoreError::Common(CoreCommonError::WrongVMWorkflow))) =
+            restored
+        {
+            return Err(WasmEdgeError::ImportObjNotWasi);
+        }
+
+        let args_cstr = strings_to_cstrings(args)?;
+        let args = args_cstr.iter().map(|s| s.as_ptr()).collect::<Vec<_>>();
         let args_len = args.len();
 
-        let envs = match envs {
-            Some(envs) => envs.into_iter().map(string_to_c_char).collect::<Vec<_>>(),
-            None => vec![],
-        };
+        let envs_cstr = strings_to_cstrings(envs)?;
+        let envs = envs_cstr.iter().map(|s| s.as_ptr()).collect::<Vec<_>>();
         let envs_len = envs.len();
 
-        let preopens = match preopens {
-            Some(preopens) => preopens
-                .into_iter()
-                .map(string_to_c_char)
-                .collect::<Vec<_>>(),
-            None => vec![],
-        };
+        let preopens_cstr = strings_to_cstrings(preopens)?;
+        let preopens = preopens_cstr.iter().map(|s| s.as_ptr()).collect::<Vec<_>>();
         let preopens_len = preopens.len();
 
         unsafe {
@@ -150,6 +161,7 @@ impl ImportObject {
                 preopens_len as u32,
             )
         };
+        Ok(())
     }
 
     /// Returns the WASI exit code.
@@ -159,6 +171,38 @@ impl ImportObject {
         unsafe { ffi::WasmEdge_ImportObjectWASIGetExitCode(self.inner.0) }
     }
 
+    /// Redirects the standard input, output, and error of the WASI host module to the file descriptors of the host process.
+    ///
+    /// The file descriptors are duplicated, so the caller still owns them. The redirection is reset by
+    /// [init_wasi](crate::ImportObject::init_wasi).
+    ///
+    /// # Arguments
+    ///
+    /// - `stdin` specifies the file descriptor to read the standard input. `None` restores the standard input of the host process.
+    ///
+    /// - `stdout` specifies the file descriptor to write the standard output. `None` restores the standard output of the host process.
+    ///
+    /// - `stderr` specifies the file descriptor to write the standard error. `None` restores the standard error of the host process.
+    ///
+    /// # Error
+    ///
+    /// If fail to redirect the standard streams, then an error is returned.
+    pub fn redirect_wasi_stdio(
+        &mut self,
+        stdin: Option<i32>,
+        stdout: Option<i32>,
+        stderr: Option<i32>,
+    ) -> WasmEdgeResult<()> {
+        unsafe {
+            check(ffi::WasmEdge_ImportObjectWASIRedirectStdio(
+                self.inner.0,
+                stdin.unwrap_or(-1),
+                stdout.unwrap_or(-1),
+                stderr.unwrap_or(-1),
+            ))
+        }
+    }
+
     /// Creates a wasmedge_process host module that contains the wasmedge_process host functions and
     /// initialize it with the parameters.
     ///
@@ -170,15 +214,14 @@ impl ImportObject {
     ///
     /// # Error
     ///
-    /// If fail to create a wasmedge_process host module, then an error is returned.
+    /// If any of the commands contains a nul byte, then a [FoundNulByte](crate::error::WasmEdgeError::FoundNulByte)
+    /// error is returned. If fail to create a wasmedge_process host module, then an error is returned.
     pub fn create_wasmedge_process(
         allowed_cmds: Option<Vec<&str>>,
         allowed: bool,
     ) -> WasmEdgeResult<Self> {
-        let cmds = match allowed_cmds {
-            Some(cmds) => cmds.iter().map(string_to_c_char).collect::<Vec<_>>(),
-            None => vec![],
-        };
+        let cmds_cstr = strings_to_cstrings(allowed_cmds)?;
+        let cmds = cmds_cstr.iter().map(|s| s.as_ptr()).collect::<Vec<_>>();
         let cmds_len = cmds.len();
 
         let ctx = unsafe {
@@ -189,6 +232,8 @@ impl ImportObject {
             false => Ok(Self {
                 inner: InnerImportObject(ctx),
                 registered: false,
+                host_funcs: Vec::new(),
+                shared_mems: Vec::new(),
             }),
         }
     }
@@ -200,11 +245,18 @@ impl ImportObject {
     /// - `allowed_cmds` specifies a white list of commands.
     ///
     /// - `allowed` determines if wasmedge_process is allowed to execute all commands on the white list.
-    pub fn init_wasmedge_process(&mut self, allowed_cmds: Option<Vec<&str>>, allowed: bool) {
-        let cmds = match allowed_cmds {
-            Some(cmds) => cmds.iter().map(string_to_c_char).collect::<Vec<_>>(),
-            None => vec![],
-        };
+    ///
+    /// # Error
+    ///
+    /// If any of the commands contains a nul byte, then a [FoundNulByte](crate::error::WasmEdgeError::FoundNulByte)
+    /// error is returned.
+    pub fn init_wasmedge_process(
+        &mut self,
+        allowed_cmds: Option<Vec<&str>>,
+        allowed: bool,
+    ) -> WasmEdgeResult<()> {
+        let cmds_cstr = strings_to_cstrings(allowed_cmds)?;
+        let cmds = cmds_cstr.iter().map(|s| s.as_ptr()).collect::<Vec<_>>();
         let cmds_len = cmds.len();
 
         unsafe {
@@ -214,7 +266,8 @@ impl ImportObject {
                 cmds_len as u32,
                 allowed,
             )
-        }
+        };
+        Ok(())
     }
 
     /// Adds a [host function](crate::Function) into the host module.
@@ -230,6 +283,10 @@ impl ImportObject {
             ffi::WasmEdge_ImportObjectAddFunction(self.inner.0, func_name.as_raw(), func.inner.0);
         }
         func.inner.0 = std::ptr::null_mut();
+        // the host module owns the function instance now, so it keeps the host function alive as well
+        if let Some(real_fn) = func.real_fn.take() {
+            self.host_funcs.push(real_fn);
+        }
     }
 
     /// Adds a [table](crate::Table) into the host module.
@@ -262,6 +319,30 @@ impl ImportObject {
         memory.inner.0 = std::ptr::null_mut();
     }
 
+    /// Adds a [shared memory](crate::SharedMemory) into the host module.
+    ///
+    /// Different from [add_memory](crate::ImportObject::add_memory), the host module does not take the ownership of
+    /// the shared memory but holds a handle of it. Therefore, the same shared memory can be added into the host
+    /// modules of different [Vm](crate::Vm)s, and the [Vm](crate::Vm)s can run in different threads.
+    ///
+    /// # Arguments
+    ///
+    /// - `name` specifies the name of the export memory in the host module.
+    ///
+    /// - `memory` specifies the shared memory to add.
+    pub fn add_shared_memory(&mut self, name: impl AsRef<str>, memory: &SharedMemory) {
+        let mem_name: WasmEdgeString = name.as_ref().into();
+        unsafe {
+            ffi::WasmEdge_ImportObjectAddSharedMemory(
+                self.inner.0,
+                mem_name.as_raw(),
+                memory.as_ptr(),
+            );
+        }
+        // the host module keeps the shared memory alive
+        self.shared_mems.push(memory.clone());
+    }
+
     /// Adds a [global](crate::Global) into the host module.
     ///
     /// # Arguments
@@ -294,8 +375,8 @@ unsafe impl Sync for InnerImportObject {}
 mod tests {
     use super::*;
     use crate::{
-        Config, Executor, FuncType, GlobalType, MemType, Mutability, RefType, Statistics, Store,
-        TableType, ValType, Vm, WasmValue,
+        error::Trap, Caller, Config, Executor, FuncType, GlobalType, MemType, Mutability, RefType,
+        Statistics, Store, TableType, ValType, Vm, WasmValue,
     };
     use std::{
         sync::{Arc, Mutex},
@@ -430,6 +511,12 @@ mod tests {
                 ]),
             );
             assert!(result.is_ok());
+            let result = ImportObject::create_wasi(None, Some(vec!["ENV1=\0"]), None);
+            assert!(result.is_err());
+            assert!(matches!(
+                result.unwrap_err(),
+                WasmEdgeError::FoundNulByte(_)
+            ));
             let result = ImportObject::create_wasi(
                 None,
                 Some(vec!["ENV1=VAL1", "ENV1=VAL2", "ENV3=VAL3"]),
@@ -442,9 +529,27 @@ mod tests {
                 ]),
             );
             assert!(result.is_ok());
-            let import_obj = result.unwrap();
+            let mut import_obj = result.unwrap();
 
             assert_eq!(import_obj.exit_code(), 0);
+
+            // redirect the standard streams
+            let result = import_obj.redirect_wasi_stdio(Some(0), Some(1), Some(2));
+            assert!(result.is_ok());
+            let result = import_obj.redirect_wasi_stdio(None, None, None);
+            assert!(result.is_ok());
+            let result = import_obj.redirect_wasi_stdio(None, Some(i32::MAX), None);
+            assert!(result.is_err());
+
+            // not a WASI import object
+            let result = ImportObject::create("extern");
+            assert!(result.is_ok());
+            let mut import_obj = result.unwrap();
+            let result = import_obj.redirect_wasi_stdio(None, None, None);
+            assert!(result.is_err());
+            let result = import_obj.init_wasi(None, None, None);
+            assert!(result.is_err());
+            assert_eq!(result.unwrap_err(), WasmEdgeError::ImportObjNotWasi);
         }
 
         // initialize WASI in VM
@@ -471,9 +576,18 @@ mod tests {
                 "ssvmAPICoreTests",
                 ".:.",
             ];
-            import_wasi.init_wasi(Some(args), Some(envs), Some(preopens));
+            let result = import_wasi.init_wasi(Some(args), Some(envs), Some(preopens));
+            assert!(result.is_ok());
 
             assert_eq!(import_wasi.exit_code(), 0);
+
+            // the strings with nul bytes are rejected
+            let result = import_wasi.init_wasi(Some(vec!["arg\0"]), None, None);
+            assert!(result.is_err());
+            assert!(matches!(
+                result.unwrap_err(),
+                WasmEdgeError::FoundNulByte(_)
+            ));
         }
     }
 
@@ -489,6 +603,13 @@ mod tests {
 
             let result = ImportObject::create_wasmedge_process(Some(vec!["arg1", "arg2"]), false);
             assert!(result.is_ok());
+
+            let result = ImportObject::create_wasmedge_process(Some(vec!["arg\0"]), false);
+            assert!(result.is_err());
+            assert!(matches!(
+                result.unwrap_err(),
+                WasmEdgeError::FoundNulByte(_)
+            ));
         }
 
         // initialize wasmedge_process in VM
@@ -504,7 +625,9 @@ mod tests {
             let result = vm.wasmedge_process_import_module_mut();
             assert!(result.is_ok());
             let mut import_wasmedge_process = result.unwrap();
-            import_wasmedge_process.init_wasmedge_process(Some(vec!["arg1", "arg2"]), false);
+            let result =
+                import_wasmedge_process.init_wasmedge_process(Some(vec!["arg1", "arg2"]), false);
+            assert!(result.is_ok());
         }
     }
 
@@ -633,21 +756,21 @@ mod tests {
         handle.join().unwrap();
     }
 
-    fn real_add(inputs: Vec<WasmValue>) -> Result<Vec<WasmValue>, u8> {
+    fn real_add(_caller: Caller, inputs: Vec<WasmValue>) -> Result<Vec<WasmValue>, Trap> {
         if inputs.len() != 2 {
-            return Err(1);
+            return Err(Trap::new("Invalid number of arguments"));
         }
 
         let a = if inputs[0].ty() == ValType::I32 {
             inputs[0].to_i32()
         } else {
-            return Err(2);
+            return Err(Trap::new("Invalid type of arguments"));
         };
 
         let b = if inputs[1].ty() == ValType::I32 {
             inputs[1].to_i32()
         } else {
-            return Err(3);
+            return Err(Trap::new("Invalid type of arguments"));
         };
 
         let c = a + b;
@@ -1,58 +1,97 @@
 //! Defines WasmEdge Function and FuncType structs.
 
 use crate::{
-    error::{FuncError, WasmEdgeError},
-    ffi, HostFunc, ValType, WasmEdgeResult, HOST_FUNCS,
+    error::{FuncError, Trap, WasmEdgeError},
+    ffi,
+    instance::memory::{MemoryRef, MemoryView},
+    io::{IntoHostFunc, WasmFnIO},
+    Engine, HostFunc, ValType, WasmEdgeResult, WasmValue,
 };
 use core::ffi::c_void;
-use rand::Rng;
-use std::convert::TryInto;
+use std::{
+    any::Any,
+    panic::{self, AssertUnwindSafe},
+};
 
 extern "C" fn wraper_fn(
-    key_ptr: *mut c_void,
-    _data: *mut c_void,
-    _mem_cxt: *mut ffi::WasmEdge_MemoryInstanceContext,
+    _this: *mut c_void,
+    data: *mut c_void,
+    mem_cxt: *mut ffi::WasmEdge_MemoryInstanceContext,
     params: *const ffi::WasmEdge_Value,
     param_len: u32,
     returns: *mut ffi::WasmEdge_Value,
     return_len: u32,
 ) -> ffi::WasmEdge_Result {
-    let key = key_ptr as *const usize as usize;
-
-    let input = {
-        let raw_input = unsafe {
-            std::slice::from_raw_parts(
-                params,
-                param_len
-                    .try_into()
-                    .expect("len of params should not greater than usize"),
-            )
-        };
-        raw_input.iter().map(|r| (*r).into()).collect::<Vec<_>>()
-    };
+    // the data pointer is the host function data owned by the Function instance
+    let data = unsafe { &*(data as *const HostFuncData) };
 
-    let return_len = return_len
-        .try_into()
-        .expect("len of returns should not greater than usize");
-    let raw_returns = unsafe { std::slice::from_raw_parts_mut(returns, return_len) };
-
-    let result = {
-        let host_functions = HOST_FUNCS.lock().expect("[wasmedge-sys] try lock failed.");
-        let real_fn = host_functions
-            .get(&key)
-            .expect("host function should be there");
-        real_fn(input)
+    // WasmEdge passes null pointers for the empty parameters and returns
+    let input = match param_len {
+        0 => Vec::new(),
+        len => {
+            let raw_input = unsafe { std::slice::from_raw_parts(params, len as usize) };
+            raw_input.iter().map(|r| (*r).into()).collect::<Vec<_>>()
+        }
     };
 
+    // a panic must not unwind across the FFI boundary, so it is caught and turned into a trap
+    let result = panic::catch_unwind(AssertUnwindSafe(|| {
+        (data.real_fn)(Caller::new(mem_cxt), input)
+    }))
+    .unwrap_or_else(|payload| {
+        Err(Trap::new(format!(
+            "The host function panicked: {}",
+            panic_message(payload.as_ref())
+        )))
+    })
+    .and_then(|v| match v.iter().map(|x| x.ty()).eq(data.return_tys.iter().copied()) {
+        true => Ok(v),
+        false => Err(Trap::new(format!(
+            "The returns of the host function do not match the function type: expected {:?}, found {:?}",
+            data.return_tys,
+            v.iter().map(|x| x.ty()).collect::<Vec<_>>()
+        ))),
+    });
+
     match result {
         Ok(v) => {
-            assert!(v.len() == return_len);
-            for (idx, item) in v.into_iter().enumerate() {
-                raw_returns[idx] = item.as_raw();
+            if return_len > 0 {
+                let raw_returns =
+                    unsafe { std::slice::from_raw_parts_mut(returns, return_len as usize) };
+                for (idx, item) in v.into_iter().enumerate() {
+                    raw_returns[idx] = item.as_raw();
+                }
             }
             ffi::WasmEdge_Result { Code: 0 }
         }
-        Err(c) => ffi::WasmEdge_Result { Code: c as u8 },
+        Err(trap) if trap.is_terminate() => ffi::WasmEdge_Result { Code: 0x01 },
+        Err(trap) => {
+            // WasmEdge reports any failure of a host function as `ExecutionFailed`, so the trap is attached to the
+            // failed execution, where the caller of the execution finds it
+            unsafe {
+                ffi::WasmEdge_HostFunctionSetFailureData(
+                    Box::into_raw(Box::new(trap)) as *mut c_void,
+                    Some(drop_trap),
+                )
+            };
+            ffi::WasmEdge_Result { Code: 0x02 }
+        }
+    }
+}
+
+// releases the trap attached to a failed execution
+unsafe extern "C" fn drop_trap(trap: *mut c_void) {
+    drop(Box::from_raw(trap as *mut Trap));
+}
+
+// extracts the message from the payload of a panic
+fn panic_message(payload: &(dyn Any + Send)) -> &str {
+    match payload.downcast_ref::<&str>() {
+        Some(msg) => msg,
+        None => match payload.downcast_ref::<String>() {
+            Some(msg) => msg.as_str(),
+            None => "Box<dyn Any>",
+        },
     }
 }
 
@@ -67,6 +106,7 @@ pub struct Function {
     pub(crate) registered: bool,
     pub(crate) name: Option<String>,
     pub(crate) mod_name: Option<String>,
+    pub(crate) real_fn: Option<InnerHostFunc>,
 }
 impl Function {
     #[allow(clippy::type_complexity)]
@@ -90,23 +130,23 @@ impl Function {
     /// the `create_binding` method.
     ///
     /// ```rust
-    /// use wasmedge_sys::{FuncType, Function, ValType, WasmValue, WasmEdgeResult};
+    /// use wasmedge_sys::{error::Trap, Caller, FuncType, Function, ValType, WasmValue, WasmEdgeResult};
     ///
-    /// fn real_add(inputs: Vec<WasmValue>) -> Result<Vec<WasmValue>, u8> {
+    /// fn real_add(_caller: Caller, inputs: Vec<WasmValue>) -> Result<Vec<WasmValue>, Trap> {
     ///     if inputs.len() != 2 {
-    ///         return Err(1);
+    ///         return Err(Trap::new("Invalid number of arguments"));
     ///     }
     ///
     ///     let a = if inputs[0].ty() == ValType::I32 {
     ///         inputs[0].to_i32()
     ///     } else {
-    ///         return Err(2);
+    ///         return Err(Trap::new("Invalid type of arguments"));
     ///     };
     ///
     ///     let b = if inputs[1].ty() == ValType::I32 {
     ///         inputs[1].to_i32()
     ///     } else {
-    ///         return Err(3);
+    ///         return Err(Trap::new("Invalid type of arguments"));
     ///     };
     ///
     ///     let c = a + b;
@@ -121,28 +161,17 @@ impl Function {
     /// let func = Function::create(&func_ty, Box::new(real_add), 0).expect("fail to create a Function instance");
     /// ```
     pub fn create(ty: &FuncType, real_fn: HostFunc, cost: u64) -> WasmEdgeResult<Self> {
-        let mut host_functions = HOST_FUNCS.lock().expect("[wasmedge-sys] try lock failed.");
-        if host_functions.len() >= host_functions.capacity() {
-            return Err(WasmEdgeError::Func(FuncError::CreateBinding(format!(
-                "The number of the host functions reaches the upper bound: {}",
-                host_functions.capacity()
-            ))));
-        }
-
-        // generate key for the coming host function
-        let mut rng = rand::thread_rng();
-        let mut key: usize = rng.gen();
-        while host_functions.contains_key(&key) {
-            key = rng.gen();
-        }
-        host_functions.insert(key, real_fn);
+        let real_fn = InnerHostFunc(Box::into_raw(Box::new(HostFuncData {
+            real_fn,
+            return_tys: ty.returns_type_iter().collect(),
+        })));
 
         let ctx = unsafe {
             ffi::WasmEdge_FunctionInstanceCreateBinding(
                 ty.inner.0,
                 Some(wraper_fn),
-                key as *const usize as *mut c_void,
                 std::ptr::null_mut(),
+                real_fn.0 as *mut c_void,
                 cost,
             )
         };
@@ -154,10 +183,53 @@ impl Function {
                 registered: false,
                 name: None,
                 mod_name: None,
+                real_fn: Some(real_fn),
             }),
         }
     }
 
+    /// Creates a [host function](crate::Function) from a Rust closure or function with typed arguments and returns.
+    ///
+    /// The [FuncType] of the host function is derived from the signature of `real_fn`, and the arguments and returns
+    /// are converted between [WasmValue](crate::WasmValue)s and the Rust types automatically. The supported argument
+    /// types are listed in [WasmValType](crate::io::WasmValType), and the return type can be `()`, a single value, or
+    /// a tuple of values.
+    ///
+    /// # Argument
+    ///
+    /// - `real_fn` specifies the closure or function to wrap.
+    ///
+    /// # Error
+    ///
+    /// If fail to create a [Function], then an error is returned.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use wasmedge_sys::{Function, ValType};
+    ///
+    /// // create a host function: (i32, i64) -> f32
+    /// let func = Function::wrap(|a: i32, b: i64| (a as i64 + b) as f32).expect("fail to create a Function instance");
+    ///
+    /// let ty = func.ty().expect("fail to get the function type");
+    /// assert_eq!(ty.params_type_iter().collect::<Vec<_>>(), vec![ValType::I32, ValType::I64]);
+    /// assert_eq!(ty.returns_type_iter().collect::<Vec<_>>(), vec![ValType::F32]);
+    /// ```
+    pub fn wrap<Args, Rets>(real_fn: impl IntoHostFunc<Args, Rets>) -> WasmEdgeResult<Self>
+    where
+        Args: WasmFnIO,
+        Rets: WasmFnIO,
+    {
+        let ty = FuncType::create(Args::types(), Rets::types())?;
+        let real_fn: HostFunc = Box::new(move |_caller, inputs| {
+            // the inputs are checked against the function type by WasmEdge before the call
+            let args = Args::from_values(&inputs)
+                .ok_or_else(|| Trap::new("The arguments do not match the function type"))?;
+            Ok(real_fn.call(args).into_values())
+        });
+        Self::create(&ty, real_fn, 0)
+    }
+
     /// Returns the name of the host function.
     pub fn name(&self) -> Option<&str> {
         match &self.name {
@@ -190,6 +262,53 @@ impl Function {
             }),
         }
     }
+
+    /// Runs this [Function] by the given [engine](crate::Engine), and returns the results.
+    ///
+    /// The [Function] can be the one found in a [store](crate::Store) or a module [instance](crate::Instance), obtained
+    /// from a function reference by [func_ref](crate::WasmValue::func_ref), or created on the host. Either a
+    /// [Vm](crate::Vm), or an [Executor](crate::Executor) paired with the [store](crate::Store) which the module
+    /// instance owning the [Function] is instantiated in, can be the engine.
+    ///
+    /// # Arguments
+    ///
+    /// - `engine` specifies the engine to run this [Function].
+    ///
+    /// - `params` specifies the argument values for this [Function].
+    ///
+    /// # Error
+    ///
+    /// If fail to run this [Function], then an error is returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use wasmedge_sys::{Executor, FuncType, Function, Store, ValType, WasmValue};
+    ///
+    /// // create a host function
+    /// let ty = FuncType::create([ValType::I32; 2], [ValType::I32]).expect("fail to create a FuncType");
+    /// let func = Function::create(
+    ///     &ty,
+    ///     Box::new(|_caller, inputs| Ok(vec![WasmValue::from_i32(inputs[0].to_i32() + inputs[1].to_i32())])),
+    ///     0,
+    /// )
+    /// .expect("fail to create a Function");
+    ///
+    /// // run the host function by an executor
+    /// let mut executor = Executor::create(None, None).expect("fail to create an Executor");
+    /// let mut store = Store::create().expect("fail to create a Store");
+    /// let returns = func
+    ///     .call((&mut executor, &mut store), [WasmValue::from_i32(1), WasmValue::from_i32(2)])
+    ///     .expect("fail to call the Function");
+    /// assert_eq!(returns[0].to_i32(), 3);
+    /// ```
+    pub fn call<E: Engine>(
+        &self,
+        engine: E,
+        params: impl IntoIterator<Item = WasmValue>,
+    ) -> WasmEdgeResult<Vec<WasmValue>> {
+        engine.run_func_ref(self, params)
+    }
 }
 impl Drop for Function {
     fn drop(&mut self) {
@@ -204,6 +323,95 @@ pub(crate) struct InnerFunc(pub(crate) *mut ffi::WasmEdge_FunctionInstanceContex
 unsafe impl Send for InnerFunc {}
 unsafe impl Sync for InnerFunc {}
 
+/// The data passed to WasmEdge as the `data` pointer of a host function instance.
+pub(crate) struct HostFuncData {
+    real_fn: HostFunc,
+    // the types of the returns, which are checked against the values returned by `real_fn`
+    return_tys: Vec<ValType>,
+}
+
+/// Owns the boxed [HostFuncData] passed to WasmEdge as the `data` pointer of a host function instance.
+///
+/// It must outlive the function instance, so it is moved along with the instance, for example, into the
+/// [ImportObject](crate::ImportObject) hosting the function.
+#[derive(Debug)]
+pub(crate) struct InnerHostFunc(pub(crate) *mut HostFuncData);
+impl Drop for InnerHostFunc {
+    fn drop(&mut self) {
+        if !self.0.is_null() {
+            unsafe { drop(Box::from_raw(self.0)) };
+        }
+    }
+}
+unsafe impl Send for InnerHostFunc {}
+unsafe impl Sync for InnerHostFunc {}
+
+/// Struct of WasmEdge Caller.
+///
+/// A [Caller] is passed to a [host function](crate::Function) on every call. It provides the host function with the
+/// access to the [memory](crate::Memory) of the module instance which calls it, so that strings and buffers can be
+/// passed between the guest and the host.
+#[derive(Debug)]
+pub struct Caller {
+    mem_cxt: *mut ffi::WasmEdge_MemoryInstanceContext,
+}
+impl Caller {
+    pub(crate) fn new(mem_cxt: *mut ffi::WasmEdge_MemoryInstanceContext) -> Self {
+        Self { mem_cxt }
+    }
+
+    /// Returns the [memory](crate::Memory) of the module instance which calls the host function.
+    ///
+    /// The returned [MemoryRef](crate::MemoryRef) borrows the [Caller], so it can not be kept after the host function
+    /// returns. If the calling module instance has no memory, then `None` is returned.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use wasmedge_sys::{error::Trap, Caller, WasmValue};
+    ///
+    /// // reads a string from the caller's memory, and returns its length
+    /// fn str_len(caller: Caller, inputs: Vec<WasmValue>) -> Result<Vec<WasmValue>, Trap> {
+    ///     let mem = caller.memory().ok_or_else(|| Trap::new("no memory"))?;
+    ///     let s = mem.read_string(inputs[0].to_i32() as u32 as u64, inputs[1].to_i32() as u32)?;
+    ///     Ok(vec![WasmValue::from_i32(s.chars().count() as i32)])
+    /// }
+    /// ```
+    pub fn memory(&self) -> Option<MemoryRef<'_>> {
+        match self.mem_cxt.is_null() {
+            true => None,
+            false => Some(MemoryRef::new(self.mem_cxt)),
+        }
+    }
+
+    /// Returns a [view](crate::MemoryView) of the [memory](crate::Memory) of the module instance which calls the host
+    /// function.
+    ///
+    /// If the calling module instance has no memory, then `None` is returned.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use wasmedge_sys::{error::Trap, Caller, WasmValue};
+    ///
+    /// // counts the zero bytes in the given range of the caller's memory without copying it
+    /// fn count_zeros(mut caller: Caller, inputs: Vec<WasmValue>) -> Result<Vec<WasmValue>, Trap> {
+    ///     let view = caller.memory_view().ok_or_else(|| Trap::new("no memory"))?;
+    ///     // the host function runs no WASM function while the slice is alive
+    ///     let data =
+    ///         unsafe { view.as_slice(inputs[0].to_i32() as u32 as u64, inputs[1].to_i32() as u32) }?;
+    ///     let count = data.iter().filter(|x| **x == 0).count();
+    ///     Ok(vec![WasmValue::from_i32(count as i32)])
+    /// }
+    /// ```
+    pub fn memory_view(&mut self) -> Option<MemoryView<'_>> {
+        match self.mem_cxt.is_null() {
+            true => None,
+            false => Some(MemoryView::new(self.mem_cxt)),
+        }
+    }
+}
+
 /// Struct of WasmEdge FuncType.
 ///
 /// A WasmEdge [FuncType] classifies the signature of a [Function], including the type information of both the arguments and the returns.
@@ -312,7 +520,7 @@ unsafe impl Sync for InnerFuncType {}
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::{Executor, ImportObject, Store, ValType, WasmValue};
+    use crate::{Executor, ImportObject, MemType, Memory, Store, ValType, WasmValue};
     use std::{
         sync::{Arc, Mutex},
         thread,
@@ -402,6 +610,264 @@ mod tests {
         assert_eq!(return_tys, vec![ValType::I32]);
     }
 
+    #[test]
+    fn test_func_host_data() {
+        // create a FuncType
+        let result = FuncType::create(vec![ValType::I32; 2], vec![ValType::I32]);
+        assert!(result.is_ok());
+        let func_ty = result.unwrap();
+
+        // the host function is dropped along with the Function instance
+        let counter = Arc::new(0);
+        {
+            let cloned = Arc::clone(&counter);
+            let result = Function::create(
+                &func_ty,
+                Box::new(move |caller, inputs| {
+                    let _ = &cloned;
+                    real_add(caller, inputs)
+                }),
+                0,
+            );
+            assert!(result.is_ok());
+            let host_func = result.unwrap();
+            assert!(host_func.real_fn.is_some());
+            assert_eq!(Arc::strong_count(&counter), 2);
+        }
+        assert_eq!(Arc::strong_count(&counter), 1);
+
+        // the host function is kept alive by the ImportObject hosting the Function instance
+        {
+            let result = ImportObject::create("extern");
+            assert!(result.is_ok());
+            let mut import = result.unwrap();
+            {
+                let cloned = Arc::clone(&counter);
+                let result = Function::create(
+                    &func_ty,
+                    Box::new(move |caller, inputs| {
+                        let _ = &cloned;
+                        real_add(caller, inputs)
+                    }),
+                    0,
+                );
+                assert!(result.is_ok());
+                let host_func = result.unwrap();
+                import.add_func("add", host_func);
+            }
+            assert_eq!(Arc::strong_count(&counter), 2);
+        }
+        assert_eq!(Arc::strong_count(&counter), 1);
+
+        // the number of the host functions is not limited
+        let funcs = (0..1000)
+            .map(|_| Function::create(&func_ty, Box::new(real_add), 0))
+            .collect::<Vec<_>>();
+        assert!(funcs.iter().all(|result| result.is_ok()));
+    }
+
+    #[test]
+    fn test_func_wrap() {
+        // wrap a closure: (i32, i32) -> i32
+        let result = Function::wrap(|a: i32, b: i32| a + b);
+        assert!(result.is_ok());
+        let host_func = result.unwrap();
+        let result = host_func.ty();
+        assert!(result.is_ok());
+        let ty = result.unwrap();
+        assert_eq!(
+            ty.params_type_iter().collect::<Vec<_>>(),
+            vec![ValType::I32; 2]
+        );
+        assert_eq!(
+            ty.returns_type_iter().collect::<Vec<_>>(),
+            vec![ValType::I32]
+        );
+
+        // wrap a closure without arguments and returns
+        let result = Function::wrap(|| {});
+        assert!(result.is_ok());
+        let host_func = result.unwrap();
+        let result = host_func.ty();
+        assert!(result.is_ok());
+        let ty = result.unwrap();
+        assert_eq!(ty.params_len(), 0);
+        assert_eq!(ty.returns_len(), 0);
+
+        // wrap a closure with multiple returns
+        let result = Function::wrap(|a: f64, b: u64| (b as f64 * a, a as i128));
+        assert!(result.is_ok());
+        let host_func = result.unwrap();
+        let result = host_func.ty();
+        assert!(result.is_ok());
+        let ty = result.unwrap();
+        assert_eq!(
+            ty.params_type_iter().collect::<Vec<_>>(),
+            vec![ValType::F64, ValType::I64]
+        );
+        assert_eq!(
+            ty.returns_type_iter().collect::<Vec<_>>(),
+            vec![ValType::F64, ValType::V128]
+        );
+
+        // call the wrapped host function
+        let result = Function::wrap(|a: i32, b: i32| a + b);
+        assert!(result.is_ok());
+        let host_func = result.unwrap();
+
+        let result = ImportObject::create("extern");
+        assert!(result.is_ok());
+        let mut import = result.unwrap();
+        import.add_func("add", host_func);
+
+        let result = Executor::create(None, None);
+        assert!(result.is_ok());
+        let mut executor = result.unwrap();
+        let result = Store::create();
+        assert!(result.is_ok());
+        let mut store = result.unwrap();
+        let result = executor.register_import_object(&mut store, &import);
+        assert!(result.is_ok());
+
+        let result = executor.run_func_registered(
+            &mut store,
+            "extern",
+            "add",
+            [WasmValue::from_i32(2), WasmValue::from_i32(3)],
+        );
+        assert!(result.is_ok());
+        let returns = result.unwrap();
+        assert_eq!(returns[0].to_i32(), 5);
+    }
+
+    #[test]
+    fn test_func_trap() {
+        #[derive(Debug, PartialEq)]
+        struct MyError(i32);
+        impl std::fmt::Display for MyError {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                write!(f, "my error: {}", self.0)
+            }
+        }
+        impl std::error::Error for MyError {}
+
+        let result = FuncType::create(vec![ValType::I32], vec![]);
+        assert!(result.is_ok());
+        let func_ty = result.unwrap();
+
+        // a host function failing with a custom error
+        let result = Function::create(
+            &func_ty,
+            Box::new(|_caller, inputs| Err(MyError(inputs[0].to_i32()).into())),
+            0,
+        );
+        assert!(result.is_ok());
+        let fail_func = result.unwrap();
+
+        // a host function terminating the execution
+        let result = Function::create(
+            &func_ty,
+            Box::new(|_caller, _inputs| Err(Trap::terminate())),
+            0,
+        );
+        assert!(result.is_ok());
+        let exit_func = result.unwrap();
+
+        let result = ImportObject::create("extern");
+        assert!(result.is_ok());
+        let mut import = result.unwrap();
+        import.add_func("fail", fail_func);
+        import.add_func("exit", exit_func);
+
+        let result = Executor::create(None, None);
+        assert!(result.is_ok());
+        let mut executor = result.unwrap();
+        let result = Store::create();
+        assert!(result.is_ok());
+        let mut store = result.unwrap();
+        let result = executor.register_import_object(&mut store, &import);
+        assert!(result.is_ok());
+
+        // the trap is returned to the caller
+        let result =
+            executor.run_func_registered(&mut store, "extern", "fail", [WasmValue::from_i32(7)]);
+        assert!(result.is_err());
+        let trap = match result.unwrap_err() {
+            WasmEdgeError::Trap(trap) => trap,
+            err => panic!("unexpected error: {}", err),
+        };
+        assert_eq!(trap.message(), "my error: 7");
+        assert!(!trap.is_terminate());
+        assert_eq!(trap.downcast_ref::<MyError>(), Some(&MyError(7)));
+
+        // terminating the execution is not an error
+        let result =
+            executor.run_func_registered(&mut store, "extern", "exit", [WasmValue::from_i32(0)]);
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn test_func_panic() {
+        let result = FuncType::create(vec![], vec![ValType::I32]);
+        assert!(result.is_ok());
+        let func_ty = result.unwrap();
+
+        // a host function which panics
+        let result = Function::create(&func_ty, Box::new(|_caller, _inputs| panic!("boom")), 0);
+        assert!(result.is_ok());
+        let panic_func = result.unwrap();
+
+        // a host function returning too many values
+        let result = Function::create(
+            &func_ty,
+            Box::new(|_caller, _inputs| Ok(vec![WasmValue::from_i32(1), WasmValue::from_i32(2)])),
+            0,
+        );
+        assert!(result.is_ok());
+        let arity_func = result.unwrap();
+
+        // a host function returning a value of the wrong type
+        let result = Function::create(
+            &func_ty,
+            Box::new(|_caller, _inputs| Ok(vec![WasmValue::from_f64(1.0)])),
+            0,
+        );
+        assert!(result.is_ok());
+        let type_func = result.unwrap();
+
+        let result = ImportObject::create("extern");
+        assert!(result.is_ok());
+        let mut import = result.unwrap();
+        import.add_func("panic", panic_func);
+        import.add_func("arity", arity_func);
+        import.add_func("type", type_func);
+
+        let result = Executor::create(None, None);
+        assert!(result.is_ok());
+        let mut executor = result.unwrap();
+        let result = Store::create();
+        assert!(result.is_ok());
+        let mut store = result.unwrap();
+        let result = executor.register_import_object(&mut store, &import);
+        assert!(result.is_ok());
+
+        // the panic is surfaced as a trap with the panic message
+        let result = executor.run_func_registered(&mut store, "extern", "panic", []);
+        assert!(result.is_err());
+        match result.unwrap_err() {
+            WasmEdgeError::Trap(trap) => {
+                assert_eq!(trap.message(), "The host function panicked: boom")
+            }
+            err => panic!("unexpected error: {}", err),
+        }
+
+        // the mismatched returns are reported as traps
+        let result = executor.run_func_registered(&mut store, "extern", "arity", []);
+        assert!(matches!(result, Err(WasmEdgeError::Trap(_))));
+        let result = executor.run_func_registered(&mut store, "extern", "type", []);
+        assert!(matches!(result, Err(WasmEdgeError::Trap(_))));
+    }
+
     #[test]
     fn test_func_call() {
         // create a FuncType
@@ -439,6 +905,72 @@ mod tests {
         // get the exported host function
         let result = instance.find_func("add");
         assert!(result.is_ok());
+        let add = result.unwrap();
+
+        // call the host function by the executor
+        let result = add.call(
+            (&mut executor, &mut store),
+            [WasmValue::from_i32(2), WasmValue::from_i32(3)],
+        );
+        assert!(result.is_ok());
+        let returns = result.unwrap();
+        assert_eq!(returns.len(), 1);
+        assert_eq!(returns[0].to_i32(), 5);
+
+        // call the host function with the wrong arguments
+        let result = add.call((&mut executor, &mut store), [WasmValue::from_i32(2)]);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_func_caller() {
+        // a caller without memory
+        let caller = Caller::new(std::ptr::null_mut());
+        assert!(caller.memory().is_none());
+
+        // a caller with memory
+        let result = MemType::create(1..=2);
+        assert!(result.is_ok());
+        let ty = result.unwrap();
+        let result = Memory::create(&ty);
+        assert!(result.is_ok());
+        let mut mem = result.unwrap();
+        let result = mem.write_string("hello", 0x10);
+        assert!(result.is_ok());
+
+        let caller = Caller::new(mem.inner.0);
+        let result = caller.memory();
+        assert!(result.is_some());
+        let mut caller_mem = result.unwrap();
+        assert_eq!(caller_mem.size(), 1);
+        let result = caller_mem.read_string(0x10, 5);
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap(), "hello");
+
+        // write through the caller's memory
+        let result = caller_mem.write_string("world", 0x20);
+        assert!(result.is_ok());
+        let result = mem.read_string(0x20, 5);
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap(), "world");
+
+        // read invalid UTF-8 data
+        let result = mem.set_data([0xff, 0xfe], 0x30);
+        assert!(result.is_ok());
+        let result = caller_mem.read_string(0x30, 2);
+        assert!(result.is_err());
+
+        // grow through the caller's memory
+        let result = caller_mem.grow(1);
+        assert!(result.is_ok());
+        assert_eq!(caller_mem.size(), 2);
+
+        // dropping the caller's memory does not delete the memory
+        drop(caller_mem);
+        assert_eq!(mem.size(), 2);
+        let result = mem.read_string(0x10, 5);
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap(), "hello");
     }
 
     #[test]
@@ -508,23 +1040,23 @@ mod tests {
         handle.join().unwrap();
     }
 
-    fn real_add(input: Vec<WasmValue>) -> Result<Vec<WasmValue>, u8> {
+    fn real_add(_caller: Caller, input: Vec<WasmValue>) -> Result<Vec<WasmValue>, Trap> {
         println!("Rust: Entering Rust function real_add");
 
         if input.len() != 2 {
-            return Err(1);
+            return Err(Trap::new("Invalid number of arguments"));
         }
 
         let a = if input[0].ty() == ValType::I32 {
             input[0].to_i32()
         } else {
-            return Err(2);
+            return Err(Trap::new("Invalid type of arguments"));
         };
 
         let b = if input[1].ty() == ValType::I32 {
             input[1].to_i32()
         } else {
-            return Err(3);
+            return Err(Trap::new("Invalid type of arguments"));
         };
 
         let c = a + b;
@@ -6,10 +6,13 @@
 //! restricts the size to which the memory can grow later.
 
 use crate::{
-    error::{check, MemError, WasmEdgeError},
+    error::{check, CoreError, CoreExecutionError, MemError, WasmEdgeError},
     ffi, WasmEdgeResult,
 };
-use std::ops::RangeInclusive;
+use std::{marker::PhantomData, ops::RangeInclusive, sync::Arc};
+
+/// The size in bytes of a WebAssembly page.
+pub(crate) const PAGE_SIZE: usize = 65536;
 
 /// Struct of WasmEdge Memory.
 ///
@@ -83,7 +86,7 @@ impl Memory {
     ///
     /// If the `offset + len` is larger than the data size in the [Memory], then an error is returned.
     ///
-    pub fn get_data(&self, offset: u32, len: u32) -> WasmEdgeResult<Vec<u8>> {
+    pub fn get_data(&self, offset: u64, len: u32) -> WasmEdgeResult<Vec<u8>> {
         let mut data = Vec::with_capacity(len as usize);
         unsafe {
             check(ffi::WasmEdge_MemoryInstanceGetData(
@@ -119,7 +122,7 @@ impl Memory {
     /// let mut mem = Memory::create(&ty).expect("fail to create a Memory");
     ///
     /// // set data and the data length is larger than the data size in the memory
-    /// let result = mem.set_data(vec![1; 10], u32::pow(2, 16) - 9);
+    /// let result = mem.set_data(vec![1; 10], u64::pow(2, 16) - 9);
     /// assert!(result.is_err());
     /// assert_eq!(result.unwrap_err(), WasmEdgeError::Core(CoreError::Execution(CoreExecutionError::MemoryOutOfBounds)));
     /// ```
@@ -147,7 +150,7 @@ impl Memory {
     pub fn set_data(
         &mut self,
         data: impl IntoIterator<Item = u8>,
-        offset: u32,
+        offset: u64,
     ) -> WasmEdgeResult<()> {
         let data = data.into_iter().collect::<Vec<u8>>();
         unsafe {
@@ -160,8 +163,57 @@ impl Memory {
         }
     }
 
+    /// Reads a UTF-8 string from the [Memory].
+    ///
+    /// # Arguments
+    ///
+    /// - `offset` specifies the data start offset in the [Memory].
+    ///
+    /// - `len` specifies the length in bytes of the string.
+    ///
+    /// # Errors
+    ///
+    /// If the `offset + len` is larger than the data size in the [Memory], or the data is not valid UTF-8, then an
+    /// error is returned.
+    pub fn read_string(&self, offset: u64, len: u32) -> WasmEdgeResult<String> {
+        let data = self.get_data(offset, len)?;
+        String::from_utf8(data).map_err(|e| WasmEdgeError::Utf8(e.utf8_error()))
+    }
+
+    /// Writes the bytes of a string into the [Memory].
+    ///
+    /// # Arguments
+    ///
+    /// - `s` specifies the string to write.
+    ///
+    /// - `offset` specifies the data start offset in the [Memory].
+    ///
+    /// # Errors
+    ///
+    /// If the sum of the `offset` and the length of the string is larger than the size of the [Memory], then an error
+    /// is returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use wasmedge_sys::{MemType, Memory};
+    ///
+    /// let ty = MemType::create(1..=2).expect("fail to create a memory type");
+    /// let mut mem = Memory::create(&ty).expect("fail to create a Memory");
+    ///
+    /// mem.write_string("hello", 10).expect("fail to write the string");
+    /// let s = mem.read_string(10, 5).expect("fail to read the string");
+    /// assert_eq!(s, "hello");
+    /// ```
+    pub fn write_string(&mut self, s: impl AsRef<str>, offset: u64) -> WasmEdgeResult<()> {
+        self.set_data(s.as_ref().bytes(), offset)
+    }
+
     /// Returns the const data pointer to the [Memory].
     ///
+    /// Notice that the returned reference covers a single byte only. Use [as_slice](crate::Memory::as_slice) to
+    /// access a range of the data.
+    ///
     /// # Arguments
     ///
     /// - `offset` specifies the data start offset in the [Memory].
@@ -174,7 +226,8 @@ impl Memory {
     ///
     /// If fail to get the data pointer, then an error is returned.
     ///
-    pub fn data_pointer(&self, offset: u32, len: u32) -> WasmEdgeResult<&u8> {
+    #[deprecated(note = "use `as_slice` instead")]
+    pub fn data_pointer(&self, offset: u64, len: u32) -> WasmEdgeResult<&u8> {
         let ptr = unsafe { ffi::WasmEdge_MemoryInstanceGetPointerConst(self.inner.0, offset, len) };
         match ptr.is_null() {
             true => Err(WasmEdgeError::Mem(MemError::ConstPtr)),
@@ -190,6 +243,9 @@ impl Memory {
 
     /// Returns the data pointer to the [Memory].
     ///
+    /// Notice that the returned reference covers a single byte only. Use [as_mut_slice](crate::Memory::as_mut_slice)
+    /// to access a range of the data.
+    ///
     /// # Arguments
     ///
     /// - `offset` specifies the data start offset in the [Memory].
@@ -201,7 +257,8 @@ impl Memory {
     ///
     /// If fail to get the data pointer, then an error is returned.
     ///
-    pub fn data_pointer_mut(&mut self, offset: u32, len: u32) -> WasmEdgeResult<&mut u8> {
+    #[deprecated(note = "use `as_mut_slice` instead")]
+    pub fn data_pointer_mut(&mut self, offset: u64, len: u32) -> WasmEdgeResult<&mut u8> {
         let ptr = unsafe { ffi::WasmEdge_MemoryInstanceGetPointer(self.inner.0, offset, len) };
         match ptr.is_null() {
             true => Err(WasmEdgeError::Mem(MemError::MutPtr)),
@@ -215,9 +272,138 @@ impl Memory {
         }
     }
 
+    /// Returns a slice of the data in the [Memory] without copying it.
+    ///
+    /// Use [get_data](crate::Memory::get_data) to copy the data out of the [Memory] without the safety requirements.
+    ///
+    /// # Arguments
+    ///
+    /// - `offset` specifies the data start offset in the [Memory].
+    ///
+    /// - `len` specifies the requested data length.
+    ///
+    /// # Errors
+    ///
+    /// If the `offset + len` is larger than the data size in the [Memory], then an error is returned.
+    ///
+    /// # Safety
+    ///
+    /// The slice points into the data of the [Memory], which is reallocated when the [Memory] grows. The caller must
+    /// ensure that the memory instance is not grown while the slice is alive, neither through another handle of the
+    /// same memory instance nor by any WASM function, including the functions run by other threads and the ones
+    /// re-entered by a host function.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use wasmedge_sys::{MemType, Memory};
+    ///
+    /// let ty = MemType::create(1..=2).expect("fail to create a memory type");
+    /// let mut mem = Memory::create(&ty).expect("fail to create a Memory");
+    ///
+    /// mem.set_data(vec![1, 2, 3], 10).expect("fail to set data");
+    /// // the memory is not grown while the slice is alive
+    /// let data = unsafe { mem.as_slice(10, 3) }.expect("fail to get the slice");
+    /// assert_eq!(data, &[1, 2, 3]);
+    /// ```
+    pub unsafe fn as_slice(&self, offset: u64, len: u32) -> WasmEdgeResult<&[u8]> {
+        self.check_bound(offset, len)?;
+        if len == 0 {
+            return Ok(&[]);
+        }
+        let ptr = ffi::WasmEdge_MemoryInstanceGetPointerConst(self.inner.0, offset, len);
+        match ptr.is_null() {
+            true => Err(WasmEdgeError::Mem(MemError::ConstPtr)),
+            false => Ok(std::slice::from_raw_parts(ptr, len as usize)),
+        }
+    }
+
+    /// Returns a mutable slice of the data in the [Memory] without copying it.
+    ///
+    /// Use [set_data](crate::Memory::set_data) to copy the data into the [Memory] without the safety requirements.
+    ///
+    /// # Arguments
+    ///
+    /// - `offset` specifies the data start offset in the [Memory].
+    ///
+    /// - `len` specifies the requested data length.
+    ///
+    /// # Errors
+    ///
+    /// If the `offset + len` is larger than the data size in the [Memory], then an error is returned.
+    ///
+    /// # Safety
+    ///
+    /// The slice points into the data of the [Memory], which is reallocated when the [Memory] grows. The caller must
+    /// ensure that the memory instance is not grown while the slice is alive, neither through another handle of the
+    /// same memory instance nor by any WASM function, including the functions run by other threads and the ones
+    /// re-entered by a host function. The caller must also ensure that the data is not accessed through other
+    /// handles while the slice is alive.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use wasmedge_sys::{MemType, Memory};
+    ///
+    /// let ty = MemType::create(1..=2).expect("fail to create a memory type");
+    /// let mut mem = Memory::create(&ty).expect("fail to create a Memory");
+    ///
+    /// // the memory is neither grown nor accessed by others while the slice is alive
+    /// let data = unsafe { mem.as_mut_slice(10, 3) }.expect("fail to get the slice");
+    /// data.copy_from_slice(&[1, 2, 3]);
+    /// assert_eq!(mem.get_data(10, 3).expect("fail to get data"), vec![1, 2, 3]);
+    /// ```
+    pub unsafe fn as_mut_slice(&mut self, offset: u64, len: u32) -> WasmEdgeResult<&mut [u8]> {
+        self.check_bound(offset, len)?;
+        if len == 0 {
+            return Ok(&mut []);
+        }
+        let ptr = ffi::WasmEdge_MemoryInstanceGetPointer(self.inner.0, offset, len);
+        match ptr.is_null() {
+            true => Err(WasmEdgeError::Mem(MemError::MutPtr)),
+            false => Ok(std::slice::from_raw_parts_mut(ptr, len as usize)),
+        }
+    }
+
+    /// Returns a [MemoryView] of the [Memory], which can not be grown through this handle while the view is alive.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use wasmedge_sys::{MemType, Memory};
+    ///
+    /// let ty = MemType::create(1..=2).expect("fail to create a memory type");
+    /// let mut mem = Memory::create(&ty).expect("fail to create a Memory");
+    ///
+    /// let mut view = mem.view();
+    /// assert_eq!(view.len(), 65536);
+    /// // the memory has no other handles, and no WASM function runs
+    /// unsafe {
+    ///     view.as_mut_slice(0, 5).expect("fail to get the slice").copy_from_slice(b"hello");
+    ///     assert_eq!(view.as_slice(0, 5).expect("fail to get the slice"), b"hello");
+    /// }
+    /// ```
+    pub fn view(&mut self) -> MemoryView<'_> {
+        MemoryView::new(self.inner.0)
+    }
+
+    /// Returns the size in bytes of the data in the [Memory].
+    pub fn data_size(&self) -> usize {
+        self.size() as usize * PAGE_SIZE
+    }
+
+    fn check_bound(&self, offset: u64, len: u32) -> WasmEdgeResult<()> {
+        match offset.saturating_add(len as u64) > self.data_size() as u64 {
+            true => Err(WasmEdgeError::Core(CoreError::Execution(
+                CoreExecutionError::MemoryOutOfBounds,
+            ))),
+            false => Ok(()),
+        }
+    }
+
     /// Returns the size, in WebAssembly pages (64 KiB of each page), of this wasm memory.
-    pub fn size(&self) -> u32 {
-        unsafe { ffi::WasmEdge_MemoryInstanceGetPageSize(self.inner.0) as u32 }
+    pub fn size(&self) -> u64 {
+        unsafe { ffi::WasmEdge_MemoryInstanceGetPageSize(self.inner.0) }
     }
 
     /// Grows this WebAssembly memory by `count` pages.
@@ -247,7 +433,7 @@ impl Memory {
     /// assert_eq!(mem.size(), 20);
     /// ```
     ///
-    pub fn grow(&mut self, count: u32) -> WasmEdgeResult<()> {
+    pub fn grow(&mut self, count: u64) -> WasmEdgeResult<()> {
         unsafe { check(ffi::WasmEdge_MemoryInstanceGrowPage(self.inner.0, count)) }
     }
 }
@@ -259,139 +445,630 @@ impl Drop for Memory {
     }
 }
 
-#[derive(Debug)]
-pub(crate) struct InnerMemory(pub(crate) *mut ffi::WasmEdge_MemoryInstanceContext);
-unsafe impl Send for InnerMemory {}
-unsafe impl Sync for InnerMemory {}
-
-/// Struct of WasmEdge MemType.
+/// Struct of WasmEdge MemoryView.
 ///
-/// A [MemType] classifies a [Memory] and its size range.
+/// A [MemoryView] is a guard which gives the zero-copy access to the data of a [Memory]. The view created by
+/// [Store::memory_view](crate::Store::memory_view) or [Vm::memory_view](crate::Vm::memory_view) borrows the runtime
+/// which the [Memory] belongs to, so that neither the [Memory] can be grown nor any WASM function can be run through
+/// that runtime while the view is alive.
+///
+/// The view created by [Memory::view] borrows the [Memory] handle only, and the view created by
+/// [Caller::memory_view](crate::Caller::memory_view) borrows the [Caller](crate::Caller) of a host function, in which
+/// the memory is not grown unless the host function runs WASM functions by itself. Notice that a [Vm](crate::Vm) created with an
+/// external [Store](crate::Store) does not borrow the [Store](crate::Store), so the view created by the
+/// [Store](crate::Store) does not prevent the [Vm](crate::Vm) from running WASM functions.
+///
+/// The borrow does not cover the other handles of the same memory instance, nor the other threads running WASM
+/// functions, so the slices are returned by the unsafe [as_slice](MemoryView::as_slice) and
+/// [as_mut_slice](MemoryView::as_mut_slice).
 #[derive(Debug)]
-pub struct MemType {
-    pub(crate) inner: InnerMemType,
-    pub(crate) registered: bool,
+pub struct MemoryView<'a> {
+    mem: Memory,
+    _marker: PhantomData<&'a mut Memory>,
 }
-impl MemType {
-    /// Create a new [MemType] to be associated with the given limit range for the capacity.
+impl<'a> MemoryView<'a> {
+    pub(crate) fn new(ctx: *mut ffi::WasmEdge_MemoryInstanceContext) -> Self {
+        Self {
+            mem: Memory {
+                inner: InnerMemory(ctx),
+                registered: true,
+            },
+            _marker: PhantomData,
+        }
+    }
+
+    /// Returns the size in bytes of the data in the viewed [Memory].
+    pub fn len(&self) -> usize {
+        self.mem.data_size()
+    }
+
+    /// Returns `true` if the viewed [Memory] has no data.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns a slice of the data in the viewed [Memory].
     ///
     /// # Arguments
     ///
-    /// - `limit` specifies the linear memory size. The start value of the limit range specifies the min size (also, initial size) of the memory, while the end value specifies the max size allowed to grow. The maximum size is `u32::MAX`.
+    /// - `offset` specifies the data start offset in the [Memory].
     ///
-    /// # Errors
+    /// - `len` specifies the requested data length.
     ///
-    /// If fail to create a [MemType], then an error is returned.
+    /// # Errors
     ///
-    /// # Example
+    /// If the `offset + len` is larger than the data size in the [Memory], then an error is returned.
     ///
-    /// ```ignore
-    /// let ty = MemType::create(0..=u32::MAX);
-    /// ```
+    /// # Safety
     ///
-    pub fn create(limit: RangeInclusive<u32>) -> WasmEdgeResult<Self> {
-        let ctx = unsafe { ffi::WasmEdge_MemoryTypeCreate(ffi::WasmEdge_Limit::from(limit)) };
-        match ctx.is_null() {
-            true => Err(WasmEdgeError::MemTypeCreate),
-            false => Ok(Self {
-                inner: InnerMemType(ctx),
-                registered: false,
-            }),
-        }
+    /// See [Memory::as_slice].
+    pub unsafe fn as_slice(&self, offset: u64, len: u32) -> WasmEdgeResult<&[u8]> {
+        self.mem.as_slice(offset, len)
     }
 
-    /// Returns the limit range of a [MemType].
+    /// Returns a mutable slice of the data in the viewed [Memory].
     ///
-    /// # Example
+    /// # Arguments
     ///
-    /// ```
-    /// use wasmedge_sys::MemType;
+    /// - `offset` specifies the data start offset in the [Memory].
     ///
-    /// let ty = MemType::create(0..=u32::MAX).expect("fail to create a MemType");
-    /// assert_eq!(ty.limit(), 0..=u32::MAX);
-    /// ```
+    /// - `len` specifies the requested data length.
     ///
-    pub fn limit(&self) -> RangeInclusive<u32> {
-        let limit = unsafe { ffi::WasmEdge_MemoryTypeGetLimit(self.inner.0) };
-        RangeInclusive::from(limit)
-    }
-}
-impl Drop for MemType {
-    fn drop(&mut self) {
-        if !self.registered && !self.inner.0.is_null() {
-            unsafe { ffi::WasmEdge_MemoryTypeDelete(self.inner.0) }
-        }
+    /// # Errors
+    ///
+    /// If the `offset + len` is larger than the data size in the [Memory], then an error is returned.
+    ///
+    /// # Safety
+    ///
+    /// See [Memory::as_mut_slice].
+    pub unsafe fn as_mut_slice(&mut self, offset: u64, len: u32) -> WasmEdgeResult<&mut [u8]> {
+        self.mem.as_mut_slice(offset, len)
     }
 }
 
+/// Struct of WasmEdge MemoryRef.
+///
+/// A [MemoryRef] is a handle of the memory borrowed from the [Caller](crate::Caller) of a host function, and can not
+/// outlive the host call. It provides the data accessors of [Memory], but never hands out the borrowed [Memory]
+/// itself, so the handle can not be moved out of the host call. Because it borrows the [Caller](crate::Caller), a
+/// [MemoryView] can not be created by [Caller::memory_view](crate::Caller::memory_view) while it is alive, so the
+/// memory can not be grown through it under a live view.
 #[derive(Debug)]
-pub(crate) struct InnerMemType(pub(crate) *mut ffi::WasmEdge_MemoryTypeContext);
-unsafe impl Send for InnerMemType {}
-unsafe impl Sync for InnerMemType {}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use crate::error::{CoreError, CoreExecutionError, WasmEdgeError};
-    use std::{
-        sync::{Arc, Mutex},
-        thread,
-    };
-
-    #[test]
-    fn test_memory_type() {
-        let result = MemType::create(0..=u32::MAX);
-        assert!(result.is_ok());
-        let ty = result.unwrap();
-        assert!(!ty.inner.0.is_null());
-        assert!(!ty.registered);
+pub struct MemoryRef<'a> {
+    mem: Memory,
+    _marker: PhantomData<&'a Memory>,
+}
+impl<'a> MemoryRef<'a> {
+    pub(crate) fn new(ctx: *mut ffi::WasmEdge_MemoryInstanceContext) -> Self {
+        Self {
+            mem: Memory {
+                inner: InnerMemory(ctx),
+                registered: true,
+            },
+            _marker: PhantomData,
+        }
+    }
 
-        let limit = ty.limit();
-        assert_eq!(limit, 0..=u32::MAX);
+    /// Returns the type of the borrowed memory.
+    ///
+    /// # Errors
+    ///
+    /// If fail to get the type from the memory, then an error is returned.
+    pub fn ty(&self) -> WasmEdgeResult<MemType> {
+        self.mem.ty()
+    }
 
-        let result = MemType::create(10..=101);
-        assert!(result.is_ok());
-        let ty = result.unwrap();
-        assert!(!ty.inner.0.is_null());
-        assert!(!ty.registered);
+    /// Copies the data from the borrowed memory to the output buffer.
+    ///
+    /// See [Memory::get_data].
+    pub fn get_data(&self, offset: u64, len: u32) -> WasmEdgeResult<Vec<u8>> {
+        self.mem.get_data(offset, len)
+    }
 
-        let limit = ty.limit();
-        assert_eq!(limit, 10..=101);
+    /// Copies the data from the given input buffer into the borrowed memory.
+    ///
+    /// See [Memory::set_data].
+    pub fn set_data(
+        &mut self,
+        data: impl IntoIterator<Item = u8>,
+        offset: u64,
+    ) -> WasmEdgeResult<()> {
+        self.mem.set_data(data, offset)
     }
 
-    #[test]
-    fn test_memory_grow() {
-        // create a Memory with a limit range [10, 20]
-        let result = MemType::create(10..=20);
-        assert!(result.is_ok());
-        let ty = result.unwrap();
-        let result = Memory::create(&ty);
-        assert!(result.is_ok());
-        let mut mem = result.unwrap();
-        assert!(!mem.inner.0.is_null());
-        assert!(!mem.registered);
+    /// Reads a UTF-8 string from the borrowed memory.
+    ///
+    /// See [Memory::read_string].
+    pub fn read_string(&self, offset: u64, len: u32) -> WasmEdgeResult<String> {
+        self.mem.read_string(offset, len)
+    }
 
-        // get type
-        let result = mem.ty();
-        assert!(result.is_ok());
-        let ty = result.unwrap();
-        assert!(!ty.inner.0.is_null());
-        assert!(ty.registered);
-        // check limit
-        assert_eq!(ty.limit(), 10..=20);
+    /// Writes the bytes of a string into the borrowed memory.
+    ///
+    /// See [Memory::write_string].
+    pub fn write_string(&mut self, s: impl AsRef<str>, offset: u64) -> WasmEdgeResult<()> {
+        self.mem.write_string(s, offset)
+    }
 
-        // check page count
-        let count = mem.size();
-        assert_eq!(count, 10);
+    /// Returns the size in bytes of the data in the borrowed memory.
+    pub fn data_size(&self) -> usize {
+        self.mem.data_size()
+    }
 
-        // grow 5 pages
-        let result = mem.grow(10);
-        assert!(result.is_ok());
-        assert_eq!(mem.size(), 20);
+    /// Returns the size, in WebAssembly pages (64 KiB of each page), of the borrowed memory.
+    pub fn size(&self) -> u64 {
+        self.mem.size()
+    }
 
-        // grow additional  pages, which causes a failure
-        let result = mem.grow(1);
-        assert!(result.is_err());
+    /// Grows the borrowed memory by `count` pages.
+    ///
+    /// See [Memory::grow].
+    pub fn grow(&mut self, count: u64) -> WasmEdgeResult<()> {
+        self.mem.grow(count)
+    }
+}
+
+#[derive(Debug)]
+pub(crate) struct InnerMemory(pub(crate) *mut ffi::WasmEdge_MemoryInstanceContext);
+unsafe impl Send for InnerMemory {}
+unsafe impl Sync for InnerMemory {}
+
+/// Struct of WasmEdge SharedMemory.
+///
+/// A [SharedMemory] is a handle of a linear memory created with a shared [MemType]. Different from [Memory], the
+/// handle is cheap to clone and can be sent to other threads, so that the same linear memory can be added into the
+/// [import objects](crate::ImportObject) of the [Vm](crate::Vm)s running in different threads by
+/// [ImportObject::add_shared_memory](crate::ImportObject::add_shared_memory). The WASM modules in these
+/// [Vm](crate::Vm)s can then synchronize with each other by the atomic instructions.
+///
+/// The linear memory is deleted when the last handle is dropped. The [import objects](crate::ImportObject) hold
+/// their own handles, so the memory always outlives the [Vm](crate::Vm)s which the memory is registered into.
+#[derive(Debug, Clone)]
+pub struct SharedMemory {
+    inner: Arc<InnerSharedMemory>,
+}
+impl SharedMemory {
+    /// Create a new [SharedMemory] of the given shared memory type.
+    ///
+    /// # Arguments
+    ///
+    /// - `ty` specifies the type of the new [SharedMemory] instance, which should be created by
+    ///   [MemType::create_shared].
+    ///
+    /// # Errors
+    ///
+    /// If the `ty` is not shared, or fail to create a [SharedMemory], then an error is returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use wasmedge_sys::{MemType, SharedMemory};
+    ///
+    /// let ty = MemType::create_shared(1..=2).expect("fail to create a shared memory type");
+    /// let memory = SharedMemory::create(&ty).expect("fail to create a shared memory");
+    ///
+    /// let handle = memory.clone();
+    /// std::thread::spawn(move || {
+    ///     handle.set_data(vec![1; 10], 10).expect("fail to set data");
+    /// })
+    /// .join()
+    /// .unwrap();
+    /// assert_eq!(memory.get_data(10, 10).unwrap(), vec![1; 10]);
+    /// ```
+    ///
+    pub fn create(ty: &MemType) -> WasmEdgeResult<Self> {
+        if !ty.is_shared() {
+            return Err(WasmEdgeError::Mem(MemError::NotShared));
+        }
+        let ctx = unsafe { ffi::WasmEdge_MemoryInstanceCreate(ty.inner.0 as *const _) };
+        match ctx.is_null() {
+            true => Err(WasmEdgeError::Mem(MemError::Create)),
+            false => Ok(Self {
+                inner: Arc::new(InnerSharedMemory(InnerMemory(ctx))),
+            }),
+        }
+    }
+
+    /// Returns the type of the [SharedMemory].
+    ///
+    /// # Errors
+    ///
+    /// If fail to get the type from the [SharedMemory], then an error is returned.
+    ///
+    pub fn ty(&self) -> WasmEdgeResult<MemType> {
+        let ty_ctx = unsafe { ffi::WasmEdge_MemoryInstanceGetMemoryType(self.as_ptr()) };
+        match ty_ctx.is_null() {
+            true => Err(WasmEdgeError::Mem(MemError::Type)),
+            false => Ok(MemType {
+                inner: InnerMemType(ty_ctx as *mut _),
+                registered: true,
+            }),
+        }
+    }
+
+    /// Copies the data from the [SharedMemory] to the output buffer.
+    ///
+    /// Notice that the data may be modified by the WASM modules running in other threads at the same time. Use the
+    /// atomic instructions in the WASM modules to synchronize the accesses.
+    ///
+    /// # Arguments
+    ///
+    /// - `offset` specifies the data start offset in the [SharedMemory].
+    ///
+    /// - `len` specifies the requested data length.
+    ///
+    /// # Errors
+    ///
+    /// If the `offset + len` is larger than the data size in the [SharedMemory], then an error is returned.
+    ///
+    pub fn get_data(&self, offset: u64, len: u32) -> WasmEdgeResult<Vec<u8>> {
+        let mut data = Vec::with_capacity(len as usize);
+        unsafe {
+            check(ffi::WasmEdge_MemoryInstanceGetData(
+                self.as_ptr(),
+                data.as_mut_ptr(),
+                offset,
+                len,
+            ))?;
+            data.set_len(len as usize);
+        }
+        Ok(data)
+    }
+
+    /// Copies the data from the given input buffer into the [SharedMemory].
+    ///
+    /// # Arguments
+    ///
+    /// - `data` specifies the data buffer to copy.
+    ///
+    /// - `offset` specifies the data start offset in the [SharedMemory].
+    ///
+    /// # Errors
+    ///
+    /// If the sum of the `offset` and the data length is larger than the size of the [SharedMemory],
+    /// then an error is returned.
+    ///
+    pub fn set_data(&self, data: impl IntoIterator<Item = u8>, offset: u64) -> WasmEdgeResult<()> {
+        let data = data.into_iter().collect::<Vec<u8>>();
+        unsafe {
+            check(ffi::WasmEdge_MemoryInstanceSetData(
+                self.as_ptr(),
+                data.as_ptr() as *mut _,
+                offset,
+                data.len() as u32,
+            ))
+        }
+    }
+
+    /// Returns the size, in WebAssembly pages (64 KiB of each page), of this shared memory.
+    pub fn size(&self) -> u64 {
+        unsafe { ffi::WasmEdge_MemoryInstanceGetPageSize(self.as_ptr()) }
+    }
+
+    /// Returns the size in bytes of the data in the [SharedMemory].
+    pub fn data_size(&self) -> usize {
+        self.size() as usize * PAGE_SIZE
+    }
+
+    /// Grows this shared memory by `count` pages.
+    ///
+    /// The data of a shared memory is never moved when growing. WasmEdge reserves the maximum size of a shared memory
+    /// up front on the platforms where growing a memory reallocates its data, so the shared memory can be grown while
+    /// the WASM modules are accessing it in other threads.
+    ///
+    /// # Arguments
+    ///
+    /// - `count` specifies the page counts to be extended to the [SharedMemory].
+    ///
+    /// # Errors
+    ///
+    /// If fail to grow the page count, then an error is returned.
+    ///
+    pub fn grow(&self, count: u64) -> WasmEdgeResult<()> {
+        unsafe { check(ffi::WasmEdge_MemoryInstanceGrowPage(self.as_ptr(), count)) }
+    }
+
+    pub(crate) fn as_ptr(&self) -> *mut ffi::WasmEdge_MemoryInstanceContext {
+        (self.inner.0).0
+    }
+}
+
+#[derive(Debug)]
+struct InnerSharedMemory(InnerMemory);
+impl Drop for InnerSharedMemory {
+    fn drop(&mut self) {
+        if !(self.0).0.is_null() {
+            unsafe { ffi::WasmEdge_MemoryInstanceDelete((self.0).0) };
+        }
+    }
+}
+
+/// Struct of WasmEdge MemType.
+///
+/// A [MemType] classifies a [Memory] and its size range.
+#[derive(Debug)]
+pub struct MemType {
+    pub(crate) inner: InnerMemType,
+    pub(crate) registered: bool,
+}
+impl MemType {
+    /// Create a new [MemType] to be associated with the given limit range for the capacity.
+    ///
+    /// # Arguments
+    ///
+    /// - `limit` specifies the linear memory size. The start value of the limit range specifies the min size (also, initial size) of the memory, while the end value specifies the max size allowed to grow. The maximum size is `u32::MAX`.
+    ///
+    /// # Errors
+    ///
+    /// If fail to create a [MemType], then an error is returned.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let ty = MemType::create(0..=u32::MAX);
+    /// ```
+    ///
+    pub fn create(limit: RangeInclusive<u32>) -> WasmEdgeResult<Self> {
+        let ctx = unsafe { ffi::WasmEdge_MemoryTypeCreate(ffi::WasmEdge_Limit::from(limit)) };
+        match ctx.is_null() {
+            true => Err(WasmEdgeError::MemTypeCreate),
+            false => Ok(Self {
+                inner: InnerMemType(ctx),
+                registered: false,
+            }),
+        }
+    }
+
+    /// Create a new shared [MemType] to be associated with the given limit range for the capacity.
+    ///
+    /// A shared memory can be accessed by the WASM modules running in different threads, and is required by the
+    /// atomic wait and notify instructions. The `Threads` option of the [Config](crate::Config) should be turned on
+    /// to load the WASM modules which define or import shared memories.
+    ///
+    /// # Arguments
+    ///
+    /// - `limit` specifies the linear memory size. Different from [MemType::create], the end value of the limit
+    ///   range is always the max size of the shared memory, even if it is equal to the start value.
+    ///
+    /// # Errors
+    ///
+    /// If fail to create a [MemType], then an error is returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use wasmedge_sys::MemType;
+    ///
+    /// let ty = MemType::create_shared(1..=1).expect("fail to create a shared MemType");
+    /// assert!(ty.is_shared());
+    /// assert_eq!(ty.limit(), 1..=1);
+    /// ```
+    ///
+    pub fn create_shared(limit: RangeInclusive<u32>) -> WasmEdgeResult<Self> {
+        let (min, max) = limit.into_inner();
+        let ctx = unsafe {
+            ffi::WasmEdge_MemoryTypeCreate(ffi::WasmEdge_Limit {
+                HasMax: true,
+                Min: min.into(),
+                Max: max.into(),
+                Shared: true,
+                Is64: false,
+            })
+        };
+        match ctx.is_null() {
+            true => Err(WasmEdgeError::MemTypeCreate),
+            false => Ok(Self {
+                inner: InnerMemType(ctx),
+                registered: false,
+            }),
+        }
+    }
+
+    /// Create a new 64-bit [MemType] to be associated with the given limit range for the capacity.
+    ///
+    /// A 64-bit memory uses `i64` values as the addresses, and is defined by the WASM modules of the memory64
+    /// proposal. The `Memory64` option of the [Config](crate::Config) should be turned on to load such WASM modules.
+    ///
+    /// # Arguments
+    ///
+    /// - `limit` specifies the linear memory size. The start value of the limit range specifies the min size (also, initial size) of the memory, while the end value specifies the max size allowed to grow. Different from [MemType::create], the limit values can be larger than `u32::MAX`.
+    ///
+    /// # Errors
+    ///
+    /// If fail to create a [MemType], then an error is returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use wasmedge_sys::MemType;
+    ///
+    /// let ty = MemType::create_64(1..=u64::pow(2, 40)).expect("fail to create a 64-bit MemType");
+    /// assert!(ty.is_64());
+    /// assert_eq!(ty.limit(), 1..=u64::pow(2, 40));
+    /// ```
+    ///
+    pub fn create_64(limit: RangeInclusive<u64>) -> WasmEdgeResult<Self> {
+        let mut limit = ffi::WasmEdge_Limit::from(limit);
+        limit.Is64 = true;
+        let ctx = unsafe { ffi::WasmEdge_MemoryTypeCreate(limit) };
+        match ctx.is_null() {
+            true => Err(WasmEdgeError::MemTypeCreate),
+            false => Ok(Self {
+                inner: InnerMemType(ctx),
+                registered: false,
+            }),
+        }
+    }
+
+    /// Checks if the [MemType] is shared or not.
+    pub fn is_shared(&self) -> bool {
+        let limit = unsafe { ffi::WasmEdge_MemoryTypeGetLimit(self.inner.0) };
+        limit.Shared
+    }
+
+    /// Checks if the [MemType] uses the 64-bit addresses or not.
+    pub fn is_64(&self) -> bool {
+        let limit = unsafe { ffi::WasmEdge_MemoryTypeGetLimit(self.inner.0) };
+        limit.Is64
+    }
+
+    /// Returns the limit range of a [MemType].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use wasmedge_sys::MemType;
+    ///
+    /// let ty = MemType::create(0..=u32::MAX).expect("fail to create a MemType");
+    /// assert_eq!(ty.limit(), 0..=u32::MAX as u64);
+    /// ```
+    ///
+    pub fn limit(&self) -> RangeInclusive<u64> {
+        let limit = unsafe { ffi::WasmEdge_MemoryTypeGetLimit(self.inner.0) };
+        RangeInclusive::from(limit)
+    }
+
+    /// Returns the max pages of a [MemType], or `None` if it has no max value.
+    pub(crate) fn max(&self) -> Option<u64> {
+        let limit = unsafe { ffi::WasmEdge_MemoryTypeGetLimit(self.inner.0) };
+        match limit.HasMax {
+            true => Some(limit.Max),
+            false => None,
+        }
+    }
+}
+impl Drop for MemType {
+    fn drop(&mut self) {
+        if !self.registered && !self.inner.0.is_null() {
+            unsafe { ffi::WasmEdge_MemoryTypeDelete(self.inner.0) }
+        }
+    }
+}
+
+#[derive(Debug)]
+pub(crate) struct InnerMemType(pub(crate) *mut ffi::WasmEdge_MemoryTypeContext);
+unsafe impl Send for InnerMemType {}
+unsafe impl Sync for InnerMemType {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        error::{
+            CoreError, CoreExecutionError, CoreInstantiationError, CoreLoadError, WasmEdgeError,
+        },
+        Config, ImportObject, Vm, WasmValue,
+    };
+    use std::{
+        sync::{Arc, Mutex},
+        thread,
+        time::Duration,
+    };
+
+    #[test]
+    fn test_memory_type() {
+        let result = MemType::create(0..=u32::MAX);
+        assert!(result.is_ok());
+        let ty = result.unwrap();
+        assert!(!ty.inner.0.is_null());
+        assert!(!ty.registered);
+
+        let limit = ty.limit();
+        assert_eq!(limit, 0..=u32::MAX as u64);
+
+        let result = MemType::create(10..=101);
+        assert!(result.is_ok());
+        let ty = result.unwrap();
+        assert!(!ty.inner.0.is_null());
+        assert!(!ty.registered);
+
+        let limit = ty.limit();
+        assert_eq!(limit, 10..=101);
+    }
+
+    #[test]
+    fn test_memory_type_64() {
+        let result = MemType::create_64(0..=u64::pow(2, 40));
+        assert!(result.is_ok());
+        let ty = result.unwrap();
+        assert!(!ty.inner.0.is_null());
+        assert!(ty.is_64());
+        assert!(!ty.is_shared());
+        assert_eq!(ty.limit(), 0..=u64::pow(2, 40));
+
+        let result = MemType::create(10..=101);
+        assert!(result.is_ok());
+        let ty = result.unwrap();
+        assert!(!ty.is_64());
+
+        // create a 64-bit Memory with a limit range [1, 2]
+        let result = MemType::create_64(1..=2);
+        assert!(result.is_ok());
+        let ty = result.unwrap();
+        let result = Memory::create(&ty);
+        assert!(result.is_ok());
+        let mut mem = result.unwrap();
+        let result = mem.ty();
+        assert!(result.is_ok());
+        assert!(result.unwrap().is_64());
+        assert_eq!(mem.size(), 1);
+
+        // access the data with the 64-bit offsets
+        let result = mem.set_data(vec![1; 10], 10);
+        assert!(result.is_ok());
+        let result = mem.get_data(10, 10);
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap(), vec![1; 10]);
+        let result = mem.get_data(u64::pow(2, 32) + 10, 10);
+        assert!(result.is_err());
+        assert_eq!(
+            result.unwrap_err(),
+            WasmEdgeError::Core(CoreError::Execution(CoreExecutionError::MemoryOutOfBounds))
+        );
+        let result = unsafe { mem.as_slice(u64::MAX, 1) };
+        assert!(result.is_err());
+
+        // grow the 64-bit Memory
+        let result = mem.grow(1);
+        assert!(result.is_ok());
+        assert_eq!(mem.size(), 2);
+        let result = mem.grow(1);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_memory_grow() {
+        // create a Memory with a limit range [10, 20]
+        let result = MemType::create(10..=20);
+        assert!(result.is_ok());
+        let ty = result.unwrap();
+        let result = Memory::create(&ty);
+        assert!(result.is_ok());
+        let mut mem = result.unwrap();
+        assert!(!mem.inner.0.is_null());
+        assert!(!mem.registered);
+
+        // get type
+        let result = mem.ty();
+        assert!(result.is_ok());
+        let ty = result.unwrap();
+        assert!(!ty.inner.0.is_null());
+        assert!(ty.registered);
+        // check limit
+        assert_eq!(ty.limit(), 10..=20);
+
+        // check page count
+        let count = mem.size();
+        assert_eq!(count, 10);
+
+        // grow 5 pages
+        let result = mem.grow(10);
+        assert!(result.is_ok());
+        assert_eq!(mem.size(), 20);
+
+        // grow additional  pages, which causes a failure
+        let result = mem.grow(1);
+        assert!(result.is_err());
     }
 
     #[test]
@@ -426,7 +1103,7 @@ mod tests {
         assert_eq!(data, vec![1; 10]);
 
         // set data and the data length is larger than the data size in the memory
-        let result = mem.set_data(vec![1; 10], u32::pow(2, 16) - 9);
+        let result = mem.set_data(vec![1; 10], u64::pow(2, 16) - 9);
         assert!(result.is_err());
         assert_eq!(
             result.unwrap_err(),
@@ -437,10 +1114,72 @@ mod tests {
         let result = mem.grow(1);
         assert!(result.is_ok());
         assert_eq!(mem.size(), 2);
-        let result = mem.set_data(vec![1; 10], u32::pow(2, 16) - 9);
+        let result = mem.set_data(vec![1; 10], u64::pow(2, 16) - 9);
         assert!(result.is_ok());
     }
 
+    #[test]
+    fn test_memory_slice() {
+        // create a Memory: the min size 1 and the max size 2
+        let result = MemType::create(1..=2);
+        assert!(result.is_ok());
+        let ty = result.unwrap();
+        let result = Memory::create(&ty);
+        assert!(result.is_ok());
+        let mut mem = result.unwrap();
+        assert_eq!(mem.data_size(), 65536);
+
+        // write data through a mutable slice
+        let result = unsafe { mem.as_mut_slice(10, 5) };
+        assert!(result.is_ok());
+        result.unwrap().copy_from_slice(b"hello");
+        let result = mem.get_data(10, 5);
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap(), b"hello");
+
+        // read data through a slice
+        let result = unsafe { mem.as_slice(8, 7) };
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap(), b"\0\0hello");
+
+        // the slice is out of bounds
+        let result = unsafe { mem.as_slice(65530, 7) };
+        assert!(result.is_err());
+        assert_eq!(
+            result.unwrap_err(),
+            WasmEdgeError::Core(CoreError::Execution(CoreExecutionError::MemoryOutOfBounds))
+        );
+        let result = unsafe { mem.as_mut_slice(u32::MAX as u64, 1) };
+        assert!(result.is_err());
+
+        // an empty slice at the end of the memory
+        let result = unsafe { mem.as_slice(65536, 0) };
+        assert!(result.is_ok());
+        assert!(result.unwrap().is_empty());
+
+        // access the data through a view
+        {
+            let mut view = mem.view();
+            assert_eq!(view.len(), 65536);
+            assert!(!view.is_empty());
+            let result = unsafe { view.as_mut_slice(65531, 5) };
+            assert!(result.is_ok());
+            result.unwrap().copy_from_slice(b"world");
+            let result = unsafe { view.as_slice(10, 5) };
+            assert!(result.is_ok());
+            assert_eq!(result.unwrap(), b"hello");
+        }
+
+        // the data is kept after growing
+        let result = mem.grow(1);
+        assert!(result.is_ok());
+        let view = mem.view();
+        assert_eq!(view.len(), 131072);
+        let result = unsafe { view.as_slice(65531, 10) };
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap(), b"world\0\0\0\0\0");
+    }
+
     #[test]
     fn test_memory_send() {
         {
@@ -524,4 +1263,303 @@ mod tests {
 
         handle.join().unwrap()
     }
+
+    #[test]
+    fn test_memory_shared() {
+        // create a shared MemType
+        let result = MemType::create_shared(1..=2);
+        assert!(result.is_ok());
+        let ty = result.unwrap();
+        assert!(ty.is_shared());
+        assert_eq!(ty.limit(), 1..=2);
+
+        // the shared memory must have the max size
+        let result = MemType::create_shared(1..=1);
+        assert!(result.is_ok());
+        let ty_fixed = result.unwrap();
+        assert!(ty_fixed.is_shared());
+        assert_eq!(ty_fixed.limit(), 1..=1);
+
+        // create a SharedMemory from a non-shared MemType
+        let result = MemType::create(1..=2);
+        assert!(result.is_ok());
+        let ty_unshared = result.unwrap();
+        assert!(!ty_unshared.is_shared());
+        let result = SharedMemory::create(&ty_unshared);
+        assert!(result.is_err());
+        assert_eq!(result.unwrap_err(), WasmEdgeError::Mem(MemError::NotShared));
+
+        // create a SharedMemory
+        let result = SharedMemory::create(&ty);
+        assert!(result.is_ok());
+        let memory = result.unwrap();
+        assert_eq!(memory.size(), 1);
+        let result = memory.ty();
+        assert!(result.is_ok());
+        assert!(result.unwrap().is_shared());
+
+        // grow and access the memory in another thread
+        let memory_cloned = memory.clone();
+        let handle = thread::spawn(move || {
+            let result = memory_cloned.grow(1);
+            assert!(result.is_ok());
+            let result = memory_cloned.set_data(vec![1; 10], PAGE_SIZE as u64);
+            assert!(result.is_ok());
+        });
+        handle.join().unwrap();
+        assert_eq!(memory.size(), 2);
+        assert_eq!(memory.data_size(), 2 * PAGE_SIZE);
+        let result = memory.get_data(PAGE_SIZE as u64, 10);
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap(), vec![1; 10]);
+
+        // grow beyond the max size
+        let result = memory.grow(1);
+        assert!(result.is_err());
+        assert_eq!(memory.size(), 2);
+    }
+
+    fn create_threads_vm(memory: &SharedMemory) -> Vm {
+        let result = Config::create();
+        assert!(result.is_ok());
+        let mut config = result.unwrap();
+        config.threads(true);
+        assert!(config.threads_enabled());
+
+        let result = Vm::create(Some(config), None);
+        assert!(result.is_ok());
+        let mut vm = result.unwrap();
+
+        // import the shared memory as "env.memory"
+        let result = ImportObject::create("env");
+        assert!(result.is_ok());
+        let mut import = result.unwrap();
+        import.add_shared_memory("memory", memory);
+        let result = vm.register_wasm_from_import(import);
+        assert!(result.is_ok());
+
+        let path = std::path::PathBuf::from(env!("WASMEDGE_DIR"))
+            .join("bindings/rust/wasmedge-sys/tests/data/threads.wasm");
+        let result = vm.load_wasm_from_file(path);
+        assert!(result.is_ok());
+        let result = vm.validate();
+        assert!(result.is_ok());
+        let result = vm.instantiate();
+        assert!(result.is_ok());
+        vm
+    }
+
+    #[test]
+    fn test_memory_shared_atomics() {
+        let result = MemType::create_shared(1..=1);
+        assert!(result.is_ok());
+        let ty = result.unwrap();
+        let result = SharedMemory::create(&ty);
+        assert!(result.is_ok());
+        let memory = result.unwrap();
+
+        let vm = create_threads_vm(&memory);
+
+        // the value at the address does not match the expected value
+        let result = vm.run_function(
+            "wait",
+            [
+                WasmValue::from_i32(0),
+                WasmValue::from_i32(1),
+                WasmValue::from_i64(-1),
+            ],
+        );
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap()[0].to_i32(), 1);
+
+        // wait for 1 millisecond and time out
+        let result = vm.run_function(
+            "wait",
+            [
+                WasmValue::from_i32(0),
+                WasmValue::from_i32(0),
+                WasmValue::from_i64(1_000_000),
+            ],
+        );
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap()[0].to_i32(), 2);
+
+        // no waiter to notify
+        let result = vm.run_function("notify", [WasmValue::from_i32(8), WasmValue::from_i32(1)]);
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap()[0].to_i32(), 0);
+
+        // wait in another thread with another Vm, and notify it in this thread
+        let memory_cloned = memory.clone();
+        let handle = thread::spawn(move || {
+            let vm = create_threads_vm(&memory_cloned);
+            let result = vm.run_function(
+                "wait",
+                [
+                    WasmValue::from_i32(8),
+                    WasmValue::from_i32(0),
+                    WasmValue::from_i64(-1),
+                ],
+            );
+            assert!(result.is_ok());
+            result.unwrap()[0].to_i32()
+        });
+        loop {
+            let result =
+                vm.run_function("notify", [WasmValue::from_i32(8), WasmValue::from_i32(1)]);
+            assert!(result.is_ok());
+            if result.unwrap()[0].to_i32() == 1 {
+                break;
+            }
+            thread::sleep(Duration::from_millis(1));
+        }
+        assert_eq!(handle.join().unwrap(), 0);
+
+        // add to the same address in several threads
+        let handles = (0..4)
+            .map(|_| {
+                let memory_cloned = memory.clone();
+                thread::spawn(move || {
+                    let vm = create_threads_vm(&memory_cloned);
+                    for _ in 0..1000 {
+                        let result = vm
+                            .run_function("add", [WasmValue::from_i32(16), WasmValue::from_i32(1)]);
+                        assert!(result.is_ok());
+                    }
+                })
+            })
+            .collect::<Vec<_>>();
+        for handle in handles {
+            handle.join().unwrap();
+        }
+        let result = vm.run_function("load", [WasmValue::from_i32(16)]);
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap()[0].to_i32(), 4000);
+        let result = memory.get_data(16, 4);
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap(), 4000u32.to_le_bytes());
+
+        // the atomic accesses should be aligned
+        let result = vm.run_function("load", [WasmValue::from_i32(2)]);
+        assert!(result.is_err());
+        assert_eq!(
+            result.unwrap_err(),
+            WasmEdgeError::Core(CoreError::Execution(
+                CoreExecutionError::UnalignedAtomicAccess
+            ))
+        );
+
+        // the memory is still alive after the Vms are dropped
+        drop(vm);
+        assert_eq!(memory.size(), 1);
+    }
+
+    #[test]
+    fn test_memory_shared_import() {
+        let path = std::path::PathBuf::from(env!("WASMEDGE_DIR"))
+            .join("bindings/rust/wasmedge-sys/tests/data/threads.wasm");
+
+        // the threads option is required to load the module
+        let result = Vm::create(None, None);
+        assert!(result.is_ok());
+        let mut vm = result.unwrap();
+        let result = vm.load_wasm_from_file(&path);
+        assert!(result.is_err());
+        assert_eq!(
+            result.unwrap_err(),
+            WasmEdgeError::Core(CoreError::Load(CoreLoadError::IntegerTooLarge))
+        );
+
+        // a non-shared memory does not match the shared memory import
+        let result = Config::create();
+        assert!(result.is_ok());
+        let mut config = result.unwrap();
+        config.threads(true);
+        let result = Vm::create(Some(config), None);
+        assert!(result.is_ok());
+        let mut vm = result.unwrap();
+        let result = ImportObject::create("env");
+        assert!(result.is_ok());
+        let mut import = result.unwrap();
+        let result = MemType::create(1..=1);
+        assert!(result.is_ok());
+        let ty = result.unwrap();
+        let result = Memory::create(&ty);
+        assert!(result.is_ok());
+        import.add_memory("memory", result.unwrap());
+        let result = vm.register_wasm_from_import(import);
+        assert!(result.is_ok());
+        let result = vm.load_wasm_from_file(&path);
+        assert!(result.is_ok());
+        let result = vm.validate();
+        assert!(result.is_ok());
+        let result = vm.instantiate();
+        assert!(result.is_err());
+        assert_eq!(
+            result.unwrap_err(),
+            WasmEdgeError::Core(CoreError::Instantiation(
+                CoreInstantiationError::IncompatibleImportType
+            ))
+        );
+    }
+
+    #[test]
+    fn test_memory_64_module() {
+        let path = std::path::PathBuf::from(env!("WASMEDGE_DIR"))
+            .join("bindings/rust/wasmedge-sys/tests/data/memory64.wasm");
+
+        // the memory64 option is required to load the module
+        let result = Vm::create(None, None);
+        assert!(result.is_ok());
+        let mut vm = result.unwrap();
+        let result = vm.load_wasm_from_file(&path);
+        assert!(result.is_err());
+
+        let result = Config::create();
+        assert!(result.is_ok());
+        let mut config = result.unwrap();
+        config.memory64(true);
+        let result = Vm::create(Some(config), None);
+        assert!(result.is_ok());
+        let mut vm = result.unwrap();
+        let result = vm.load_wasm_from_file(&path);
+        assert!(result.is_ok());
+        let result = vm.validate();
+        assert!(result.is_ok());
+        let result = vm.instantiate();
+        assert!(result.is_ok());
+
+        // the addresses and the page counts are i64 values
+        let result = vm.run_function("store", [WasmValue::from_i64(8), WasmValue::from_i32(42)]);
+        assert!(result.is_ok());
+        let result = vm.run_function("load", [WasmValue::from_i64(8)]);
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap()[0].to_i32(), 42);
+        let result = vm.run_function("size", []);
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap()[0].to_i64(), 1);
+        let result = vm.run_function("grow", [WasmValue::from_i64(1)]);
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap()[0].to_i64(), 1);
+        let result = vm.run_function("grow", [WasmValue::from_i64(1)]);
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap()[0].to_i64(), -1);
+
+        // the addresses larger than `u32::MAX` are out of bounds
+        let result = vm.run_function("load", [WasmValue::from_i64(1 << 32)]);
+        assert!(result.is_err());
+        assert_eq!(
+            result.unwrap_err(),
+            WasmEdgeError::Core(CoreError::Execution(CoreExecutionError::MemoryOutOfBounds))
+        );
+
+        // the exported memory is a 64-bit memory
+        let result = vm.memory_view("memory");
+        assert!(result.is_ok());
+        let view = result.unwrap();
+        assert_eq!(view.len(), 2 * PAGE_SIZE);
+        let result = unsafe { view.as_slice(8, 4) };
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap(), 42u32.to_le_bytes());
+    }
 }
@@ -7,11 +7,11 @@ pub mod module;
 pub mod table;
 
 #[doc(inline)]
-pub use function::{FuncType, Function};
+pub use function::{Caller, FuncType, Function};
 #[doc(inline)]
 pub use global::{Global, GlobalType};
 #[doc(inline)]
-pub use memory::{MemType, Memory};
+pub use memory::{MemType, Memory, MemoryRef, MemoryView, SharedMemory};
 #[doc(inline)]
 pub use module::Instance;
 #[doc(inline)]
@@ -4,8 +4,10 @@ use crate::{
     error::{InstanceError, WasmEdgeError},
     ffi,
     instance::{function::InnerFunc, global::InnerGlobal, memory::InnerMemory, table::InnerTable},
+    io::WasmFnIO,
+    typed_func::{Target, TypedFunc},
     types::WasmEdgeString,
-    Function, Global, Memory, Store, Table, WasmEdgeResult,
+    Executor, Function, Global, Memory, ResourceUsage, Snapshot, Store, Table, WasmEdgeResult,
 };
 
 /// Struct of WasmEdge Instance.
@@ -58,10 +60,34 @@ impl<'store> Instance<'store> {
                 registered: true,
                 name: Some(name.as_ref().to_string()),
                 mod_name: self.name(),
+                real_fn: None,
             }),
         }
     }
 
+    /// Returns a [TypedFunc](crate::TypedFunc) handle to an exported function in this [module instance](crate::Instance)
+    /// by the given function name. The handle calls the function with the given [executor](crate::Executor), which is
+    /// mutably borrowed while the handle is alive.
+    ///
+    /// # Arguments
+    ///
+    /// - `executor` specifies the [executor](crate::Executor) to run the function.
+    ///
+    /// - `name` specifies the name of the target exported [function](crate::Function).
+    ///
+    /// # Error
+    ///
+    /// If fail to find the target [function](crate::Function), or its signature does not match, then an error is
+    /// returned.
+    pub fn typed_func<'a, Params: WasmFnIO, Rets: WasmFnIO>(
+        &'a self,
+        executor: &'a mut Executor,
+        name: impl AsRef<str>,
+    ) -> WasmEdgeResult<TypedFunc<'a, Params, Rets>> {
+        let func = self.find_func(name.as_ref())?;
+        TypedFunc::new(Target::Executor(executor, self.store), func, name.as_ref())
+    }
+
     /// Returns the exported [table](crate::Table) instance in this [module instance](crate::Instance)
     /// by the given table name.
     ///
@@ -242,6 +268,11 @@ impl<'store> Instance<'store> {
         }
     }
 
+    /// Returns the [tables](crate::Table) and [memories](crate::Memory) in use by this module instance.
+    pub fn resource_usage(&self) -> ResourceUsage {
+        ResourceUsage::of(self.inner.0)
+    }
+
     /// Returns the length of the exported [globals](crate::Global) in this module.
     pub fn global_len(&self) -> u32 {
         unsafe { ffi::WasmEdge_ModuleInstanceListGlobalLength(self.inner.0) }
@@ -271,6 +302,112 @@ impl<'store> Instance<'store> {
             false => None,
         }
     }
+
+    /// Returns the [memories](crate::Memory) defined in this module instance, including the non-exported ones and
+    /// excluding the imported ones.
+    pub fn defined_memories(&self) -> Vec<Memory> {
+        let len = unsafe { ffi::WasmEdge_ModuleInstanceGetDefinedMemoryLength(self.inner.0) };
+        (0..len)
+            .filter_map(|idx| {
+                let ctx = unsafe {
+                    ffi::WasmEdge_ModuleInstanceGetDefinedMemory(
+                        self.inner.0,
+                        self.store.inner.0,
+                        idx,
+                    )
+                };
+                match ctx.is_null() {
+                    true => None,
+                    false => Some(Memory {
+                        inner: InnerMemory(ctx),
+                        registered: true,
+                    }),
+                }
+            })
+            .collect()
+    }
+
+    /// Returns the [tables](crate::Table) defined in this module instance, including the non-exported ones and
+    /// excluding the imported ones.
+    pub fn defined_tables(&self) -> Vec<Table> {
+        let len = unsafe { ffi::WasmEdge_ModuleInstanceGetDefinedTableLength(self.inner.0) };
+        (0..len)
+            .filter_map(|idx| {
+                let ctx = unsafe {
+                    ffi::WasmEdge_ModuleInstanceGetDefinedTable(
+                        self.inner.0,
+                        self.store.inner.0,
+                        idx,
+                    )
+                };
+                match ctx.is_null() {
+                    true => None,
+                    false => Some(Table {
+                        inner: InnerTable(ctx),
+                        registered: true,
+                    }),
+                }
+            })
+            .collect()
+    }
+
+    // returns the size in bytes of the data segment at the given index, which is 0 if the data segment is dropped
+    pub(crate) fn data_segment_size(&self, idx: u32) -> u32 {
+        unsafe { ffi::WasmEdge_ModuleInstanceGetDataSegmentSize(self.inner.0, idx) }
+    }
+
+    /// Returns the [globals](crate::Global) defined in this module instance, including the non-exported ones and
+    /// excluding the imported ones.
+    pub fn defined_globals(&self) -> Vec<Global> {
+        let len = unsafe { ffi::WasmEdge_ModuleInstanceGetDefinedGlobalLength(self.inner.0) };
+        (0..len)
+            .filter_map(|idx| {
+                let ctx = unsafe {
+                    ffi::WasmEdge_ModuleInstanceGetDefinedGlobal(
+                        self.inner.0,
+                        self.store.inner.0,
+                        idx,
+                    )
+                };
+                match ctx.is_null() {
+                    true => None,
+                    false => Some(Global {
+                        inner: InnerGlobal(ctx),
+                        registered: true,
+                    }),
+                }
+            })
+            .collect()
+    }
+
+    /// Takes a [Snapshot] of the memories and the mutable globals defined in this module instance.
+    ///
+    /// # Error
+    ///
+    /// If a mutable global of reference type is defined in this module instance, or fail to read the data of the
+    /// memories, then an error is returned.
+    pub fn snapshot(&self) -> WasmEdgeResult<Snapshot> {
+        Snapshot::capture(self)
+    }
+
+    /// Restores a [Snapshot] into this module instance.
+    ///
+    /// The [Snapshot] should be taken from an instance of the same [Module](crate::Module). The memories smaller than
+    /// the recorded ones are grown, and the data and the values of the mutable globals are overwritten.
+    ///
+    /// # Argument
+    ///
+    /// - `snapshot` specifies the [Snapshot] to restore.
+    ///
+    /// # Error
+    ///
+    /// If the memories or the globals in this module instance do not match the [Snapshot], a memory is larger than
+    /// the recorded one, or the recorded pages exceed the limit of a memory, then an error is returned and nothing is
+    /// restored. If growing a memory fails, then an error is returned before any data is written, while the memories
+    /// grown before are left grown.
+    pub fn restore(&self, snapshot: &Snapshot) -> WasmEdgeResult<()> {
+        snapshot.restore(self)
+    }
 }
 
 #[derive(Debug)]
@@ -282,8 +419,8 @@ unsafe impl Sync for InnerInstance {}
 mod tests {
     use super::*;
     use crate::{
-        Config, Executor, FuncType, GlobalType, ImportObject, MemType, Mutability, RefType,
-        TableType, ValType, Vm, WasmValue,
+        error::Trap, Caller, Config, Executor, FuncType, GlobalType, ImportObject, MemType,
+        Mutability, RefType, TableType, ValType, Vm, WasmValue,
     };
 
     #[test]
@@ -291,7 +428,7 @@ mod tests {
         let vm = create_vm();
         let result = vm.store_mut();
         assert!(result.is_ok());
-        let mut store = result.unwrap();
+        let store = result.unwrap();
 
         // get the module named "extern"
         let result = store.named_module("extern_module");
@@ -341,7 +478,7 @@ mod tests {
         let result = memory.ty();
         assert!(result.is_ok());
         let ty = result.unwrap();
-        assert_eq!(ty.limit(), 0..=u32::MAX);
+        assert_eq!(ty.limit(), 0..=u32::MAX as u64);
 
         // get the exported global named "global"
         let result = instance.find_global("global");
@@ -361,7 +498,7 @@ mod tests {
         let vm = create_vm();
         let result = vm.store_mut();
         assert!(result.is_ok());
-        let mut store = result.unwrap();
+        let store = result.unwrap();
 
         // get the module named "extern"
         let result = store.named_module("extern_module");
@@ -535,21 +672,21 @@ mod tests {
         vm
     }
 
-    fn real_add(inputs: Vec<WasmValue>) -> Result<Vec<WasmValue>, u8> {
+    fn real_add(_caller: Caller, inputs: Vec<WasmValue>) -> Result<Vec<WasmValue>, Trap> {
         if inputs.len() != 2 {
-            return Err(1);
+            return Err(Trap::new("Invalid number of arguments"));
         }
 
         let a = if inputs[0].ty() == ValType::I32 {
             inputs[0].to_i32()
         } else {
-            return Err(2);
+            return Err(Trap::new("Invalid type of arguments"));
         };
 
         let b = if inputs[1].ty() == ValType::I32 {
             inputs[1].to_i32()
         } else {
-            return Err(3);
+            return Err(Trap::new("Invalid type of arguments"));
         };
 
         let c = a + b;
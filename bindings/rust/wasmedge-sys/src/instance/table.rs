@@ -6,12 +6,12 @@
 //! the end resticts the upper bound (inclusive).
 
 use crate::{
-    error::{check, TableError, WasmEdgeError},
+    error::{check, CoreError, CoreExecutionError, TableError, WasmEdgeError},
     ffi,
-    types::{RefType, WasmValue},
+    types::{RefType, ValType, WasmValue},
     WasmEdgeResult,
 };
-use std::ops::RangeInclusive;
+use std::ops::{Range, RangeInclusive};
 
 /// Struct of WasmEdge Table.
 ///
@@ -113,6 +113,126 @@ impl Table {
         }
     }
 
+    /// Sets the elements in a range of the [Table] to the given value.
+    ///
+    /// # Arguments
+    ///
+    /// - `data` specifies the value to be stored in each of the elements.
+    ///
+    /// - `offset` specifies the position of the first element to be set.
+    ///
+    /// - `len` specifies the number of the elements to be set.
+    ///
+    /// # Error
+    ///
+    /// If the range exceeds the capacity of the [Table] or the type of `data` does not match the element type, then
+    /// an error is returned and the [Table] is not modified.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use wasmedge_sys::{RefType, TableType, Table, WasmValue};
+    ///
+    /// let ty = TableType::create(RefType::ExternRef, 10..=20).expect("fail to create a TableType");
+    /// let mut table = Table::create(&ty).expect("fail to create a Table");
+    ///
+    /// let mut value = 100;
+    /// table
+    ///     .fill(WasmValue::from_extern_ref(&mut value), 2, 3)
+    ///     .expect("fail to fill the table");
+    /// assert!(table.get_data(1).unwrap().is_null_ref());
+    /// assert!(!table.get_data(4).unwrap().is_null_ref());
+    /// ```
+    pub fn fill(&mut self, data: WasmValue, offset: u32, len: u32) -> WasmEdgeResult<()> {
+        self.check_bound(offset, len)?;
+        self.check_elem_ty(&data)?;
+        for idx in offset..offset + len {
+            self.set_data(data, idx)?;
+        }
+        Ok(())
+    }
+
+    /// Copies the elements in a range of the [Table] to another position of the same [Table].
+    ///
+    /// The source and destination ranges may overlap.
+    ///
+    /// # Arguments
+    ///
+    /// - `src` specifies the range of the elements to be copied.
+    ///
+    /// - `dest` specifies the position of the first element to be overwritten.
+    ///
+    /// # Error
+    ///
+    /// If either of the ranges exceeds the capacity of the [Table], then an error is returned and the [Table] is not
+    /// modified.
+    pub fn copy_within(&mut self, src: Range<u32>, dest: u32) -> WasmEdgeResult<()> {
+        let len = src.end.saturating_sub(src.start);
+        self.check_bound(src.start, len)?;
+        self.check_bound(dest, len)?;
+        let data = src
+            .map(|idx| self.get_data(idx))
+            .collect::<WasmEdgeResult<Vec<_>>>()?;
+        for (idx, value) in (dest..).zip(data) {
+            self.set_data(value, idx)?;
+        }
+        Ok(())
+    }
+
+    /// Sets the elements of the [Table] starting at a specific position to the values yielded by the given iterator.
+    ///
+    /// This is the host-side counterpart of initializing a table with an element segment, and is convenient to
+    /// install a batch of [function references](crate::WasmValue::from_func_ref) at once.
+    ///
+    /// # Arguments
+    ///
+    /// - `offset` specifies the position of the first element to be set.
+    ///
+    /// - `data` specifies the values to be stored.
+    ///
+    /// # Error
+    ///
+    /// If the values exceed the capacity of the [Table] or any of them does not match the element type, then an error
+    /// is returned and the [Table] is not modified.
+    pub fn init_from_iter(
+        &mut self,
+        offset: u32,
+        data: impl IntoIterator<Item = WasmValue>,
+    ) -> WasmEdgeResult<()> {
+        let data = data.into_iter().collect::<Vec<_>>();
+        let len = u32::try_from(data.len()).unwrap_or(u32::MAX);
+        self.check_bound(offset, len)?;
+        for value in data.iter() {
+            self.check_elem_ty(value)?;
+        }
+        for (idx, value) in (offset..).zip(data) {
+            self.set_data(value, idx)?;
+        }
+        Ok(())
+    }
+
+    fn check_bound(&self, offset: u32, len: u32) -> WasmEdgeResult<()> {
+        match offset as u64 + len as u64 > self.capacity() as u64 {
+            true => Err(WasmEdgeError::Core(CoreError::Execution(
+                CoreExecutionError::TableOutOfBounds,
+            ))),
+            false => Ok(()),
+        }
+    }
+
+    fn check_elem_ty(&self, data: &WasmValue) -> WasmEdgeResult<()> {
+        let elem_ty = match self.ty()?.elem_ty() {
+            RefType::FuncRef => ValType::FuncRef,
+            RefType::ExternRef => ValType::ExternRef,
+        };
+        match data.ty() == elem_ty {
+            true => Ok(()),
+            false => Err(WasmEdgeError::Core(CoreError::Execution(
+                CoreExecutionError::RefTypeMismatch,
+            ))),
+        }
+    }
+
     /// Returns the capacity of the [Table].
     ///
     /// # Example
@@ -235,7 +355,8 @@ impl TableType {
     /// ```
     pub fn limit(&self) -> RangeInclusive<u32> {
         let limit = unsafe { ffi::WasmEdge_TableTypeGetLimit(self.inner.0) };
-        limit.into()
+        // the limits of the tables are checked to fit in u32 on creating
+        RangeInclusive::try_from(limit).expect("the limits of a table fit in u32")
     }
 }
 
@@ -247,7 +368,7 @@ unsafe impl Sync for InnerTableType {}
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::{FuncType, Function, RefType, ValType};
+    use crate::{error::Trap, Caller, FuncType, Function, RefType, ValType};
     use std::{
         sync::{Arc, Mutex},
         thread,
@@ -354,6 +475,82 @@ mod tests {
         assert_eq!(return_tys, [ValType::I32]);
     }
 
+    #[test]
+    fn test_table_bulk() {
+        // create a TableType instance
+        let result = TableType::create(RefType::ExternRef, 10..=20);
+        assert!(result.is_ok());
+        let ty = result.unwrap();
+
+        // create a Table instance
+        let result = Table::create(&ty);
+        assert!(result.is_ok());
+        let mut table = result.unwrap();
+
+        let mut values = [1, 2, 3];
+
+        // fill the table
+        let result = table.fill(WasmValue::from_extern_ref(&mut values[0]), 2, 3);
+        assert!(result.is_ok());
+        assert!(table.get_data(1).unwrap().is_null_ref());
+        assert!(!table.get_data(2).unwrap().is_null_ref());
+        assert!(!table.get_data(4).unwrap().is_null_ref());
+        assert!(table.get_data(5).unwrap().is_null_ref());
+
+        // fill out of the bounds
+        let result = table.fill(WasmValue::from_extern_ref(&mut values[0]), 8, 3);
+        assert!(result.is_err());
+        assert_eq!(
+            result.unwrap_err(),
+            WasmEdgeError::Core(CoreError::Execution(CoreExecutionError::TableOutOfBounds))
+        );
+        assert!(table.get_data(8).unwrap().is_null_ref());
+
+        // fill with the mismatched type
+        let result = table.fill(WasmValue::from_null_ref(RefType::FuncRef), 0, 1);
+        assert!(result.is_err());
+        assert_eq!(
+            result.unwrap_err(),
+            WasmEdgeError::Core(CoreError::Execution(CoreExecutionError::RefTypeMismatch))
+        );
+
+        // init the table from an iterator
+        let data = values
+            .iter_mut()
+            .map(WasmValue::from_extern_ref)
+            .collect::<Vec<_>>();
+        let result = table.init_from_iter(6, data.clone());
+        assert!(result.is_ok());
+        assert_eq!(table.get_data(6).unwrap().extern_ref::<i32>(), Some(&1));
+        assert_eq!(table.get_data(8).unwrap().extern_ref::<i32>(), Some(&3));
+
+        // init out of the bounds
+        let result = table.init_from_iter(8, data);
+        assert!(result.is_err());
+        assert_eq!(
+            result.unwrap_err(),
+            WasmEdgeError::Core(CoreError::Execution(CoreExecutionError::TableOutOfBounds))
+        );
+        assert!(table.get_data(9).unwrap().is_null_ref());
+
+        // copy the overlapped elements
+        let result = table.copy_within(6..9, 7);
+        assert!(result.is_ok());
+        assert_eq!(table.get_data(7).unwrap().extern_ref::<i32>(), Some(&1));
+        assert_eq!(table.get_data(8).unwrap().extern_ref::<i32>(), Some(&2));
+        assert_eq!(table.get_data(9).unwrap().extern_ref::<i32>(), Some(&3));
+
+        // copy out of the bounds
+        let result = table.copy_within(8..11, 0);
+        assert!(result.is_err());
+        assert_eq!(
+            result.unwrap_err(),
+            WasmEdgeError::Core(CoreError::Execution(CoreExecutionError::TableOutOfBounds))
+        );
+        let result = table.copy_within(0..3, 8);
+        assert!(result.is_err());
+    }
+
     #[test]
     fn test_table_send() {
         // create a TableType instance
@@ -423,23 +620,23 @@ mod tests {
         handle.join().unwrap();
     }
 
-    fn real_add(input: Vec<WasmValue>) -> Result<Vec<WasmValue>, u8> {
+    fn real_add(_caller: Caller, input: Vec<WasmValue>) -> Result<Vec<WasmValue>, Trap> {
         println!("Rust: Entering Rust function real_add");
 
         if input.len() != 2 {
-            return Err(1);
+            return Err(Trap::new("Invalid number of arguments"));
         }
 
         let a = if input[0].ty() == ValType::I32 {
             input[0].to_i32()
         } else {
-            return Err(2);
+            return Err(Trap::new("Invalid type of arguments"));
         };
 
         let b = if input[1].ty() == ValType::I32 {
             input[0].to_i32()
         } else {
-            return Err(3);
+            return Err(Trap::new("Invalid type of arguments"));
         };
 
         let c = a + b;
@@ -1,170 +1,284 @@
-use crate::WasmValue;
-use std::marker::PhantomData;
-
-fn match_value(s: &str) -> WasmValue {
-    match s {
-        "i8" => WasmValue::from_i32(0),
-        "u8" => WasmValue::from_i32(0),
-        "i16" => WasmValue::from_i32(0),
-        "u16" => WasmValue::from_i32(0),
-        "i32" => WasmValue::from_i32(0),
-        "u32" => WasmValue::from_i64(0),
-        "i64" => WasmValue::from_i64(0),
-        "f32" => WasmValue::from_f32(0.),
-        "f64" => WasmValue::from_f64(0.),
-        "u128" => WasmValue::from_v128(0),
-        _ => panic!("unsupport type for WasmFnIO"),
+//! Defines the traits for converting between Rust types and WasmEdge values, which are used by the typed
+//! [host functions](crate::Function::wrap).
+
+use crate::{ffi, ValType, WasmValue};
+
+/// Defines a Rust type that can be mapped to a WebAssembly value type.
+///
+/// The trait is implemented for `i32`, `u32`, `i64`, `u64`, `f32`, `f64`, `i128` and `u128`. The unsigned integers
+/// are interpreted as the signed integers of the same width, and the 128-bit integers are mapped to `V128`.
+pub trait WasmValType: Sized {
+    /// Returns the WebAssembly value type of the Rust type.
+    fn ty() -> ValType;
+
+    /// Converts a [WasmValue] into the Rust type. If the type of the [WasmValue] does not match, then `None` is
+    /// returned.
+    fn from_value(value: &WasmValue) -> Option<Self>;
+
+    /// Converts the Rust value into a [WasmValue].
+    fn into_value(self) -> WasmValue;
+}
+
+macro_rules! impl_wasm_val_type {
+    ($t:ty, $ty:ident, $from:ident, $to:ident, $raw:ty) => {
+        impl WasmValType for $t {
+            fn ty() -> ValType {
+                ValType::$ty
+            }
+
+            fn from_value(value: &WasmValue) -> Option<Self> {
+                match value.ty() {
+                    ValType::$ty => Some(value.$to() as $t),
+                    _ => None,
+                }
+            }
+
+            fn into_value(self) -> WasmValue {
+                WasmValue::$from(self as $raw)
+            }
+        }
+    };
+}
+
+impl_wasm_val_type!(i32, I32, from_i32, to_i32, i32);
+impl_wasm_val_type!(u32, I32, from_i32, to_i32, i32);
+impl_wasm_val_type!(i64, I64, from_i64, to_i64, i64);
+impl_wasm_val_type!(u64, I64, from_i64, to_i64, i64);
+impl_wasm_val_type!(f32, F32, from_f32, to_f32, f32);
+impl_wasm_val_type!(f64, F64, from_f64, to_f64, f64);
+impl_wasm_val_type!(i128, V128, from_v128, to_v128, i128);
+impl_wasm_val_type!(u128, V128, from_v128, to_v128, i128);
+
+/// Defines a sequence of Rust values that can be mapped to the arguments or the returns of a WebAssembly function.
+///
+/// The trait is implemented for `()`, for every type implementing [WasmValType], and for the tuples of up to 16
+/// such types.
+pub trait WasmFnIO: Sized {
+    /// Returns the WebAssembly value types of the sequence.
+    fn types() -> Vec<ValType>;
+
+    /// Converts a slice of [WasmValue]s into the Rust values. If the length or any type of the values does not match,
+    /// then `None` is returned.
+    fn from_values(values: &[WasmValue]) -> Option<Self>;
+
+    /// Converts the Rust values into [WasmValue]s.
+    fn into_values(self) -> Vec<WasmValue>;
+
+    /// Converts a slice of raw WasmEdge values into the Rust values. If the length or any type of the values does not
+    /// match, then `None` is returned.
+    #[doc(hidden)]
+    fn from_raw(raw: &[ffi::WasmEdge_Value]) -> Option<Self> {
+        Self::from_values(&raw.iter().map(|x| WasmValue::from(*x)).collect::<Vec<_>>())
+    }
+
+    /// Writes the Rust values as raw WasmEdge values into the beginning of `raw`, and returns the number of the
+    /// values. `raw` must be long enough to hold all the values.
+    #[doc(hidden)]
+    fn write_raw(self, raw: &mut [ffi::WasmEdge_Value]) -> usize {
+        let values = self.into_values();
+        for (raw, value) in raw.iter_mut().zip(&values) {
+            *raw = value.as_raw();
+        }
+        values.len()
     }
 }
-pub trait WasmFnIO {
-    fn parameters() -> Vec<WasmValue>
-    where
-        Self: Sized,
-    {
+
+impl WasmFnIO for () {
+    fn types() -> Vec<ValType> {
         Vec::new()
     }
+
+    fn from_values(values: &[WasmValue]) -> Option<Self> {
+        match values.is_empty() {
+            true => Some(()),
+            false => None,
+        }
+    }
+
+    fn into_values(self) -> Vec<WasmValue> {
+        Vec::new()
+    }
+
+    fn from_raw(raw: &[ffi::WasmEdge_Value]) -> Option<Self> {
+        match raw.is_empty() {
+            true => Some(()),
+            false => None,
+        }
+    }
+
+    fn write_raw(self, _raw: &mut [ffi::WasmEdge_Value]) -> usize {
+        0
+    }
 }
 
-macro_rules! factory_io {
-    ($i:ident, ($($o:ident),+)) => {
-        #[allow(non_snake_case)]
-        pub struct $i<$($o),+> {
-            $($o: PhantomData<$o>),+
+impl<T: WasmValType> WasmFnIO for T {
+    fn types() -> Vec<ValType> {
+        vec![T::ty()]
+    }
+
+    fn from_values(values: &[WasmValue]) -> Option<Self> {
+        match values {
+            [value] => T::from_value(value),
+            _ => None,
         }
+    }
+
+    fn into_values(self) -> Vec<WasmValue> {
+        vec![self.into_value()]
+    }
+
+    fn from_raw(raw: &[ffi::WasmEdge_Value]) -> Option<Self> {
+        match raw {
+            [raw] => T::from_value(&WasmValue::from(*raw)),
+            _ => None,
+        }
+    }
+
+    fn write_raw(self, raw: &mut [ffi::WasmEdge_Value]) -> usize {
+        raw[0] = self.into_value().as_raw();
+        1
+    }
+}
+
+/// Defines a Rust closure or function that can be wrapped into a [host function](crate::Function::wrap).
+///
+/// The trait is implemented for every `Fn(A1, A2, ..., An) -> R + Send + Sync + 'static` with up to 16 arguments,
+/// wherein each argument type implements [WasmValType] and `R` implements [WasmFnIO]. The `Args` parameter is the
+/// tuple of the argument types.
+pub trait IntoHostFunc<Args: WasmFnIO, Rets: WasmFnIO>: Send + Sync + 'static {
+    /// Calls the closure with the given arguments.
+    fn call(&self, args: Args) -> Rets;
+}
+
+macro_rules! impl_wasm_fn {
+    ($($a:ident),+) => {
+        impl<$($a: WasmValType),+> WasmFnIO for ($($a,)+) {
+            fn types() -> Vec<ValType> {
+                vec![$($a::ty()),+]
+            }
+
+            #[allow(non_snake_case)]
+            fn from_values(values: &[WasmValue]) -> Option<Self> {
+                match values {
+                    [$($a),+] => Some(($($a::from_value($a)?,)+)),
+                    _ => None,
+                }
+            }
+
+            #[allow(non_snake_case)]
+            fn into_values(self) -> Vec<WasmValue> {
+                let ($($a,)+) = self;
+                vec![$($a.into_value()),+]
+            }
 
-        impl<$($o),+> WasmFnIO for $i<$($o),+> {
-            fn parameters() -> Vec<WasmValue> {
-                vec![$(match_value(std::any::type_name::<$o>())),+]
+            #[allow(non_snake_case)]
+            fn from_raw(raw: &[ffi::WasmEdge_Value]) -> Option<Self> {
+                match raw {
+                    [$($a),+] => Some(($($a::from_value(&WasmValue::from(*$a))?,)+)),
+                    _ => None,
+                }
+            }
+
+            #[allow(non_snake_case)]
+            fn write_raw(self, raw: &mut [ffi::WasmEdge_Value]) -> usize {
+                let ($($a,)+) = self;
+                let mut len = 0;
+                $(
+                    raw[len] = $a.into_value().as_raw();
+                    len += 1;
+                )+
+                len
+            }
+        }
+
+        impl<F, R, $($a: WasmValType),+> IntoHostFunc<($($a,)+), R> for F
+        where
+            F: Fn($($a),+) -> R + Send + Sync + 'static,
+            R: WasmFnIO,
+        {
+            #[allow(non_snake_case)]
+            fn call(&self, args: ($($a,)+)) -> R {
+                let ($($a,)+) = args;
+                self($($a),+)
             }
         }
+    };
+}
+
+impl<F, R> IntoHostFunc<(), R> for F
+where
+    F: Fn() -> R + Send + Sync + 'static,
+    R: WasmFnIO,
+{
+    fn call(&self, _args: ()) -> R {
+        self()
     }
 }
 
-factory_io!(I1, (O1));
-factory_io!(I2, (O1, O2));
-factory_io!(I3, (O1, O2, O3));
-factory_io!(I4, (O1, O2, O3, O4));
-factory_io!(I5, (O1, O2, O3, O4, O5));
-factory_io!(I6, (O1, O2, O3, O4, O5, O6));
-factory_io!(I7, (O1, O2, O3, O4, O5, O6, O7));
-factory_io!(I8, (O1, O2, O3, O4, O5, O6, O7, O8));
-factory_io!(I9, (O1, O2, O3, O4, O5, O6, O7, O8, O9));
-factory_io!(I10, (O1, O2, O3, O4, O5, O6, O7, O8, O9, O10));
-factory_io!(I11, (O1, O2, O3, O4, O5, O6, O7, O8, O9, O10, O11));
-factory_io!(I12, (O1, O2, O3, O4, O5, O6, O7, O8, O9, O10, O11, O12));
-factory_io!(
-    I13,
-    (O1, O2, O3, O4, O5, O6, O7, O8, O9, O10, O11, O12, O13)
-);
-factory_io!(
-    I14,
-    (O1, O2, O3, O4, O5, O6, O7, O8, O9, O10, O11, O12, O13, O14)
-);
-factory_io!(
-    I15,
-    (O1, O2, O3, O4, O5, O6, O7, O8, O9, O10, O11, O12, O13, O14, O15)
-);
-factory_io!(
-    I16,
-    (O1, O2, O3, O4, O5, O6, O7, O8, O9, O10, O11, O12, O13, O14, O15, O16)
-);
-factory_io!(
-    I17,
-    (O1, O2, O3, O4, O5, O6, O7, O8, O9, O10, O11, O12, O13, O14, O15, O16, O17)
-);
-factory_io!(
-    I18,
-    (O1, O2, O3, O4, O5, O6, O7, O8, O9, O10, O11, O12, O13, O14, O15, O16, O17, O18)
-);
-factory_io!(
-    I19,
-    (O1, O2, O3, O4, O5, O6, O7, O8, O9, O10, O11, O12, O13, O14, O15, O16, O17, O18, O19)
-);
-factory_io!(
-    I20,
-    (O1, O2, O3, O4, O5, O6, O7, O8, O9, O10, O11, O12, O13, O14, O15, O16, O17, O18, O19, O20)
-);
-factory_io!(
-    I21,
-    (
-        O1, O2, O3, O4, O5, O6, O7, O8, O9, O10, O11, O12, O13, O14, O15, O16, O17, O18, O19, O20,
-        O21
-    )
-);
-factory_io!(
-    I22,
-    (
-        O1, O2, O3, O4, O5, O6, O7, O8, O9, O10, O11, O12, O13, O14, O15, O16, O17, O18, O19, O20,
-        O21, O22
-    )
-);
-factory_io!(
-    I23,
-    (
-        O1, O2, O3, O4, O5, O6, O7, O8, O9, O10, O11, O12, O13, O14, O15, O16, O17, O18, O19, O20,
-        O21, O22, O23
-    )
-);
-factory_io!(
-    I24,
-    (
-        O1, O2, O3, O4, O5, O6, O7, O8, O9, O10, O11, O12, O13, O14, O15, O16, O17, O18, O19, O20,
-        O21, O22, O23, O24
-    )
-);
-factory_io!(
-    I25,
-    (
-        O1, O2, O3, O4, O5, O6, O7, O8, O9, O10, O11, O12, O13, O14, O15, O16, O17, O18, O19, O20,
-        O21, O22, O23, O24, O25
-    )
-);
-factory_io!(
-    I26,
-    (
-        O1, O2, O3, O4, O5, O6, O7, O8, O9, O10, O11, O12, O13, O14, O15, O16, O17, O18, O19, O20,
-        O21, O22, O23, O24, O25, O26
-    )
-);
-factory_io!(
-    I27,
-    (
-        O1, O2, O3, O4, O5, O6, O7, O8, O9, O10, O11, O12, O13, O14, O15, O16, O17, O18, O19, O20,
-        O21, O22, O23, O24, O25, O26, O27
-    )
-);
-factory_io!(
-    I28,
-    (
-        O1, O2, O3, O4, O5, O6, O7, O8, O9, O10, O11, O12, O13, O14, O15, O16, O17, O18, O19, O20,
-        O21, O22, O23, O24, O25, O26, O27, O28
-    )
-);
-factory_io!(
-    I29,
-    (
-        O1, O2, O3, O4, O5, O6, O7, O8, O9, O10, O11, O12, O13, O14, O15, O16, O17, O18, O19, O20,
-        O21, O22, O23, O24, O25, O26, O27, O28, O29
-    )
-);
-factory_io!(
-    I30,
-    (
-        O1, O2, O3, O4, O5, O6, O7, O8, O9, O10, O11, O12, O13, O14, O15, O16, O17, O18, O19, O20,
-        O21, O22, O23, O24, O25, O26, O27, O28, O29, O30
-    )
-);
-factory_io!(
-    I31,
-    (
-        O1, O2, O3, O4, O5, O6, O7, O8, O9, O10, O11, O12, O13, O14, O15, O16, O17, O18, O19, O20,
-        O21, O22, O23, O24, O25, O26, O27, O28, O29, O30, O31
-    )
-);
-factory_io!(
-    I32,
-    (
-        O1, O2, O3, O4, O5, O6, O7, O8, O9, O10, O11, O12, O13, O14, O15, O16, O17, O18, O19, O20,
-        O21, O22, O23, O24, O25, O26, O27, O28, O29, O30, O31, O32
-    )
-);
+impl_wasm_fn!(A1);
+impl_wasm_fn!(A1, A2);
+impl_wasm_fn!(A1, A2, A3);
+impl_wasm_fn!(A1, A2, A3, A4);
+impl_wasm_fn!(A1, A2, A3, A4, A5);
+impl_wasm_fn!(A1, A2, A3, A4, A5, A6);
+impl_wasm_fn!(A1, A2, A3, A4, A5, A6, A7);
+impl_wasm_fn!(A1, A2, A3, A4, A5, A6, A7, A8);
+impl_wasm_fn!(A1, A2, A3, A4, A5, A6, A7, A8, A9);
+impl_wasm_fn!(A1, A2, A3, A4, A5, A6, A7, A8, A9, A10);
+impl_wasm_fn!(A1, A2, A3, A4, A5, A6, A7, A8, A9, A10, A11);
+impl_wasm_fn!(A1, A2, A3, A4, A5, A6, A7, A8, A9, A10, A11, A12);
+impl_wasm_fn!(A1, A2, A3, A4, A5, A6, A7, A8, A9, A10, A11, A12, A13);
+impl_wasm_fn!(A1, A2, A3, A4, A5, A6, A7, A8, A9, A10, A11, A12, A13, A14);
+impl_wasm_fn!(A1, A2, A3, A4, A5, A6, A7, A8, A9, A10, A11, A12, A13, A14, A15);
+impl_wasm_fn!(A1, A2, A3, A4, A5, A6, A7, A8, A9, A10, A11, A12, A13, A14, A15, A16);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_io_wasm_val_type() {
+        assert_eq!(i32::ty(), ValType::I32);
+        assert_eq!(u64::ty(), ValType::I64);
+        assert_eq!(u128::ty(), ValType::V128);
+
+        let value = 3i32.into_value();
+        assert_eq!(value.ty(), ValType::I32);
+        assert_eq!(i32::from_value(&value), Some(3));
+        assert_eq!(i64::from_value(&value), None);
+
+        // unsigned integers are reinterpreted as signed integers of the same width
+        let value = u32::MAX.into_value();
+        assert_eq!(value.to_i32(), -1);
+        assert_eq!(u32::from_value(&value), Some(u32::MAX));
+    }
+
+    #[test]
+    fn test_io_wasm_fn_io() {
+        assert!(<()>::types().is_empty());
+        assert_eq!(f32::types(), vec![ValType::F32]);
+        assert_eq!(
+            <(i32, i64, f64)>::types(),
+            vec![ValType::I32, ValType::I64, ValType::F64]
+        );
+
+        let values = (1i32, 2i64, 3.5f64).into_values();
+        assert_eq!(values.len(), 3);
+        assert_eq!(<(i32, i64, f64)>::from_values(&values), Some((1, 2, 3.5)));
+        // the length does not match
+        assert_eq!(<(i32, i64)>::from_values(&values), None);
+        // a type does not match
+        assert_eq!(<(i32, i32, f64)>::from_values(&values), None);
+        assert_eq!(<()>::from_values(&[]), Some(()));
+
+        // the raw values round-trip without the WasmValue wrapping
+        let mut raw = [WasmValue::from_i32(0).as_raw(); 4];
+        assert_eq!((1i32, 2i64, 3.5f64).write_raw(&mut raw), 3);
+        assert_eq!(<(i32, i64, f64)>::from_raw(&raw[..3]), Some((1, 2, 3.5)));
+        assert_eq!(<(i32, i64)>::from_raw(&raw[..3]), None);
+        assert_eq!(7u32.write_raw(&mut raw), 1);
+        assert_eq!(u32::from_raw(&raw[..1]), Some(7));
+        assert_eq!(().write_raw(&mut raw), 0);
+        assert_eq!(<()>::from_raw(&[]), Some(()));
+    }
+}
@@ -11,13 +11,9 @@
 
 #![deny(rust_2018_idioms, unreachable_pub)]
 
-#[macro_use]
-extern crate lazy_static;
-
-use std::{
-    collections::HashMap,
-    sync::{Arc, Mutex},
-};
+// allows the derive macros to refer to this crate as `wasmedge_sys` in the unit tests
+#[cfg(test)]
+extern crate self as wasmedge_sys;
 
 #[doc(hidden)]
 #[allow(warnings)]
@@ -25,14 +21,20 @@ pub mod ffi {
     include!(concat!(env!("OUT_DIR"), "/wasmedge.rs"));
 }
 #[doc(hidden)]
+pub mod async_result;
+#[doc(hidden)]
 #[cfg(feature = "aot")]
 pub mod compiler;
 #[doc(hidden)]
 pub mod config;
+#[doc(hidden)]
+pub mod engine;
 pub mod error;
 #[doc(hidden)]
 pub mod executor;
 #[doc(hidden)]
+pub mod guest;
+#[doc(hidden)]
 pub mod import_obj;
 pub mod instance;
 #[doc(hidden)]
@@ -40,10 +42,16 @@ pub mod io;
 #[doc(hidden)]
 pub mod loader;
 pub mod module;
+mod pre_init;
+pub mod ptr;
+#[doc(hidden)]
+pub mod snapshot;
 #[doc(hidden)]
 pub mod statistics;
 #[doc(hidden)]
 pub mod store;
+#[doc(hidden)]
+pub mod typed_func;
 pub mod types;
 pub mod utils;
 #[doc(hidden)]
@@ -51,20 +59,26 @@ pub mod validator;
 #[doc(hidden)]
 pub mod vm;
 
+#[doc(inline)]
+pub use async_result::AsyncResult;
 #[doc(inline)]
 #[cfg(feature = "aot")]
 pub use compiler::Compiler;
 #[doc(inline)]
 pub use config::Config;
 #[doc(inline)]
+pub use engine::Engine;
+#[doc(inline)]
 pub use executor::Executor;
 #[doc(inline)]
+pub use guest::{GuestAllocator, GuestBuffer};
+#[doc(inline)]
 pub use import_obj::ImportObject;
 #[doc(inline)]
 pub use instance::{
-    function::{FuncType, Function},
+    function::{Caller, FuncType, Function},
     global::{Global, GlobalType},
-    memory::{MemType, Memory},
+    memory::{MemType, Memory, MemoryRef, MemoryView, SharedMemory},
     module::Instance,
     table::{Table, TableType},
 };
@@ -73,9 +87,15 @@ pub use loader::Loader;
 #[doc(inline)]
 pub use module::{Export, Import, Module};
 #[doc(inline)]
+pub use ptr::{WasmPod, WasmPtr, WasmSlice};
+#[doc(inline)]
+pub use snapshot::Snapshot;
+#[doc(inline)]
 pub use statistics::Statistics;
 #[doc(inline)]
-pub use store::Store;
+pub use store::{ResourceLimiter, ResourceUsage, Store};
+#[doc(inline)]
+pub use typed_func::TypedFunc;
 #[doc(inline)]
 pub use types::{
     CompilerOptimizationLevel, CompilerOutputFormat, ExternalType, Mutability, RefType, ValType,
@@ -85,20 +105,16 @@ pub use types::{
 pub use validator::Validator;
 #[doc(inline)]
 pub use vm::Vm;
+#[doc(inline)]
+pub use wasmedge_macro::WasmPod;
 
 /// The WasmEdge result type.
 pub type WasmEdgeResult<T> = Result<T, error::WasmEdgeError>;
 
-/// Type alias for a host function.
-pub type HostFunc = Box<dyn Fn(Vec<WasmValue>) -> Result<Vec<WasmValue>, u8> + Send + Sync>;
-
-lazy_static! {
-    static ref HOST_FUNCS: Arc<Mutex<HashMap<usize, HostFunc>>> =
-        Arc::new(Mutex::new(HashMap::with_capacity(
-            std::env::var("MAX_HOST_FUNC_LENGTH")
-                .map(|s| s
-                    .parse::<usize>()
-                    .expect("MAX_HOST_FUNC_LENGTH should be a positive integer."))
-                .unwrap_or(500)
-        )));
-}
+/// Type alias for a host function. The [Caller] argument gives the host function the access to the memory of the
+/// calling module instance.
+///
+/// If the host function panics, or returns the values not matching its [FuncType], then the execution fails with a
+/// [Trap](error::Trap) instead.
+pub type HostFunc =
+    Box<dyn Fn(Caller, Vec<WasmValue>) -> Result<Vec<WasmValue>, error::Trap> + Send + Sync>;
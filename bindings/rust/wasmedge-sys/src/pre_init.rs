@@ -0,0 +1,719 @@
+//! Defines the rewriting of the pre-initialized WASM binaries.
+//!
+//! After the initialization function of a module is run by [Vm::pre_initialize](crate::Vm::pre_initialize), the
+//! [Snapshot] of the module instance is written back into the WASM binary: the data of the memories become the new
+//! active data segments, the values of the mutable globals become the new initial values, the passive data segments
+//! dropped by the initialization become empty, and the start function and the export of the initialization function
+//! are removed.
+//!
+//! The elements of the tables can not be written back, since the function references are not mapped to the function
+//! indices, so the initialization changing the tables fails the pre-initialization instead.
+
+use crate::{
+    error::{PreInitError, WasmEdgeError},
+    snapshot::{GlobalSnapshot, Snapshot},
+    Instance, WasmEdgeResult,
+};
+
+// the section ids
+const SEC_IMPORT: u8 = 2;
+const SEC_MEMORY: u8 = 5;
+const SEC_GLOBAL: u8 = 6;
+const SEC_EXPORT: u8 = 7;
+const SEC_START: u8 = 8;
+const SEC_DATA: u8 = 11;
+const SEC_DATA_COUNT: u8 = 12;
+
+// the sections of a WASM binary, as the pairs of the section id and the payload
+type Sections<'a> = Vec<(u8, &'a [u8])>;
+
+// the zero bytes shorter than the gap are kept in the data segment, instead of splitting the data segment
+const DATA_SEGMENT_GAP: usize = 8;
+
+/// The name of the export of the start function in the staged WASM binary.
+pub(crate) const START_EXPORT: &str = "\0wasmedge_pre_init_start";
+
+/// Stages the WASM binary to pre-initialize. The start section is removed, and the start function is exported as
+/// [START_EXPORT] instead, so that the tables can be captured before any function of the module is run. Returns the
+/// staged WASM binary, and whether the start function exists.
+pub(crate) fn stage(wasm: &[u8]) -> WasmEdgeResult<(Vec<u8>, bool)> {
+    let (header, sections) = parse(wasm)?;
+    let start = match find(&sections, SEC_START) {
+        Some(payload) => Reader(payload).u32()?,
+        None => return Ok((wasm.to_vec(), false)),
+    };
+
+    let mut exports = Vec::new();
+    let has_exports = match find(&sections, SEC_EXPORT) {
+        Some(payload) => {
+            let mut reader = Reader(payload);
+            let count = reader.u32()?.checked_add(1).ok_or_else(malformed)?;
+            write_u32(&mut exports, count);
+            exports.extend_from_slice(reader.0);
+            true
+        }
+        None => {
+            write_u32(&mut exports, 1);
+            false
+        }
+    };
+    write_u32(&mut exports, START_EXPORT.len() as u32);
+    exports.extend_from_slice(START_EXPORT.as_bytes());
+    exports.push(0x00);
+    write_u32(&mut exports, start);
+
+    let mut output = header.to_vec();
+    for (id, payload) in sections.iter() {
+        match *id {
+            SEC_EXPORT => write_section(&mut output, SEC_EXPORT, &exports),
+            // the export section is right before the start section
+            SEC_START if !has_exports => write_section(&mut output, SEC_EXPORT, &exports),
+            SEC_START => (),
+            _ => write_section(&mut output, *id, payload),
+        }
+    }
+    Ok((output, true))
+}
+
+/// Records the elements of the tables defined by the module instance.
+pub(crate) fn capture_tables(instance: &Instance<'_>) -> WasmEdgeResult<Vec<Vec<u128>>> {
+    instance
+        .defined_tables()
+        .iter()
+        .map(|table| {
+            (0..table.capacity() as u32)
+                .map(|idx| Ok(table.get_data(idx)?.as_raw().Value))
+                .collect()
+        })
+        .collect()
+}
+
+/// Checks that the tables defined by the module instance are the same as the ones recorded by [capture_tables].
+pub(crate) fn check_tables(instance: &Instance<'_>, tables: &[Vec<u128>]) -> WasmEdgeResult<()> {
+    match capture_tables(instance)?
+        .iter()
+        .zip(tables)
+        .position(|(current, captured)| current != captured)
+    {
+        Some(idx) => Err(WasmEdgeError::PreInit(PreInitError::TableChanged(
+            idx as u32,
+        ))),
+        None => Ok(()),
+    }
+}
+
+/// Rewrites the WASM binary with the [Snapshot] taken after the initialization function named `init_func` is run.
+/// `data_size` returns the size in bytes of the data segment at the given index after the initialization.
+pub(crate) fn rewrite(
+    wasm: &[u8],
+    snapshot: &Snapshot,
+    data_size: impl Fn(u32) -> u32,
+    init_func: &str,
+) -> WasmEdgeResult<Vec<u8>> {
+    let (header, sections) = parse(wasm)?;
+
+    // the memories recorded in the snapshot are the ones defined in the module
+    if let Some(payload) = find(&sections, SEC_IMPORT) {
+        if has_imported_memory(payload)? {
+            return Err(WasmEdgeError::PreInit(PreInitError::ImportedMemory));
+        }
+    }
+    let (memories, mem_is_64) = match find(&sections, SEC_MEMORY) {
+        Some(payload) => rewrite_memories(payload, snapshot)?,
+        None => (Vec::new(), Vec::new()),
+    };
+    let has_data_count = find(&sections, SEC_DATA_COUNT).is_some();
+    let (count, data) = rewrite_data(
+        find(&sections, SEC_DATA),
+        snapshot,
+        &mem_is_64,
+        has_data_count,
+        data_size,
+    )?;
+
+    let mut output = header.to_vec();
+    for (id, payload) in sections.iter() {
+        let payload = match *id {
+            SEC_MEMORY => memories.clone(),
+            SEC_GLOBAL => rewrite_globals(payload, snapshot)?,
+            SEC_EXPORT => rewrite_exports(payload, init_func)?,
+            // the start function has been run before the initialization function
+            SEC_START => continue,
+            SEC_DATA => data.clone(),
+            SEC_DATA_COUNT => {
+                let mut payload = Vec::new();
+                write_u32(&mut payload, count);
+                payload
+            }
+            _ => payload.to_vec(),
+        };
+        write_section(&mut output, *id, &payload);
+    }
+    // the data section is the last one except the custom sections
+    if find(&sections, SEC_DATA).is_none() && count > 0 {
+        write_section(&mut output, SEC_DATA, &data);
+    }
+
+    Ok(output)
+}
+
+// splits the WASM binary into the header and the sections
+fn parse(wasm: &[u8]) -> WasmEdgeResult<(&[u8], Sections<'_>)> {
+    let mut reader = Reader(wasm);
+    let header = reader.take(8)?;
+    if header[0..4] != *b"\0asm" {
+        return Err(malformed());
+    }
+    let mut sections = Vec::new();
+    while !reader.0.is_empty() {
+        let id = reader.byte()?;
+        let len = reader.u32()?;
+        sections.push((id, reader.take(len as usize)?));
+    }
+    Ok((header, sections))
+}
+
+fn has_imported_memory(payload: &[u8]) -> WasmEdgeResult<bool> {
+    let mut reader = Reader(payload);
+    for _ in 0..reader.u32()? {
+        reader.name()?;
+        reader.name()?;
+        match reader.byte()? {
+            // function
+            0x00 => {
+                reader.u32()?;
+            }
+            // table
+            0x01 => {
+                reader.byte()?;
+                reader.limit()?;
+            }
+            // memory
+            0x02 => return Ok(true),
+            // global
+            0x03 => {
+                reader.take(2)?;
+            }
+            _ => return Err(malformed()),
+        }
+    }
+    Ok(false)
+}
+
+fn rewrite_memories(payload: &[u8], snapshot: &Snapshot) -> WasmEdgeResult<(Vec<u8>, Vec<bool>)> {
+    let mut reader = Reader(payload);
+    let count = reader.u32()?;
+    if count as usize != snapshot.memories.len() {
+        return Err(malformed());
+    }
+    let mut output = Vec::new();
+    let mut is_64 = Vec::new();
+    write_u32(&mut output, count);
+    for mem in snapshot.memories.iter() {
+        let (flag, _, max) = reader.limit()?;
+        // the initial size is the size after the initialization
+        output.push(flag);
+        write_u64(&mut output, mem.pages);
+        if let Some(max) = max {
+            write_u64(&mut output, max);
+        }
+        is_64.push(flag & 0x04 != 0);
+    }
+    Ok((output, is_64))
+}
+
+fn rewrite_globals(payload: &[u8], snapshot: &Snapshot) -> WasmEdgeResult<Vec<u8>> {
+    let mut reader = Reader(payload);
+    let count = reader.u32()?;
+    if count as usize != snapshot.globals.len() {
+        return Err(malformed());
+    }
+    let mut output = Vec::new();
+    write_u32(&mut output, count);
+    for global in snapshot.globals.iter() {
+        let ty = reader.take(2)?;
+        let expr = reader.const_expr()?;
+        output.extend_from_slice(ty);
+        match *global {
+            // the const globals may refer to the imported globals, so that they are kept as is
+            GlobalSnapshot::Const => output.extend_from_slice(expr),
+            GlobalSnapshot::I32(val) => {
+                output.push(0x41);
+                write_i64(&mut output, val as i64);
+            }
+            GlobalSnapshot::I64(val) => {
+                output.push(0x42);
+                write_i64(&mut output, val);
+            }
+            GlobalSnapshot::F32(val) => {
+                output.push(0x43);
+                output.extend_from_slice(&val.to_le_bytes());
+            }
+            GlobalSnapshot::F64(val) => {
+                output.push(0x44);
+                output.extend_from_slice(&val.to_le_bytes());
+            }
+            GlobalSnapshot::V128(val) => {
+                output.extend_from_slice(&[0xFD, 0x0C]);
+                output.extend_from_slice(&val.to_le_bytes());
+            }
+        }
+        if *global != GlobalSnapshot::Const {
+            output.push(0x0B);
+        }
+    }
+    Ok(output)
+}
+
+fn rewrite_exports(payload: &[u8], init_func: &str) -> WasmEdgeResult<Vec<u8>> {
+    let mut reader = Reader(payload);
+    let mut exports = Vec::new();
+    for _ in 0..reader.u32()? {
+        let start = reader.0;
+        let name = reader.name()?;
+        let kind = reader.byte()?;
+        reader.u32()?;
+        if kind == 0x00 && name == init_func.as_bytes() {
+            continue;
+        }
+        exports.push(&start[..start.len() - reader.0.len()]);
+    }
+    let mut output = Vec::new();
+    write_u32(&mut output, exports.len() as u32);
+    for export in exports {
+        output.extend_from_slice(export);
+    }
+    Ok(output)
+}
+
+fn rewrite_data(
+    payload: Option<&[u8]>,
+    snapshot: &Snapshot,
+    mem_is_64: &[bool],
+    has_data_count: bool,
+    data_size: impl Fn(u32) -> u32,
+) -> WasmEdgeResult<(u32, Vec<u8>)> {
+    let mut segments: Vec<Vec<u8>> = Vec::new();
+
+    // The active data segments have been dropped after the instantiation. If the data count section exists, the data
+    // segments may be referred by the indices in the code, so that they are replaced by the empty passive ones. The
+    // passive data segments dropped by the initialization are replaced by the empty passive ones too.
+    if let Some(payload) = payload {
+        let mut reader = Reader(payload);
+        for idx in 0..reader.u32()? {
+            let start = reader.0;
+            let active = match reader.u32()? {
+                0x00 => {
+                    reader.const_expr()?;
+                    true
+                }
+                0x01 => false,
+                0x02 => {
+                    reader.u32()?;
+                    reader.const_expr()?;
+                    true
+                }
+                _ => return Err(malformed()),
+            };
+            let len = reader.u32()?;
+            reader.take(len as usize)?;
+            match active {
+                true if has_data_count => segments.push(vec![0x01, 0x00]),
+                true => (),
+                false if len > 0 && data_size(idx) == 0 => segments.push(vec![0x01, 0x00]),
+                false => segments.push(start[..start.len() - reader.0.len()].to_vec()),
+            }
+        }
+    }
+
+    // the data of the memories become the new active data segments
+    for (idx, mem) in snapshot.memories.iter().enumerate() {
+        let data = &mem.data;
+        let mut offset = 0;
+        while let Some(start) = data[offset..].iter().position(|b| *b != 0) {
+            // the data segment ends before a long enough gap of zero bytes
+            let start = offset + start;
+            let mut end = start;
+            let mut zeros = 0;
+            for (pos, byte) in data.iter().enumerate().skip(start) {
+                match *byte {
+                    0 => zeros += 1,
+                    _ => {
+                        end = pos + 1;
+                        zeros = 0;
+                    }
+                }
+                if zeros >= DATA_SEGMENT_GAP {
+                    break;
+                }
+            }
+
+            let mut segment = Vec::new();
+            match idx {
+                0 => segment.push(0x00),
+                _ => {
+                    segment.push(0x02);
+                    write_u32(&mut segment, idx as u32);
+                }
+            }
+            match mem_is_64.get(idx) {
+                Some(true) => {
+                    segment.push(0x42);
+                    write_i64(&mut segment, start as i64);
+                }
+                _ => {
+                    segment.push(0x41);
+                    write_i64(&mut segment, start as u32 as i32 as i64);
+                }
+            }
+            segment.push(0x0B);
+            write_u32(&mut segment, (end - start) as u32);
+            segment.extend_from_slice(&data[start..end]);
+            segments.push(segment);
+            offset = end;
+        }
+    }
+
+    let mut output = Vec::new();
+    write_u32(&mut output, segments.len() as u32);
+    for segment in segments.iter() {
+        output.extend_from_slice(segment);
+    }
+    Ok((segments.len() as u32, output))
+}
+
+fn find<'a>(sections: &[(u8, &'a [u8])], id: u8) -> Option<&'a [u8]> {
+    sections
+        .iter()
+        .find(|(sec_id, _)| *sec_id == id)
+        .map(|(_, payload)| *payload)
+}
+
+fn write_section(output: &mut Vec<u8>, id: u8, payload: &[u8]) {
+    output.push(id);
+    write_u32(output, payload.len() as u32);
+    output.extend_from_slice(payload);
+}
+
+fn write_u32(output: &mut Vec<u8>, val: u32) {
+    write_u64(output, val as u64)
+}
+
+fn write_u64(output: &mut Vec<u8>, mut val: u64) {
+    loop {
+        let byte = (val & 0x7F) as u8;
+        val >>= 7;
+        match val {
+            0 => {
+                output.push(byte);
+                return;
+            }
+            _ => output.push(byte | 0x80),
+        }
+    }
+}
+
+fn write_i64(output: &mut Vec<u8>, mut val: i64) {
+    loop {
+        let byte = (val & 0x7F) as u8;
+        val >>= 7;
+        if (val == 0 && byte & 0x40 == 0) || (val == -1 && byte & 0x40 != 0) {
+            output.push(byte);
+            return;
+        }
+        output.push(byte | 0x80);
+    }
+}
+
+fn malformed() -> WasmEdgeError {
+    WasmEdgeError::PreInit(PreInitError::Malformed)
+}
+
+// reads the WASM binary
+struct Reader<'a>(&'a [u8]);
+impl<'a> Reader<'a> {
+    fn take(&mut self, len: usize) -> WasmEdgeResult<&'a [u8]> {
+        if len > self.0.len() {
+            return Err(malformed());
+        }
+        let (bytes, rest) = self.0.split_at(len);
+        self.0 = rest;
+        Ok(bytes)
+    }
+
+    fn byte(&mut self) -> WasmEdgeResult<u8> {
+        Ok(self.take(1)?[0])
+    }
+
+    fn u64(&mut self) -> WasmEdgeResult<u64> {
+        let mut val = 0;
+        for shift in (0..64).step_by(7) {
+            let byte = self.byte()?;
+            val |= ((byte & 0x7F) as u64) << shift;
+            if byte & 0x80 == 0 {
+                return Ok(val);
+            }
+        }
+        Err(malformed())
+    }
+
+    fn u32(&mut self) -> WasmEdgeResult<u32> {
+        u32::try_from(self.u64()?).map_err(|_| malformed())
+    }
+
+    fn name(&mut self) -> WasmEdgeResult<&'a [u8]> {
+        let len = self.u32()?;
+        self.take(len as usize)
+    }
+
+    // returns the flag, the min, and the max of a limit
+    fn limit(&mut self) -> WasmEdgeResult<(u8, u64, Option<u64>)> {
+        let flag = self.byte()?;
+        let min = self.u64()?;
+        let max = match flag & 0x01 {
+            0 => None,
+            _ => Some(self.u64()?),
+        };
+        Ok((flag, min, max))
+    }
+
+    // returns the const expression including the `end` instruction
+    fn const_expr(&mut self) -> WasmEdgeResult<&'a [u8]> {
+        let start = self.0;
+        loop {
+            match self.byte()? {
+                // end
+                0x0B => break,
+                // i32.const, i64.const
+                0x41 | 0x42 => {
+                    self.u64()?;
+                }
+                // f32.const
+                0x43 => {
+                    self.take(4)?;
+                }
+                // f64.const
+                0x44 => {
+                    self.take(8)?;
+                }
+                // global.get, ref.func
+                0x23 | 0xD2 => {
+                    self.u32()?;
+                }
+                // ref.null
+                0xD0 => {
+                    self.byte()?;
+                }
+                // v128.const
+                0xFD => match self.u32()? {
+                    0x0C => {
+                        self.take(16)?;
+                    }
+                    _ => return Err(malformed()),
+                },
+                // the extended const instructions
+                0x6A | 0x6B | 0x6C | 0x7C | 0x7D | 0x7E => (),
+                _ => return Err(malformed()),
+            }
+        }
+        Ok(&start[..start.len() - self.0.len()])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        error::{CoreError, CoreExecutionError, StoreError},
+        Vm,
+    };
+
+    fn run_i32(vm: &Vm, func_name: &str) -> i32 {
+        let result = vm.run_function(func_name, []);
+        assert!(result.is_ok());
+        result.unwrap()[0].to_i32()
+    }
+
+    #[test]
+    fn test_pre_init() {
+        let path = std::path::PathBuf::from(env!("WASMEDGE_DIR"))
+            .join("bindings/rust/wasmedge-sys/tests/data/pre_init.wasm");
+        let result = std::fs::read(path);
+        assert!(result.is_ok());
+        let wasm = result.unwrap();
+
+        // the initialization function should be exported
+        let result = Vm::create(None, None);
+        assert!(result.is_ok());
+        let mut vm = result.unwrap();
+        let result = vm.pre_initialize(&wasm, "not_exist");
+        assert!(result.is_err());
+        assert_eq!(
+            result.unwrap_err(),
+            WasmEdgeError::Store(StoreError::NotFoundFunc("not_exist".into()))
+        );
+
+        let result = vm.pre_initialize(&wasm, "initialize");
+        assert!(result.is_ok());
+        let initialized = result.unwrap();
+        assert_eq!(run_i32(&vm, "ready"), 1);
+
+        // instantiate the pre-initialized module
+        let result = Vm::create(None, None);
+        assert!(result.is_ok());
+        let mut vm = result.unwrap();
+        let result = vm.load_wasm_from_buffer(&initialized);
+        assert!(result.is_ok());
+        let result = vm.validate();
+        assert!(result.is_ok());
+        let result = vm.instantiate();
+        assert!(result.is_ok());
+
+        // the globals are initialized, and the start function is not run again
+        assert_eq!(run_i32(&vm, "ready"), 1);
+        let result = vm.run_function("heap", []);
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap()[0].to_i64(), 65536);
+        assert_eq!(run_i32(&vm, "started"), 1);
+
+        // the initialization function is not exported
+        let result = vm.run_function("initialize", []);
+        assert!(result.is_err());
+
+        // the memory is initialized
+        let result = vm.store_mut();
+        assert!(result.is_ok());
+        let store = result.unwrap();
+        let result = store.find_memory("memory");
+        assert!(result.is_ok());
+        let memory = result.unwrap();
+        assert_eq!(memory.size(), 2);
+        let result = memory.read_string(0, 4);
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap(), "init");
+        let result = memory.read_string(65636, 5);
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap(), "hello");
+
+        // the passive data segment is kept at the same index
+        let result = vm.run_function("copy", []);
+        assert!(result.is_ok());
+        let result = memory.read_string(200, 7);
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap(), "passive");
+
+        // the initial size of the memory is the size after the initialization
+        let result = memory.ty();
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap().limit(), 2..=4);
+    }
+
+    #[test]
+    fn test_pre_init_table_changed() {
+        // (module
+        //   (table 1 funcref)
+        //   (func (export "initialize")
+        //     ref.null func
+        //     i32.const 1
+        //     table.grow 0
+        //     drop))
+        let wasm = [
+            0x00, 0x61, 0x73, 0x6D, 0x01, 0x00, 0x00, 0x00, // header
+            0x01, 0x04, 0x01, 0x60, 0x00, 0x00, // type section
+            0x03, 0x02, 0x01, 0x00, // function section
+            0x04, 0x04, 0x01, 0x70, 0x00, 0x01, // table section
+            0x07, 0x0E, 0x01, 0x0A, 0x69, 0x6E, 0x69, 0x74, 0x69, 0x61, 0x6C, 0x69, 0x7A, 0x65,
+            0x00, 0x00, // export section
+            0x0A, 0x0C, 0x01, 0x0A, 0x00, 0xD0, 0x70, 0x41, 0x01, 0xFC, 0x0F, 0x00, 0x1A,
+            0x0B, // code section
+        ];
+
+        let result = Vm::create(None, None);
+        assert!(result.is_ok());
+        let mut vm = result.unwrap();
+        let result = vm.pre_initialize(wasm, "initialize");
+        assert!(result.is_err());
+        assert_eq!(
+            result.unwrap_err(),
+            WasmEdgeError::PreInit(PreInitError::TableChanged(0))
+        );
+    }
+
+    #[test]
+    fn test_pre_init_data_dropped() {
+        // (module
+        //   (memory 1)
+        //   (data "abc")
+        //   (func (export "initialize")
+        //     data.drop 0)
+        //   (func (export "copy")
+        //     i32.const 0
+        //     i32.const 0
+        //     i32.const 3
+        //     memory.init 0))
+        let wasm = [
+            0x00, 0x61, 0x73, 0x6D, 0x01, 0x00, 0x00, 0x00, // header
+            0x01, 0x04, 0x01, 0x60, 0x00, 0x00, // type section
+            0x03, 0x03, 0x02, 0x00, 0x00, // function section
+            0x05, 0x03, 0x01, 0x00, 0x01, // memory section
+            0x07, 0x15, 0x02, 0x0A, 0x69, 0x6E, 0x69, 0x74, 0x69, 0x61, 0x6C, 0x69, 0x7A, 0x65,
+            0x00, 0x00, 0x04, 0x63, 0x6F, 0x70, 0x79, 0x00, 0x01, // export section
+            0x0C, 0x01, 0x01, // data count section
+            0x0A, 0x14, 0x02, 0x05, 0x00, 0xFC, 0x09, 0x00, 0x0B, 0x0C, 0x00, 0x41, 0x00, 0x41,
+            0x00, 0x41, 0x03, 0xFC, 0x08, 0x00, 0x00, 0x0B, // code section
+            0x0B, 0x06, 0x01, 0x01, 0x03, 0x61, 0x62, 0x63, // data section
+        ];
+
+        let result = Vm::create(None, None);
+        assert!(result.is_ok());
+        let mut vm = result.unwrap();
+        let result = vm.pre_initialize(wasm, "initialize");
+        assert!(result.is_ok());
+        let initialized = result.unwrap();
+
+        // the dropped data segment stays dropped in the pre-initialized module
+        let result = Vm::create(None, None);
+        assert!(result.is_ok());
+        let mut vm = result.unwrap();
+        let result = vm.load_wasm_from_buffer(&initialized);
+        assert!(result.is_ok());
+        let result = vm.validate();
+        assert!(result.is_ok());
+        let result = vm.instantiate();
+        assert!(result.is_ok());
+        let result = vm.run_function("copy", []);
+        assert!(result.is_err());
+        assert_eq!(
+            result.unwrap_err(),
+            WasmEdgeError::Core(CoreError::Execution(CoreExecutionError::MemoryOutOfBounds))
+        );
+    }
+
+    #[test]
+    fn test_pre_init_rewrite() {
+        let snapshot = Snapshot {
+            memories: vec![],
+            globals: vec![],
+        };
+
+        // the module importing a memory
+        let wasm = [
+            0x00, 0x61, 0x73, 0x6D, 0x01, 0x00, 0x00, 0x00, 0x02, 0x0C, 0x01, 0x03, 0x65, 0x6E,
+            0x76, 0x03, 0x6D, 0x65, 0x6D, 0x02, 0x00, 0x01,
+        ];
+        let result = rewrite(&wasm, &snapshot, |_| 0, "initialize");
+        assert!(result.is_err());
+        assert_eq!(
+            result.unwrap_err(),
+            WasmEdgeError::PreInit(PreInitError::ImportedMemory)
+        );
+
+        // the truncated module
+        let result = rewrite(&wasm[..12], &snapshot, |_| 0, "initialize");
+        assert!(result.is_err());
+        assert_eq!(
+            result.unwrap_err(),
+            WasmEdgeError::PreInit(PreInitError::Malformed)
+        );
+    }
+}
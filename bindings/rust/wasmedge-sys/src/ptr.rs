@@ -0,0 +1,703 @@
+//! Defines WasmEdge WasmPtr and WasmSlice structs, which access the typed values at guest addresses in a
+//! [memory](crate::Memory).
+//!
+//! The values are stored in the little-endian byte order, which is the byte order of WebAssembly. A type can be
+//! stored in a [memory](crate::Memory) if it implements [WasmPod]. For a `#[repr(C)]` struct, [WasmPod] can be derived.
+//!
+//! The values are copied out of and into the [memory](crate::Memory), so no reference into the data of the memory is
+//! held, and the memory can be grown or accessed through other handles at any time. Use the unsafe
+//! [Memory::as_slice](crate::Memory::as_slice) to access the data without copying.
+
+use crate::{
+    error::{CoreError, CoreExecutionError, MemError, WasmEdgeError},
+    Memory, WasmEdgeResult,
+};
+use std::{fmt, marker::PhantomData};
+
+/// Defines a plain-old-data type which can be read from and written to the linear memory of WebAssembly.
+///
+/// The trait is implemented for the integers, `f32`, `f64`, the arrays of [WasmPod] types, and [WasmPtr]. It can be
+/// derived for a `#[repr(C)]` struct, of which all the fields implement [WasmPod]. The fields are laid out in the
+/// declaration order, and each field is aligned to the alignment of its type, the same as the layout of the C struct
+/// compiled to `wasm32`.
+///
+/// # Example
+///
+/// ```
+/// use wasmedge_sys::{WasmPod, WasmPtr};
+///
+/// #[derive(WasmPod)]
+/// #[repr(C)]
+/// struct IoVec {
+///     buf: WasmPtr<u8>,
+///     len: u32,
+/// }
+///
+/// assert_eq!(IoVec::SIZE, 8);
+/// ```
+pub trait WasmPod: Sized {
+    /// The size in bytes of the type in the linear memory.
+    const SIZE: usize;
+
+    /// The alignment in bytes of the type in the linear memory.
+    const ALIGN: usize;
+
+    /// Reads a value from the given bytes in the little-endian byte order. The length of `bytes` must be
+    /// [SIZE](WasmPod::SIZE).
+    fn from_le_bytes(bytes: &[u8]) -> Self;
+
+    /// Writes the value into the given bytes in the little-endian byte order. The length of `bytes` must be
+    /// [SIZE](WasmPod::SIZE).
+    fn write_le_bytes(&self, bytes: &mut [u8]);
+}
+
+/// Rounds `offset` up to the multiple of `align`. It is used by the derived [WasmPod] implementations.
+#[doc(hidden)]
+pub const fn align_up(offset: usize, align: usize) -> usize {
+    offset.div_ceil(align) * align
+}
+
+macro_rules! impl_wasm_pod {
+    ($($t:ty),+) => {
+        $(
+            impl WasmPod for $t {
+                const SIZE: usize = std::mem::size_of::<$t>();
+                const ALIGN: usize = std::mem::size_of::<$t>();
+
+                fn from_le_bytes(bytes: &[u8]) -> Self {
+                    let mut buf = [0; std::mem::size_of::<$t>()];
+                    buf.copy_from_slice(bytes);
+                    <$t>::from_le_bytes(buf)
+                }
+
+                fn write_le_bytes(&self, bytes: &mut [u8]) {
+                    bytes.copy_from_slice(&self.to_le_bytes());
+                }
+            }
+        )+
+    };
+}
+
+impl_wasm_pod!(u8, i8, u16, i16, u32, i32, u64, i64, u128, i128, f32, f64);
+
+impl<T: WasmPod, const N: usize> WasmPod for [T; N] {
+    const SIZE: usize = T::SIZE * N;
+    const ALIGN: usize = T::ALIGN;
+
+    fn from_le_bytes(bytes: &[u8]) -> Self {
+        std::array::from_fn(|i| T::from_le_bytes(&bytes[i * T::SIZE..(i + 1) * T::SIZE]))
+    }
+
+    fn write_le_bytes(&self, bytes: &mut [u8]) {
+        for (i, value) in self.iter().enumerate() {
+            value.write_le_bytes(&mut bytes[i * T::SIZE..(i + 1) * T::SIZE]);
+        }
+    }
+}
+
+/// Struct of WasmEdge WasmPtr.
+///
+/// A [WasmPtr] is a guest address of a value of type `T` in a [memory](crate::Memory). It is 4 bytes in the linear
+/// memory, so it can be used as a field of a [WasmPod] struct.
+pub struct WasmPtr<T> {
+    offset: u32,
+    _marker: PhantomData<fn() -> T>,
+}
+impl<T> WasmPtr<T> {
+    /// Creates a [WasmPtr] pointing to the given guest address.
+    ///
+    /// # Argument
+    ///
+    /// - `offset` specifies the guest address in the [memory](crate::Memory).
+    pub fn new(offset: u32) -> Self {
+        Self {
+            offset,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Creates a null [WasmPtr].
+    pub fn null() -> Self {
+        Self::new(0)
+    }
+
+    /// Returns the guest address of the [WasmPtr].
+    pub fn offset(&self) -> u32 {
+        self.offset
+    }
+
+    /// Returns `true` if the [WasmPtr] is null.
+    pub fn is_null(&self) -> bool {
+        self.offset == 0
+    }
+
+    /// Casts the [WasmPtr] to a pointer of another type.
+    pub fn cast<U>(self) -> WasmPtr<U> {
+        WasmPtr::new(self.offset)
+    }
+}
+impl<T: WasmPod> WasmPtr<T> {
+    /// Returns the [WasmPtr] pointing to the `count`-th value of type `T` after this one.
+    ///
+    /// # Argument
+    ///
+    /// - `count` specifies the number of values to move forward.
+    ///
+    /// # Error
+    ///
+    /// If the result address overflows the 32-bit address space, then an error is returned.
+    pub fn advance(self, count: u32) -> WasmEdgeResult<Self> {
+        byte_len::<T>(count)
+            .and_then(|len| self.offset.checked_add(len))
+            .map(Self::new)
+            .ok_or_else(out_of_bounds)
+    }
+
+    /// Returns a [WasmSlice] of `len` values of type `T` starting at this [WasmPtr].
+    ///
+    /// # Argument
+    ///
+    /// - `len` specifies the number of values in the slice.
+    ///
+    /// # Error
+    ///
+    /// If the slice overflows the 32-bit address space, then an error is returned.
+    pub fn slice(self, len: u32) -> WasmEdgeResult<WasmSlice<T>> {
+        WasmSlice::new(self.offset, len)
+    }
+
+    /// Reads the value pointed by the [WasmPtr] from the given [memory](crate::Memory).
+    ///
+    /// # Argument
+    ///
+    /// - `mem` specifies the [memory](crate::Memory) to read.
+    ///
+    /// # Error
+    ///
+    /// If the value is out of the bounds of the [memory](crate::Memory), then an error is returned.
+    pub fn read(&self, mem: &Memory) -> WasmEdgeResult<T> {
+        let len = byte_len::<T>(1).ok_or_else(out_of_bounds)?;
+        let bytes = mem.get_data(self.offset.into(), len)?;
+        Ok(T::from_le_bytes(&bytes))
+    }
+
+    /// Writes the value pointed by the [WasmPtr] into the given [memory](crate::Memory).
+    ///
+    /// # Arguments
+    ///
+    /// - `mem` specifies the [memory](crate::Memory) to write.
+    ///
+    /// - `value` specifies the value to write.
+    ///
+    /// # Error
+    ///
+    /// If the value is out of the bounds of the [memory](crate::Memory), then an error is returned.
+    pub fn write(&self, mem: &mut Memory, value: &T) -> WasmEdgeResult<()> {
+        let len = byte_len::<T>(1).ok_or_else(out_of_bounds)?;
+        let mut bytes = vec![0; len as usize];
+        value.write_le_bytes(&mut bytes);
+        mem.set_data(bytes, self.offset.into())
+    }
+}
+impl WasmPtr<u8> {
+    /// Writes a string in UTF-8 at the [WasmPtr], and returns the [WasmSlice] of the written bytes.
+    ///
+    /// # Arguments
+    ///
+    /// - `mem` specifies the [memory](crate::Memory) to write.
+    ///
+    /// - `s` specifies the string to write.
+    ///
+    /// # Error
+    ///
+    /// If the string is out of the bounds of the [memory](crate::Memory), then an error is returned.
+    pub fn write_utf8(
+        &self,
+        mem: &mut Memory,
+        s: impl AsRef<str>,
+    ) -> WasmEdgeResult<WasmSlice<u8>> {
+        let bytes = s.as_ref().as_bytes();
+        let slice = WasmSlice::new(self.offset, len_u32(bytes.len())?)?;
+        slice.write(mem, bytes)?;
+        Ok(slice)
+    }
+}
+impl WasmPtr<u16> {
+    /// Writes a string in UTF-16 at the [WasmPtr], and returns the [WasmSlice] of the written code units.
+    ///
+    /// # Arguments
+    ///
+    /// - `mem` specifies the [memory](crate::Memory) to write.
+    ///
+    /// - `s` specifies the string to write.
+    ///
+    /// # Error
+    ///
+    /// If the string is out of the bounds of the [memory](crate::Memory), then an error is returned.
+    pub fn write_utf16(
+        &self,
+        mem: &mut Memory,
+        s: impl AsRef<str>,
+    ) -> WasmEdgeResult<WasmSlice<u16>> {
+        let units = s.as_ref().encode_utf16().collect::<Vec<_>>();
+        let slice = WasmSlice::new(self.offset, len_u32(units.len())?)?;
+        slice.write(mem, &units)?;
+        Ok(slice)
+    }
+}
+impl<T> Clone for WasmPtr<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+impl<T> Copy for WasmPtr<T> {}
+impl<T> PartialEq for WasmPtr<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.offset == other.offset
+    }
+}
+impl<T> Eq for WasmPtr<T> {}
+impl<T> fmt::Debug for WasmPtr<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "WasmPtr({:#x})", self.offset)
+    }
+}
+impl<T> WasmPod for WasmPtr<T> {
+    const SIZE: usize = 4;
+    const ALIGN: usize = 4;
+
+    fn from_le_bytes(bytes: &[u8]) -> Self {
+        Self::new(<u32 as WasmPod>::from_le_bytes(bytes))
+    }
+
+    fn write_le_bytes(&self, bytes: &mut [u8]) {
+        self.offset.write_le_bytes(bytes)
+    }
+}
+
+/// Struct of WasmEdge WasmSlice.
+///
+/// A [WasmSlice] is a sequence of `len` values of type `T` starting at a guest address in a
+/// [memory](crate::Memory).
+pub struct WasmSlice<T> {
+    ptr: WasmPtr<T>,
+    len: u32,
+}
+impl<T: WasmPod> WasmSlice<T> {
+    /// Creates a [WasmSlice] of `len` values starting at the given guest address.
+    ///
+    /// # Arguments
+    ///
+    /// - `offset` specifies the guest address of the first value.
+    ///
+    /// - `len` specifies the number of values in the slice.
+    ///
+    /// # Error
+    ///
+    /// If the slice overflows the 32-bit address space, then an error is returned.
+    pub fn new(offset: u32, len: u32) -> WasmEdgeResult<Self> {
+        match byte_len::<T>(len).and_then(|bytes| offset.checked_add(bytes)) {
+            Some(_) => Ok(Self {
+                ptr: WasmPtr::new(offset),
+                len,
+            }),
+            None => Err(out_of_bounds()),
+        }
+    }
+
+    /// Returns the [WasmPtr] to the first value of the [WasmSlice].
+    pub fn ptr(&self) -> WasmPtr<T> {
+        self.ptr
+    }
+
+    /// Returns the number of values in the [WasmSlice].
+    pub fn len(&self) -> u32 {
+        self.len
+    }
+
+    /// Returns `true` if the [WasmSlice] has no value.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the [WasmPtr] to the value at the given index.
+    ///
+    /// # Argument
+    ///
+    /// - `index` specifies the index of the value.
+    ///
+    /// # Error
+    ///
+    /// If the index is out of the bounds of the [WasmSlice], then an error is returned.
+    pub fn index(&self, index: u32) -> WasmEdgeResult<WasmPtr<T>> {
+        match index < self.len {
+            true => self.ptr.advance(index),
+            false => Err(out_of_bounds()),
+        }
+    }
+
+    /// Returns the [WasmSlice] of the values in the range `start..end`.
+    ///
+    /// # Arguments
+    ///
+    /// - `start` specifies the index of the first value.
+    ///
+    /// - `end` specifies the index after the last value.
+    ///
+    /// # Error
+    ///
+    /// If the range is out of the bounds of the [WasmSlice], then an error is returned.
+    pub fn subslice(&self, start: u32, end: u32) -> WasmEdgeResult<Self> {
+        match start <= end && end <= self.len {
+            true => Ok(Self {
+                ptr: self.ptr.advance(start)?,
+                len: end - start,
+            }),
+            false => Err(out_of_bounds()),
+        }
+    }
+
+    /// Reads all the values of the [WasmSlice] from the given [memory](crate::Memory).
+    ///
+    /// # Argument
+    ///
+    /// - `mem` specifies the [memory](crate::Memory) to read.
+    ///
+    /// # Error
+    ///
+    /// If the [WasmSlice] is out of the bounds of the [memory](crate::Memory), then an error is returned.
+    pub fn read(&self, mem: &Memory) -> WasmEdgeResult<Vec<T>> {
+        let bytes = mem.get_data(self.ptr.offset.into(), self.byte_len())?;
+        // the bytes are split by index, since `T` may be zero-sized
+        Ok((0..self.len as usize)
+            .map(|i| T::from_le_bytes(&bytes[i * T::SIZE..(i + 1) * T::SIZE]))
+            .collect())
+    }
+
+    /// Writes the given values into the [WasmSlice] in the given [memory](crate::Memory).
+    ///
+    /// # Arguments
+    ///
+    /// - `mem` specifies the [memory](crate::Memory) to write.
+    ///
+    /// - `values` specifies the values to write. Its length must be the same as the length of the [WasmSlice].
+    ///
+    /// # Error
+    ///
+    /// If the length of `values` does not match, or the [WasmSlice] is out of the bounds of the
+    /// [memory](crate::Memory), then an error is returned.
+    pub fn write(&self, mem: &mut Memory, values: &[T]) -> WasmEdgeResult<()> {
+        if values.len() != self.len as usize {
+            return Err(WasmEdgeError::Mem(MemError::SliceLen {
+                expected: self.len,
+                found: values.len(),
+            }));
+        }
+        let mut bytes = vec![0; self.byte_len() as usize];
+        // the bytes are split by index, since `T` may be zero-sized
+        for (i, value) in values.iter().enumerate() {
+            value.write_le_bytes(&mut bytes[i * T::SIZE..(i + 1) * T::SIZE]);
+        }
+        mem.set_data(bytes, self.ptr.offset.into())
+    }
+
+    fn byte_len(&self) -> u32 {
+        // the length is checked when the slice is created
+        self.len * T::SIZE as u32
+    }
+}
+impl WasmSlice<u8> {
+    /// Reads the bytes of the [WasmSlice] as a UTF-8 string.
+    ///
+    /// # Argument
+    ///
+    /// - `mem` specifies the [memory](crate::Memory) to read.
+    ///
+    /// # Error
+    ///
+    /// If the [WasmSlice] is out of the bounds of the [memory](crate::Memory), or the bytes are not valid UTF-8, then
+    /// an error is returned.
+    pub fn read_utf8(&self, mem: &Memory) -> WasmEdgeResult<String> {
+        let bytes = mem.get_data(self.ptr.offset.into(), self.len)?;
+        Ok(std::str::from_utf8(&bytes)?.to_string())
+    }
+}
+impl WasmSlice<u16> {
+    /// Reads the code units of the [WasmSlice] as a UTF-16 string.
+    ///
+    /// # Argument
+    ///
+    /// - `mem` specifies the [memory](crate::Memory) to read.
+    ///
+    /// # Error
+    ///
+    /// If the [WasmSlice] is out of the bounds of the [memory](crate::Memory), or the code units are not valid
+    /// UTF-16, then an error is returned.
+    pub fn read_utf16(&self, mem: &Memory) -> WasmEdgeResult<String> {
+        let units = self.read(mem)?;
+        String::from_utf16(&units).map_err(|_| WasmEdgeError::Utf16)
+    }
+}
+impl<T> Clone for WasmSlice<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+impl<T> Copy for WasmSlice<T> {}
+impl<T> PartialEq for WasmSlice<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.ptr == other.ptr && self.len == other.len
+    }
+}
+impl<T> Eq for WasmSlice<T> {}
+impl<T> fmt::Debug for WasmSlice<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "WasmSlice({:#x}, {})", self.ptr.offset, self.len)
+    }
+}
+
+/// Returns the size in bytes of `count` values of type `T`, or `None` if it overflows `u32`.
+fn byte_len<T: WasmPod>(count: u32) -> Option<u32> {
+    u32::try_from(T::SIZE).ok()?.checked_mul(count)
+}
+
+fn len_u32(len: usize) -> WasmEdgeResult<u32> {
+    u32::try_from(len).map_err(|_| out_of_bounds())
+}
+
+fn out_of_bounds() -> WasmEdgeError {
+    WasmEdgeError::Core(CoreError::Execution(CoreExecutionError::MemoryOutOfBounds))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{MemType, WasmPod};
+
+    #[derive(Debug, PartialEq, WasmPod)]
+    #[repr(C)]
+    struct Header {
+        tag: u8,
+        len: u32,
+        data: WasmPtr<u16>,
+        ts: u64,
+    }
+
+    #[derive(Debug, PartialEq, WasmPod)]
+    #[repr(C)]
+    struct Pair(u16, [u8; 3]);
+
+    #[derive(Debug, PartialEq, WasmPod)]
+    #[repr(C)]
+    struct Empty([u8; 0]);
+
+    fn create_memory() -> Memory {
+        let result = MemType::create(1..=2);
+        assert!(result.is_ok());
+        let ty = result.unwrap();
+        let result = Memory::create(&ty);
+        assert!(result.is_ok());
+        result.unwrap()
+    }
+
+    #[test]
+    fn test_ptr_pod() {
+        let mut mem = create_memory();
+
+        // the values are stored in little-endian
+        let ptr = WasmPtr::<u32>::new(8);
+        let result = ptr.write(&mut mem, &0x12345678);
+        assert!(result.is_ok());
+        let result = mem.get_data(8, 4);
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap(), vec![0x78, 0x56, 0x34, 0x12]);
+        let result = ptr.read(&mem);
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap(), 0x12345678);
+        let result = ptr.cast::<u16>().read(&mem);
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap(), 0x5678);
+
+        let ptr = WasmPtr::<f64>::new(16);
+        let result = ptr.write(&mut mem, &-1.5);
+        assert!(result.is_ok());
+        let result = ptr.read(&mem);
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap(), -1.5);
+
+        // arrays
+        let ptr = WasmPtr::<[i16; 3]>::new(32);
+        let result = ptr.write(&mut mem, &[1, -2, 3]);
+        assert!(result.is_ok());
+        let result = ptr.read(&mem);
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap(), [1, -2, 3]);
+
+        // out of bounds
+        let ptr = WasmPtr::<u64>::new(65530);
+        let result = ptr.read(&mem);
+        assert!(result.is_err());
+        assert_eq!(result.unwrap_err(), out_of_bounds());
+        let result = ptr.write(&mut mem, &1);
+        assert!(result.is_err());
+        let result = WasmPtr::<u64>::new(u32::MAX).advance(1);
+        assert!(result.is_err());
+
+        // pointer arithmetic
+        let result = WasmPtr::<u64>::new(8).advance(2);
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap().offset(), 24);
+        assert!(WasmPtr::<u8>::null().is_null());
+    }
+
+    #[test]
+    fn test_ptr_slice() {
+        let mut mem = create_memory();
+
+        let result = WasmSlice::<u32>::new(100, 4);
+        assert!(result.is_ok());
+        let slice = result.unwrap();
+        assert_eq!(slice.len(), 4);
+        let result = slice.write(&mut mem, &[1, 2, 3, 4]);
+        assert!(result.is_ok());
+        let result = slice.read(&mem);
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap(), vec![1, 2, 3, 4]);
+
+        // access a single value
+        let result = slice.index(2);
+        assert!(result.is_ok());
+        let ptr = result.unwrap();
+        assert_eq!(ptr.offset(), 108);
+        let result = ptr.read(&mem);
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap(), 3);
+        let result = slice.index(4);
+        assert!(result.is_err());
+
+        // subslice
+        let result = slice.subslice(1, 3);
+        assert!(result.is_ok());
+        let result = result.unwrap().read(&mem);
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap(), vec![2, 3]);
+        let result = slice.subslice(3, 5);
+        assert!(result.is_err());
+
+        // the length of values does not match
+        let result = slice.write(&mut mem, &[1, 2]);
+        assert!(result.is_err());
+
+        // out of bounds
+        let result = WasmSlice::<u32>::new(65532, 2);
+        assert!(result.is_ok());
+        let result = result.unwrap().read(&mem);
+        assert!(result.is_err());
+        let result = WasmSlice::<u32>::new(u32::MAX - 3, 2);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_ptr_zero_sized() {
+        let mut mem = create_memory();
+
+        // the zero-sized values occupy no bytes
+        let result = WasmSlice::<[u32; 0]>::new(100, 3);
+        assert!(result.is_ok());
+        let slice = result.unwrap();
+        let result = slice.write(&mut mem, &[[], [], []]);
+        assert!(result.is_ok());
+        let result = slice.read(&mem);
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap().len(), 3);
+        let result = slice.index(2);
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap().offset(), 100);
+
+        // an array of the zero-sized values
+        let ptr = WasmPtr::<[Empty; 2]>::new(100);
+        let result = ptr.write(&mut mem, &[Empty([]), Empty([])]);
+        assert!(result.is_ok());
+        let result = ptr.read(&mem);
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap(), [Empty([]), Empty([])]);
+    }
+
+    #[test]
+    fn test_ptr_string() {
+        let mut mem = create_memory();
+
+        // UTF-8
+        let result = WasmPtr::<u8>::new(10).write_utf8(&mut mem, "hello, 世界");
+        assert!(result.is_ok());
+        let slice = result.unwrap();
+        assert_eq!(slice.len(), 13);
+        let result = slice.read_utf8(&mem);
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap(), "hello, 世界");
+        let result = slice.subslice(0, 8).unwrap().read_utf8(&mem);
+        assert!(result.is_err());
+
+        // UTF-16
+        let result = WasmPtr::<u16>::new(100).write_utf16(&mut mem, "hello, 世界");
+        assert!(result.is_ok());
+        let slice = result.unwrap();
+        assert_eq!(slice.len(), 9);
+        let result = mem.get_data(100, 2);
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap(), vec![b'h', 0]);
+        let result = slice.read_utf16(&mem);
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap(), "hello, 世界");
+
+        // an unpaired surrogate
+        let result = WasmPtr::<u16>::new(200).write(&mut mem, &0xd800);
+        assert!(result.is_ok());
+        let result = WasmSlice::<u16>::new(200, 1).unwrap().read_utf16(&mem);
+        assert!(result.is_err());
+        assert_eq!(result.unwrap_err(), WasmEdgeError::Utf16);
+    }
+
+    #[test]
+    fn test_ptr_derive() {
+        // the layout of the C struct in wasm32
+        assert_eq!(Header::ALIGN, 8);
+        assert_eq!(Header::SIZE, 24);
+        assert_eq!(Pair::ALIGN, 2);
+        assert_eq!(Pair::SIZE, 6);
+
+        let mut mem = create_memory();
+        let header = Header {
+            tag: 1,
+            len: 2,
+            data: WasmPtr::new(0x100),
+            ts: 3,
+        };
+        let ptr = WasmPtr::<Header>::new(64);
+        let result = ptr.write(&mut mem, &header);
+        assert!(result.is_ok());
+        let result = mem.get_data(64, 24);
+        assert!(result.is_ok());
+        assert_eq!(
+            result.unwrap(),
+            vec![1, 0, 0, 0, 2, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0]
+        );
+        let result = ptr.read(&mem);
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap(), header);
+
+        // a slice of structs
+        let pairs = [Pair(1, [2, 3, 4]), Pair(5, [6, 7, 8])];
+        let result = WasmSlice::<Pair>::new(128, 2);
+        assert!(result.is_ok());
+        let slice = result.unwrap();
+        let result = slice.write(&mut mem, &pairs);
+        assert!(result.is_ok());
+        let result = mem.get_data(128, 12);
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap(), vec![1, 0, 2, 3, 4, 0, 5, 0, 6, 7, 8, 0]);
+        let result = slice.read(&mem);
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap(), pairs);
+    }
+}
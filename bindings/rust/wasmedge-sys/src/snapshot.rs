@@ -0,0 +1,543 @@
+//! Defines WasmEdge Snapshot struct.
+//!
+//! A [Snapshot] records the data of the memories and the values of the mutable globals defined by a
+//! [module instance](crate::Instance). Restoring a [Snapshot] into a fresh instantiation of the same
+//! [Module](crate::Module) skips the expensive initialization of the module, and restoring it into the original
+//! [module instance](crate::Instance) rolls back the changes made after the [Snapshot] was taken.
+
+use crate::{
+    error::{SnapshotError, WasmEdgeError},
+    instance::memory::PAGE_SIZE,
+    Instance, Memory, Mutability, ValType, WasmEdgeResult, WasmValue,
+};
+
+// the magic number and the version of the serialized snapshots
+const MAGIC: &[u8; 4] = b"\0wss";
+const VERSION: u32 = 1;
+
+// the max length in bytes of the data copied from or to a memory at once
+const CHUNK_SIZE: u64 = 1 << 24;
+
+// the max pages of a memory, including the 64-bit ones
+const MAX_PAGES: u64 = 65536;
+
+/// Struct of WasmEdge Snapshot.
+///
+/// A [Snapshot] is taken by [Instance::snapshot](crate::Instance::snapshot) and restored by
+/// [Instance::restore](crate::Instance::restore). Both the exported and the non-exported memories and globals are
+/// recorded, while the imported ones are not, since they are owned by other module instances. The tables, and the
+/// data and element segments dropped by the WASM functions, are not recorded either, so restoring a [Snapshot] does
+/// not roll back their changes.
+///
+/// A [Snapshot] can be serialized by [Snapshot::to_bytes] and deserialized by [Snapshot::from_bytes], so that it can
+/// be stored and restored in another process.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Snapshot {
+    pub(crate) memories: Vec<MemorySnapshot>,
+    pub(crate) globals: Vec<GlobalSnapshot>,
+}
+impl Snapshot {
+    pub(crate) fn capture(instance: &Instance<'_>) -> WasmEdgeResult<Self> {
+        let mut memories = Vec::new();
+        for mem in instance.defined_memories() {
+            let pages = mem.size();
+            let mut data = Vec::with_capacity(mem.data_size());
+            let mut offset = 0;
+            while offset < mem.data_size() as u64 {
+                let len = CHUNK_SIZE.min(mem.data_size() as u64 - offset);
+                data.extend(mem.get_data(offset, len as u32)?);
+                offset += len;
+            }
+            // the trailing zeros are restored by zeroing the rest of the memory
+            let len = data.iter().rposition(|b| *b != 0).map_or(0, |pos| pos + 1);
+            data.truncate(len);
+            data.shrink_to_fit();
+            memories.push(MemorySnapshot { pages, data });
+        }
+
+        let mut globals = Vec::new();
+        for (idx, global) in instance.defined_globals().iter().enumerate() {
+            let ty = global.ty()?;
+            if ty.mutability() == Mutability::Const {
+                globals.push(GlobalSnapshot::Const);
+                continue;
+            }
+            let val = global.get_value();
+            globals.push(match ty.value_type() {
+                ValType::I32 => GlobalSnapshot::I32(val.to_i32()),
+                ValType::I64 => GlobalSnapshot::I64(val.to_i64()),
+                ValType::F32 => GlobalSnapshot::F32(val.to_f32().to_bits()),
+                ValType::F64 => GlobalSnapshot::F64(val.to_f64().to_bits()),
+                ValType::V128 => GlobalSnapshot::V128(val.to_v128()),
+                _ => {
+                    return Err(WasmEdgeError::Snapshot(SnapshotError::RefGlobal(
+                        idx as u32,
+                    )))
+                }
+            });
+        }
+
+        Ok(Self { memories, globals })
+    }
+
+    pub(crate) fn restore(&self, instance: &Instance<'_>) -> WasmEdgeResult<()> {
+        let mut memories = instance.defined_memories();
+        let mut globals = instance.defined_globals();
+
+        // check all the memories and globals before modifying any of them
+        if memories.len() != self.memories.len() {
+            return Err(mismatch(format!(
+                "{} memories are defined, but {} are recorded",
+                memories.len(),
+                self.memories.len()
+            )));
+        }
+        for (idx, (mem, snapshot)) in memories.iter().zip(&self.memories).enumerate() {
+            if mem.size() > snapshot.pages {
+                return Err(mismatch(format!(
+                    "the memory {} has {} pages, more than the {} recorded pages",
+                    idx,
+                    mem.size(),
+                    snapshot.pages
+                )));
+            }
+            let max = mem.ty()?.max().unwrap_or(MAX_PAGES).min(MAX_PAGES);
+            if snapshot.pages > max {
+                return Err(mismatch(format!(
+                    "the memory {} can grow to {} pages at most, less than the {} recorded pages",
+                    idx, max, snapshot.pages
+                )));
+            }
+        }
+        if globals.len() != self.globals.len() {
+            return Err(mismatch(format!(
+                "{} globals are defined, but {} are recorded",
+                globals.len(),
+                self.globals.len()
+            )));
+        }
+        for (idx, (global, snapshot)) in globals.iter().zip(&self.globals).enumerate() {
+            let ty = global.ty()?;
+            let matched = match snapshot {
+                GlobalSnapshot::Const => ty.mutability() == Mutability::Const,
+                _ => ty.mutability() == Mutability::Var && Some(ty.value_type()) == snapshot.ty(),
+            };
+            if !matched {
+                return Err(mismatch(format!(
+                    "the type of the global {} is not matched",
+                    idx
+                )));
+            }
+        }
+
+        // grow all the memories before writing any of them, so that a failed growing leaves the data untouched
+        for (mem, snapshot) in memories.iter_mut().zip(&self.memories) {
+            if mem.size() < snapshot.pages {
+                mem.grow(snapshot.pages - mem.size())?;
+            }
+        }
+        for (mem, snapshot) in memories.iter_mut().zip(&self.memories) {
+            snapshot.restore(mem)?;
+        }
+        for (global, snapshot) in globals.iter_mut().zip(&self.globals) {
+            if let Some(val) = snapshot.value() {
+                global.set_value(val)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Serializes the [Snapshot] into bytes.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        bytes.extend_from_slice(MAGIC);
+        bytes.extend_from_slice(&VERSION.to_le_bytes());
+
+        bytes.extend_from_slice(&(self.memories.len() as u32).to_le_bytes());
+        for mem in self.memories.iter() {
+            bytes.extend_from_slice(&mem.pages.to_le_bytes());
+            bytes.extend_from_slice(&(mem.data.len() as u64).to_le_bytes());
+            bytes.extend_from_slice(&mem.data);
+        }
+
+        bytes.extend_from_slice(&(self.globals.len() as u32).to_le_bytes());
+        for global in self.globals.iter() {
+            match global {
+                GlobalSnapshot::Const => bytes.push(0x00),
+                GlobalSnapshot::I32(val) => {
+                    bytes.push(0x01);
+                    bytes.extend_from_slice(&val.to_le_bytes());
+                }
+                GlobalSnapshot::I64(val) => {
+                    bytes.push(0x02);
+                    bytes.extend_from_slice(&val.to_le_bytes());
+                }
+                GlobalSnapshot::F32(val) => {
+                    bytes.push(0x03);
+                    bytes.extend_from_slice(&val.to_le_bytes());
+                }
+                GlobalSnapshot::F64(val) => {
+                    bytes.push(0x04);
+                    bytes.extend_from_slice(&val.to_le_bytes());
+                }
+                GlobalSnapshot::V128(val) => {
+                    bytes.push(0x05);
+                    bytes.extend_from_slice(&val.to_le_bytes());
+                }
+            }
+        }
+
+        bytes
+    }
+
+    /// Deserializes a [Snapshot] from the bytes serialized by [Snapshot::to_bytes].
+    ///
+    /// # Argument
+    ///
+    /// - `bytes` specifies the serialized [Snapshot].
+    ///
+    /// # Error
+    ///
+    /// If the bytes are malformed, then an error is returned.
+    pub fn from_bytes(bytes: impl AsRef<[u8]>) -> WasmEdgeResult<Self> {
+        let mut reader = Reader(bytes.as_ref());
+        if reader.take(MAGIC.len())? != MAGIC || u32::from_le_bytes(reader.take_array()?) != VERSION
+        {
+            return Err(WasmEdgeError::Snapshot(SnapshotError::Malformed));
+        }
+
+        let count = u32::from_le_bytes(reader.take_array()?);
+        let mut memories = Vec::new();
+        for _ in 0..count {
+            let pages = u64::from_le_bytes(reader.take_array()?);
+            let len = u64::from_le_bytes(reader.take_array()?);
+            if len > pages.saturating_mul(PAGE_SIZE as u64) {
+                return Err(WasmEdgeError::Snapshot(SnapshotError::Malformed));
+            }
+            let data = reader.take(len as usize)?.to_vec();
+            memories.push(MemorySnapshot { pages, data });
+        }
+
+        let count = u32::from_le_bytes(reader.take_array()?);
+        let mut globals = Vec::new();
+        for _ in 0..count {
+            globals.push(match reader.take_array::<1>()?[0] {
+                0x00 => GlobalSnapshot::Const,
+                0x01 => GlobalSnapshot::I32(i32::from_le_bytes(reader.take_array()?)),
+                0x02 => GlobalSnapshot::I64(i64::from_le_bytes(reader.take_array()?)),
+                0x03 => GlobalSnapshot::F32(u32::from_le_bytes(reader.take_array()?)),
+                0x04 => GlobalSnapshot::F64(u64::from_le_bytes(reader.take_array()?)),
+                0x05 => GlobalSnapshot::V128(i128::from_le_bytes(reader.take_array()?)),
+                _ => return Err(WasmEdgeError::Snapshot(SnapshotError::Malformed)),
+            });
+        }
+
+        match reader.0.is_empty() {
+            true => Ok(Self { memories, globals }),
+            false => Err(WasmEdgeError::Snapshot(SnapshotError::Malformed)),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct MemorySnapshot {
+    pub(crate) pages: u64,
+    pub(crate) data: Vec<u8>,
+}
+impl MemorySnapshot {
+    // the memory should have been grown to the recorded pages
+    fn restore(&self, mem: &mut Memory) -> WasmEdgeResult<()> {
+        let mut offset = 0;
+        while offset < mem.data_size() as u64 {
+            let len = CHUNK_SIZE.min(mem.data_size() as u64 - offset);
+            let start = (offset as usize).min(self.data.len());
+            let end = (offset as usize + len as usize).min(self.data.len());
+            let mut chunk = vec![0; len as usize];
+            chunk[..end - start].copy_from_slice(&self.data[start..end]);
+            mem.set_data(chunk, offset)?;
+            offset += len;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum GlobalSnapshot {
+    Const,
+    I32(i32),
+    I64(i64),
+    // the floating-point values are recorded by bits, so that the NaN payloads are kept
+    F32(u32),
+    F64(u64),
+    V128(i128),
+}
+impl GlobalSnapshot {
+    fn ty(&self) -> Option<ValType> {
+        match self {
+            GlobalSnapshot::Const => None,
+            GlobalSnapshot::I32(_) => Some(ValType::I32),
+            GlobalSnapshot::I64(_) => Some(ValType::I64),
+            GlobalSnapshot::F32(_) => Some(ValType::F32),
+            GlobalSnapshot::F64(_) => Some(ValType::F64),
+            GlobalSnapshot::V128(_) => Some(ValType::V128),
+        }
+    }
+
+    fn value(&self) -> Option<WasmValue> {
+        match *self {
+            GlobalSnapshot::Const => None,
+            GlobalSnapshot::I32(val) => Some(WasmValue::from_i32(val)),
+            GlobalSnapshot::I64(val) => Some(WasmValue::from_i64(val)),
+            GlobalSnapshot::F32(val) => Some(WasmValue::from_f32(f32::from_bits(val))),
+            GlobalSnapshot::F64(val) => Some(WasmValue::from_f64(f64::from_bits(val))),
+            GlobalSnapshot::V128(val) => Some(WasmValue::from_v128(val)),
+        }
+    }
+}
+
+fn mismatch(msg: String) -> WasmEdgeError {
+    WasmEdgeError::Snapshot(SnapshotError::Mismatch(msg))
+}
+
+// reads the serialized snapshot
+struct Reader<'a>(&'a [u8]);
+impl<'a> Reader<'a> {
+    fn take(&mut self, len: usize) -> WasmEdgeResult<&'a [u8]> {
+        if len > self.0.len() {
+            return Err(WasmEdgeError::Snapshot(SnapshotError::Malformed));
+        }
+        let (bytes, rest) = self.0.split_at(len);
+        self.0 = rest;
+        Ok(bytes)
+    }
+
+    fn take_array<const N: usize>(&mut self) -> WasmEdgeResult<[u8; N]> {
+        let mut array = [0; N];
+        array.copy_from_slice(self.take(N)?);
+        Ok(array)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Config, Vm};
+
+    fn create_vm() -> Vm {
+        let result = Config::create();
+        assert!(result.is_ok());
+        let config = result.unwrap();
+        let result = Vm::create(Some(config), None);
+        assert!(result.is_ok());
+        let mut vm = result.unwrap();
+
+        let path = std::path::PathBuf::from(env!("WASMEDGE_DIR"))
+            .join("bindings/rust/wasmedge-sys/tests/data/snapshot.wasm");
+        let result = vm.load_wasm_from_file(path);
+        assert!(result.is_ok());
+        let result = vm.validate();
+        assert!(result.is_ok());
+        let result = vm.instantiate();
+        assert!(result.is_ok());
+        vm
+    }
+
+    fn counter(vm: &Vm) -> i32 {
+        let result = vm.run_function("counter", []);
+        assert!(result.is_ok());
+        result.unwrap()[0].to_i32()
+    }
+
+    #[test]
+    fn test_snapshot_restore() {
+        let vm = create_vm();
+        assert!(vm.run_function("bump", []).is_ok());
+        assert!(vm.run_function("bump", []).is_ok());
+        assert!(vm.run_function("grow", []).is_ok());
+        assert_eq!(counter(&vm), 2);
+
+        // take a snapshot of the active module
+        let result = vm.store_mut();
+        assert!(result.is_ok());
+        let store = result.unwrap();
+        let result = store.active_module();
+        assert!(result.is_ok());
+        let instance = result.unwrap();
+        let result = instance.find_memory("memory");
+        assert!(result.is_ok());
+        let mut memory = result.unwrap();
+        let result = memory.write_string("hello", PAGE_SIZE as u64 + 100);
+        assert!(result.is_ok());
+        let result = instance.snapshot();
+        assert!(result.is_ok());
+        let snapshot = result.unwrap();
+
+        // serialize and deserialize the snapshot
+        let bytes = snapshot.to_bytes();
+        let result = Snapshot::from_bytes(&bytes);
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap(), snapshot);
+
+        // restore the snapshot into a fresh instantiation
+        let fresh_vm = create_vm();
+        assert_eq!(counter(&fresh_vm), 0);
+        let result = fresh_vm.store_mut();
+        assert!(result.is_ok());
+        let fresh_store = result.unwrap();
+        let result = fresh_store.active_module();
+        assert!(result.is_ok());
+        let fresh_instance = result.unwrap();
+        let result = fresh_instance.restore(&snapshot);
+        assert!(result.is_ok());
+        assert_eq!(counter(&fresh_vm), 2);
+        let result = fresh_instance.find_memory("memory");
+        assert!(result.is_ok());
+        let memory = result.unwrap();
+        assert_eq!(memory.size(), 2);
+        let result = memory.read_string(0, 4);
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap(), "init");
+        let result = memory.get_data(16, 4);
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap(), 2i32.to_le_bytes());
+        let result = memory.read_string(PAGE_SIZE as u64 + 100, 5);
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap(), "hello");
+        let result = fresh_vm.run_function("bump", []);
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap()[0].to_i32(), 3);
+
+        // roll back the changes of the original module instance
+        assert!(vm.run_function("bump", []).is_ok());
+        let result = instance.find_memory("memory");
+        assert!(result.is_ok());
+        let mut memory = result.unwrap();
+        let result = memory.write_string("world", PAGE_SIZE as u64 + 200);
+        assert!(result.is_ok());
+        let result = instance.restore(&snapshot);
+        assert!(result.is_ok());
+        assert_eq!(counter(&vm), 2);
+        let result = memory.get_data(PAGE_SIZE as u64 + 200, 5);
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap(), vec![0; 5]);
+    }
+
+    #[test]
+    fn test_snapshot_mismatch() {
+        // the snapshot of a fresh instantiation has less pages
+        let fresh_vm = create_vm();
+        let result = fresh_vm.store_mut();
+        assert!(result.is_ok());
+        let fresh_store = result.unwrap();
+        let result = fresh_store.active_module();
+        assert!(result.is_ok());
+        let result = result.unwrap().snapshot();
+        assert!(result.is_ok());
+        let snapshot = result.unwrap();
+
+        let vm = create_vm();
+        assert!(vm.run_function("bump", []).is_ok());
+        assert!(vm.run_function("grow", []).is_ok());
+        let result = vm.store_mut();
+        assert!(result.is_ok());
+        let store = result.unwrap();
+        let result = store.active_module();
+        assert!(result.is_ok());
+        let instance = result.unwrap();
+        let result = instance.restore(&snapshot);
+        assert!(result.is_err());
+        assert!(matches!(
+            result.unwrap_err(),
+            WasmEdgeError::Snapshot(SnapshotError::Mismatch(_))
+        ));
+        // nothing is restored
+        assert_eq!(counter(&vm), 1);
+    }
+
+    #[test]
+    fn test_snapshot_partial() {
+        let result = Config::create();
+        assert!(result.is_ok());
+        let config = result.unwrap();
+        // the option of the multi-memories proposal is not exposed by Config
+        unsafe {
+            crate::ffi::WasmEdge_ConfigureAddProposal(
+                config.inner.0,
+                crate::ffi::WasmEdge_Proposal_MultiMemories,
+            )
+        };
+        let result = Vm::create(Some(config), None);
+        assert!(result.is_ok());
+        let mut vm = result.unwrap();
+        let path = std::path::PathBuf::from(env!("WASMEDGE_DIR"))
+            .join("bindings/rust/wasmedge-sys/tests/data/multi_memory.wasm");
+        let result = vm.load_wasm_from_file(path);
+        assert!(result.is_ok());
+        let result = vm.validate();
+        assert!(result.is_ok());
+        let result = vm.instantiate();
+        assert!(result.is_ok());
+
+        let result = vm.store_mut();
+        assert!(result.is_ok());
+        let store = result.unwrap();
+        let result = store.active_module();
+        assert!(result.is_ok());
+        let instance = result.unwrap();
+        let result = instance.snapshot();
+        assert!(result.is_ok());
+        let mut snapshot = result.unwrap();
+        assert_eq!(snapshot.memories.len(), 2);
+
+        // the first memory can grow to the recorded pages, while the second one can not
+        snapshot.memories[0].pages = 2;
+        snapshot.memories[0].data = b"done".to_vec();
+        snapshot.memories[1].pages = 2;
+        let result = instance.restore(&snapshot);
+        assert!(result.is_err());
+        assert!(matches!(
+            result.unwrap_err(),
+            WasmEdgeError::Snapshot(SnapshotError::Mismatch(_))
+        ));
+
+        // nothing is restored
+        let result = instance.find_memory("memory");
+        assert!(result.is_ok());
+        let memory = result.unwrap();
+        assert_eq!(memory.size(), 1);
+        let result = memory.read_string(0, 4);
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap(), "init");
+        let result = instance.find_memory("small");
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap().size(), 1);
+    }
+
+    #[test]
+    fn test_snapshot_malformed() {
+        let result = Snapshot::from_bytes([0x00, 0x61, 0x73, 0x6d]);
+        assert!(result.is_err());
+        assert_eq!(
+            result.unwrap_err(),
+            WasmEdgeError::Snapshot(SnapshotError::Malformed)
+        );
+
+        let snapshot = Snapshot {
+            memories: vec![MemorySnapshot {
+                pages: 1,
+                data: vec![1, 2, 3],
+            }],
+            globals: vec![GlobalSnapshot::Const, GlobalSnapshot::F64(1.5f64.to_bits())],
+        };
+        let mut bytes = snapshot.to_bytes();
+        let result = Snapshot::from_bytes(&bytes);
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap(), snapshot);
+
+        // truncated
+        bytes.pop();
+        let result = Snapshot::from_bytes(&bytes);
+        assert!(result.is_err());
+    }
+}
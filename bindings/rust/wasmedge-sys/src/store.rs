@@ -6,13 +6,18 @@ use crate::{
     instance::{
         function::{Function, InnerFunc},
         global::{Global, InnerGlobal},
-        memory::{InnerMemory, Memory},
+        memory::{InnerMemory, Memory, MemoryView},
         module::{InnerInstance, Instance},
         table::{InnerTable, Table},
     },
-    types::WasmEdgeString,
+    types::{ExternalType, WasmEdgeString},
     WasmEdgeResult,
 };
+use std::{
+    borrow::Cow,
+    os::raw::c_void,
+    panic::{self, AssertUnwindSafe},
+};
 
 /// Struct of Wasmedge Store.
 ///
@@ -63,6 +68,7 @@ impl Store {
                 registered: true,
                 name: Some(name.as_ref().to_string()),
                 mod_name: None,
+                real_fn: None,
             }),
         }
     }
@@ -103,6 +109,7 @@ impl Store {
                 registered: true,
                 name: Some(func_name.as_ref().to_string()),
                 mod_name: Some(mod_name.as_ref().to_string()),
+                real_fn: None,
             }),
         }
     }
@@ -197,6 +204,24 @@ impl Store {
         }
     }
 
+    /// Returns a [view](crate::MemoryView) of the exported [memory](crate::Memory) instance in the anonymous
+    /// [module](crate::Module) by the given memory name.
+    ///
+    /// The view mutably borrows the [Store], so that no WASM function can be run by an [Executor](crate::Executor)
+    /// on the [Store] while the view is alive.
+    ///
+    /// # Argument
+    ///
+    /// - `name` specifies the target exported [memory](crate::Memory) instance.
+    ///
+    /// # Error
+    ///
+    /// If fail to find the target [memory](crate::Memory), then an error is returned.
+    pub fn memory_view(&mut self, name: impl AsRef<str>) -> WasmEdgeResult<MemoryView<'_>> {
+        let mem = self.find_memory(name)?;
+        Ok(MemoryView::new(mem.inner.0))
+    }
+
     /// Returns the exported [memory](crate::Memory) instance in the registered [module](crate::Module)
     /// by the given memory name and module name.
     ///
@@ -614,7 +639,7 @@ impl Store {
     /// # Error
     ///
     /// If fail to find the target [module instance](crate::Instance), then an error is returned.
-    pub fn active_module(&mut self) -> WasmEdgeResult<Instance<'_>> {
+    pub fn active_module(&self) -> WasmEdgeResult<Instance<'_>> {
         let ctx = unsafe { ffi::WasmEdge_StoreGetActiveModule(self.inner.0) };
         match ctx.is_null() {
             true => Err(WasmEdgeError::Store(StoreError::NotFoundActiveModule)),
@@ -634,7 +659,7 @@ impl Store {
     /// # Error
     ///
     /// If fail to find the target [module instance](crate::Instance), then an error is returned.
-    pub fn named_module(&mut self, name: impl AsRef<str>) -> WasmEdgeResult<Instance<'_>> {
+    pub fn named_module(&self, name: impl AsRef<str>) -> WasmEdgeResult<Instance<'_>> {
         let mod_name: WasmEdgeString = name.as_ref().into();
         let ctx = unsafe { ffi::WasmEdge_StoreFindModule(self.inner.0, mod_name.as_raw()) };
         match ctx.is_null() {
@@ -754,6 +779,69 @@ impl Store {
         }
         Ok(())
     }
+
+    /// Sets the [ResourceLimiter] which is consulted before growing the [tables](crate::Table) and
+    /// [memories](crate::Memory) instantiated in this [Store].
+    ///
+    /// The limiter replaces the previous one, if any. It applies to the growing caused by the `table.grow` and
+    /// `memory.grow` instructions as well as by [Table::grow](crate::Table::grow) and
+    /// [Memory::grow](crate::Memory::grow). The tables and memories created by the host, such as the ones in an
+    /// [ImportObject](crate::ImportObject), are not limited.
+    ///
+    /// # Argument
+    ///
+    /// - `limiter` specifies the [ResourceLimiter] to set.
+    pub fn set_resource_limiter(&mut self, limiter: impl ResourceLimiter + 'static) {
+        let data: Box<Box<dyn ResourceLimiter>> = Box::new(Box::new(limiter));
+        unsafe {
+            ffi::WasmEdge_StoreSetResourceLimiter(
+                self.inner.0,
+                Some(resource_limiter_wrapper),
+                Box::into_raw(data) as *mut c_void,
+                Some(resource_limiter_finalizer),
+            )
+        }
+    }
+
+    /// Removes the [ResourceLimiter] of this [Store], so that the growing of the tables and memories is limited by
+    /// their types only.
+    pub fn remove_resource_limiter(&mut self) {
+        unsafe {
+            ffi::WasmEdge_StoreSetResourceLimiter(self.inner.0, None, std::ptr::null_mut(), None)
+        }
+    }
+
+    /// Returns the current [ResourceUsage] of a module instance in this [Store].
+    ///
+    /// # Argument
+    ///
+    /// - `mod_name` specifies the name of the registered module instance. `None` means the active anonymous module.
+    ///
+    /// # Error
+    ///
+    /// If fail to find the target [module instance](crate::Instance), then an error is returned.
+    pub fn resource_usage(&self, mod_name: Option<&str>) -> WasmEdgeResult<ResourceUsage> {
+        let ctx = match mod_name {
+            Some(name) => {
+                let raw_name: WasmEdgeString = name.into();
+                let ctx = unsafe { ffi::WasmEdge_StoreFindModule(self.inner.0, raw_name.as_raw()) };
+                if ctx.is_null() {
+                    return Err(WasmEdgeError::Store(StoreError::NotFoundModule(
+                        name.to_string(),
+                    )));
+                }
+                ctx
+            }
+            None => {
+                let ctx = unsafe { ffi::WasmEdge_StoreGetActiveModule(self.inner.0) };
+                if ctx.is_null() {
+                    return Err(WasmEdgeError::Store(StoreError::NotFoundActiveModule));
+                }
+                ctx
+            }
+        };
+        Ok(ResourceUsage::of(ctx))
+    }
 }
 impl Drop for Store {
     fn drop(&mut self) {
@@ -768,16 +856,131 @@ pub(crate) struct InnerStore(pub(crate) *mut ffi::WasmEdge_StoreContext);
 unsafe impl Send for InnerStore {}
 unsafe impl Sync for InnerStore {}
 
+/// Defines the limiter consulted before growing the [tables](crate::Table) and [memories](crate::Memory) in a
+/// [Store].
+///
+/// Each method returns `true` to allow the growing, or `false` to deny it, in which case the `memory.grow` or
+/// `table.grow` instruction returns `-1` and [Memory::grow](crate::Memory::grow) or
+/// [Table::grow](crate::Table::grow) returns an error. The growing beyond the maximum of the type is always denied
+/// without consulting the limiter. By default, all the growing is allowed.
+///
+/// The limiter may be called from any thread running a function of the [Store], therefore it must be [Send] and
+/// [Sync]. A panic in the limiter denies the growing.
+pub trait ResourceLimiter: Send + Sync {
+    /// Decides whether a [memory](crate::Memory) can grow.
+    ///
+    /// # Arguments
+    ///
+    /// - `mod_name` specifies the name of the module instance defining the memory. `None` means the active anonymous
+    ///   module.
+    ///
+    /// - `index` specifies the index of the memory in the module.
+    ///
+    /// - `current` specifies the current size in pages.
+    ///
+    /// - `desired` specifies the size in pages after growing.
+    fn memory_growing(
+        &self,
+        _mod_name: Option<&str>,
+        _index: u32,
+        _current: u32,
+        _desired: u32,
+    ) -> bool {
+        true
+    }
+
+    /// Decides whether a [table](crate::Table) can grow.
+    ///
+    /// # Arguments
+    ///
+    /// - `mod_name` specifies the name of the module instance defining the table. `None` means the active anonymous
+    ///   module.
+    ///
+    /// - `index` specifies the index of the table in the module.
+    ///
+    /// - `current` specifies the current size in elements.
+    ///
+    /// - `desired` specifies the size in elements after growing.
+    fn table_growing(
+        &self,
+        _mod_name: Option<&str>,
+        _index: u32,
+        _current: u32,
+        _desired: u32,
+    ) -> bool {
+        true
+    }
+}
+
+/// Struct of the resources in use by a module instance.
+///
+/// Only the [tables](crate::Table) and [memories](crate::Memory) defined by the module instance are counted; the
+/// imported ones are counted by the module instances defining them.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct ResourceUsage {
+    /// The total size in pages of the memories.
+    pub memory_pages: u64,
+    /// The total size in elements of the tables.
+    pub table_elements: u64,
+}
+impl ResourceUsage {
+    pub(crate) fn of(ctx: *const ffi::WasmEdge_ModuleInstanceContext) -> Self {
+        unsafe {
+            ResourceUsage {
+                memory_pages: ffi::WasmEdge_ModuleInstanceGetMemoryPageSizeInUse(ctx),
+                table_elements: ffi::WasmEdge_ModuleInstanceGetTableSizeInUse(ctx),
+            }
+        }
+    }
+}
+
+extern "C" fn resource_limiter_wrapper(
+    data: *mut c_void,
+    mod_name: ffi::WasmEdge_String,
+    ty: ffi::WasmEdge_ExternalType,
+    index: u32,
+    current: u32,
+    desired: u32,
+) -> bool {
+    // the data pointer is the limiter owned by the Store
+    let limiter = unsafe { &*(data as *const Box<dyn ResourceLimiter>) };
+    let mod_name = match mod_name.Length {
+        0 => None,
+        len => {
+            let bytes =
+                unsafe { std::slice::from_raw_parts(mod_name.Buf as *const u8, len as usize) };
+            Some(String::from_utf8_lossy(bytes))
+        }
+    };
+    let mod_name = mod_name.as_ref().map(Cow::as_ref);
+
+    // a panic must not unwind across the FFI boundary, so it is caught and the growing is denied
+    panic::catch_unwind(AssertUnwindSafe(|| match ExternalType::from(ty) {
+        ExternalType::Memory => limiter.memory_growing(mod_name, index, current, desired),
+        ExternalType::Table => limiter.table_growing(mod_name, index, current, desired),
+        _ => true,
+    }))
+    .unwrap_or(false)
+}
+
+extern "C" fn resource_limiter_finalizer(data: *mut c_void) {
+    drop(unsafe { Box::from_raw(data as *mut Box<dyn ResourceLimiter>) });
+}
+
 #[cfg(test)]
 mod tests {
-    use super::Store;
+    use super::{ResourceLimiter, ResourceUsage, Store};
     use crate::{
+        error::Trap,
         instance::{Function, Global, GlobalType, MemType, Memory, Table, TableType},
         types::WasmValue,
-        Config, Executor, FuncType, ImportObject, Mutability, RefType, ValType, Vm,
+        Caller, Config, Executor, FuncType, ImportObject, Mutability, RefType, ValType, Vm,
     };
     use std::{
-        sync::{Arc, Mutex},
+        sync::{
+            atomic::{AtomicU32, Ordering},
+            Arc, Mutex,
+        },
         thread,
     };
 
@@ -1020,7 +1223,7 @@ mod tests {
         // get the store in vm
         let result = vm.store_mut();
         assert!(result.is_ok());
-        let mut store = result.unwrap();
+        let store = result.unwrap();
 
         // get the active module
         let result = store.active_module();
@@ -1077,7 +1280,7 @@ mod tests {
         // get the store in vm
         let result = vm.store_mut();
         assert!(result.is_ok());
-        let mut store = result.unwrap();
+        let store = result.unwrap();
 
         // get the module named "extern"
         let result = store.named_module("extern");
@@ -1107,21 +1310,130 @@ mod tests {
         assert_eq!(return_types, [ValType::I32]);
     }
 
-    fn real_add(inputs: Vec<WasmValue>) -> Result<Vec<WasmValue>, u8> {
+    #[test]
+    fn test_store_resource_limiter() {
+        // a limiter which gives each module a quota of memory pages
+        struct Quota {
+            pages: u32,
+            calls: Arc<AtomicU32>,
+            drops: Arc<AtomicU32>,
+        }
+        impl ResourceLimiter for Quota {
+            fn memory_growing(
+                &self,
+                mod_name: Option<&str>,
+                index: u32,
+                current: u32,
+                desired: u32,
+            ) -> bool {
+                assert_eq!(mod_name, Some("tenant"));
+                assert_eq!(index, 0);
+                assert!(desired > current);
+                self.calls.fetch_add(1, Ordering::SeqCst);
+                desired <= self.pages
+            }
+        }
+        impl Drop for Quota {
+            fn drop(&mut self) {
+                self.drops.fetch_add(1, Ordering::SeqCst);
+            }
+        }
+
+        // create a Store context
+        let result = Store::create();
+        assert!(result.is_ok());
+        let mut store = result.unwrap();
+
+        // create a Vm context with the given Store
+        let result = Vm::create(None, Some(&mut store));
+        assert!(result.is_ok());
+        let mut vm = result.unwrap();
+
+        // register a wasm module from a wasm file
+        let path = std::path::PathBuf::from(env!("WASMEDGE_DIR"))
+            .join("bindings/rust/wasmedge-sys/tests/data/alloc.wasm");
+        let result = vm.register_wasm_from_file("tenant", path);
+        assert!(result.is_ok());
+
+        // get the store in vm
+        let result = vm.store_mut();
+        assert!(result.is_ok());
+        let mut store = result.unwrap();
+
+        // check the resource usage
+        let result = store.resource_usage(Some("tenant"));
+        assert!(result.is_ok());
+        assert_eq!(
+            result.unwrap(),
+            ResourceUsage {
+                memory_pages: 1,
+                table_elements: 0
+            }
+        );
+        assert!(store.resource_usage(Some("no-such-module")).is_err());
+        assert!(store.resource_usage(None).is_err());
+
+        // set the limiter
+        let calls = Arc::new(AtomicU32::new(0));
+        let drops = Arc::new(AtomicU32::new(0));
+        store.set_resource_limiter(Quota {
+            pages: 3,
+            calls: calls.clone(),
+            drops: drops.clone(),
+        });
+
+        // grow the memory within the quota
+        let result = store.find_memory_registered("tenant", "memory");
+        assert!(result.is_ok());
+        let mut memory = result.unwrap();
+        assert!(memory.grow(2).is_ok());
+        assert_eq!(memory.size(), 3);
+
+        // grow the memory beyond the quota
+        assert!(memory.grow(1).is_err());
+        assert_eq!(memory.size(), 3);
+        assert_eq!(calls.load(Ordering::SeqCst), 2);
+
+        // check the resource usage through the module instance
+        let result = store.named_module("tenant");
+        assert!(result.is_ok());
+        let instance = result.unwrap();
+        assert_eq!(instance.resource_usage().memory_pages, 3);
+
+        // replace the limiter, and the previous one is dropped
+        store.set_resource_limiter(Quota {
+            pages: 4,
+            calls: calls.clone(),
+            drops: drops.clone(),
+        });
+        assert_eq!(drops.load(Ordering::SeqCst), 1);
+        assert!(memory.grow(1).is_ok());
+        assert!(memory.grow(1).is_err());
+        assert_eq!(calls.load(Ordering::SeqCst), 4);
+
+        // remove the limiter
+        store.remove_resource_limiter();
+        assert_eq!(drops.load(Ordering::SeqCst), 2);
+        assert!(memory.grow(1).is_ok());
+        assert_eq!(memory.size(), 5);
+        assert_eq!(calls.load(Ordering::SeqCst), 4);
+    }
+
+    fn real_add(_caller: Caller, inputs: Vec<WasmValue>) -> Result<Vec<WasmValue>, Trap> {
         if inputs.len() != 2 {
-            return Err(1);
+            return Err(Trap::new("Invalid number of arguments"));
         }
 
         let a = if inputs[0].ty() == ValType::I32 {
             inputs[0].to_i32()
         } else {
-            return Err(2);
+            return Err(Trap::new("Invalid type of arguments"));
         };
 
         let b = if inputs[1].ty() == ValType::I32 {
             inputs[1].to_i32()
         } else {
-            return Err(3);
+            return Err(Trap::new("Invalid type of arguments"));
         };
 
         let c = a + b;
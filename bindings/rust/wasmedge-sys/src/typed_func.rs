@@ -0,0 +1,226 @@
+//! Defines WasmEdge TypedFunc struct.
+
+use crate::{
+    error::{check, FuncError, WasmEdgeError},
+    ffi,
+    instance::function::{FuncType, InnerFunc},
+    io::WasmFnIO,
+    Executor, Function, Store, Vm, WasmEdgeResult,
+};
+use std::marker::PhantomData;
+
+/// The maximum number of the arguments or the returns of a [TypedFunc], which is the same as the one of the tuples
+/// implementing [WasmFnIO].
+const MAX_VALUES: usize = 16;
+
+/// Struct of WasmEdge TypedFunc.
+///
+/// A [TypedFunc] is a handle to an exported WASM function, of which the signature has been checked against the Rust
+/// types `Params` and `Rets` when the handle is created. It is created by [Vm::typed_func](crate::Vm::typed_func),
+/// [Vm::typed_registered_func](crate::Vm::typed_registered_func), or
+/// [Instance::typed_func](crate::Instance::typed_func), and then called with native Rust values.
+///
+/// The function instance is resolved once when the handle is created, so calling a [TypedFunc] neither looks up the
+/// function by its name nor queries the function type again. The arguments and the returns are passed in the buffers
+/// on the stack without the [WasmValue](crate::WasmValue) wrapping, so a call does not allocate.
+///
+/// The supported types of `Params` and `Rets` are the same as the ones of [Function::wrap](crate::Function::wrap).
+#[derive(Debug)]
+pub struct TypedFunc<'a, Params, Rets> {
+    target: Target<'a>,
+    func: InnerFunc,
+    func_name: String,
+    returns_len: usize,
+    _marker: PhantomData<fn(Params) -> Rets>,
+}
+impl<'a, Params: WasmFnIO, Rets: WasmFnIO> TypedFunc<'a, Params, Rets> {
+    pub(crate) fn new(target: Target<'a>, func: Function, func_name: &str) -> WasmEdgeResult<Self> {
+        let ty: FuncType = func.ty()?;
+        if ty.params_len() > MAX_VALUES
+            || ty.returns_len() > MAX_VALUES
+            || ty.params_type_iter().ne(Params::types())
+            || ty.returns_type_iter().ne(Rets::types())
+        {
+            return Err(WasmEdgeError::Func(FuncError::SignatureMismatch(
+                func_name.to_string(),
+            )));
+        }
+
+        Ok(Self {
+            target,
+            func: InnerFunc(func.inner.0),
+            func_name: func_name.to_string(),
+            returns_len: ty.returns_len(),
+            _marker: PhantomData,
+        })
+    }
+
+    /// Calls the WASM function with the given arguments, and returns the results.
+    ///
+    /// # Argument
+    ///
+    /// - `params` specifies the arguments of the WASM function, which is `()`, a single value, or a tuple of values.
+    ///
+    /// # Error
+    ///
+    /// If fail to run the WASM function, then an error is returned.
+    pub fn call(&self, params: Params) -> WasmEdgeResult<Rets> {
+        let zero = unsafe { ffi::WasmEdge_ValueGenI32(0) };
+        let mut raw_params = [zero; MAX_VALUES];
+        let mut raw_returns = [zero; MAX_VALUES];
+        let params_len = params.write_raw(&mut raw_params);
+
+        unsafe {
+            let result = match &self.target {
+                Target::Vm(vm) => ffi::WasmEdge_VMExecuteFunction(
+                    vm.inner.0,
+                    self.func.0,
+                    raw_params.as_ptr(),
+                    params_len as u32,
+                    raw_returns.as_mut_ptr(),
+                    self.returns_len as u32,
+                ),
+                Target::Executor(executor, store) => ffi::WasmEdge_ExecutorInvokeFunction(
+                    executor.inner.0,
+                    store.inner.0,
+                    self.func.0,
+                    raw_params.as_ptr(),
+                    params_len as u32,
+                    raw_returns.as_mut_ptr(),
+                    self.returns_len as u32,
+                ),
+            };
+            check(result).map_err(|err| match &self.target {
+                Target::Vm(vm) => vm.execution_error(err),
+                Target::Executor(executor, _) => executor.execution_error(err),
+            })?;
+        }
+
+        Rets::from_raw(&raw_returns[..self.returns_len]).ok_or_else(|| {
+            WasmEdgeError::Func(FuncError::SignatureMismatch(self.func_name.clone()))
+        })
+    }
+}
+
+/// The runtime used to call a [TypedFunc].
+#[derive(Debug)]
+pub(crate) enum Target<'a> {
+    Vm(&'a Vm),
+    Executor(&'a mut Executor, &'a Store),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Loader, Validator};
+
+    #[test]
+    fn test_typed_func_vm() {
+        let result = Vm::create(None, None);
+        assert!(result.is_ok());
+        let mut vm = result.unwrap();
+
+        let path = std::path::PathBuf::from(env!("WASMEDGE_DIR"))
+            .join("bindings/rust/wasmedge-sys/tests/data/fibonacci.wasm");
+        let result = vm.register_wasm_from_file("fib-module", &path);
+        assert!(result.is_ok());
+        let result = vm.load_wasm_from_file(&path);
+        assert!(result.is_ok());
+        let result = vm.validate();
+        assert!(result.is_ok());
+        let result = vm.instantiate();
+        assert!(result.is_ok());
+
+        // call a function in the anonymous module
+        let result = vm.typed_func::<i32, i32>("fib");
+        assert!(result.is_ok());
+        let fib = result.unwrap();
+        let result = fib.call(5);
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap(), 8);
+        let result = fib.call(10);
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap(), 89);
+
+        // call a function in a registered module
+        let result = vm.typed_registered_func::<(i32,), i32>("fib-module", "fib");
+        assert!(result.is_ok());
+        let fib = result.unwrap();
+        let result = fib.call((5,));
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap(), 8);
+
+        // the signature does not match
+        let result = vm.typed_func::<i64, i32>("fib");
+        assert!(result.is_err());
+        assert_eq!(
+            result.unwrap_err(),
+            WasmEdgeError::Func(FuncError::SignatureMismatch("fib".into()))
+        );
+        let result = vm.typed_func::<i32, ()>("fib");
+        assert!(result.is_err());
+
+        // the function does not exist
+        let result = vm.typed_func::<i32, i32>("fib2");
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_typed_func_instance() {
+        let result = Executor::create(None, None);
+        assert!(result.is_ok());
+        let mut executor = result.unwrap();
+        let result = Store::create();
+        assert!(result.is_ok());
+        let mut store = result.unwrap();
+
+        // load, validate and instantiate a wasm module
+        let result = Loader::create(None);
+        assert!(result.is_ok());
+        let loader = result.unwrap();
+        let path = std::path::PathBuf::from(env!("WASMEDGE_DIR"))
+            .join("bindings/rust/wasmedge-sys/tests/data/fibonacci.wasm");
+        let result = loader.from_file(path);
+        assert!(result.is_ok());
+        let module = result.unwrap();
+        let result = Validator::create(None);
+        assert!(result.is_ok());
+        let validator = result.unwrap();
+        let result = validator.validate(&module);
+        assert!(result.is_ok());
+        let result = executor.register_named_module(&mut store, &module, "fib-module");
+        assert!(result.is_ok());
+        let result = executor.register_active_module(&mut store, &module);
+        assert!(result.is_ok());
+
+        // call a function in the active module instance
+        {
+            let result = store.active_module();
+            assert!(result.is_ok());
+            let instance = result.unwrap();
+            let result = instance.typed_func::<i32, i32>(&mut executor, "fib");
+            assert!(result.is_ok());
+            let fib = result.unwrap();
+            let result = fib.call(5);
+            assert!(result.is_ok());
+            assert_eq!(result.unwrap(), 8);
+        }
+
+        // call a function in a named module instance
+        {
+            let result = store.named_module("fib-module");
+            assert!(result.is_ok());
+            let instance = result.unwrap();
+            let result = instance.typed_func::<i32, i32>(&mut executor, "fib");
+            assert!(result.is_ok());
+            let fib = result.unwrap();
+            let result = fib.call(10);
+            assert!(result.is_ok());
+            assert_eq!(result.unwrap(), 89);
+
+            // the signature does not match
+            let result = instance.typed_func::<(i32, i32), i32>(&mut executor, "fib");
+            assert!(result.is_err());
+        }
+    }
+}
@@ -37,23 +37,41 @@ impl From<RefType> for ffi::WasmEdge_RefType {
 
 impl From<std::ops::RangeInclusive<u32>> for ffi::WasmEdge_Limit {
     fn from(range: std::ops::RangeInclusive<u32>) -> Self {
+        let (start, end) = range.into_inner();
+        Self::from(start as u64..=end as u64)
+    }
+}
+impl From<std::ops::RangeInclusive<u64>> for ffi::WasmEdge_Limit {
+    fn from(range: std::ops::RangeInclusive<u64>) -> Self {
         let (start, end) = range.into_inner();
         if start == end {
             Self {
                 Min: start,
                 Max: end,
                 HasMax: false,
+                Shared: false,
+                Is64: false,
             }
         } else {
             Self {
                 Min: start,
                 Max: end,
                 HasMax: true,
+                Shared: false,
+                Is64: false,
             }
         }
     }
 }
-impl From<ffi::WasmEdge_Limit> for std::ops::RangeInclusive<u32> {
+impl TryFrom<ffi::WasmEdge_Limit> for std::ops::RangeInclusive<u32> {
+    type Error = std::num::TryFromIntError;
+
+    fn try_from(limit: ffi::WasmEdge_Limit) -> Result<Self, Self::Error> {
+        let (start, end) = std::ops::RangeInclusive::<u64>::from(limit).into_inner();
+        Ok(Self::new(u32::try_from(start)?, u32::try_from(end)?))
+    }
+}
+impl From<ffi::WasmEdge_Limit> for std::ops::RangeInclusive<u64> {
     fn from(limit: ffi::WasmEdge_Limit) -> Self {
         let start = limit.Min;
         let end = match limit.HasMax {
@@ -480,6 +498,7 @@ impl WasmValue {
                         registered: true,
                         name: None,
                         mod_name: None,
+                        real_fn: None,
                     })
                 }
             }
@@ -770,6 +789,23 @@ mod tests {
         handle.join().unwrap();
     }
 
+    #[test]
+    fn test_types_limit() {
+        let limit = ffi::WasmEdge_Limit::from(10u64..=20);
+        let result = std::ops::RangeInclusive::<u32>::try_from(limit);
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap(), 10..=20);
+
+        // the limits beyond u32 are not truncated
+        let limit = ffi::WasmEdge_Limit::from(10u64..=(u32::MAX as u64 + 1));
+        let result = std::ops::RangeInclusive::<u32>::try_from(limit);
+        assert!(result.is_err());
+        assert_eq!(
+            std::ops::RangeInclusive::<u64>::from(limit),
+            10..=(u32::MAX as u64 + 1)
+        );
+    }
+
     #[test]
     fn test_types_string() {
         let s: WasmEdgeString = "hello".into();
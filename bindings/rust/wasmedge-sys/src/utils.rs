@@ -23,9 +23,13 @@ pub(crate) fn path_to_cstring(path: &Path) -> WasmEdgeResult<CString> {
     }
 }
 
-pub(crate) fn string_to_c_char(arg: impl AsRef<str>) -> *const std::os::raw::c_char {
-    let s = CString::new(arg.as_ref()).unwrap();
-    s.as_ptr()
+// the returned strings own the data which the pointers passed to the C API refer to, so they must outlive the call
+pub(crate) fn strings_to_cstrings(strs: Option<Vec<&str>>) -> WasmEdgeResult<Vec<CString>> {
+    Ok(strs
+        .unwrap_or_default()
+        .into_iter()
+        .map(CString::new)
+        .collect::<Result<Vec<_>, _>>()?)
 }
 
 /// Full version.
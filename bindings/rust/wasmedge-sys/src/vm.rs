@@ -1,17 +1,24 @@
 //! Defines WasmEdge Vm struct.
 
 use crate::{
-    error::{check, VmError, WasmEdgeError},
+    async_result::AsyncResult,
+    error::{check, host_func_trap, read_backtrace, VmError, WasmBacktrace, WasmEdgeError},
     ffi,
     ffi::{WasmEdge_HostRegistration_Wasi, WasmEdge_HostRegistration_WasmEdge_Process},
     import_obj::{ImportObject, InnerImportObject},
-    instance::function::{FuncType, InnerFuncType},
+    instance::{
+        function::{FuncType, Function, InnerFuncType},
+        memory::MemoryView,
+    },
+    io::WasmFnIO,
+    pre_init,
     statistics::{InnerStat, Statistics},
     store::{InnerStore, Store},
+    typed_func::{Target, TypedFunc},
     types::WasmEdgeString,
     utils, Config, Module, WasmEdgeResult, WasmValue,
 };
-use std::{collections::HashMap, path::Path};
+use std::{collections::HashMap, path::Path, time::Duration};
 
 /// Struct of WasmEdge Vm.
 ///
@@ -333,6 +340,138 @@ impl Vm {
         self.run_function(func_name, params)
     }
 
+    /// Asynchronously runs a [function](crate::Function) defined in a WASM file.
+    ///
+    /// This is the asynchronous version of [run_wasm_from_file](Vm::run_wasm_from_file). The loading, validation,
+    /// instantiation and invocation are performed on a background thread.
+    ///
+    /// # Arguments
+    ///
+    /// - `path` specifies the file path to a WASM file.
+    ///
+    /// - `func_name` specifies the name of the [function](crate::Function).
+    ///
+    /// - `params` specifies the the parameter values which are used by the [function](crate::Function).
+    ///
+    /// # Error
+    ///
+    /// If fail to start the asynchronous execution, then an error is returned. The errors raised during the execution
+    /// are returned by [AsyncResult::get_returns](crate::AsyncResult::get_returns).
+    pub fn run_wasm_from_file_async(
+        &mut self,
+        path: impl AsRef<Path>,
+        func_name: impl AsRef<str>,
+        params: impl IntoIterator<Item = WasmValue>,
+    ) -> WasmEdgeResult<AsyncResult<'_>> {
+        let path = utils::path_to_cstring(path.as_ref())?;
+
+        // prepare parameters
+        let raw_params = params.into_iter().map(|x| x.as_raw()).collect::<Vec<_>>();
+
+        let func_name: WasmEdgeString = func_name.as_ref().into();
+        let ctx = unsafe {
+            ffi::WasmEdge_VMAsyncRunWasmFromFile(
+                self.inner.0,
+                path.as_ptr(),
+                func_name.as_raw(),
+                raw_params.as_ptr(),
+                raw_params.len() as u32,
+            )
+        };
+
+        match ctx.is_null() {
+            true => Err(WasmEdgeError::AsyncResultCreate),
+            false => Ok(AsyncResult::new(ctx)),
+        }
+    }
+
+    /// Asynchronously instantiates a WASM module from a buffer and invokes a function by name.
+    ///
+    /// This is the asynchronous version of [run_wasm_from_buffer](Vm::run_wasm_from_buffer). The buffer is read by
+    /// the background thread, so it must outlive the returned [AsyncResult](crate::AsyncResult).
+    ///
+    /// # Arguments
+    ///
+    /// - `buffer` specifies the buffer of a WASM binary.
+    ///
+    /// - `func_name` specifies the name of the [function](crate::Function).
+    ///
+    /// - `params` specifies the parameter values which are used by the [function](crate::Function).
+    ///
+    /// # Error
+    ///
+    /// If fail to start the asynchronous execution, then an error is returned. The errors raised during the execution
+    /// are returned by [AsyncResult::get_returns](crate::AsyncResult::get_returns).
+    pub fn run_wasm_from_buffer_async<'a>(
+        &'a mut self,
+        buffer: &'a [u8],
+        func_name: impl AsRef<str>,
+        params: impl IntoIterator<Item = WasmValue>,
+    ) -> WasmEdgeResult<AsyncResult<'a>> {
+        // prepare parameters
+        let raw_params = params.into_iter().map(|x| x.as_raw()).collect::<Vec<_>>();
+
+        let func_name: WasmEdgeString = func_name.as_ref().into();
+        let ctx = unsafe {
+            ffi::WasmEdge_VMAsyncRunWasmFromBuffer(
+                self.inner.0,
+                buffer.as_ptr(),
+                buffer.len() as u32,
+                func_name.as_raw(),
+                raw_params.as_ptr(),
+                raw_params.len() as u32,
+            )
+        };
+
+        match ctx.is_null() {
+            true => Err(WasmEdgeError::AsyncResultCreate),
+            false => Ok(AsyncResult::new(ctx)),
+        }
+    }
+
+    /// Asynchronously instantiates a WASM module from a WasmEdge AST [Module](crate::Module) and invokes a function
+    /// by name.
+    ///
+    /// This is the asynchronous version of [run_wasm_from_module](Vm::run_wasm_from_module).
+    ///
+    /// # Arguments
+    ///
+    /// - `module` specifies the WasmEdge AST [Module](crate::Module) generated by [Loader](crate::Loader) or [Compiler](crate::Compiler).
+    ///
+    /// - `func_name` specifies the name of the [function](crate::Function).
+    ///
+    /// - `params` specifies the parameter values which are used by the [function](crate::Function).
+    ///
+    /// # Error
+    ///
+    /// If fail to start the asynchronous execution, then an error is returned. The errors raised during the execution
+    /// are returned by [AsyncResult::get_returns](crate::AsyncResult::get_returns).
+    pub fn run_wasm_from_module_async(
+        &mut self,
+        module: &Module,
+        func_name: impl AsRef<str>,
+        params: impl IntoIterator<Item = WasmValue>,
+    ) -> WasmEdgeResult<AsyncResult<'_>> {
+        // prepare parameters
+        let raw_params = params.into_iter().map(|x| x.as_raw()).collect::<Vec<_>>();
+
+        let func_name: WasmEdgeString = func_name.as_ref().into();
+        let ctx = unsafe {
+            ffi::WasmEdge_VMAsyncRunWasmFromASTModule(
+                self.inner.0,
+                module.inner.0 as *const _,
+                func_name.as_raw(),
+                raw_params.as_ptr(),
+                raw_params.len() as u32,
+            )
+        };
+
+        match ctx.is_null() {
+            true => Err(WasmEdgeError::AsyncResultCreate),
+            false => Ok(AsyncResult::new(ctx)),
+        }
+    }
+
     /// Loads a WASM module from a WasmEdge AST [Module](crate::Module).
     ///
     /// This is the first step to invoke a WASM function step by step.
@@ -465,7 +604,8 @@ impl Vm {
                 raw_params.len() as u32,
                 returns.as_mut_ptr(),
                 returns_len,
-            ))?;
+            ))
+            .map_err(|err| self.execution_error(err))?;
             returns.set_len(returns_len as usize);
         }
 
@@ -514,13 +654,230 @@ impl Vm {
                 raw_params.len() as u32,
                 returns.as_mut_ptr(),
                 returns_len,
-            ))?;
+            ))
+            .map_err(|err| self.execution_error(err))?;
             returns.set_len(returns_len as usize);
         }
 
         Ok(returns.into_iter().map(Into::into).collect::<Vec<_>>())
     }
 
+    /// Runs the given [function](crate::Function) in the [Vm].
+    ///
+    /// Unlike `run_function` and `run_registered_function`, the target function is not looked up by name, so the
+    /// functions which are not exported can be run as well, for example, the one obtained from an element of a
+    /// [table](crate::Table) of function references by [func_ref](crate::WasmValue::func_ref).
+    ///
+    /// # Arguments
+    ///
+    /// - `func` specifies the function to run.
+    ///
+    /// - `params` specifies the parameter values passed to the function.
+    ///
+    /// # Error
+    ///
+    /// If fail to run the function, then an error is returned.
+    pub fn run_func_ref(
+        &self,
+        func: &Function,
+        params: impl IntoIterator<Item = WasmValue>,
+    ) -> WasmEdgeResult<Vec<WasmValue>> {
+        // prepare parameters
+        let raw_params = params.into_iter().map(|x| x.as_raw()).collect::<Vec<_>>();
+
+        // get the info of the funtion return
+        let returns_len = func.ty()?.returns_len();
+        let mut returns = Vec::with_capacity(returns_len);
+
+        unsafe {
+            check(ffi::WasmEdge_VMExecuteFunction(
+                self.inner.0,
+                func.inner.0,
+                raw_params.as_ptr(),
+                raw_params.len() as u32,
+                returns.as_mut_ptr(),
+                returns_len as u32,
+            ))
+            .map_err(|err| self.execution_error(err))?;
+            returns.set_len(returns_len);
+        }
+
+        Ok(returns.into_iter().map(Into::into).collect::<Vec<_>>())
+    }
+
+    // completes the error of the execution which just failed on the current thread with the trap of the failed host
+    // function
+    pub(crate) fn execution_error(&self, err: WasmEdgeError) -> WasmEdgeError {
+        host_func_trap(err, unsafe {
+            ffi::WasmEdge_VMGetHostFailureData(self.inner.0)
+        })
+    }
+
+    /// Returns the [backtrace](crate::error::WasmBacktrace) of the last execution run by the calling thread, if it
+    /// failed.
+    ///
+    /// The backtrace is recorded when an execution of the interpreter fails, and cleared when the next execution on
+    /// the calling thread starts, so the executions of the [Vm] on the other threads do not affect it. Call it right
+    /// after the failed `run_*` method returns its error. The backtraces of the asynchronous executions are returned
+    /// by [AsyncResult::backtrace](crate::AsyncResult::backtrace) instead. `None` is returned if the last execution
+    /// succeeded, or failed before running any WASM function.
+    pub fn backtrace(&self) -> Option<WasmBacktrace> {
+        let backtrace = read_backtrace(|frames, len| unsafe {
+            ffi::WasmEdge_VMGetBacktrace(self.inner.0, frames, len)
+        });
+        (!backtrace.frames().is_empty()).then_some(backtrace)
+    }
+
+    /// Runs an exported WASM function by name, and interrupts it if it does not end within the given timeout. The WASM
+    /// function is hosted by the anonymous [module](crate::Module) in the [store](crate::Store) of the [Vm].
+    ///
+    /// The interpreter checks for the interruption at every function call and branch. For AOT compiled code, the check
+    /// is generated only if the module is compiled with [interruptible](crate::Config::interruptible) enabled;
+    /// otherwise, the execution can not be interrupted and the call returns when the function ends. Only this execution
+    /// is interrupted; the other executions of the [Vm] keep running.
+    ///
+    /// # Arguments
+    ///
+    /// - `func_name` specifies the name of the exported WASM function to run.
+    ///
+    /// - `params` specifies the parameter values passed to the exported WASM function.
+    ///
+    /// - `timeout` specifies the maximum duration the WASM function is allowed to run.
+    ///
+    /// # Error
+    ///
+    /// If the timeout elapsed, then [ExecutionTimeout](crate::error::WasmEdgeError::ExecutionTimeout) is returned. If
+    /// fail to run the WASM function, then an error is returned.
+    pub fn run_function_with_timeout(
+        &self,
+        func_name: impl AsRef<str>,
+        params: impl IntoIterator<Item = WasmValue>,
+        timeout: Duration,
+    ) -> WasmEdgeResult<Vec<WasmValue>> {
+        self.run_function_async(func_name, params)?
+            .get_returns_with_timeout(timeout)
+    }
+
+    /// Runs an exported WASM function by its name and the module's name in which the WASM function is hosted, and
+    /// interrupts it if it does not end within the given timeout.
+    ///
+    /// See [run_function_with_timeout](Vm::run_function_with_timeout) for the details of the interruption.
+    ///
+    /// # Arguments
+    ///
+    /// - `mod_name` specifies the name of the WASM module registered into the [store](crate::Store) of the [Vm].
+    ///
+    /// - `func_name` specifies the name of the exported WASM function to run.
+    ///
+    /// - `params` specifies the parameter values passed to the exported WASM function.
+    ///
+    /// - `timeout` specifies the maximum duration the WASM function is allowed to run.
+    ///
+    /// # Error
+    ///
+    /// If the timeout elapsed, then [ExecutionTimeout](crate::error::WasmEdgeError::ExecutionTimeout) is returned. If
+    /// fail to run the WASM function, then an error is returned.
+    pub fn run_registered_function_with_timeout(
+        &self,
+        mod_name: impl AsRef<str>,
+        func_name: impl AsRef<str>,
+        params: impl IntoIterator<Item = WasmValue>,
+        timeout: Duration,
+    ) -> WasmEdgeResult<Vec<WasmValue>> {
+        self.run_registered_function_async(mod_name, func_name, params)?
+            .get_returns_with_timeout(timeout)
+    }
+
+    /// Asynchronously runs an exported WASM function by name. The WASM function is hosted by the anonymous
+    /// [module](crate::Module) in the [store](crate::Store) of the [Vm].
+    ///
+    /// This is the asynchronous version of [run_function](Vm::run_function). The returned
+    /// [AsyncResult](crate::AsyncResult) can be used to wait for, cancel, or collect the result of the execution.
+    ///
+    /// # Arguments
+    ///
+    /// - `func_name` specifies the name of the exported WASM function to run.
+    ///
+    /// - `params` specifies the parameter values passed to the exported WASM function.
+    ///
+    /// # Error
+    ///
+    /// If fail to start the asynchronous execution, then an error is returned. The errors raised during the execution
+    /// are returned by [AsyncResult::get_returns](crate::AsyncResult::get_returns).
+    pub fn run_function_async(
+        &self,
+        func_name: impl AsRef<str>,
+        params: impl IntoIterator<Item = WasmValue>,
+    ) -> WasmEdgeResult<AsyncResult<'_>> {
+        // check if func_name is one of the names of the functions in the store
+        self.contains_func_name(func_name.as_ref())?;
+
+        // prepare parameters
+        let raw_params = params.into_iter().map(|x| x.as_raw()).collect::<Vec<_>>();
+
+        let func_name: WasmEdgeString = func_name.as_ref().into();
+        let ctx = unsafe {
+            ffi::WasmEdge_VMAsyncExecute(
+                self.inner.0,
+                func_name.as_raw(),
+                raw_params.as_ptr(),
+                raw_params.len() as u32,
+            )
+        };
+
+        match ctx.is_null() {
+            true => Err(WasmEdgeError::AsyncResultCreate),
+            false => Ok(AsyncResult::new(ctx)),
+        }
+    }
+
+    /// Asynchronously runs an exported WASM function by its name and the module's name in which the WASM function is
+    /// hosted.
+    ///
+    /// This is the asynchronous version of [run_registered_function](Vm::run_registered_function).
+    ///
+    /// # Arguments
+    ///
+    /// - `mod_name` specifies the name of the WASM module registered into the [store](crate::Store) of the [Vm].
+    ///
+    /// - `func_name` specifies the name of the exported WASM function to run.
+    ///
+    /// - `params` specifies the parameter values passed to the exported WASM function.
+    ///
+    /// # Error
+    ///
+    /// If fail to start the asynchronous execution, then an error is returned. The errors raised during the execution
+    /// are returned by [AsyncResult::get_returns](crate::AsyncResult::get_returns).
+    pub fn run_registered_function_async(
+        &self,
+        mod_name: impl AsRef<str>,
+        func_name: impl AsRef<str>,
+        params: impl IntoIterator<Item = WasmValue>,
+    ) -> WasmEdgeResult<AsyncResult<'_>> {
+        // check if func_name is one of the names of the registered functions in the store
+        self.contains_reg_func_name(mod_name.as_ref(), func_name.as_ref())?;
+
+        // prepare parameters
+        let raw_params = params.into_iter().map(|x| x.as_raw()).collect::<Vec<_>>();
+
+        let mod_name: WasmEdgeString = mod_name.as_ref().into();
+        let func_name: WasmEdgeString = func_name.as_ref().into();
+        let ctx = unsafe {
+            ffi::WasmEdge_VMAsyncExecuteRegistered(
+                self.inner.0,
+                mod_name.as_raw(),
+                func_name.as_raw(),
+                raw_params.as_ptr(),
+                raw_params.len() as u32,
+            )
+        };
+
+        match ctx.is_null() {
+            true => Err(WasmEdgeError::AsyncResultCreate),
+            false => Ok(AsyncResult::new(ctx)),
+        }
+    }
+
     /// Returns the function type of a WASM function by its name. The function is hosted in the anonymous [module](crate::Module) of the [Vm].
     ///
     /// # Argument
@@ -587,19 +944,70 @@ impl Vm {
         }
     }
 
-    /// Resets the [`Vm`].
-    pub fn reset(&mut self) {
-        unsafe { ffi::WasmEdge_VMCleanup(self.inner.0) }
-    }
-
-    /// Returns the length of the exported function list.
-    pub fn function_list_len(&self) -> usize {
-        unsafe { ffi::WasmEdge_VMGetFunctionListLength(self.inner.0) as usize }
+    /// Returns a [TypedFunc](crate::TypedFunc) handle to an exported WASM function by its name. The WASM function is
+    /// hosted by the anonymous [module](crate::Module) in the [store](crate::Store) of the [Vm].
+    ///
+    /// The signature of the WASM function is checked against `Params` and `Rets` once, so the returned handle can be
+    /// called repeatedly with native Rust values.
+    ///
+    /// # Argument
+    ///
+    /// - `func_name` specifies the name of the target WASM function.
+    ///
+    /// # Error
+    ///
+    /// If fail to find the WASM function, or its signature does not match, then an error is returned.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let fib = vm.typed_func::<i32, i32>("fib")?;
+    /// assert_eq!(fib.call(5)?, 8);
+    /// ```
+    pub fn typed_func<Params: WasmFnIO, Rets: WasmFnIO>(
+        &self,
+        func_name: impl AsRef<str>,
+    ) -> WasmEdgeResult<TypedFunc<'_, Params, Rets>> {
+        let func = self.store_mut()?.find_func(func_name.as_ref())?;
+        TypedFunc::new(Target::Vm(self), func, func_name.as_ref())
     }
 
-    /// Returns an iterator of the exported functions.
-    pub fn function_iter(&self) -> impl Iterator<Item = (Option<String>, Option<FuncType>)> {
-        let len = self.function_list_len();
+    /// Returns a [TypedFunc](crate::TypedFunc) handle to an exported WASM function by its name and the module's name
+    /// in which the WASM function is hosted.
+    ///
+    /// # Arguments
+    ///
+    /// - `mod_name` specifies the name of the WASM module registered into the [store](crate::Store) of the [Vm].
+    ///
+    /// - `func_name` specifies the name of the target WASM function.
+    ///
+    /// # Error
+    ///
+    /// If fail to find the WASM function, or its signature does not match, then an error is returned.
+    pub fn typed_registered_func<Params: WasmFnIO, Rets: WasmFnIO>(
+        &self,
+        mod_name: impl AsRef<str>,
+        func_name: impl AsRef<str>,
+    ) -> WasmEdgeResult<TypedFunc<'_, Params, Rets>> {
+        let func = self
+            .store_mut()?
+            .find_func_registered(mod_name.as_ref(), func_name.as_ref())?;
+        TypedFunc::new(Target::Vm(self), func, func_name.as_ref())
+    }
+
+    /// Resets the [`Vm`].
+    pub fn reset(&mut self) {
+        unsafe { ffi::WasmEdge_VMCleanup(self.inner.0) }
+    }
+
+    /// Returns the length of the exported function list.
+    pub fn function_list_len(&self) -> usize {
+        unsafe { ffi::WasmEdge_VMGetFunctionListLength(self.inner.0) as usize }
+    }
+
+    /// Returns an iterator of the exported functions.
+    pub fn function_iter(&self) -> impl Iterator<Item = (Option<String>, Option<FuncType>)> {
+        let len = self.function_list_len();
         let mut names = Vec::with_capacity(len);
         let mut types = Vec::with_capacity(len);
         unsafe {
@@ -637,6 +1045,8 @@ impl Vm {
             false => Ok(ImportObject {
                 inner: InnerImportObject(io_ctx),
                 registered: true,
+                host_funcs: Vec::new(),
+                shared_mems: Vec::new(),
             }),
         }
     }
@@ -656,6 +1066,8 @@ impl Vm {
             false => Ok(ImportObject {
                 inner: InnerImportObject(io_ctx),
                 registered: true,
+                host_funcs: Vec::new(),
+                shared_mems: Vec::new(),
             }),
         }
     }
@@ -672,6 +1084,75 @@ impl Vm {
         }
     }
 
+    /// Returns a [view](crate::MemoryView) of the exported [memory](crate::Memory) instance in the anonymous
+    /// [module](crate::Module) by the given memory name.
+    ///
+    /// The view mutably borrows the [Vm], so that no WASM function can be run by the [Vm] while the view is alive.
+    ///
+    /// # Argument
+    ///
+    /// - `name` specifies the target exported [memory](crate::Memory) instance.
+    ///
+    /// # Error
+    ///
+    /// If fail to find the target [memory](crate::Memory), then an error is returned.
+    pub fn memory_view(&mut self, name: impl AsRef<str>) -> WasmEdgeResult<MemoryView<'_>> {
+        let mem = self.store_mut()?.find_memory(name)?;
+        Ok(MemoryView::new(mem.inner.0))
+    }
+
+    /// Pre-initializes a WASM module, and returns the WASM binary of the initialized module.
+    ///
+    /// The WASM module is instantiated as the anonymous [module](crate::Module) in the [Vm], and then the exported
+    /// initialization function is run. After that, the data of the memories and the values of the mutable globals
+    /// defined by the module are written back into the WASM binary, so that instantiating the returned WASM binary
+    /// skips the initialization. The start function and the export of the initialization function are removed from
+    /// the returned WASM binary.
+    ///
+    /// Notice that the states out of the module, such as the imported globals and the host resources, are not recorded.
+    /// The modules which import memories are not supported, and the initialization, including the start function,
+    /// must not change the tables defined by the module.
+    ///
+    /// # Arguments
+    ///
+    /// - `wasm` specifies the WASM binary to pre-initialize.
+    ///
+    /// - `init_func` specifies the name of the exported initialization function, which has no parameters.
+    ///
+    /// # Error
+    ///
+    /// If fail to instantiate the module or run the initialization function, or the module can not be
+    /// pre-initialized, then an error is returned. If the initialization changes a table, then
+    /// [TableChanged](crate::error::PreInitError::TableChanged) is returned.
+    pub fn pre_initialize(
+        &mut self,
+        wasm: impl AsRef<[u8]>,
+        init_func: impl AsRef<str>,
+    ) -> WasmEdgeResult<Vec<u8>> {
+        let wasm = wasm.as_ref();
+        // the start function is run after the tables are captured, so that its changes of the tables are detected
+        let (staged, has_start) = pre_init::stage(wasm)?;
+        self.load_wasm_from_buffer(&staged)?;
+        self.validate()?;
+        self.instantiate()?;
+        let tables = pre_init::capture_tables(&self.store_mut()?.active_module()?)?;
+        if has_start {
+            self.run_function(pre_init::START_EXPORT, [])?;
+        }
+        self.run_function(init_func.as_ref(), [])?;
+
+        let store = self.store_mut()?;
+        let instance = store.active_module()?;
+        pre_init::check_tables(&instance, &tables)?;
+        let snapshot = instance.snapshot()?;
+        pre_init::rewrite(
+            wasm,
+            &snapshot,
+            |idx| instance.data_segment_size(idx),
+            init_func.as_ref(),
+        )
+    }
+
     /// Returns the mutable [Statistics](crate::Statistics) from the [Vm].
     pub fn statistics_mut(&self) -> WasmEdgeResult<Statistics> {
         let stat_ctx = unsafe { ffi::WasmEdge_VMGetStatisticsContext(self.inner.0) };
@@ -752,9 +1233,10 @@ mod tests {
     use crate::{
         error::{
             CoreCommonError, CoreError, CoreExecutionError, CoreInstantiationError, CoreLoadError,
-            StoreError, VmError, WasmEdgeError,
+            StoreError, Trap, VmError, WasmEdgeError,
         },
-        Config, FuncType, Function, ImportObject, Loader, Module, Store, ValType, WasmValue,
+        Caller, Config, FuncType, Function, ImportObject, Loader, Module, Store, ValType,
+        WasmValue,
     };
     use std::{
         sync::{Arc, Mutex},
@@ -1543,6 +2025,560 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_vm_backtrace() {
+        // (module
+        //   (func (export "trap") call 1)
+        //   (func unreachable))
+        let wasm_bytes = [
+            0x00, 0x61, 0x73, 0x6d, 0x01, 0x00, 0x00, 0x00, // header
+            0x01, 0x04, 0x01, 0x60, 0x00, 0x00, // type section
+            0x03, 0x03, 0x02, 0x00, 0x00, // function section
+            0x07, 0x08, 0x01, 0x04, 0x74, 0x72, 0x61, 0x70, 0x00, 0x00, // export section
+            0x0a, 0x0a, 0x02, // code section
+            0x04, 0x00, 0x10, 0x01, 0x0b, // code of func[0]
+            0x03, 0x00, 0x00, 0x0b, // code of func[1]
+        ];
+
+        let result = Vm::create(None, None);
+        assert!(result.is_ok());
+        let mut vm = result.unwrap();
+        let result = vm.load_wasm_from_buffer(&wasm_bytes);
+        assert!(result.is_ok());
+        let result = vm.validate();
+        assert!(result.is_ok());
+        let result = vm.instantiate();
+        assert!(result.is_ok());
+
+        let result = vm.run_function("trap", []);
+        assert!(result.is_err());
+        assert_eq!(
+            result.unwrap_err(),
+            WasmEdgeError::Core(CoreError::Execution(CoreExecutionError::Unreachable))
+        );
+
+        // the frames from the innermost to the outermost
+        let result = vm.backtrace();
+        assert!(result.is_some());
+        let backtrace = result.unwrap();
+        let frames = backtrace.frames();
+        assert_eq!(frames.len(), 2);
+        assert_eq!(frames[0].module_name(), None);
+        assert_eq!(frames[0].func_index(), Some(1));
+        assert_eq!(frames[0].func_name(), None);
+        assert_eq!(frames[0].offset(), Some(0x27));
+        assert_eq!(frames[1].func_index(), Some(0));
+        assert_eq!(frames[1].func_name(), Some("trap"));
+        assert_eq!(frames[1].offset(), Some(0x22));
+        assert_eq!(
+            backtrace.to_string(),
+            "wasm backtrace:\n   0: func[1] @ 0x27\n   1: trap @ 0x22"
+        );
+
+        // the backtrace is kept for the thread which ran the execution
+        thread::scope(|s| {
+            s.spawn(|| assert!(vm.backtrace().is_none()));
+        });
+        assert!(vm.backtrace().is_some());
+
+        // the backtrace of an asynchronous execution is kept by its handle
+        let result = vm.run_function_async("trap", []);
+        assert!(result.is_ok());
+        let async_result = result.unwrap();
+        assert!(async_result.get_returns().is_err());
+        let result = async_result.backtrace();
+        assert!(result.is_some());
+        assert_eq!(result.unwrap(), backtrace);
+        drop(async_result);
+
+        // the errors raised before the execution have no backtrace
+        let result = vm.run_function("trap", [WasmValue::from_i32(1)]);
+        assert!(result.is_err());
+        assert!(vm.backtrace().is_none());
+    }
+
+    #[test]
+    fn test_vm_memory_view() {
+        // (module
+        //   (memory (export "memory") 1 2)
+        //   (func (export "grow") (result i32) i32.const 1 memory.grow))
+        let wasm_bytes = [
+            0x00, 0x61, 0x73, 0x6d, 0x01, 0x00, 0x00, 0x00, // header
+            0x01, 0x05, 0x01, 0x60, 0x00, 0x01, 0x7f, // type section
+            0x03, 0x02, 0x01, 0x00, // function section
+            0x05, 0x04, 0x01, 0x01, 0x01, 0x02, // memory section
+            0x07, 0x11, 0x02, 0x06, 0x6d, 0x65, 0x6d, 0x6f, 0x72, 0x79, 0x02, 0x00, 0x04, 0x67,
+            0x72, 0x6f, 0x77, 0x00, 0x00, // export section
+            0x0a, 0x08, 0x01, 0x06, 0x00, 0x41, 0x01, 0x40, 0x00, 0x0b, // code section
+        ];
+
+        let result = Vm::create(None, None);
+        assert!(result.is_ok());
+        let mut vm = result.unwrap();
+        let result = vm.load_wasm_from_buffer(&wasm_bytes);
+        assert!(result.is_ok());
+        let result = vm.validate();
+        assert!(result.is_ok());
+        let result = vm.instantiate();
+        assert!(result.is_ok());
+
+        // write data through the view
+        {
+            let result = vm.memory_view("memory");
+            assert!(result.is_ok());
+            let mut view = result.unwrap();
+            assert_eq!(view.len(), 65536);
+            let result = unsafe { view.as_mut_slice(65530, 6) };
+            assert!(result.is_ok());
+            result.unwrap().copy_from_slice(b"wasmed");
+            let result = unsafe { view.as_slice(65530, 7) };
+            assert!(result.is_err());
+        }
+
+        // grow the memory by running the wasm function
+        let result = vm.run_function("grow", []);
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap()[0].to_i32(), 1);
+
+        // the data is kept after growing
+        let result = vm.memory_view("memory");
+        assert!(result.is_ok());
+        let view = result.unwrap();
+        assert_eq!(view.len(), 131072);
+        let result = unsafe { view.as_slice(65530, 7) };
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap(), b"wasmed\0");
+
+        // the memory does not exist
+        let result = vm.memory_view("mem");
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_vm_run_func_ref() {
+        let result = Vm::create(None, None);
+        assert!(result.is_ok());
+        let mut vm = result.unwrap();
+        let path = std::path::PathBuf::from(env!("WASMEDGE_DIR"))
+            .join("bindings/rust/wasmedge-sys/tests/data/table.wasm");
+        let result = vm.load_wasm_from_file(path);
+        assert!(result.is_ok());
+        let result = vm.validate();
+        assert!(result.is_ok());
+        let result = vm.instantiate();
+        assert!(result.is_ok());
+
+        let result = vm.store_mut();
+        assert!(result.is_ok());
+        let store = result.unwrap();
+
+        // run the exported function found in the store
+        let result = store.find_func("dispatch");
+        assert!(result.is_ok());
+        let dispatch = result.unwrap();
+        let result = dispatch.call(&vm, [WasmValue::from_i32(0), WasmValue::from_i32(6)]);
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap()[0].to_i32(), 12);
+
+        // run the callback which is not exported by its function reference
+        let result = store.find_table("callbacks");
+        assert!(result.is_ok());
+        let table = result.unwrap();
+        let result = table.get_data(1);
+        assert!(result.is_ok());
+        let result = result.unwrap().func_ref();
+        assert!(result.is_some());
+        let square = result.unwrap();
+        let result = square.call(&vm, [WasmValue::from_i32(6)]);
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap()[0].to_i32(), 36);
+        let result = vm.run_func_ref(&square, [WasmValue::from_i64(6)]);
+        assert!(result.is_err());
+
+        // run a host function which is not registered into the store
+        let result = FuncType::create([ValType::I32], [ValType::I32]);
+        assert!(result.is_ok());
+        let func_ty = result.unwrap();
+        let result = Function::create(
+            &func_ty,
+            Box::new(|_caller, inputs| Ok(vec![WasmValue::from_i32(-inputs[0].to_i32())])),
+            0,
+        );
+        assert!(result.is_ok());
+        let negate = result.unwrap();
+        let result = negate.call(&vm, [WasmValue::from_i32(6)]);
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap()[0].to_i32(), -6);
+    }
+
+    #[test]
+    fn test_vm_run_function_async() {
+        let result = Vm::create(None, None);
+        assert!(result.is_ok());
+        let mut vm = result.unwrap();
+
+        // load, validate and instantiate a wasm module
+        let module = load_fib_module();
+        let result = vm.load_wasm_from_module(&module);
+        assert!(result.is_ok());
+        let result = vm.validate();
+        assert!(result.is_ok());
+        let result = vm.instantiate();
+        assert!(result.is_ok());
+
+        // run a function asynchronously
+        let result = vm.run_function_async("fib", [WasmValue::from_i32(5)]);
+        assert!(result.is_ok());
+        let async_result = result.unwrap();
+        async_result.wait();
+        assert_eq!(async_result.returns_len(), 1);
+        let result = async_result.get_returns();
+        assert!(result.is_ok());
+        let returns = result.unwrap();
+        assert_eq!(returns[0].to_i32(), 8);
+
+        // run a function with the parameters of wrong type
+        let result = vm.run_function_async("fib", [WasmValue::from_i64(5)]);
+        assert!(result.is_ok());
+        let async_result = result.unwrap();
+        assert_eq!(async_result.returns_len(), 0);
+        let result = async_result.get_returns();
+        assert!(result.is_err());
+        assert_eq!(
+            result.unwrap_err(),
+            WasmEdgeError::Core(CoreError::Execution(CoreExecutionError::FuncTypeMismatch))
+        );
+
+        // run a function: the specified function name is non-existant
+        let result = vm.run_function_async("fib2", [WasmValue::from_i32(5)]);
+        assert!(result.is_err());
+        assert_eq!(
+            result.unwrap_err(),
+            WasmEdgeError::Store(StoreError::NotFoundFunc("fib2".into()))
+        );
+
+        // cancel a long-running function
+        let result = vm.run_function_async("fib", [WasmValue::from_i32(100)]);
+        assert!(result.is_ok());
+        let async_result = result.unwrap();
+        assert!(!async_result.wait_for(std::time::Duration::from_millis(100)));
+        async_result.cancel();
+        let result = async_result.get_returns();
+        assert!(result.is_err());
+        assert_eq!(
+            result.unwrap_err(),
+            WasmEdgeError::Core(CoreError::Common(CoreCommonError::Interrupted))
+        );
+    }
+
+    #[test]
+    fn test_vm_run_function_with_timeout() {
+        let result = Vm::create(None, None);
+        assert!(result.is_ok());
+        let mut vm = result.unwrap();
+
+        // load, validate and instantiate a wasm module
+        let module = load_fib_module();
+        let result = vm.load_wasm_from_module(&module);
+        assert!(result.is_ok());
+        let result = vm.validate();
+        assert!(result.is_ok());
+        let result = vm.instantiate();
+        assert!(result.is_ok());
+
+        // run a function which ends before the timeout
+        let result = vm.run_function_with_timeout(
+            "fib",
+            [WasmValue::from_i32(5)],
+            std::time::Duration::from_secs(10),
+        );
+        assert!(result.is_ok());
+        let returns = result.unwrap();
+        assert_eq!(returns[0].to_i32(), 8);
+
+        // run a long-running function
+        let result = vm.run_function_with_timeout(
+            "fib",
+            [WasmValue::from_i32(100)],
+            std::time::Duration::from_millis(100),
+        );
+        assert!(result.is_err());
+        assert_eq!(result.unwrap_err(), WasmEdgeError::ExecutionTimeout);
+
+        // the Vm is still usable after the timeout
+        let result = vm.run_function("fib", [WasmValue::from_i32(5)]);
+        assert!(result.is_ok());
+        let returns = result.unwrap();
+        assert_eq!(returns[0].to_i32(), 8);
+
+        // run a long-running registered function
+        let result = vm.register_wasm_from_module("fib-module", load_fib_module());
+        assert!(result.is_ok());
+        let result = vm.run_registered_function_with_timeout(
+            "fib-module",
+            "fib",
+            [WasmValue::from_i32(100)],
+            std::time::Duration::from_millis(100),
+        );
+        assert!(result.is_err());
+        assert_eq!(result.unwrap_err(), WasmEdgeError::ExecutionTimeout);
+
+        // the timeout of a call does not interrupt the other calls running concurrently
+        thread::scope(|s| {
+            let handle = s.spawn(|| vm.run_function("fib", [WasmValue::from_i32(30)]));
+            let result = vm.run_function_with_timeout(
+                "fib",
+                [WasmValue::from_i32(100)],
+                std::time::Duration::from_millis(100),
+            );
+            assert!(result.is_err());
+            assert_eq!(result.unwrap_err(), WasmEdgeError::ExecutionTimeout);
+
+            let result = handle.join().unwrap();
+            assert!(result.is_ok());
+            let returns = result.unwrap();
+            assert_eq!(returns[0].to_i32(), 1346269);
+        });
+    }
+
+    #[test]
+    fn test_vm_concurrent_traps() {
+        let result = Vm::create(None, None);
+        assert!(result.is_ok());
+        let mut vm = result.unwrap();
+
+        // a host function failing with a trap carrying its argument
+        let result = FuncType::create(vec![ValType::I32], vec![]);
+        assert!(result.is_ok());
+        let func_ty = result.unwrap();
+        let result = Function::create(
+            &func_ty,
+            Box::new(|_caller, inputs| {
+                thread::sleep(std::time::Duration::from_millis(20));
+                Err(Trap::new(format!("fail: {}", inputs[0].to_i32())))
+            }),
+            0,
+        );
+        assert!(result.is_ok());
+        let fail_func = result.unwrap();
+        let result = ImportObject::create("extern");
+        assert!(result.is_ok());
+        let mut import_obj = result.unwrap();
+        import_obj.add_func("fail", fail_func);
+        let result = vm.register_wasm_from_import(import_obj);
+        assert!(result.is_ok());
+
+        // the concurrent calls failing in the same host function get their own traps
+        thread::scope(|s| {
+            let handles = (0..4)
+                .map(|i| {
+                    let vm = &vm;
+                    s.spawn(move || {
+                        let result =
+                            vm.run_registered_function("extern", "fail", [WasmValue::from_i32(i)]);
+                        assert!(result.is_err());
+                        assert_eq!(
+                            result.unwrap_err(),
+                            WasmEdgeError::Trap(Trap::new(format!("fail: {}", i)))
+                        );
+                    })
+                })
+                .collect::<Vec<_>>();
+
+            let async_results = (4..8)
+                .map(|i| {
+                    let result = vm.run_registered_function_async(
+                        "extern",
+                        "fail",
+                        [WasmValue::from_i32(i)],
+                    );
+                    assert!(result.is_ok());
+                    (i, result.unwrap())
+                })
+                .collect::<Vec<_>>();
+            for (i, async_result) in async_results {
+                let result = async_result.get_returns();
+                assert!(result.is_err());
+                assert_eq!(
+                    result.unwrap_err(),
+                    WasmEdgeError::Trap(Trap::new(format!("fail: {}", i)))
+                );
+            }
+
+            for handle in handles {
+                assert!(handle.join().is_ok());
+            }
+        });
+    }
+
+    #[test]
+    fn test_vm_run_function_with_timeout_at_the_end() {
+        let result = Vm::create(None, None);
+        assert!(result.is_ok());
+        let mut vm = result.unwrap();
+
+        // a host function has no stop check, so the timeout fires while it runs and is not consumed
+        let result = ImportObject::create("extern");
+        assert!(result.is_ok());
+        let mut import_obj = result.unwrap();
+        let result = Function::wrap(|| {
+            thread::sleep(std::time::Duration::from_millis(300));
+            1i32
+        });
+        assert!(result.is_ok());
+        import_obj.add_func("slow", result.unwrap());
+        let result = Function::wrap(|| 2i32);
+        assert!(result.is_ok());
+        import_obj.add_func("fast", result.unwrap());
+        let result = vm.register_wasm_from_import(import_obj);
+        assert!(result.is_ok());
+
+        let result = vm.run_registered_function_with_timeout(
+            "extern",
+            "slow",
+            [],
+            std::time::Duration::from_millis(50),
+        );
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap()[0].to_i32(), 1);
+
+        // the cancellation requested by the timeout does not interrupt the next run
+        let result = vm.run_registered_function("extern", "fast", []);
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap()[0].to_i32(), 2);
+    }
+
+    #[test]
+    fn test_vm_run_registered_function_async() {
+        let result = Vm::create(None, None);
+        assert!(result.is_ok());
+        let mut vm = result.unwrap();
+
+        // register a wasm module from an AST module
+        let mod_name = "reg-wasm-ast";
+        let result = vm.register_wasm_from_module(mod_name, load_fib_module());
+        assert!(result.is_ok());
+
+        // run a registered function asynchronously
+        let result = vm.run_registered_function_async(mod_name, "fib", [WasmValue::from_i32(5)]);
+        assert!(result.is_ok());
+        let async_result = result.unwrap();
+        assert!(async_result.wait_for(std::time::Duration::from_secs(10)));
+        let result = async_result.get_returns();
+        assert!(result.is_ok());
+        let returns = result.unwrap();
+        assert_eq!(returns[0].to_i32(), 8);
+
+        // run a registered function but give a wrong function name.
+        let result = vm.run_registered_function_async(mod_name, "fib2", [WasmValue::from_i32(5)]);
+        assert!(result.is_err());
+        assert_eq!(
+            result.unwrap_err(),
+            WasmEdgeError::Store(StoreError::NotFoundFuncRegistered {
+                func_name: "fib2".into(),
+                mod_name: "reg-wasm-ast".into(),
+            })
+        );
+    }
+
+    #[test]
+    fn test_vm_run_registered_function_async_with_trap() {
+        let result = Vm::create(None, None);
+        assert!(result.is_ok());
+        let mut vm = result.unwrap();
+
+        let result = ImportObject::create("extern");
+        assert!(result.is_ok());
+        let mut import_obj = result.unwrap();
+        let result = FuncType::create(vec![], vec![ValType::I32]);
+        assert!(result.is_ok());
+        let func_ty = result.unwrap();
+        let result = Function::create(
+            &func_ty,
+            Box::new(|_caller, _inputs| Err(Trap::new("async failure"))),
+            0,
+        );
+        assert!(result.is_ok());
+        import_obj.add_func("fail", result.unwrap());
+        let result = vm.register_wasm_from_import(import_obj);
+        assert!(result.is_ok());
+
+        // the host function runs on a thread of WasmEdge, but its trap is still returned
+        let result = vm.run_registered_function_async("extern", "fail", []);
+        assert!(result.is_ok());
+        let async_result = result.unwrap();
+        let result = async_result.get_returns();
+        assert!(result.is_err());
+        match result.unwrap_err() {
+            WasmEdgeError::Trap(trap) => assert_eq!(trap.message(), "async failure"),
+            err => panic!("unexpected error: {}", err),
+        }
+
+        // the trap is taken once, so it is not reported by a later failure
+        let result = async_result.get_returns();
+        assert_eq!(
+            result.unwrap_err(),
+            WasmEdgeError::Core(CoreError::Execution(CoreExecutionError::ExecutionFailed))
+        );
+    }
+
+    #[test]
+    fn test_vm_run_wasm_async() {
+        let result = Vm::create(None, None);
+        assert!(result.is_ok());
+        let mut vm = result.unwrap();
+
+        let path = std::path::PathBuf::from(env!("WASMEDGE_DIR"))
+            .join("bindings/rust/wasmedge-sys/tests/data/fibonacci.wasm");
+
+        // run a function from a wasm file asynchronously
+        {
+            let result = vm.run_wasm_from_file_async(&path, "fib", [WasmValue::from_i32(5)]);
+            assert!(result.is_ok());
+            let async_result = result.unwrap();
+            let result = async_result.get_returns();
+            assert!(result.is_ok());
+            assert_eq!(result.unwrap()[0].to_i32(), 8);
+        }
+
+        // run a function from a non-existent file asynchronously
+        {
+            let result = vm.run_wasm_from_file_async("no_file", "fib", [WasmValue::from_i32(5)]);
+            assert!(result.is_ok());
+            let async_result = result.unwrap();
+            let result = async_result.get_returns();
+            assert!(result.is_err());
+            assert_eq!(
+                result.unwrap_err(),
+                WasmEdgeError::Core(CoreError::Load(CoreLoadError::IllegalPath))
+            );
+        }
+
+        // run a function from a wasm buffer asynchronously
+        let result = std::fs::read(path);
+        assert!(result.is_ok());
+        let buffer = result.unwrap();
+        {
+            let result = vm.run_wasm_from_buffer_async(&buffer, "fib", [WasmValue::from_i32(5)]);
+            assert!(result.is_ok());
+            let async_result = result.unwrap();
+            let result = async_result.get_returns();
+            assert!(result.is_ok());
+            assert_eq!(result.unwrap()[0].to_i32(), 8);
+        }
+
+        // run a function from an AST module asynchronously
+        let module = load_fib_module();
+        {
+            let result = vm.run_wasm_from_module_async(&module, "fib", [WasmValue::from_i32(5)]);
+            assert!(result.is_ok());
+            let async_result = result.unwrap();
+            let result = async_result.get_returns();
+            assert!(result.is_ok());
+            assert_eq!(result.unwrap()[0].to_i32(), 8);
+        }
+    }
+
     #[test]
     fn test_vm_send() {
         // create a Config context
@@ -1845,21 +2881,21 @@ mod tests {
         result.unwrap()
     }
 
-    fn real_add(inputs: Vec<WasmValue>) -> Result<Vec<WasmValue>, u8> {
+    fn real_add(_caller: Caller, inputs: Vec<WasmValue>) -> Result<Vec<WasmValue>, Trap> {
         if inputs.len() != 2 {
-            return Err(1);
+            return Err(Trap::new("Invalid number of arguments"));
         }
 
         let a = if inputs[0].ty() == ValType::I32 {
             inputs[0].to_i32()
         } else {
-            return Err(2);
+            return Err(Trap::new("Invalid type of arguments"));
         };
 
         let b = if inputs[1].ty() == ValType::I32 {
             inputs[1].to_i32()
         } else {
-            return Err(3);
+            return Err(Trap::new("Invalid type of arguments"));
         };
 
         let c = a + b;
@@ -1,4 +1,4 @@
-use wasmedge_sys::{FuncType, Function, ImportObject, ValType, WasmValue};
+use wasmedge_sys::{error::Trap, Caller, FuncType, Function, ImportObject, ValType, WasmValue};
 
 pub fn create_extern_module(name: impl AsRef<str>) -> ImportObject {
     // create an ImportObj module
@@ -57,25 +57,25 @@ pub fn create_extern_module(name: impl AsRef<str>) -> ImportObject {
     import_obj
 }
 
-fn _real_add(inputs: Vec<WasmValue>) -> Result<Vec<WasmValue>, u8> {
+fn _real_add(_caller: Caller, inputs: Vec<WasmValue>) -> Result<Vec<WasmValue>, Trap> {
     if inputs.len() != 2 {
-        return Err(1);
+        return Err(Trap::new("Invalid number of arguments"));
     }
 
     if inputs.len() != 2 {
-        return Err(1);
+        return Err(Trap::new("Invalid number of arguments"));
     }
 
     let a = if inputs[0].ty() == ValType::I32 {
         inputs[0].to_i32()
     } else {
-        return Err(2);
+        return Err(Trap::new("Invalid type of arguments"));
     };
 
     let b = if inputs[1].ty() == ValType::I32 {
         inputs[0].to_i32()
     } else {
-        return Err(3);
+        return Err(Trap::new("Invalid type of arguments"));
     };
 
     let c = a + b;
@@ -83,11 +83,11 @@ fn _real_add(inputs: Vec<WasmValue>) -> Result<Vec<WasmValue>, u8> {
     Ok(vec![WasmValue::from_i32(c)])
 }
 
-fn extern_add(inputs: Vec<WasmValue>) -> Result<Vec<WasmValue>, u8> {
+fn extern_add(_caller: Caller, inputs: Vec<WasmValue>) -> Result<Vec<WasmValue>, Trap> {
     let val1 = if inputs[0].ty() == ValType::ExternRef {
         inputs[0]
     } else {
-        return Err(2);
+        return Err(Trap::new("Invalid type of arguments"));
     };
     let val1 = val1
         .extern_ref::<i32>()
@@ -96,17 +96,17 @@ fn extern_add(inputs: Vec<WasmValue>) -> Result<Vec<WasmValue>, u8> {
     let val2 = if inputs[1].ty() == ValType::I32 {
         inputs[1].to_i32()
     } else {
-        return Err(3);
+        return Err(Trap::new("Invalid type of arguments"));
     };
 
     Ok(vec![WasmValue::from_i32(val1 + val2)])
 }
 
-fn extern_sub(inputs: Vec<WasmValue>) -> Result<Vec<WasmValue>, u8> {
+fn extern_sub(_caller: Caller, inputs: Vec<WasmValue>) -> Result<Vec<WasmValue>, Trap> {
     let val1 = if inputs[0].ty() == ValType::ExternRef {
         inputs[0]
     } else {
-        return Err(2);
+        return Err(Trap::new("Invalid type of arguments"));
     };
 
     let val1 = val1
@@ -116,17 +116,17 @@ fn extern_sub(inputs: Vec<WasmValue>) -> Result<Vec<WasmValue>, u8> {
     let val2 = if inputs[1].ty() == ValType::I32 {
         inputs[1].to_i32()
     } else {
-        return Err(3);
+        return Err(Trap::new("Invalid type of arguments"));
     };
 
     Ok(vec![WasmValue::from_i32(val1 - val2)])
 }
 
-fn extern_mul(inputs: Vec<WasmValue>) -> Result<Vec<WasmValue>, u8> {
+fn extern_mul(_caller: Caller, inputs: Vec<WasmValue>) -> Result<Vec<WasmValue>, Trap> {
     let val1 = if inputs[0].ty() == ValType::ExternRef {
         inputs[0]
     } else {
-        return Err(2);
+        return Err(Trap::new("Invalid type of arguments"));
     };
     let val1 = val1
         .extern_ref::<i32>()
@@ -135,17 +135,17 @@ fn extern_mul(inputs: Vec<WasmValue>) -> Result<Vec<WasmValue>, u8> {
     let val2 = if inputs[1].ty() == ValType::I32 {
         inputs[1].to_i32()
     } else {
-        return Err(3);
+        return Err(Trap::new("Invalid type of arguments"));
     };
 
     Ok(vec![WasmValue::from_i32(val1 * val2)])
 }
 
-fn extern_div(inputs: Vec<WasmValue>) -> Result<Vec<WasmValue>, u8> {
+fn extern_div(_caller: Caller, inputs: Vec<WasmValue>) -> Result<Vec<WasmValue>, Trap> {
     let val1 = if inputs[0].ty() == ValType::ExternRef {
         inputs[0]
     } else {
-        return Err(2);
+        return Err(Trap::new("Invalid type of arguments"));
     };
     let val1 = val1
         .extern_ref::<i32>()
@@ -154,16 +154,16 @@ fn extern_div(inputs: Vec<WasmValue>) -> Result<Vec<WasmValue>, u8> {
     let val2 = if inputs[1].ty() == ValType::I32 {
         inputs[1].to_i32()
     } else {
-        return Err(3);
+        return Err(Trap::new("Invalid type of arguments"));
     };
 
     Ok(vec![WasmValue::from_i32(val1 / val2)])
 }
 
-fn extern_term(_inputs: Vec<WasmValue>) -> Result<Vec<WasmValue>, u8> {
+fn extern_term(_caller: Caller, _inputs: Vec<WasmValue>) -> Result<Vec<WasmValue>, Trap> {
     Ok(vec![WasmValue::from_i32(1234)])
 }
 
-fn extern_fail(_inputs: Vec<WasmValue>) -> Result<Vec<WasmValue>, u8> {
-    Err(0x02)
+fn extern_fail(_caller: Caller, _inputs: Vec<WasmValue>) -> Result<Vec<WasmValue>, Trap> {
+    Err(Trap::new("The host function fails"))
 }
@@ -0,0 +1,38 @@
+(module
+  (memory (export "memory") 1)
+  (global $top (mut i32) (i32.const 1024))
+  (global $frees (mut i32) (i32.const 0))
+
+  ;; a bump allocator which never reuses the memory
+  (func $malloc (export "malloc") (export "allocate") (param $size i32) (result i32)
+    global.get $top
+    global.get $top
+    local.get $size
+    i32.add
+    global.set $top)
+
+  ;; counts the calls to the deallocation functions
+  (func (export "free") (param $ptr i32)
+    global.get $frees
+    i32.const 1
+    i32.add
+    global.set $frees)
+
+  ;; returns the last byte of the buffer
+  (func (export "last") (param $ptr i32) (param $len i32) (result i32)
+    local.get $ptr
+    local.get $len
+    i32.add
+    i32.const 1
+    i32.sub
+    i32.load8_u)
+
+  (func (export "frees") (result i32)
+    global.get $frees)
+
+  (func (export "deallocate") (param $ptr i32) (param $len i32)
+    global.get $frees
+    i32.const 1
+    i32.add
+    global.set $frees)
+)
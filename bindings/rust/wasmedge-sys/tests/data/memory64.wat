@@ -0,0 +1,21 @@
+(module
+  (memory (export "memory") i64 1 2)
+
+  (func (export "store") (param $addr i64) (param $value i32)
+    local.get $addr
+    local.get $value
+    i32.store)
+
+  (func (export "load") (param $addr i64) (result i32)
+    local.get $addr
+    i32.load)
+
+  ;; returns the page count
+  (func (export "size") (result i64)
+    memory.size)
+
+  ;; returns the old page count, or -1 if failed
+  (func (export "grow") (param $count i64) (result i64)
+    local.get $count
+    memory.grow)
+)
@@ -0,0 +1,5 @@
+(module
+  (memory (export "memory") 1 4)
+  (memory (export "small") 1 1)
+  (data (memory 0) (i32.const 0) "init")
+)
@@ -0,0 +1,50 @@
+(module
+  (memory (export "memory") 1 4)
+  (global $ready (mut i32) (i32.const 0))
+  (global $heap (mut i64) (i64.const 0))
+  (data (i32.const 0) "init")
+  (data $passive "passive")
+  (start $start)
+
+  ;; counts the calls of the start function at the address 8
+  (func $start
+    i32.const 8
+    i32.const 8
+    i32.load8_u
+    i32.const 1
+    i32.add
+    i32.store8)
+
+  ;; grows the memory, and writes "hello" into the new page
+  (func (export "initialize")
+    i32.const 1
+    memory.grow
+    drop
+    i32.const 65636
+    i32.const 0x6c6c6568
+    i32.store
+    i32.const 65640
+    i32.const 0x6f
+    i32.store8
+    i32.const 1
+    global.set $ready
+    i64.const 65536
+    global.set $heap)
+
+  (func (export "ready") (result i32)
+    global.get $ready)
+
+  (func (export "heap") (result i64)
+    global.get $heap)
+
+  (func (export "started") (result i32)
+    i32.const 8
+    i32.load8_u)
+
+  ;; copies the passive data segment to the address 200
+  (func (export "copy")
+    i32.const 200
+    i32.const 0
+    i32.const 7
+    memory.init $passive)
+)
@@ -0,0 +1,25 @@
+(module
+  (memory (export "memory") 1 4)
+  (global $counter (mut i32) (i32.const 0))
+  (global $scale f64 (f64.const 1.5))
+  (data (i32.const 0) "init")
+
+  ;; increases the counter which is not exported, and stores it at the address 16
+  (func (export "bump") (result i32)
+    global.get $counter
+    i32.const 1
+    i32.add
+    global.set $counter
+    i32.const 16
+    global.get $counter
+    i32.store
+    global.get $counter)
+
+  (func (export "counter") (result i32)
+    global.get $counter)
+
+  ;; returns the old page count, or -1 if failed
+  (func (export "grow") (result i32)
+    i32.const 1
+    memory.grow)
+)
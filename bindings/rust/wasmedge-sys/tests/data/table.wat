@@ -0,0 +1,22 @@
+(module
+  (type $callback (func (param i32) (result i32)))
+  (table (export "callbacks") 4 funcref)
+  (elem (i32.const 0) $double $square)
+
+  ;; the callbacks are not exported, and can be reached only by the table
+  (func $double (type $callback)
+    local.get 0
+    i32.const 2
+    i32.mul)
+
+  (func $square (type $callback)
+    local.get 0
+    local.get 0
+    i32.mul)
+
+  ;; calls the callback at the given slot of the table with the given value
+  (func (export "dispatch") (param i32 i32) (result i32)
+    local.get 1
+    local.get 0
+    call_indirect (type $callback))
+)
@@ -0,0 +1,26 @@
+(module
+  (import "env" "memory" (memory 1 1 shared))
+
+  ;; returns 0 if woken, 1 if the value does not match, and 2 if timed out
+  (func (export "wait") (param $addr i32) (param $expected i32) (param $timeout i64) (result i32)
+    local.get $addr
+    local.get $expected
+    local.get $timeout
+    memory.atomic.wait32)
+
+  ;; returns the count of the woken waiters
+  (func (export "notify") (param $addr i32) (param $count i32) (result i32)
+    local.get $addr
+    local.get $count
+    memory.atomic.notify)
+
+  ;; returns the old value
+  (func (export "add") (param $addr i32) (param $value i32) (result i32)
+    local.get $addr
+    local.get $value
+    i32.atomic.rmw.add)
+
+  (func (export "load") (param $addr i32) (result i32)
+    local.get $addr
+    i32.atomic.load)
+)
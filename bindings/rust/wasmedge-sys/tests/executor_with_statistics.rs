@@ -1,6 +1,6 @@
 mod common;
 use wasmedge_sys::{
-    error::{CoreError, CoreExecutionError, StoreError, WasmEdgeError},
+    error::{CoreError, CoreExecutionError, StoreError, Trap, WasmEdgeError},
     Config, Executor, Loader, Statistics, Store, Validator, WasmValue,
 };
 
@@ -263,6 +263,6 @@ fn test_executor_with_statistics() {
     assert!(result.is_err());
     assert_eq!(
         result.unwrap_err(),
-        WasmEdgeError::Core(CoreError::Execution(CoreExecutionError::ExecutionFailed))
+        WasmEdgeError::Trap(Trap::new("The host function fails"))
     );
 }
@@ -1,7 +1,7 @@
 #[cfg(feature = "aot")]
 use wasmedge_sys::{
-    Compiler, CompilerOptimizationLevel, CompilerOutputFormat, Config, FuncType, Function,
-    ImportObject, Vm, WasmValue,
+    error::Trap, Caller, Compiler, CompilerOptimizationLevel, CompilerOutputFormat, Config,
+    FuncType, Function, ImportObject, Vm, WasmValue,
 };
 
 #[cfg(feature = "aot")]
@@ -99,6 +99,6 @@ fn create_spec_test_module() -> ImportObject {
     import_obj
 }
 
-fn spec_test_print(_inputs: Vec<WasmValue>) -> Result<Vec<WasmValue>, u8> {
+fn spec_test_print(_caller: Caller, _inputs: Vec<WasmValue>) -> Result<Vec<WasmValue>, Trap> {
     Ok(vec![])
 }
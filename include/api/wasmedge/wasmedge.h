@@ -64,11 +64,30 @@ typedef struct WasmEdge_Limit {
   /// Boolean to describe has max value or not.
   bool HasMax;
   /// Minimum value.
-  uint32_t Min;
+  uint64_t Min;
   /// Maximum value. Will be ignored if the `HasMax` is false.
-  uint32_t Max;
+  uint64_t Max;
+  /// Boolean to describe the memory is shared or not. Will be ignored for the
+  /// table types. The shared memory must have the max value.
+  bool Shared;
+  /// Boolean to describe the memory uses the 64-bit index type or not. Will be
+  /// ignored for the table types. Only the limits of the 64-bit memories can
+  /// be larger than `UINT32_MAX`.
+  bool Is64;
 } WasmEdge_Limit;
 
+/// Struct of WASM backtrace frame.
+typedef struct WasmEdge_BacktraceFrame {
+  /// Module instance name of the function. Empty for the active module.
+  WasmEdge_String ModuleName;
+  /// Function index in the module instance. UINT32_MAX if unknown.
+  uint32_t FuncIndex;
+  /// Exported name of the function. Empty if the function is not exported.
+  WasmEdge_String FuncName;
+  /// Offset of the instruction in the module binary. UINT32_MAX if unknown.
+  uint32_t Offset;
+} WasmEdge_BacktraceFrame;
+
 /// Opaque struct of WasmEdge configure.
 typedef struct WasmEdge_ConfigureContext WasmEdge_ConfigureContext;
 
@@ -962,7 +981,8 @@ WasmEdge_FunctionTypeDelete(WasmEdge_FunctionTypeContext *Cxt);
 /// \param RefType the reference type of the table type.
 /// \param Limit the limit struct of the table type.
 ///
-/// \returns pointer to context, NULL if failed.
+/// \returns pointer to context, NULL if failed or the limit is larger than
+/// `UINT32_MAX`.
 WASMEDGE_CAPI_EXPORT extern WasmEdge_TableTypeContext *
 WasmEdge_TableTypeCreate(const enum WasmEdge_RefType RefType,
                          const WasmEdge_Limit Limit);
@@ -1003,7 +1023,9 @@ WasmEdge_TableTypeDelete(WasmEdge_TableTypeContext *Cxt);
 ///
 /// \param Limit the limit struct of the memory type.
 ///
-/// \returns pointer to context, NULL if failed.
+/// \returns pointer to context, NULL if failed, the limit is shared without
+/// the max value, or the limit of the 32-bit memory is larger than
+/// `UINT32_MAX`.
 WASMEDGE_CAPI_EXPORT extern WasmEdge_MemoryTypeContext *
 WasmEdge_MemoryTypeCreate(const WasmEdge_Limit Limit);
 
@@ -1516,6 +1538,95 @@ WASMEDGE_CAPI_EXPORT extern WasmEdge_Result WasmEdge_ExecutorInvokeRegistered(
     const WasmEdge_Value *Params, const uint32_t ParamLen,
     WasmEdge_Value *Returns, const uint32_t ReturnLen);
 
+/// Invoke a WASM function by its function instance.
+///
+/// The function instance can be found in the module instances in the store,
+/// or obtained from a function reference, such as the elements in a table
+/// instance. The host function instances which are not registered into the
+/// store can also be invoked.
+///
+/// \param Cxt the WasmEdge_ExecutorContext.
+/// \param StoreCxt the WasmEdge_StoreContext which the module instantiated in.
+/// \param FuncCxt the WasmEdge_FunctionInstanceContext to invoke.
+/// \param Params the WasmEdge_Value buffer with the parameter values.
+/// \param ParamLen the parameter buffer length.
+/// \param [out] Returns the WasmEdge_Value buffer to fill the return values.
+/// \param ReturnLen the return buffer length.
+///
+/// \returns WasmEdge_Result. Call `WasmEdge_ResultGetMessage` for the error
+/// message.
+WASMEDGE_CAPI_EXPORT extern WasmEdge_Result WasmEdge_ExecutorInvokeFunction(
+    WasmEdge_ExecutorContext *Cxt, WasmEdge_StoreContext *StoreCxt,
+    const WasmEdge_FunctionInstanceContext *FuncCxt,
+    const WasmEdge_Value *Params, const uint32_t ParamLen,
+    WasmEdge_Value *Returns, const uint32_t ReturnLen);
+
+/// Stop the execution of the executor context.
+///
+/// This function is thread-safe and can be called from another thread. The
+/// running execution will be interrupted at the next stop check, and the
+/// `WasmEdge_ExecutorInvoke` or `WasmEdge_ExecutorInvokeRegistered` call will
+/// return with the interrupted error. For the AOT compiled code, the stop check
+/// is available only if the `interruptible` configuration is enabled when
+/// compiling.
+///
+/// \param Cxt the WasmEdge_ExecutorContext to stop.
+WASMEDGE_CAPI_EXPORT extern void
+WasmEdge_ExecutorStop(WasmEdge_ExecutorContext *Cxt);
+
+/// Discard the pending stop request of the executor context.
+///
+/// A stop request which arrives just after the execution ended is not consumed
+/// by any stop check, and it would interrupt the next invocation. The caller
+/// which requested the stop can call this function after the invocation
+/// returned to discard such a request.
+///
+/// \param Cxt the WasmEdge_ExecutorContext to reset.
+WASMEDGE_CAPI_EXPORT extern void
+WasmEdge_ExecutorResetStop(WasmEdge_ExecutorContext *Cxt);
+
+/// Get the backtrace of the last execution run by the calling thread.
+///
+/// The backtrace is recorded when the execution of the interpreter fails, from
+/// the innermost frame to the outermost one, and cleared when the next
+/// execution on the calling thread starts. It's empty if the last execution
+/// succeeded or failed in AOT compiled code. The failure is recorded per
+/// execution on the thread running it, so the concurrent executions of the same
+/// executor context on other threads do not overwrite it.
+/// The module names and function names filled into the `Frames` array are
+/// allocated, and the caller should call the `WasmEdge_StringDelete` to delete
+/// them.
+/// If the `Frames` buffer length is smaller than the number of frames, the
+/// overflowed frames will be discarded.
+///
+/// This function is thread-safe.
+///
+/// \param Cxt the WasmEdge_ExecutorContext.
+/// \param [out] Frames the WasmEdge_BacktraceFrame buffer to fill the frames.
+/// \param Len the buffer length.
+///
+/// \returns the number of frames in the backtrace.
+WASMEDGE_CAPI_EXPORT extern uint32_t
+WasmEdge_ExecutorGetBacktrace(const WasmEdge_ExecutorContext *Cxt,
+                              WasmEdge_BacktraceFrame *Frames,
+                              const uint32_t Len);
+
+/// Get the failure data of the last execution run by the calling thread.
+///
+/// When a host function fails the execution, the data it attached by
+/// `WasmEdge_HostFunctionSetFailureData` is kept with the failure of the
+/// execution. The failure is recorded per execution on the thread running it,
+/// so the concurrent executions of the same executor context on other threads
+/// do not overwrite it. The record is cleared when the next execution on the
+/// calling thread starts.
+///
+/// \param Cxt the WasmEdge_ExecutorContext.
+///
+/// \returns pointer to the failure data, NULL if the last execution did not
+/// fail in a host function or no data was attached.
+WASMEDGE_CAPI_EXPORT extern const void *
+WasmEdge_ExecutorGetHostFailureData(const WasmEdge_ExecutorContext *Cxt);
+
 /// Deletion of the WasmEdge_ExecutorContext.
 ///
 /// After calling this function, the context will be freed and should __NOT__ be
@@ -2059,6 +2170,49 @@ WasmEdge_StoreListModule(const WasmEdge_StoreContext *Cxt,
 WASMEDGE_CAPI_EXPORT extern void
 WasmEdge_StoreDelete(WasmEdge_StoreContext *Cxt);
 
+/// Callback of the resource limiter of a store.
+///
+/// The callback is consulted before growing a table or memory instance which
+/// is instantiated in the store, no matter the growing is caused by the
+/// `table.grow` or `memory.grow` instructions or by the API. The growing is
+/// denied if the callback returns false.
+///
+/// \param Data the data pointer set by `WasmEdge_StoreSetResourceLimiter`.
+/// \param ModuleName the name of the module which instantiates the instance.
+/// The name is empty for the anonymous active module. The string is only
+/// valid in the callback, and the callback should __NOT__ call the
+/// `WasmEdge_StringDelete`.
+/// \param Type the external type of the instance, which is
+/// `WasmEdge_ExternalType_Table` or `WasmEdge_ExternalType_Memory`.
+/// \param Index the index of the instance in the module.
+/// \param Current the current size in elements or in pages.
+/// \param Desired the desired size in elements or in pages.
+///
+/// \returns true to allow the growing, false to deny it.
+typedef bool (*WasmEdge_ResourceLimiter_t)(
+    void *Data, const WasmEdge_String ModuleName,
+    const enum WasmEdge_ExternalType Type, const uint32_t Index,
+    const uint32_t Current, const uint32_t Desired);
+
+/// Set the resource limiter of the WasmEdge_StoreContext.
+///
+/// The limiter replaces the previous one, and the finalizer of the previous
+/// one is invoked when it is not used any more. The finalizer of the last
+/// limiter is invoked when the store context is deleted.
+///
+/// This function is thread-safe.
+///
+/// \param Cxt the WasmEdge_StoreContext.
+/// \param Limiter the callback of the resource limiter. NULL to remove the
+/// resource limiter.
+/// \param Data the additional object, such as the pointer to a data structure,
+/// to be passed to the callback.
+/// \param Finalizer the function to free the `Data`. NULL if not required.
+WASMEDGE_CAPI_EXPORT extern void
+WasmEdge_StoreSetResourceLimiter(WasmEdge_StoreContext *Cxt,
+                                 WasmEdge_ResourceLimiter_t Limiter, void *Data,
+                                 void (*Finalizer)(void *));
+
 // <<<<<<<< WasmEdge store functions <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<
 
 // >>>>>>>> WasmEdge module instance functions >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>
@@ -2262,6 +2416,146 @@ WASMEDGE_CAPI_EXPORT extern uint32_t
 WasmEdge_ModuleInstanceListGlobal(const WasmEdge_ModuleInstanceContext *Cxt,
                                   WasmEdge_String *Names, const uint32_t Len);
 
+/// Get the total size in elements of the table instances defined in a module
+/// instance, excluding the imported ones.
+///
+/// This function is thread-safe.
+///
+/// \param Cxt the WasmEdge_ModuleInstanceContext.
+///
+/// \returns the total size of the tables.
+WASMEDGE_CAPI_EXPORT extern uint64_t WasmEdge_ModuleInstanceGetTableSizeInUse(
+    const WasmEdge_ModuleInstanceContext *Cxt);
+
+/// Get the total size in pages of the memory instances defined in a module
+/// instance, excluding the imported ones.
+///
+/// This function is thread-safe.
+///
+/// \param Cxt the WasmEdge_ModuleInstanceContext.
+///
+/// \returns the total page size of the memories.
+WASMEDGE_CAPI_EXPORT extern uint64_t
+WasmEdge_ModuleInstanceGetMemoryPageSizeInUse(
+    const WasmEdge_ModuleInstanceContext *Cxt);
+
+/// Get the length of the memory instances defined in a module instance,
+/// excluding the imported ones.
+///
+/// This function is thread-safe.
+///
+/// \param Cxt the WasmEdge_ModuleInstanceContext.
+///
+/// \returns length of the defined memory instances.
+WASMEDGE_CAPI_EXPORT extern uint32_t
+WasmEdge_ModuleInstanceGetDefinedMemoryLength(
+    const WasmEdge_ModuleInstanceContext *Cxt);
+
+/// Get the memory instance context defined in a module instance by the index.
+///
+/// Different from `WasmEdge_ModuleInstanceFindMemory`, the memory instances
+/// which are not exported can also be got by this function.
+///
+/// The result memory instance context links to the memory instance in the store
+/// context and owned by the store context, and the caller should __NOT__ call
+/// the `WasmEdge_MemoryInstanceDelete`.
+///
+/// This function is thread-safe.
+///
+/// \param Cxt the WasmEdge_ModuleInstanceContext.
+/// \param StoreCxt the WasmEdge_StoreContext.
+/// \param Idx the index in the defined memory instances, excluding the
+/// imported ones.
+///
+/// \returns pointer to the memory instance context. NULL if out of range.
+WASMEDGE_CAPI_EXPORT extern WasmEdge_MemoryInstanceContext *
+WasmEdge_ModuleInstanceGetDefinedMemory(
+    const WasmEdge_ModuleInstanceContext *Cxt, WasmEdge_StoreContext *StoreCxt,
+    const uint32_t Idx);
+
+/// Get the length of the global instances defined in a module instance,
+/// excluding the imported ones.
+///
+/// This function is thread-safe.
+///
+/// \param Cxt the WasmEdge_ModuleInstanceContext.
+///
+/// \returns length of the defined global instances.
+WASMEDGE_CAPI_EXPORT extern uint32_t
+WasmEdge_ModuleInstanceGetDefinedGlobalLength(
+    const WasmEdge_ModuleInstanceContext *Cxt);
+
+/// Get the global instance context defined in a module instance by the index.
+///
+/// Different from `WasmEdge_ModuleInstanceFindGlobal`, the global instances
+/// which are not exported can also be got by this function.
+///
+/// The result global instance context links to the global instance in the store
+/// context and owned by the store context, and the caller should __NOT__ call
+/// the `WasmEdge_GlobalInstanceDelete`.
+///
+/// This function is thread-safe.
+///
+/// \param Cxt the WasmEdge_ModuleInstanceContext.
+/// \param StoreCxt the WasmEdge_StoreContext.
+/// \param Idx the index in the defined global instances, excluding the
+/// imported ones.
+///
+/// \returns pointer to the global instance context. NULL if out of range.
+WASMEDGE_CAPI_EXPORT extern WasmEdge_GlobalInstanceContext *
+WasmEdge_ModuleInstanceGetDefinedGlobal(
+    const WasmEdge_ModuleInstanceContext *Cxt, WasmEdge_StoreContext *StoreCxt,
+    const uint32_t Idx);
+
+/// Get the length of the table instances defined in a module instance,
+/// excluding the imported ones.
+///
+/// This function is thread-safe.
+///
+/// \param Cxt the WasmEdge_ModuleInstanceContext.
+///
+/// \returns length of the defined table instances.
+WASMEDGE_CAPI_EXPORT extern uint32_t
+WasmEdge_ModuleInstanceGetDefinedTableLength(
+    const WasmEdge_ModuleInstanceContext *Cxt);
+
+/// Get the table instance context defined in a module instance by the index.
+///
+/// Different from `WasmEdge_ModuleInstanceFindTable`, the table instances
+/// which are not exported can also be got by this function.
+///
+/// The result table instance context links to the table instance in the store
+/// context and owned by the store context, and the caller should __NOT__ call
+/// the `WasmEdge_TableInstanceDelete`.
+///
+/// This function is thread-safe.
+///
+/// \param Cxt the WasmEdge_ModuleInstanceContext.
+/// \param StoreCxt the WasmEdge_StoreContext.
+/// \param Idx the index in the defined table instances, excluding the
+/// imported ones.
+///
+/// \returns pointer to the table instance context. NULL if out of range.
+WASMEDGE_CAPI_EXPORT extern WasmEdge_TableInstanceContext *
+WasmEdge_ModuleInstanceGetDefinedTable(
+    const WasmEdge_ModuleInstanceContext *Cxt, WasmEdge_StoreContext *StoreCxt,
+    const uint32_t Idx);
+
+/// Get the size in bytes of a data segment of a module instance by the index.
+///
+/// The active data segments are dropped after the instantiation, and the
+/// passive ones are dropped by the `data.drop` instruction. The dropped data
+/// segments have the size 0.
+///
+/// This function is thread-safe.
+///
+/// \param Cxt the WasmEdge_ModuleInstanceContext.
+/// \param Idx the index of the data segment.
+///
+/// \returns the size of the data segment. 0 if dropped or out of range.
+WASMEDGE_CAPI_EXPORT extern uint32_t WasmEdge_ModuleInstanceGetDataSegmentSize(
+    const WasmEdge_ModuleInstanceContext *Cxt, const uint32_t Idx);
+
 // <<<<<<<< WasmEdge module instance functions <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<
 
 // >>>>>>>> WasmEdge function instance functions >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>
@@ -2421,6 +2715,35 @@ WASMEDGE_CAPI_EXPORT extern const WasmEdge_FunctionTypeContext *
 WasmEdge_FunctionInstanceGetFunctionType(
     const WasmEdge_FunctionInstanceContext *Cxt);
 
+/// Get the external data of the host function instance.
+///
+/// The external data is the `Data` pointer passed to the
+/// `WasmEdge_FunctionInstanceCreate` or
+/// `WasmEdge_FunctionInstanceCreateBinding` when creating the host function.
+///
+/// \param Cxt the WasmEdge_FunctionInstanceContext.
+///
+/// \returns the pointer to the external data, NULL if the function instance is
+/// not a host function created by the C API.
+WASMEDGE_CAPI_EXPORT extern void *WasmEdge_FunctionInstanceGetData(
+    const WasmEdge_FunctionInstanceContext *Cxt);
+
+/// Attach the failure data to the execution which calls the host function.
+///
+/// This function should be called in a host function right before it returns
+/// `WasmEdge_Result_Fail`. The data is kept with the failure of the execution
+/// calling the host function, and can be retrieved by
+/// `WasmEdge_ExecutorGetHostFailureData`, `WasmEdge_VMGetHostFailureData`, or
+/// `WasmEdge_AsyncGetHostFailureData` after the execution failed. The ownership
+/// of the data is transferred, and the `Finalizer` is called to release it when
+/// the failure record is cleared.
+///
+/// \param Data the pointer to the failure data.
+/// \param Finalizer the function to release the data. NULL if the data need not
+/// be released.
+WASMEDGE_CAPI_EXPORT extern void
+WasmEdge_HostFunctionSetFailureData(void *Data, void (*Finalizer)(void *));
+
 /// Deletion of the WasmEdge_FunctionInstanceContext.
 ///
 /// After calling this function, the context will be freed and should __NOT__ be
@@ -2519,10 +2842,14 @@ WasmEdge_TableInstanceDelete(WasmEdge_TableInstanceContext *Cxt);
 /// The caller owns the object and should call `WasmEdge_MemoryInstanceDelete`
 /// to free it.
 ///
+/// The memory instances are capped at 65536 pages (4 GiB), including the
+/// 64-bit ones of the memory64 proposal.
+///
 /// \param MemType the memory type context to initialize the memory instance
 /// context.
 ///
-/// \returns pointer to context, NULL if failed.
+/// \returns pointer to context, NULL if failed or the minimum pages exceed
+/// 65536.
 WASMEDGE_CAPI_EXPORT extern WasmEdge_MemoryInstanceContext *
 WasmEdge_MemoryInstanceCreate(const WasmEdge_MemoryTypeContext *MemType);
 
@@ -2550,7 +2877,7 @@ WasmEdge_MemoryInstanceGetMemoryType(const WasmEdge_MemoryInstanceContext *Cxt);
 /// message.
 WASMEDGE_CAPI_EXPORT extern WasmEdge_Result
 WasmEdge_MemoryInstanceGetData(const WasmEdge_MemoryInstanceContext *Cxt,
-                               uint8_t *Data, const uint32_t Offset,
+                               uint8_t *Data, const uint64_t Offset,
                                const uint32_t Length);
 
 /// Copy the data into a memory instance from the input buffer.
@@ -2565,7 +2892,7 @@ WasmEdge_MemoryInstanceGetData(const WasmEdge_MemoryInstanceContext *Cxt,
 /// message.
 WASMEDGE_CAPI_EXPORT extern WasmEdge_Result
 WasmEdge_MemoryInstanceSetData(WasmEdge_MemoryInstanceContext *Cxt,
-                               const uint8_t *Data, const uint32_t Offset,
+                               const uint8_t *Data, const uint64_t Offset,
                                const uint32_t Length);
 
 /// Get the data pointer in a memory instance.
@@ -2578,7 +2905,7 @@ WasmEdge_MemoryInstanceSetData(WasmEdge_MemoryInstanceContext *Cxt,
 /// \returns the pointer to data with the start offset. NULL if failed.
 WASMEDGE_CAPI_EXPORT extern uint8_t *
 WasmEdge_MemoryInstanceGetPointer(WasmEdge_MemoryInstanceContext *Cxt,
-                                  const uint32_t Offset, const uint32_t Length);
+                                  const uint64_t Offset, const uint32_t Length);
 
 /// Get the const data pointer in a const memory instance.
 ///
@@ -2590,7 +2917,7 @@ WasmEdge_MemoryInstanceGetPointer(WasmEdge_MemoryInstanceContext *Cxt,
 /// \returns the pointer to data with the start offset. NULL if failed.
 WASMEDGE_CAPI_EXPORT extern const uint8_t *
 WasmEdge_MemoryInstanceGetPointerConst(
-    const WasmEdge_MemoryInstanceContext *Cxt, const uint32_t Offset,
+    const WasmEdge_MemoryInstanceContext *Cxt, const uint64_t Offset,
     const uint32_t Length);
 
 /// Get the current page size (64 KiB of each page) of a memory instance.
@@ -2598,7 +2925,7 @@ WasmEdge_MemoryInstanceGetPointerConst(
 /// \param Cxt the WasmEdge_MemoryInstanceContext.
 ///
 /// \returns the page size of the memory instance.
-WASMEDGE_CAPI_EXPORT extern uint32_t
+WASMEDGE_CAPI_EXPORT extern uint64_t
 WasmEdge_MemoryInstanceGetPageSize(const WasmEdge_MemoryInstanceContext *Cxt);
 
 /// Grow a memory instance with a page size.
@@ -2610,7 +2937,7 @@ WasmEdge_MemoryInstanceGetPageSize(const WasmEdge_MemoryInstanceContext *Cxt);
 /// message.
 WASMEDGE_CAPI_EXPORT extern WasmEdge_Result
 WasmEdge_MemoryInstanceGrowPage(WasmEdge_MemoryInstanceContext *Cxt,
-                                const uint32_t Page);
+                                const uint64_t Page);
 
 /// Deletion of the WasmEdge_MemoryInstanceContext.
 ///
@@ -2710,8 +3037,9 @@ WasmEdge_ImportObjectCreate(const WasmEdge_String ModuleName);
 /// length is 0.
 /// \param EnvLen the length of the environment variables.
 /// \param Preopens the directory paths to preopen. String format in
-/// `PATH1:PATH2` means the path mapping, or the same path will be mapped. NULL
-/// if the length is 0.
+/// `PATH1:PATH2` means the path mapping, or the same path will be mapped. The
+/// `:readonly` suffix, such as `PATH1:PATH2:readonly`, preopens the directory
+/// as read-only. NULL if the length is 0.
 /// \param PreopenLen the length of the directory paths to preopen.
 ///
 /// \returns pointer to context, NULL if failed.
@@ -2733,8 +3061,9 @@ WasmEdge_ImportObjectCreateWASI(const char *const *Args, const uint32_t ArgLen,
 /// length is 0.
 /// \param EnvLen the length of the environment variables.
 /// \param Preopens the directory paths to preopen. String format in
-/// `PATH1:PATH2` means the path mapping, or the same path will be mapped. NULL
-/// if the length is 0.
+/// `PATH1:PATH2` means the path mapping, or the same path will be mapped. The
+/// `:readonly` suffix, such as `PATH1:PATH2:readonly`, preopens the directory
+/// as read-only. NULL if the length is 0.
 /// \param PreopenLen the length of the directory paths to preopen.
 WASMEDGE_CAPI_EXPORT extern void WasmEdge_ImportObjectInitWASI(
     WasmEdge_ImportObjectContext *Cxt, const char *const *Args,
@@ -2753,6 +3082,27 @@ WASMEDGE_CAPI_EXPORT extern void WasmEdge_ImportObjectInitWASI(
 WASMEDGE_CAPI_EXPORT extern uint32_t
 WasmEdge_ImportObjectWASIGetExitCode(WasmEdge_ImportObjectContext *Cxt);
 
+/// Redirect the standard input, output, and error of the WASI import object.
+///
+/// This function will redirect the standard streams of the WASI guest to the
+/// host file descriptors, such as the ends of pipes. The file descriptors are
+/// duplicated, and the caller still owns them. A negative file descriptor
+/// restores the corresponding standard stream of the host process. The
+/// redirection is reset by `WasmEdge_ImportObjectInitWASI`.
+///
+/// \param Cxt the WasmEdge_ImportObjectContext of WASI import object.
+/// \param StdIn the host file descriptor for the standard input.
+/// \param StdOut the host file descriptor for the standard output.
+/// \param StdErr the host file descriptor for the standard error.
+///
+/// \returns WasmEdge_Result. Call `WasmEdge_ResultGetMessage` for the error
+/// message.
+WASMEDGE_CAPI_EXPORT extern WasmEdge_Result
+WasmEdge_ImportObjectWASIRedirectStdio(WasmEdge_ImportObjectContext *Cxt,
+                                       const int32_t StdIn,
+                                       const int32_t StdOut,
+                                       const int32_t StdErr);
+
 /// Creation of the WasmEdge_ImportObjectContext for the wasmedge_process
 /// specification.
 ///
@@ -2843,6 +3193,24 @@ WasmEdge_ImportObjectAddMemory(WasmEdge_ImportObjectContext *Cxt,
                                const WasmEdge_String Name,
                                WasmEdge_MemoryInstanceContext *MemoryCxt);
 
+/// Add a shared memory instance context into a WasmEdge_ImportObjectContext.
+///
+/// Different from `WasmEdge_ImportObjectAddMemory`, the ownership of the
+/// memory instance context is __NOT__ moved. The same memory instance context
+/// can be added into the import objects registered into different VMs, and the
+/// VMs can run in different threads. The caller should delete the memory
+/// instance context after all the VMs and stores which the import objects
+/// registered into are deleted. The memory instance context will not be added
+/// if it is not created with a shared memory type.
+///
+/// \param Cxt the WasmEdge_ImportObjectContext to add the memory instance.
+/// \param Name the export memory name WasmEdge_String.
+/// \param MemoryCxt the shared WasmEdge_MemoryInstanceContext to add.
+WASMEDGE_CAPI_EXPORT extern void
+WasmEdge_ImportObjectAddSharedMemory(WasmEdge_ImportObjectContext *Cxt,
+                                     const WasmEdge_String Name,
+                                     WasmEdge_MemoryInstanceContext *MemoryCxt);
+
 /// Add a global instance context into a WasmEdge_ImportObjectContext.
 ///
 /// Move the global instance context into the import object. The caller should
@@ -2887,6 +3255,9 @@ WASMEDGE_CAPI_EXPORT bool WasmEdge_AsyncWaitFor(WasmEdge_Async *Cxt,
 
 /// Cancel a WasmEdge_Async execution.
 ///
+/// Only this execution is interrupted. The other executions of the same VM
+/// context keep running.
+///
 /// \param Cxt the WasmEdge_ASync.
 WASMEDGE_CAPI_EXPORT void WasmEdge_AsyncCancel(WasmEdge_Async *Cxt);
 
@@ -2920,6 +3291,33 @@ WasmEdge_AsyncGetReturnsLength(WasmEdge_Async *Cxt);
 WASMEDGE_CAPI_EXPORT WasmEdge_Result WasmEdge_AsyncGet(
     WasmEdge_Async *Cxt, WasmEdge_Value *Returns, const uint32_t ReturnLen);
 
+/// Wait and get the backtrace of the WasmEdge_Async execution.
+///
+/// This function will wait until the execution finished. See
+/// `WasmEdge_ExecutorGetBacktrace` for the details of the backtrace. The module
+/// names and function names filled into the `Frames` array are allocated, and
+/// the caller should call the `WasmEdge_StringDelete` to delete them.
+///
+/// \param Cxt the WasmEdge_ASync.
+/// \param [out] Frames the WasmEdge_BacktraceFrame buffer to fill the frames.
+/// \param Len the buffer length.
+///
+/// \returns the number of frames in the backtrace.
+WASMEDGE_CAPI_EXPORT uint32_t WasmEdge_AsyncGetBacktrace(
+    WasmEdge_Async *Cxt, WasmEdge_BacktraceFrame *Frames, const uint32_t Len);
+
+/// Wait and get the failure data of the WasmEdge_Async execution.
+///
+/// This function will wait until the execution finished. See
+/// `WasmEdge_ExecutorGetHostFailureData` for the details of the failure data.
+///
+/// \param Cxt the WasmEdge_ASync.
+///
+/// \returns pointer to the failure data, NULL if the execution did not fail in
+/// a host function or no data was attached.
+WASMEDGE_CAPI_EXPORT const void *
+WasmEdge_AsyncGetHostFailureData(WasmEdge_Async *Cxt);
+
 /// Deletion of the WasmEdge_Async.
 ///
 /// After calling this function, the context will be freed and should __NOT__ be
@@ -3327,6 +3725,31 @@ WASMEDGE_CAPI_EXPORT extern WasmEdge_Result WasmEdge_VMExecuteRegistered(
     const WasmEdge_String FuncName, const WasmEdge_Value *Params,
     const uint32_t ParamLen, WasmEdge_Value *Returns, const uint32_t ReturnLen);
 
+/// Invoke a WASM function by its function instance.
+///
+/// The function instance can be found in the module instances in the store of
+/// the VM context, or obtained from a function reference, such as the elements
+/// in a table instance. The host function instances which are not registered
+/// into the store can also be invoked. If the `Returns` buffer length is
+/// smaller than the arity of the function, the overflowed return values will
+/// be discarded.
+///
+/// This function is thread-safe.
+///
+/// \param Cxt the WasmEdge_VMContext.
+/// \param FuncCxt the WasmEdge_FunctionInstanceContext to invoke.
+/// \param Params the WasmEdge_Value buffer with the parameter values.
+/// \param ParamLen the parameter buffer length.
+/// \param [out] Returns the WasmEdge_Value buffer to fill the return values.
+/// \param ReturnLen the return buffer length.
+///
+/// \returns WasmEdge_Result. Call `WasmEdge_ResultGetMessage` for the error
+/// message.
+WASMEDGE_CAPI_EXPORT extern WasmEdge_Result WasmEdge_VMExecuteFunction(
+    WasmEdge_VMContext *Cxt, const WasmEdge_FunctionInstanceContext *FuncCxt,
+    const WasmEdge_Value *Params, const uint32_t ParamLen,
+    WasmEdge_Value *Returns, const uint32_t ReturnLen);
+
 /// Asynchronous invoke a WASM function by name.
 ///
 /// This is the final step to invoke a WASM function step by step.
@@ -3523,6 +3946,38 @@ WasmEdge_VMGetStoreContext(WasmEdge_VMContext *Cxt);
 WASMEDGE_CAPI_EXPORT extern WasmEdge_StatisticsContext *
 WasmEdge_VMGetStatisticsContext(WasmEdge_VMContext *Cxt);
 
+/// Get the backtrace of the last execution run by the calling thread.
+///
+/// See `WasmEdge_ExecutorGetBacktrace` for the details. The executions of the
+/// asynchronous VM functions run on their own threads, so call
+/// `WasmEdge_AsyncGetBacktrace` for them instead. The module names and function
+/// names filled into the `Frames` array are allocated, and the caller should
+/// call the `WasmEdge_StringDelete` to delete them.
+///
+/// This function is thread-safe.
+///
+/// \param Cxt the WasmEdge_VMContext.
+/// \param [out] Frames the WasmEdge_BacktraceFrame buffer to fill the frames.
+/// \param Len the buffer length.
+///
+/// \returns the number of frames in the backtrace.
+WASMEDGE_CAPI_EXPORT extern uint32_t
+WasmEdge_VMGetBacktrace(const WasmEdge_VMContext *Cxt,
+                        WasmEdge_BacktraceFrame *Frames, const uint32_t Len);
+
+/// Get the failure data of the last execution run by the calling thread.
+///
+/// See `WasmEdge_ExecutorGetHostFailureData` for the details. The executions of
+/// the asynchronous VM functions run on their own threads, so call
+/// `WasmEdge_AsyncGetHostFailureData` for them instead.
+///
+/// \param Cxt the WasmEdge_VMContext.
+///
+/// \returns pointer to the failure data, NULL if the last execution did not
+/// fail in a host function or no data was attached.
+WASMEDGE_CAPI_EXPORT extern const void *
+WasmEdge_VMGetHostFailureData(const WasmEdge_VMContext *Cxt);
+
 /// Deletion of the WasmEdge_VMContext.
 ///
 /// After calling this function, the context will be freed and should __NOT__ be
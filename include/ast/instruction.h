@@ -166,12 +166,12 @@ public:
   uint32_t &getStackOffset() noexcept { return Data.Indices.StackOffset; }
 
   /// Getter and setter of memory alignment.
-  uint32_t getMemoryAlign() const noexcept { return Data.Memories.MemAlign; }
-  uint32_t &getMemoryAlign() noexcept { return Data.Memories.MemAlign; }
+  uint8_t getMemoryAlign() const noexcept { return Data.Memories.MemAlign; }
+  uint8_t &getMemoryAlign() noexcept { return Data.Memories.MemAlign; }
 
   /// Getter of memory offset.
-  uint32_t getMemoryOffset() const noexcept { return Data.Memories.MemOffset; }
-  uint32_t &getMemoryOffset() noexcept { return Data.Memories.MemOffset; }
+  uint64_t getMemoryOffset() const noexcept { return Data.Memories.MemOffset; }
+  uint64_t &getMemoryOffset() noexcept { return Data.Memories.MemOffset; }
 
   /// Getter of memory lane.
   uint8_t getMemoryLane() const noexcept { return Data.Memories.MemLane; }
@@ -245,12 +245,12 @@ private:
       uint32_t ValTypeListSize;
       ValType *ValTypeList;
     } SelectT;
-    // Type 7: TargetIdx, MemAlign, MemOffset, and MemLane.
+    // Type 7: TargetIdx, MemAlign, MemLane, and MemOffset.
     struct {
       uint32_t TargetIdx;
-      uint32_t MemAlign;
-      uint32_t MemOffset;
+      uint8_t MemAlign;
       uint8_t MemLane;
+      uint64_t MemOffset;
     } Memories;
     // Type 8: Num.
 #if defined(__x86_64__) || defined(__aarch64__)
@@ -0,0 +1,172 @@
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-FileCopyrightText: 2019-2022 Second State INC
+
+#include "executor/executor.h"
+#include "runtime/instance/memory.h"
+
+#include <cstdint>
+
+namespace WasmEdge {
+namespace Executor {
+
+namespace detail {
+
+/// Calculate and check the effective address of the atomic instructions.
+inline Expect<uint64_t>
+calcAtomicAddress(Runtime::Instance::MemoryInstance &MemInst,
+                  const AST::Instruction &Instr, const uint64_t Addr,
+                  const uint32_t Size) noexcept {
+  // Calculate EA = i + offset. Only the 64-bit addresses can overflow.
+  const uint64_t EA = Addr + Instr.getMemoryOffset();
+  if (Instr.getMemoryOffset() > std::numeric_limits<uint64_t>::max() - Addr ||
+      !MemInst.checkAccessBound(EA, Size)) {
+    spdlog::error(ErrCode::MemoryOutOfBounds);
+    spdlog::error(ErrInfo::InfoBoundary(EA, Size, MemInst.getBoundIdx()));
+    spdlog::error(
+        ErrInfo::InfoInstruction(Instr.getOpCode(), Instr.getOffset()));
+    return Unexpect(ErrCode::MemoryOutOfBounds);
+  }
+  // The atomic accesses should be aligned to the access size.
+  if (EA % Size != 0) {
+    spdlog::error(ErrCode::UnalignedAtomicAccess);
+    spdlog::error(
+        ErrInfo::InfoInstruction(Instr.getOpCode(), Instr.getOffset()));
+    return Unexpect(ErrCode::UnalignedAtomicAccess);
+  }
+  return EA;
+}
+
+/// Atomic read-modify-write operations.
+struct AtomicAdd {
+  template <typename I> static I run(I *Ptr, I Val) noexcept {
+    return __atomic_fetch_add(Ptr, Val, __ATOMIC_SEQ_CST);
+  }
+};
+struct AtomicSub {
+  template <typename I> static I run(I *Ptr, I Val) noexcept {
+    return __atomic_fetch_sub(Ptr, Val, __ATOMIC_SEQ_CST);
+  }
+};
+struct AtomicAnd {
+  template <typename I> static I run(I *Ptr, I Val) noexcept {
+    return __atomic_fetch_and(Ptr, Val, __ATOMIC_SEQ_CST);
+  }
+};
+struct AtomicOr {
+  template <typename I> static I run(I *Ptr, I Val) noexcept {
+    return __atomic_fetch_or(Ptr, Val, __ATOMIC_SEQ_CST);
+  }
+};
+struct AtomicXor {
+  template <typename I> static I run(I *Ptr, I Val) noexcept {
+    return __atomic_fetch_xor(Ptr, Val, __ATOMIC_SEQ_CST);
+  }
+};
+struct AtomicXchg {
+  template <typename I> static I run(I *Ptr, I Val) noexcept {
+    return __atomic_exchange_n(Ptr, Val, __ATOMIC_SEQ_CST);
+  }
+};
+
+} // namespace detail
+
+template <typename T, typename I>
+TypeU<T> Executor::runAtomicLoadOp(Runtime::StackManager &StackMgr,
+                                   Runtime::Instance::MemoryInstance &MemInst,
+                                   const AST::Instruction &Instr) {
+  ValVariant &Val = StackMgr.getTop();
+  if (auto Res = detail::calcAtomicAddress(
+          MemInst, Instr, getIndexTypeValue(MemInst, Val), sizeof(I))) {
+    I *Ptr = MemInst.getPointer<I *>(*Res);
+    Val.emplace<T>(static_cast<T>(__atomic_load_n(Ptr, __ATOMIC_SEQ_CST)));
+  } else {
+    return Unexpect(Res);
+  }
+  return {};
+}
+
+template <typename T, typename I>
+TypeU<T> Executor::runAtomicStoreOp(Runtime::StackManager &StackMgr,
+                                    Runtime::Instance::MemoryInstance &MemInst,
+                                    const AST::Instruction &Instr) {
+  const T C = StackMgr.pop().get<T>();
+  const uint64_t Addr = getIndexTypeValue(MemInst, StackMgr.pop());
+  if (auto Res = detail::calcAtomicAddress(MemInst, Instr, Addr, sizeof(I))) {
+    I *Ptr = MemInst.getPointer<I *>(*Res);
+    __atomic_store_n(Ptr, static_cast<I>(C), __ATOMIC_SEQ_CST);
+  } else {
+    return Unexpect(Res);
+  }
+  return {};
+}
+
+template <typename T, typename I, typename Op>
+TypeU<T> Executor::runAtomicRmwOp(Runtime::StackManager &StackMgr,
+                                  Runtime::Instance::MemoryInstance &MemInst,
+                                  const AST::Instruction &Instr) {
+  const T C = StackMgr.pop().get<T>();
+  ValVariant &Val = StackMgr.getTop();
+  if (auto Res = detail::calcAtomicAddress(
+          MemInst, Instr, getIndexTypeValue(MemInst, Val), sizeof(I))) {
+    I *Ptr = MemInst.getPointer<I *>(*Res);
+    // The narrow operations wrap the operand and zero-extend the old value.
+    Val.emplace<T>(static_cast<T>(Op::run(Ptr, static_cast<I>(C))));
+  } else {
+    return Unexpect(Res);
+  }
+  return {};
+}
+
+template <typename T, typename I>
+TypeU<T>
+Executor::runAtomicCmpxchgOp(Runtime::StackManager &StackMgr,
+                             Runtime::Instance::MemoryInstance &MemInst,
+                             const AST::Instruction &Instr) {
+  const T Replacement = StackMgr.pop().get<T>();
+  const T Expected = StackMgr.pop().get<T>();
+  ValVariant &Val = StackMgr.getTop();
+  if (auto Res = detail::calcAtomicAddress(
+          MemInst, Instr, getIndexTypeValue(MemInst, Val), sizeof(I))) {
+    I *Ptr = MemInst.getPointer<I *>(*Res);
+    // The expected value is wrapped as well. If not matched, the value in
+    // memory is returned without storing.
+    I Loaded = static_cast<I>(Expected);
+    __atomic_compare_exchange_n(Ptr, &Loaded, static_cast<I>(Replacement),
+                                false, __ATOMIC_SEQ_CST, __ATOMIC_SEQ_CST);
+    Val.emplace<T>(static_cast<T>(Loaded));
+  } else {
+    return Unexpect(Res);
+  }
+  return {};
+}
+
+template <typename T>
+TypeU<T> Executor::runAtomicWaitOp(Runtime::StackManager &StackMgr,
+                                   Runtime::Instance::MemoryInstance &MemInst,
+                                   const AST::Instruction &Instr) {
+  const int64_t Timeout = StackMgr.pop().get<int64_t>();
+  const T Expected = StackMgr.pop().get<T>();
+  ValVariant &Val = StackMgr.getTop();
+  if (auto Res = detail::calcAtomicAddress(
+          MemInst, Instr, getIndexTypeValue(MemInst, Val), sizeof(T))) {
+    // Only the shared memory can be waited on.
+    if (unlikely(!MemInst.isShared())) {
+      spdlog::error(ErrCode::ExpectSharedMemory);
+      spdlog::error(
+          ErrInfo::InfoInstruction(Instr.getOpCode(), Instr.getOffset()));
+      return Unexpect(ErrCode::ExpectSharedMemory);
+    }
+    if (auto Woken = MemInst.atomicWait<T>(*Res, Expected, Timeout,
+                                           *ExecutionContext.StopToken)) {
+      Val.emplace<uint32_t>(*Woken);
+    } else {
+      return Unexpect(Woken);
+    }
+  } else {
+    return Unexpect(Res);
+  }
+  return {};
+}
+
+} // namespace Executor
+} // namespace WasmEdge
@@ -9,6 +9,29 @@
 namespace WasmEdge {
 namespace Executor {
 
+inline uint64_t
+Executor::getIndexTypeValue(const Runtime::Instance::MemoryInstance &MemInst,
+                            const ValVariant &Val) noexcept {
+  return MemInst.is64() ? Val.get<uint64_t>() : Val.get<uint32_t>();
+}
+
+inline Expect<uint64_t>
+Executor::calcEffectiveAddress(const Runtime::Instance::MemoryInstance &MemInst,
+                               const AST::Instruction &Instr,
+                               const uint64_t Addr,
+                               const uint32_t Size) noexcept {
+  // Calculate EA = i + offset. Only the 64-bit addresses can overflow.
+  if (unlikely(Instr.getMemoryOffset() >
+               std::numeric_limits<uint64_t>::max() - Addr)) {
+    spdlog::error(ErrCode::MemoryOutOfBounds);
+    spdlog::error(ErrInfo::InfoBoundary(Addr, Size, MemInst.getBoundIdx()));
+    spdlog::error(
+        ErrInfo::InfoInstruction(Instr.getOpCode(), Instr.getOffset()));
+    return Unexpect(ErrCode::MemoryOutOfBounds);
+  }
+  return Addr + Instr.getMemoryOffset();
+}
+
 template <typename T>
 TypeT<T> Executor::runLoadOp(Runtime::StackManager &StackMgr,
                              Runtime::Instance::MemoryInstance &MemInst,
@@ -16,20 +39,15 @@ TypeT<T> Executor::runLoadOp(Runtime::StackManager &StackMgr,
                              const uint32_t BitWidth) {
   // Calculate EA
   ValVariant &Val = StackMgr.getTop();
-  if (Val.get<uint32_t>() >
-      std::numeric_limits<uint32_t>::max() - Instr.getMemoryOffset()) {
-    spdlog::error(ErrCode::MemoryOutOfBounds);
-    spdlog::error(ErrInfo::InfoBoundary(
-        Val.get<uint32_t>() + static_cast<uint64_t>(Instr.getMemoryOffset()),
-        BitWidth / 8, MemInst.getBoundIdx()));
-    spdlog::error(
-        ErrInfo::InfoInstruction(Instr.getOpCode(), Instr.getOffset()));
-    return Unexpect(ErrCode::MemoryOutOfBounds);
+  auto EA = calcEffectiveAddress(
+      MemInst, Instr, getIndexTypeValue(MemInst, Val), BitWidth / 8);
+  if (unlikely(!EA)) {
+    return Unexpect(EA);
   }
-  uint32_t EA = Val.get<uint32_t>() + Instr.getMemoryOffset();
 
   // Value = Mem.Data[EA : N / 8]
-  if (auto Res = MemInst.loadValue(Val.emplace<T>(), EA, BitWidth / 8); !Res) {
+  if (auto Res = MemInst.loadValue(Val.emplace<T>(), *EA, BitWidth / 8);
+      !Res) {
     spdlog::error(
         ErrInfo::InfoInstruction(Instr.getOpCode(), Instr.getOffset()));
     return Unexpect(Res);
@@ -46,20 +64,14 @@ TypeN<T> Executor::runStoreOp(Runtime::StackManager &StackMgr,
   T C = StackMgr.pop().get<T>();
 
   // Calculate EA = i + offset
-  uint32_t I = StackMgr.pop().get<uint32_t>();
-  if (I > std::numeric_limits<uint32_t>::max() - Instr.getMemoryOffset()) {
-    spdlog::error(ErrCode::MemoryOutOfBounds);
-    spdlog::error(ErrInfo::InfoBoundary(
-        I + static_cast<uint64_t>(Instr.getMemoryOffset()), BitWidth / 8,
-        MemInst.getBoundIdx()));
-    spdlog::error(
-        ErrInfo::InfoInstruction(Instr.getOpCode(), Instr.getOffset()));
-    return Unexpect(ErrCode::MemoryOutOfBounds);
+  const uint64_t I = getIndexTypeValue(MemInst, StackMgr.pop());
+  auto EA = calcEffectiveAddress(MemInst, Instr, I, BitWidth / 8);
+  if (unlikely(!EA)) {
+    return Unexpect(EA);
   }
-  uint32_t EA = I + Instr.getMemoryOffset();
 
   // Store value to bytes.
-  if (auto Res = MemInst.storeValue(C, EA, BitWidth / 8); !Res) {
+  if (auto Res = MemInst.storeValue(C, *EA, BitWidth / 8); !Res) {
     spdlog::error(
         ErrInfo::InfoInstruction(Instr.getOpCode(), Instr.getOffset()));
     return Unexpect(Res);
@@ -75,21 +87,15 @@ Executor::runLoadExpandOp(Runtime::StackManager &StackMgr,
   static_assert(sizeof(TOut) == sizeof(TIn) * 2);
   // Calculate EA
   ValVariant &Val = StackMgr.getTop();
-  if (Val.get<uint32_t>() >
-      std::numeric_limits<uint32_t>::max() - Instr.getMemoryOffset()) {
-    spdlog::error(ErrCode::MemoryOutOfBounds);
-    spdlog::error(ErrInfo::InfoBoundary(
-        Val.get<uint32_t>() + static_cast<uint64_t>(Instr.getMemoryOffset()), 8,
-        MemInst.getBoundIdx()));
-    spdlog::error(
-        ErrInfo::InfoInstruction(Instr.getOpCode(), Instr.getOffset()));
-    return Unexpect(ErrCode::MemoryOutOfBounds);
+  auto EA = calcEffectiveAddress(
+      MemInst, Instr, getIndexTypeValue(MemInst, Val), 8);
+  if (unlikely(!EA)) {
+    return Unexpect(EA);
   }
-  uint32_t EA = Val.get<uint32_t>() + Instr.getMemoryOffset();
 
   // Value = Mem.Data[EA : N / 8]
   uint64_t Buffer;
-  if (auto Res = MemInst.loadValue(Buffer, EA, 8); !Res) {
+  if (auto Res = MemInst.loadValue(Buffer, *EA, 8); !Res) {
     spdlog::error(
         ErrInfo::InfoInstruction(Instr.getOpCode(), Instr.getOffset()));
     return Unexpect(Res);
@@ -119,22 +125,16 @@ Executor::runLoadSplatOp(Runtime::StackManager &StackMgr,
                          const AST::Instruction &Instr) {
   // Calculate EA
   ValVariant &Val = StackMgr.getTop();
-  if (Val.get<uint32_t>() >
-      std::numeric_limits<uint32_t>::max() - Instr.getMemoryOffset()) {
-    spdlog::error(ErrCode::MemoryOutOfBounds);
-    spdlog::error(ErrInfo::InfoBoundary(
-        Val.get<uint32_t>() + static_cast<uint64_t>(Instr.getMemoryOffset()),
-        sizeof(T), MemInst.getBoundIdx()));
-    spdlog::error(
-        ErrInfo::InfoInstruction(Instr.getOpCode(), Instr.getOffset()));
-    return Unexpect(ErrCode::MemoryOutOfBounds);
+  auto EA = calcEffectiveAddress(
+      MemInst, Instr, getIndexTypeValue(MemInst, Val), sizeof(T));
+  if (unlikely(!EA)) {
+    return Unexpect(EA);
   }
-  uint32_t EA = Val.get<uint32_t>() + Instr.getMemoryOffset();
 
   // Value = Mem.Data[EA : N / 8]
   using VT [[gnu::vector_size(16)]] = T;
   uint64_t Buffer;
-  if (auto Res = MemInst.loadValue(Buffer, EA, sizeof(T)); !Res) {
+  if (auto Res = MemInst.loadValue(Buffer, *EA, sizeof(T)); !Res) {
     spdlog::error(
         ErrInfo::InfoInstruction(Instr.getOpCode(), Instr.getOffset()));
     return Unexpect(Res);
@@ -163,21 +163,15 @@ Expect<void> Executor::runLoadLaneOp(Runtime::StackManager &StackMgr,
 
   // Calculate EA
   ValVariant &Val = StackMgr.getTop();
-  const uint32_t Offset = Val.get<uint32_t>();
-  if (Offset > std::numeric_limits<uint32_t>::max() - Instr.getMemoryOffset()) {
-    spdlog::error(ErrCode::MemoryOutOfBounds);
-    spdlog::error(ErrInfo::InfoBoundary(
-        Offset + static_cast<uint64_t>(Instr.getMemoryOffset()), sizeof(T),
-        MemInst.getBoundIdx()));
-    spdlog::error(
-        ErrInfo::InfoInstruction(Instr.getOpCode(), Instr.getOffset()));
-    return Unexpect(ErrCode::MemoryOutOfBounds);
+  auto EA = calcEffectiveAddress(
+      MemInst, Instr, getIndexTypeValue(MemInst, Val), sizeof(T));
+  if (unlikely(!EA)) {
+    return Unexpect(EA);
   }
-  const uint32_t EA = Offset + Instr.getMemoryOffset();
 
   // Value = Mem.Data[EA : N / 8]
   uint64_t Buffer;
-  if (auto Res = MemInst.loadValue(Buffer, EA, sizeof(T)); !Res) {
+  if (auto Res = MemInst.loadValue(Buffer, *EA, sizeof(T)); !Res) {
     spdlog::error(
         ErrInfo::InfoInstruction(Instr.getOpCode(), Instr.getOffset()));
     return Unexpect(Res);
@@ -198,20 +192,14 @@ Executor::runStoreLaneOp(Runtime::StackManager &StackMgr,
   const TBuf C = StackMgr.pop().get<VT>()[Instr.getMemoryLane()];
 
   // Calculate EA = i + offset
-  uint32_t I = StackMgr.pop().get<uint32_t>();
-  if (I > std::numeric_limits<uint32_t>::max() - Instr.getMemoryOffset()) {
-    spdlog::error(ErrCode::MemoryOutOfBounds);
-    spdlog::error(ErrInfo::InfoBoundary(
-        I + static_cast<uint64_t>(Instr.getMemoryOffset()), sizeof(T),
-        MemInst.getBoundIdx()));
-    spdlog::error(
-        ErrInfo::InfoInstruction(Instr.getOpCode(), Instr.getOffset()));
-    return Unexpect(ErrCode::MemoryOutOfBounds);
+  const uint64_t I = getIndexTypeValue(MemInst, StackMgr.pop());
+  auto EA = calcEffectiveAddress(MemInst, Instr, I, sizeof(T));
+  if (unlikely(!EA)) {
+    return Unexpect(EA);
   }
-  uint32_t EA = I + Instr.getMemoryOffset();
 
   // Store value to bytes.
-  if (auto Res = MemInst.storeValue(C, EA, sizeof(T)); !Res) {
+  if (auto Res = MemInst.storeValue(C, *EA, sizeof(T)); !Res) {
     spdlog::error(
         ErrInfo::InfoInstruction(Instr.getOpCode(), Instr.getOffset()));
     return Unexpect(Res);
@@ -26,6 +26,8 @@
 #include <atomic>
 #include <csignal>
 #include <cstdint>
+#include <memory>
+#include <string>
 #include <string_view>
 #include <type_traits>
 #include <utility>
@@ -120,9 +122,13 @@ public:
          Span<const ValVariant> Params, Span<const ValType> ParamTypes);
 
   /// Register new thread
-  void newThread() noexcept {
+  void newThread() noexcept { newThread(StopToken); }
+
+  /// Register new thread whose executions check the given stop token instead
+  /// of the one of the executor. The token must outlive the executions.
+  void newThread(std::atomic_uint32_t &Token) noexcept {
     This = this;
-    ExecutionContext.StopToken = &StopToken;
+    ExecutionContext.StopToken = &Token;
     if (Stat) {
       ExecutionContext.InstrCount = &Stat->getInstrCountRef();
       ExecutionContext.CostTable = Stat->getCostTable().data();
@@ -131,10 +137,51 @@ public:
     }
   }
 
-  /// Stop execution
+  /// Stop the executions which check the stop token of the executor
   void stop() noexcept { StopToken.store(1, std::memory_order_relaxed); }
 
+  /// Discard a stop request which is not consumed by any stop check.
+  void resetStop() noexcept { StopToken.store(0, std::memory_order_relaxed); }
+
+  /// Frame of the backtrace of a failed execution.
+  struct BacktraceFrame {
+    /// Module instance name of the function. Empty for the active module.
+    std::string ModuleName;
+    /// Function index in the module instance. UINT32_MAX if unknown.
+    uint32_t FuncIdx;
+    /// Exported name of the function. Empty if not exported.
+    std::string FuncName;
+    /// Offset of the instruction in the module binary. UINT32_MAX if unknown.
+    uint32_t Offset;
+  };
+
+  /// Failure of an execution, recorded on the thread running the execution.
+  struct ExecutionFailure {
+    /// Backtrace of the failed execution, from the innermost frame to the
+    /// outermost one.
+    std::vector<BacktraceFrame> Backtrace;
+    /// Data attached by the host function which failed the execution.
+    std::shared_ptr<void> HostData;
+  };
+
+  /// Getter of the failure of the last execution on the current thread.
+  static const ExecutionFailure &getExecutionFailure() noexcept {
+    return Failure;
+  }
+
+  /// Attach the failure data to the execution on the current thread. Called
+  /// by the host function which is going to fail the execution.
+  static void setHostFailureData(std::shared_ptr<void> Data) noexcept {
+    PendingHostData = std::move(Data);
+  }
+
 private:
+  /// Consume the stop request of the current execution. Returns true if the
+  /// execution is requested to stop.
+  bool consumeStop() noexcept {
+    return ExecutionContext.StopToken->exchange(0, std::memory_order_relaxed);
+  }
+
   /// Run Wasm bytecode expression for initialization.
   Expect<void> runExpression(Runtime::StoreManager &StoreMgr,
                              Runtime::StackManager &StackMgr,
@@ -219,6 +266,11 @@ private:
                 const Runtime::Instance::FunctionInstance &Func,
                 const AST::InstrView::iterator RetIt, bool IsTailCall = false);
 
+  /// Helper function for recording the backtrace when the execution fails at
+  /// the instruction of PC.
+  void recordBacktrace(const Runtime::StackManager &StackMgr,
+                       AST::InstrView::iterator PC);
+
   /// Helper function for branching to label.
   Expect<void> branchToLabel(Runtime::StackManager &StackMgr,
                              uint32_t EraseBegin, uint32_t EraseEnd,
@@ -249,6 +301,22 @@ private:
   getDataInstByIdx(Runtime::StackManager &StackMgr, const uint32_t Idx) const;
   /// @}
 
+  /// \name Helper Functions for memory instructions.
+  /// @{
+  /// Helper function for getting the address or length operand in the index
+  /// type of the memory (Memory64 proposal).
+  static uint64_t
+  getIndexTypeValue(const Runtime::Instance::MemoryInstance &MemInst,
+                    const ValVariant &Val) noexcept;
+
+  /// Helper function for calculating the effective address of the memory
+  /// instructions.
+  static Expect<uint64_t>
+  calcEffectiveAddress(const Runtime::Instance::MemoryInstance &MemInst,
+                       const AST::Instruction &Instr, const uint64_t Addr,
+                       const uint32_t Size) noexcept;
+  /// @}
+
   /// \name Run instructions functions
   /// @{
   /// ======= Control instructions =======
@@ -337,6 +405,30 @@ private:
   Expect<void> runMemoryFillOp(Runtime::StackManager &StackMgr,
                                Runtime::Instance::MemoryInstance &MemInst,
                                const AST::Instruction &Instr);
+  /// ======= Atomic instructions =======
+  template <typename T, typename I>
+  TypeU<T> runAtomicLoadOp(Runtime::StackManager &StackMgr,
+                           Runtime::Instance::MemoryInstance &MemInst,
+                           const AST::Instruction &Instr);
+  template <typename T, typename I>
+  TypeU<T> runAtomicStoreOp(Runtime::StackManager &StackMgr,
+                            Runtime::Instance::MemoryInstance &MemInst,
+                            const AST::Instruction &Instr);
+  template <typename T, typename I, typename Op>
+  TypeU<T> runAtomicRmwOp(Runtime::StackManager &StackMgr,
+                          Runtime::Instance::MemoryInstance &MemInst,
+                          const AST::Instruction &Instr);
+  template <typename T, typename I>
+  TypeU<T> runAtomicCmpxchgOp(Runtime::StackManager &StackMgr,
+                              Runtime::Instance::MemoryInstance &MemInst,
+                              const AST::Instruction &Instr);
+  template <typename T>
+  TypeU<T> runAtomicWaitOp(Runtime::StackManager &StackMgr,
+                           Runtime::Instance::MemoryInstance &MemInst,
+                           const AST::Instruction &Instr);
+  Expect<void> runAtomicNotifyOp(Runtime::StackManager &StackMgr,
+                                 Runtime::Instance::MemoryInstance &MemInst,
+                                 const AST::Instruction &Instr);
   /// ======= Test and Relation Numeric instructions =======
   template <typename T> TypeU<T> runEqzOp(ValVariant &Val) const;
   template <typename T>
@@ -616,6 +708,10 @@ private:
   static thread_local Runtime::StackManager *CurrentStack;
   /// Execution context for compiled functions
   static thread_local ExecutionContextStruct ExecutionContext;
+  /// Failure of the last execution on the current thread
+  static thread_local ExecutionFailure Failure;
+  /// Failure data attached by the running host function
+  static thread_local std::shared_ptr<void> PendingHostData;
   /// @}
 
 private:
@@ -640,6 +736,7 @@ private:
 } // namespace Executor
 } // namespace WasmEdge
 
+#include "engine/atomic.ipp"
 #include "engine/binary_numeric.ipp"
 #include "engine/cast_numeric.ipp"
 #include "engine/memory.ipp"
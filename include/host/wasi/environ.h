@@ -44,6 +44,18 @@ public:
 
   void fini() noexcept;
 
+  /// Redirect the standard input, output, and error of the guest.
+  ///
+  /// The host file descriptors are duplicated, so the caller keeps the
+  /// ownership of them. A negative descriptor restores the corresponding
+  /// standard stream of the host process. Nothing is changed on failure.
+  ///
+  /// @param[in] StdIn The host file descriptor to read the standard input.
+  /// @param[in] StdOut The host file descriptor to write the standard output.
+  /// @param[in] StdErr The host file descriptor to write the standard error.
+  /// @return Nothing or WASI error
+  WasiExpect<void> redirectStdio(int StdIn, int StdOut, int StdErr) noexcept;
+
   WasiExpect<void> getAddrInfo(std::string_view Node, std::string_view Service,
                                const __wasi_addrinfo_t &Hint,
                                uint32_t MaxResLength,
@@ -176,6 +176,15 @@ public:
 
   static INode stdErr() noexcept;
 
+  /// Duplicate a file descriptor of the host process.
+  ///
+  /// The returned INode owns the duplicated descriptor, so the caller keeps
+  /// the ownership of the original one.
+  ///
+  /// @param[in] Fd The file descriptor of the host process.
+  /// @return The INode of the duplicated descriptor, or WASI error.
+  static WasiExpect<INode> fromFd(int Fd) noexcept;
+
   /// Open a file or directory.
   ///
   /// @param[in] Path The absolut path of the file or directory to open.
@@ -72,6 +72,13 @@ public:
     Mems.insert_or_assign(std::string(Name), std::move(Mem));
   }
 
+  /// Add a memory without moving the ownership. The memory instance should be
+  /// shared and should outlive all the stores which this import object is
+  /// registered into.
+  void addSharedMemory(std::string_view Name, Instance::MemoryInstance *Mem) {
+    SharedMems.insert_or_assign(std::string(Name), Mem);
+  }
+
   void addHostGlobal(std::string_view Name,
                      std::unique_ptr<Instance::GlobalInstance> &&Glob) {
     addHostGlobal(Name, Glob);
@@ -87,6 +94,11 @@ public:
 
   const InstMap<Instance::MemoryInstance> &getMems() const { return Mems; }
 
+  const std::map<std::string, Instance::MemoryInstance *> &
+  getSharedMems() const {
+    return SharedMems;
+  }
+
   const InstMap<Instance::GlobalInstance> &getGlobals() const { return Globs; }
 
 protected:
@@ -96,6 +108,7 @@ protected:
   InstMap<Instance::TableInstance> Tabs;
   InstMap<Instance::MemoryInstance> Mems;
   InstMap<Instance::GlobalInstance> Globs;
+  std::map<std::string, Instance::MemoryInstance *> SharedMems;
 };
 
 } // namespace Runtime
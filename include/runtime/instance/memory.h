@@ -20,10 +20,16 @@
 #include "system/allocator.h"
 
 #include <algorithm>
+#include <atomic>
+#include <chrono>
+#include <condition_variable>
 #include <cstdint>
 #include <cstring>
 #include <fstream>
+#include <functional>
+#include <map>
 #include <memory>
+#include <mutex>
 #include <set>
 #include <utility>
 
@@ -36,10 +42,15 @@ class MemoryInstance {
 public:
   static inline constexpr const uint64_t kPageSize = UINT64_C(65536);
   static inline constexpr const uint64_t k4G = UINT64_C(0x100000000);
+  /// Maximum pages count, 65536. The 64-bit memories are also capped at 4 GiB,
+  /// so the page counts of all memory instances fit in uint32_t.
+  static inline constexpr const uint64_t kMaxPageCount = k4G / kPageSize;
   MemoryInstance() = delete;
   MemoryInstance(MemoryInstance &&Inst) noexcept
       : MemType(Inst.MemType), DataPtr(Inst.DataPtr),
-        PageLimit(Inst.PageLimit) {
+        ReservedPages(Inst.ReservedPages), PageLimit(Inst.PageLimit),
+        GrowingCallback(std::move(Inst.GrowingCallback)),
+        Waiters(std::move(Inst.Waiters)) {
     Inst.DataPtr = nullptr;
   }
   MemoryInstance(const AST::MemoryType &MType,
@@ -51,52 +62,78 @@ public:
           PageLimit);
       return;
     }
-    DataPtr = Allocator::allocate(MemType.getLimit().getMin());
+    // The data of a shared memory is accessed by the threads concurrently, so
+    // it must not be moved when growing. Reserve the maximum size up front if
+    // the allocator moves the data to grow it.
+    if (isShared() && !Allocator::resizesInPlace()) {
+      ReservedPages = static_cast<uint32_t>(
+          std::min(MemType.getLimit().getMax(), uint64_t(PageLimit)));
+    }
+    DataPtr = Allocator::allocate(std::max(
+        ReservedPages, static_cast<uint32_t>(MemType.getLimit().getMin())));
     if (DataPtr == nullptr) {
       spdlog::error("Unable to find usable memory address");
       return;
     }
   }
   ~MemoryInstance() noexcept {
-    Allocator::release(DataPtr, MemType.getLimit().getMin());
+    Allocator::release(
+        DataPtr, std::max(ReservedPages,
+                          static_cast<uint32_t>(MemType.getLimit().getMin())));
   }
 
   /// Get page size of memory.data
   uint32_t getPageSize() const noexcept {
     // The memory page size is binded with the limit in memory type.
-    return MemType.getLimit().getMin();
+    return static_cast<uint32_t>(MemType.getLimit().getMin());
   }
 
   /// Getter of memory type.
   const AST::MemoryType &getMemoryType() const { return MemType; }
 
+  /// Check is the memory shared (Threads proposal).
+  bool isShared() const noexcept { return MemType.getLimit().isShared(); }
+
+  /// Check is the memory 64-bit (Memory64 proposal).
+  bool is64() const noexcept { return MemType.getLimit().is64(); }
+
   /// Check access size is valid.
-  bool checkAccessBound(uint32_t Offset, uint32_t Length) const noexcept {
-    const uint64_t AccessLen =
-        static_cast<uint64_t>(Offset) + static_cast<uint64_t>(Length);
-    return AccessLen <= MemType.getLimit().getMin() * kPageSize;
+  bool checkAccessBound(uint64_t Offset, uint64_t Length) const noexcept {
+    // The 64-bit offset and length may overflow when adding.
+    const uint64_t Size = MemType.getLimit().getMin() * kPageSize;
+    return Offset <= Size && Length <= Size - Offset;
   }
 
   /// Get boundary index.
   uint32_t getBoundIdx() const noexcept {
     return MemType.getLimit().getMin() > 0
-               ? MemType.getLimit().getMin() * kPageSize - 1
+               ? static_cast<uint32_t>(MemType.getLimit().getMin() * kPageSize -
+                                       1)
                : 0;
   }
 
+  /// Set the callback consulted before growing pages. The callback receives
+  /// the current and the desired page sizes, and denies the growing by
+  /// returning false.
+  void setGrowingCallback(std::function<bool(uint32_t, uint32_t)> Callback) {
+    GrowingCallback = std::move(Callback);
+  }
+
   /// Grow page
-  bool growPage(const uint32_t Count) {
+  bool growPage(const uint64_t Count) {
     if (Count == 0) {
       return true;
     }
-    // Maximum pages count, 65536
-    uint32_t MaxPageCaped = k4G / kPageSize;
-    uint32_t Min = MemType.getLimit().getMin();
-    uint32_t Max = MemType.getLimit().getMax();
+    // The memory can be grown by the threads concurrently, and the waiters
+    // read the data under the same lock.
+    std::unique_lock Lock(Mutex);
+    uint64_t MaxPageCaped = kMaxPageCount;
+    const uint64_t Min = MemType.getLimit().getMin();
+    const uint64_t Max = MemType.getLimit().getMax();
     if (MemType.getLimit().hasMax()) {
       MaxPageCaped = std::min(Max, MaxPageCaped);
     }
-    if (Count + Min > MaxPageCaped) {
+    if (Count > MaxPageCaped || Count + Min > MaxPageCaped) {
       return false;
     }
     if (Count + Min > PageLimit) {
@@ -104,19 +141,27 @@ public:
                     PageLimit);
       return false;
     }
-    if (auto NewPtr = Allocator::resize(DataPtr, Min, Min + Count);
-        NewPtr == nullptr) {
+    const uint32_t OldPages = static_cast<uint32_t>(Min);
+    const uint32_t NewPages = static_cast<uint32_t>(Min + Count);
+    if (GrowingCallback && !GrowingCallback(OldPages, NewPages)) {
       return false;
-    } else {
-      DataPtr = NewPtr;
     }
-    MemType.getLimit().setMin(Min + Count);
+    // The reserved pages are already allocated.
+    if (NewPages > ReservedPages) {
+      if (auto NewPtr = Allocator::resize(DataPtr, OldPages, NewPages);
+          NewPtr == nullptr) {
+        return false;
+      } else {
+        DataPtr = NewPtr;
+      }
+    }
+    MemType.getLimit().setMin(NewPages);
     return true;
   }
 
   /// Get slice of Data[Offset : Offset + Length - 1]
-  Expect<Span<Byte>> getBytes(const uint32_t Offset,
-                              const uint32_t Length) const noexcept {
+  Expect<Span<Byte>> getBytes(const uint64_t Offset,
+                              const uint64_t Length) const noexcept {
     // Check the memory boundary.
     if (unlikely(!checkAccessBound(Offset, Length))) {
       spdlog::error(ErrCode::MemoryOutOfBounds);
@@ -127,8 +172,8 @@ public:
   }
 
   /// Replace the bytes of Data[Offset :] by Slice[Start : Start + Legnth - 1]
-  Expect<void> setBytes(Span<const Byte> Slice, const uint32_t Offset,
-                        const uint32_t Start, const uint32_t Length) {
+  Expect<void> setBytes(Span<const Byte> Slice, const uint64_t Offset,
+                        const uint64_t Start, const uint64_t Length) {
     // Check the memory boundary.
     if (unlikely(!checkAccessBound(Offset, Length))) {
       spdlog::error(ErrCode::MemoryOutOfBounds);
@@ -137,7 +182,7 @@ public:
     }
 
     // Check the input data validation.
-    if (unlikely(Start + Length > Slice.size())) {
+    if (unlikely(Start > Slice.size() || Length > Slice.size() - Start)) {
       spdlog::error(ErrCode::MemoryOutOfBounds);
       spdlog::error(ErrInfo::InfoBoundary(Offset, Length, getBoundIdx()));
       return Unexpect(ErrCode::MemoryOutOfBounds);
@@ -152,8 +197,8 @@ public:
   }
 
   /// Fill the bytes of Data[Offset : Offset + Length - 1] by Val.
-  Expect<void> fillBytes(const uint8_t Val, const uint32_t Offset,
-                         const uint32_t Length) {
+  Expect<void> fillBytes(const uint8_t Val, const uint64_t Offset,
+                         const uint64_t Length) {
     // Check the memory boundary.
     if (unlikely(!checkAccessBound(Offset, Length))) {
       spdlog::error(ErrCode::MemoryOutOfBounds);
@@ -169,8 +214,8 @@ public:
   }
 
   /// Get an uint8 array from Data[Offset : Offset + Length - 1]
-  Expect<void> getArray(uint8_t *Arr, const uint32_t Offset,
-                        const uint32_t Length,
+  Expect<void> getArray(uint8_t *Arr, const uint64_t Offset,
+                        const uint64_t Length,
                         const bool IsReverse = false) const noexcept {
     // Check the memory boundary.
     if (unlikely(!checkAccessBound(Offset, Length))) {
@@ -190,8 +235,8 @@ public:
   }
 
   /// Replace Data[Offset : Offset + Length - 1] to an uint8 array
-  Expect<void> setArray(const uint8_t *Arr, const uint32_t Offset,
-                        const uint32_t Length, const bool IsReverse = false) {
+  Expect<void> setArray(const uint8_t *Arr, const uint64_t Offset,
+                        const uint64_t Length, const bool IsReverse = false) {
     // Check the memory boundary.
     if (unlikely(!checkAccessBound(Offset, Length))) {
       spdlog::error(ErrCode::MemoryOutOfBounds);
@@ -212,7 +257,7 @@ public:
   /// Get pointer to specific offset of memory or null.
   template <typename T>
   typename std::enable_if_t<std::is_pointer_v<T>, T>
-  getPointerOrNull(const uint32_t Offset) const {
+  getPointerOrNull(const uint64_t Offset) const {
     if (Offset == 0 ||
         unlikely(!checkAccessBound(Offset, sizeof(std::remove_pointer_t<T>)))) {
       return nullptr;
@@ -223,9 +268,9 @@ public:
   /// Get pointer to specific offset of memory.
   template <typename T>
   typename std::enable_if_t<std::is_pointer_v<T>, T>
-  getPointer(const uint32_t Offset, const uint32_t Size = 1) const {
+  getPointer(const uint64_t Offset, const uint32_t Size = 1) const {
     using Type = std::remove_pointer_t<T>;
-    uint32_t ByteSize = static_cast<uint32_t>(sizeof(Type)) * Size;
+    const uint64_t ByteSize = static_cast<uint64_t>(sizeof(Type)) * Size;
     if (unlikely(!checkAccessBound(Offset, ByteSize))) {
       return nullptr;
     }
@@ -245,7 +290,7 @@ public:
   /// \returns void when success, ErrCode when failed.
   template <typename T>
   typename std::enable_if_t<IsWasmNumV<T>, Expect<void>>
-  loadValue(T &Value, const uint32_t Offset,
+  loadValue(T &Value, const uint64_t Offset,
             const uint32_t Length) const noexcept {
     // Check the data boundary.
     if (unlikely(Length > sizeof(T))) {
@@ -299,7 +344,7 @@ public:
   /// \returns void when success, ErrCode when failed.
   template <typename T>
   typename std::enable_if_t<IsWasmNativeNumV<T>, Expect<void>>
-  storeValue(const T &Value, const uint32_t Offset, const uint32_t Length) {
+  storeValue(const T &Value, const uint64_t Offset, const uint32_t Length) {
     // Check the data boundary.
     if (unlikely(Length > sizeof(T))) {
       spdlog::error(ErrCode::MemoryOutOfBounds);
@@ -322,12 +367,100 @@ public:
 
   uint8_t *getDataPtr() const noexcept { return DataPtr; }
 
+  /// Wait on the address until notified or timeout (Threads proposal).
+  ///
+  /// The address should be checked for the boundary and alignment before
+  /// calling this function.
+  ///
+  /// \param Offset the address in data array to wait on.
+  /// \param Expected the expected value at the address.
+  /// \param Timeout the timeout in nanoseconds. Negative for infinity.
+  /// \param StopToken the stop token of the execution, which is checked
+  /// periodically while waiting.
+  ///
+  /// \returns 0 if woken by notifying, 1 if the loaded value does not match
+  /// the expected value, and 2 if timed out. ErrCode::Interrupted if the
+  /// execution is stopped while waiting.
+  template <typename T>
+  typename std::enable_if_t<IsWasmNativeNumV<T>, Expect<uint32_t>>
+  atomicWait(const uint64_t Offset, const T Expected, const int64_t Timeout,
+             std::atomic_uint32_t &StopToken) noexcept {
+    std::unique_lock Lock(Mutex);
+    if (__atomic_load_n(reinterpret_cast<T *>(&DataPtr[Offset]),
+                        __ATOMIC_SEQ_CST) != Expected) {
+      return 1;
+    }
+    Waiter W;
+    auto It = Waiters.emplace(Offset, &W);
+    // Clamp the timeout to prevent the deadline overflowing.
+    const auto Deadline = std::chrono::steady_clock::now() +
+                          std::chrono::nanoseconds(std::min(
+                              Timeout, INT64_C(0x3FFFFFFFFFFFFFFF)));
+    // Wait in slices to check the stop token between them.
+    while (!W.Notified) {
+      if (unlikely(StopToken.exchange(0, std::memory_order_relaxed))) {
+        Waiters.erase(It);
+        spdlog::error(ErrCode::Interrupted);
+        return Unexpect(ErrCode::Interrupted);
+      }
+      const auto Now = std::chrono::steady_clock::now();
+      if (Timeout < 0) {
+        W.Cond.wait_until(Lock, Now + kWaitSlice);
+      } else if (Now < Deadline) {
+        W.Cond.wait_until(Lock, std::min(Now + kWaitSlice, Deadline));
+      } else {
+        // Timed out. The notified waiters have been removed by the notifier.
+        Waiters.erase(It);
+        return 2;
+      }
+    }
+    return 0;
+  }
+
+  /// Notify the waiters on the address (Threads proposal).
+  ///
+  /// \param Offset the address in data array to notify.
+  /// \param Count the maximum count of the waiters to wake up.
+  ///
+  /// \returns the count of the woken waiters.
+  uint32_t atomicNotify(const uint64_t Offset, const uint32_t Count) noexcept {
+    std::unique_lock Lock(Mutex);
+    uint32_t Woken = 0;
+    auto [It, End] = Waiters.equal_range(Offset);
+    // The waiters on the same address are woken in the waiting order.
+    while (It != End && Woken < Count) {
+      It->second->Notified = true;
+      It->second->Cond.notify_one();
+      It = Waiters.erase(It);
+      ++Woken;
+    }
+    return Woken;
+  }
+
 private:
+  /// Interval of checking the stop token while waiting.
+  static inline constexpr const std::chrono::milliseconds kWaitSlice{10};
+
+  /// Waiter on an address of the memory.
+  struct Waiter {
+    std::condition_variable Cond;
+    bool Notified = false;
+  };
+
   /// \name Data of memory instance.
   /// @{
   AST::MemoryType MemType;
   uint8_t *DataPtr = nullptr;
+  /// Pages allocated up front, which are not moved when growing.
+  uint32_t ReservedPages = 0;
   const uint32_t PageLimit;
+  std::function<bool(uint32_t, uint32_t)> GrowingCallback;
+  /// @}
+
+  /// \name Waiters of atomic wait instructions and the lock of growing.
+  /// @{
+  std::mutex Mutex;
+  std::multimap<uint64_t, Waiter *> Waiters;
   /// @}
 };
 
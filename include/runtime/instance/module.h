@@ -277,6 +277,25 @@ public:
     return static_cast<uint32_t>(DataInsts.size());
   }
 
+  /// Get the total size of the table and memory instances defined in this
+  /// module, excluding the imported ones.
+  uint64_t getTableSizeInUse() const {
+    std::shared_lock Lock(Mutex);
+    uint64_t Size = 0;
+    for (uint32_t I = ImpTableNum; I < TabInsts.size(); ++I) {
+      Size += TabInsts[I]->getSize();
+    }
+    return Size;
+  }
+  uint64_t getMemPageSizeInUse() const {
+    std::shared_lock Lock(Mutex);
+    uint64_t Size = 0;
+    for (uint32_t I = ImpMemNum; I < MemInsts.size(); ++I) {
+      Size += MemInsts[I]->getPageSize();
+    }
+    return Size;
+  }
+
   /// Set the start function index and find the function instance.
   void setStartIdx(const uint32_t Idx) {
     std::unique_lock Lock(Mutex);
@@ -20,6 +20,7 @@
 
 #include <algorithm>
 #include <cstdint>
+#include <functional>
 #include <vector>
 
 namespace WasmEdge {
@@ -55,6 +56,13 @@ public:
            UINT32_C(1);
   }
 
+  /// Set the callback consulted before growing the table. The callback
+  /// receives the current and the desired sizes, and denies the growing by
+  /// returning false.
+  void setGrowingCallback(std::function<bool(uint32_t, uint32_t)> Callback) {
+    GrowingCallback = std::move(Callback);
+  }
+
   /// Grow table with initialization value.
   bool growTable(const uint32_t Count, const RefVariant Val) {
     uint32_t MaxSizeCaped = std::numeric_limits<uint32_t>::max();
@@ -66,6 +74,9 @@ public:
     if (Count > MaxSizeCaped - Refs.size()) {
       return false;
     }
+    if (Count > 0 && GrowingCallback && !GrowingCallback(Min, Min + Count)) {
+      return false;
+    }
     Refs.resize(Refs.size() + Count);
     std::fill_n(Refs.end() - Count, Count, Val);
     TabType.getLimit().setMin(Min + Count);
@@ -153,6 +164,7 @@ private:
   /// @{
   AST::TableType TabType;
   std::vector<RefVariant> Refs;
+  std::function<bool(uint32_t, uint32_t)> GrowingCallback;
   /// @}
 };
 
@@ -27,13 +27,15 @@ public:
   struct Frame {
     Frame() = delete;
     Frame(Instance::ModuleInstance *Mod, AST::InstrView::iterator FromIt,
-          uint32_t L, uint32_t A, uint32_t V) noexcept
-        : Module(Mod), From(FromIt), Locals(L), Arity(A), VPos(V) {}
+          uint32_t L, uint32_t A, uint32_t V,
+          const Instance::FunctionInstance *F) noexcept
+        : Module(Mod), From(FromIt), Locals(L), Arity(A), VPos(V), Func(F) {}
     Instance::ModuleInstance *Module;
     AST::InstrView::iterator From;
     uint32_t Locals;
     uint32_t Arity;
     uint32_t VPos;
+    const Instance::FunctionInstance *Func;
   };
 
   using Value = ValVariant;
@@ -79,9 +81,11 @@ public:
   /// Push a new frame entry to stack.
   void pushFrame(Instance::ModuleInstance *Module,
                  AST::InstrView::iterator From, uint32_t LocalNum = 0,
-                 uint32_t Arity = 0, bool IsTailCall = false) noexcept {
+                 uint32_t Arity = 0, bool IsTailCall = false,
+                 const Instance::FunctionInstance *Func = nullptr) noexcept {
     if (likely(!IsTailCall)) {
-      FrameStack.emplace_back(Module, From, LocalNum, Arity, ValueStack.size());
+      FrameStack.emplace_back(Module, From, LocalNum, Arity, ValueStack.size(),
+                              Func);
     } else {
       assuming(!FrameStack.empty());
       assuming(FrameStack.back().VPos >= FrameStack.back().Locals);
@@ -91,6 +95,7 @@ public:
                            FrameStack.back().Locals,
                        ValueStack.end() - LocalNum);
       FrameStack.back().Module = Module;
+      FrameStack.back().Func = Func;
       FrameStack.back().Locals = LocalNum;
       FrameStack.back().Arity = Arity;
       FrameStack.back().VPos = static_cast<uint32_t>(ValueStack.size());
@@ -133,6 +138,9 @@ public:
     return FrameStack.back().Module;
   }
 
+  /// Getter of the frames, from the bottom to the top of stack.
+  Span<const Frame> getFrames() const noexcept { return FrameStack; }
+
   /// Reset stack.
   void reset() noexcept {
     ValueStack.clear();
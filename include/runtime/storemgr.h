@@ -21,9 +21,11 @@
 #include "runtime/instance/module.h"
 #include "runtime/instance/table.h"
 
+#include <functional>
 #include <memory>
 #include <mutex>
 #include <shared_mutex>
+#include <string_view>
 #include <type_traits>
 #include <vector>
 
@@ -217,6 +219,32 @@ public:
     return Unexpect(ErrCode::WrongInstanceAddress);
   }
 
+  /// Resource limiter consulted before growing the table and memory instances
+  /// instantiated in this store. The arguments are the external type, the
+  /// module name, the instance index in the module, and the current and the
+  /// desired sizes. The growing is denied if the limiter returns false.
+  using ResourceLimiter = std::function<bool(
+      ExternalType, std::string_view, uint32_t, uint32_t, uint32_t)>;
+
+  /// Set the resource limiter. Pass an empty function to remove it.
+  void setResourceLimiter(ResourceLimiter Limiter) {
+    std::unique_lock Lock(LimiterMutex);
+    ResLimiter = std::move(Limiter);
+  }
+
+  /// Check the growing of a table or memory instance by the resource limiter.
+  bool checkGrowing(const ExternalType Type, std::string_view ModName,
+                    const uint32_t Idx, const uint32_t Current,
+                    const uint32_t Desired) const {
+    ResourceLimiter Limiter;
+    {
+      // Copy the limiter to allow it being reset in the callback.
+      std::shared_lock Lock(LimiterMutex);
+      Limiter = ResLimiter;
+    }
+    return !Limiter || Limiter(Type, ModName, Idx, Current, Desired);
+  }
+
   /// Reset store.
   void reset(bool IsResetRegistered = false) {
     std::unique_lock Lock(Mutex);
@@ -340,6 +368,12 @@ private:
   /// @{
   std::map<std::string, Instance::ModuleInstance *, std::less<>> ModMap;
   /// @}
+
+  /// \name Resource limiter.
+  /// @{
+  mutable std::shared_mutex LimiterMutex;
+  ResourceLimiter ResLimiter;
+  /// @}
 };
 
 } // namespace Runtime
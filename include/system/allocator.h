@@ -22,6 +22,8 @@ public:
   static uint8_t *allocate(uint32_t PageCount) noexcept;
   static uint8_t *resize(uint8_t *Pointer, uint32_t OldPageCount,
                          uint32_t NewPageCount) noexcept;
+  /// Whether `resize` keeps the address of the data.
+  static bool resizesInPlace() noexcept;
   static void release(uint8_t *Pointer, uint32_t PageCount) noexcept;
 
   static uint8_t *allocate_chunk(uint64_t Size) noexcept;
@@ -68,6 +68,7 @@ private:
                                  Span<const ValType> Returns);
 
   static inline const uint32_t LIMIT_MEMORYTYPE = 1U << 16;
+  static inline const uint64_t LIMIT_MEMORYTYPE64 = UINT64_C(1) << 48;
   /// Proposal configure
   const Configure Conf;
   /// Formal checker
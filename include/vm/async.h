@@ -15,7 +15,9 @@
 
 #include "vm.h"
 
+#include <atomic>
 #include <future>
+#include <memory>
 #include <thread>
 
 namespace WasmEdge {
@@ -27,15 +29,21 @@ public:
   Async() noexcept = default;
   template <typename... FArgsT, typename... ArgsT>
   Async(T (VM::*FPtr)(FArgsT...), VM &TargetVM, ArgsT &&...Args)
-      : VMPtr(&TargetVM) {
+      : State(std::make_shared<ExecutionState>()) {
     std::promise<T> Promise;
     Future = Promise.get_future();
     Thread =
-        std::thread([FPtr, P = std::move(Promise),
+        std::thread([FPtr, P = std::move(Promise), S = State,
                      Tuple = std::tuple(
                          &TargetVM, std::forward<ArgsT>(Args)...)]() mutable {
-          std::get<0>(Tuple)->newThread();
-          P.set_value(std::apply(FPtr, Tuple));
+          // The execution checks its own stop token, so that canceling it
+          // does not interrupt the other executions of the VM.
+          std::get<0>(Tuple)->newThread(S->StopToken);
+          auto Res = std::apply(FPtr, Tuple);
+          // The failure is recorded on this thread. Keep it before the result
+          // is ready.
+          S->Failure = std::get<0>(Tuple)->getExecutionFailure();
+          P.set_value(std::move(Res));
         });
     Thread.detach();
   }
@@ -67,19 +75,33 @@ public:
     using std::swap;
     swap(LHS.Future, RHS.Future);
     swap(LHS.Thread, RHS.Thread);
-    swap(LHS.VMPtr, RHS.VMPtr);
+    swap(LHS.State, RHS.State);
   }
 
   void cancel() noexcept {
-    if (likely(VMPtr)) {
-      VMPtr->stop();
+    if (likely(State != nullptr)) {
+      State->StopToken.store(1, std::memory_order_relaxed);
     }
   }
 
+  /// Getter of the failure of the execution. Wait until the execution ends.
+  const Executor::Executor::ExecutionFailure &getExecutionFailure() const {
+    wait();
+    return State->Failure;
+  }
+
 private:
+  /// State of the execution, shared with the execution thread.
+  struct ExecutionState {
+    /// Stop token of the execution.
+    std::atomic_uint32_t StopToken = 0;
+    /// Failure of the execution, set when the execution ends.
+    Executor::Executor::ExecutionFailure Failure;
+  };
+
   std::shared_future<T> Future;
   std::thread Thread;
-  VM *VMPtr;
+  std::shared_ptr<ExecutionState> State;
 };
 
 } // namespace VM
@@ -149,6 +149,15 @@ public:
     return unsafeExecute(ModName, Func, Params, ParamTypes);
   }
 
+  /// Execute function instance with given input.
+  Expect<std::vector<std::pair<ValVariant, ValType>>>
+  execute(const Runtime::Instance::FunctionInstance &FuncInst,
+          Span<const ValVariant> Params = {},
+          Span<const ValType> ParamTypes = {}) {
+    std::shared_lock Lock(Mutex);
+    return ExecutorEngine.invoke(StoreRef, FuncInst, Params, ParamTypes);
+  }
+
   /// Asynchronous execute wasm with given input.
   Async<Expect<std::vector<std::pair<ValVariant, ValType>>>>
   asyncExecute(std::string_view Func, Span<const ValVariant> Params = {},
@@ -162,8 +171,16 @@ public:
 
   /// Register new thread
   void newThread() noexcept { ExecutorEngine.newThread(); }
+  /// Register new thread with the stop token of its executions
+  void newThread(std::atomic_uint32_t &Token) noexcept {
+    ExecutorEngine.newThread(Token);
+  }
   /// Stop execution
   void stop() noexcept { ExecutorEngine.stop(); }
+  /// Getter of the failure of the last execution on the current thread
+  const Executor::Executor::ExecutionFailure &getExecutionFailure() const {
+    return ExecutorEngine.getExecutionFailure();
+  }
 
   /// ======= Functions which are stageless. =======
   /// Clean up VM status
@@ -4484,6 +4484,41 @@ Expect<void> Compiler::compile(Span<const Byte> Data, const AST::Module &Module,
     return Unexpect(ErrCode::NotValidated);
   }
 
+  // The atomic instructions of the threads proposal are not supported yet.
+  if (Conf.hasProposal(Proposal::Threads)) {
+    for (const auto &Code : Module.getCodeSection().getContent()) {
+      for (const auto &Instr : Code.getExpr().getInstrs()) {
+        if (Instr.getOpCode() >= OpCode::Memory__atomic__notify &&
+            Instr.getOpCode() <= OpCode::I64__atomic__rmw32__cmpxchg_u) {
+          spdlog::error("atomic instruction at offset {} is not supported by "
+                        "the AOT compiler",
+                        Instr.getOffset());
+          return Unexpect(ErrCode::IllegalOpCode);
+        }
+      }
+    }
+  }
+
+  // The 64-bit memories of the memory64 proposal are not supported yet.
+  if (Conf.hasProposal(Proposal::Memory64)) {
+    bool Has64BitMemory = false;
+    for (const auto &ImpDesc : Module.getImportSection().getContent()) {
+      if (ImpDesc.getExternalType() == ExternalType::Memory &&
+          ImpDesc.getExternalMemoryType().getLimit().is64()) {
+        Has64BitMemory = true;
+      }
+    }
+    for (const auto &MemType : Module.getMemorySection().getContent()) {
+      if (MemType.getLimit().is64()) {
+        Has64BitMemory = true;
+      }
+    }
+    if (Has64BitMemory) {
+      spdlog::error("64-bit memory is not supported by the AOT compiler");
+      return Unexpect(ErrCode::IllegalGrammar);
+    }
+  }
+
   using namespace std::literals;
 
   std::unique_lock Lock(Mutex);
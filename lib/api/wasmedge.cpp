@@ -295,6 +295,27 @@ inline uint32_t fillMap(const std::map<std::string, T *, std::less<>> &Map,
   return static_cast<uint32_t>(Map.size());
 }
 
+// Helper function of retrieving the backtrace frames.
+inline uint32_t
+fillBacktrace(const std::vector<Executor::Executor::BacktraceFrame> &Backtrace,
+              WasmEdge_BacktraceFrame *Frames, const uint32_t Len) {
+  if (Frames) {
+    for (uint32_t I = 0; I < Len && I < Backtrace.size(); I++) {
+      const auto &Frame = Backtrace[I];
+      Frames[I] = WasmEdge_BacktraceFrame{
+          .ModuleName = WasmEdge_StringCreateByBuffer(
+              Frame.ModuleName.data(),
+              static_cast<uint32_t>(Frame.ModuleName.length())),
+          .FuncIndex = Frame.FuncIdx,
+          .FuncName = WasmEdge_StringCreateByBuffer(
+              Frame.FuncName.data(),
+              static_cast<uint32_t>(Frame.FuncName.length())),
+          .Offset = Frame.Offset};
+    }
+  }
+  return static_cast<uint32_t>(Backtrace.size());
+}
+
 // C API Host function class
 class CAPIHostFunc : public Runtime::HostFunctionBase {
 public:
@@ -313,6 +334,8 @@ public:
   }
   ~CAPIHostFunc() noexcept override = default;
 
+  void *getData() const noexcept { return Data; }
+
   Expect<void> run(Runtime::Instance::MemoryInstance *MemInst,
                    Span<const ValVariant> Args,
                    Span<ValVariant> Rets) override {
@@ -371,6 +394,7 @@ CONVTO(ExpType, AST::ExportDesc, ExportType, const)
 CONVTO(Store, Runtime::StoreManager, Store, )
 CONVTO(Mod, Runtime::Instance::ModuleInstance, ModuleInstance, const)
 CONVTO(Func, Runtime::Instance::FunctionInstance, FunctionInstance, )
+CONVTO(Func, Runtime::Instance::FunctionInstance, FunctionInstance, const)
 CONVTO(Tab, Runtime::Instance::TableInstance, TableInstance, )
 CONVTO(Mem, Runtime::Instance::MemoryInstance, MemoryInstance, )
 CONVTO(Glob, Runtime::Instance::GlobalInstance, GlobalInstance, )
@@ -623,7 +647,8 @@ WasmEdge_ResultGetMessage(const WasmEdge_Result Res) {
 WASMEDGE_CAPI_EXPORT bool WasmEdge_LimitIsEqual(const WasmEdge_Limit Lim1,
                                                 const WasmEdge_Limit Lim2) {
   return Lim1.HasMax == Lim2.HasMax && Lim1.Min == Lim2.Min &&
-         Lim1.Max == Lim2.Max;
+         Lim1.Max == Lim2.Max && Lim1.Shared == Lim2.Shared &&
+         Lim1.Is64 == Lim2.Is64;
 }
 
 // <<<<<<<< WasmEdge limit functions <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<
@@ -1032,6 +1057,9 @@ WASMEDGE_CAPI_EXPORT WasmEdge_TableTypeContext *
 WasmEdge_TableTypeCreate(const enum WasmEdge_RefType RefType,
                          const WasmEdge_Limit Limit) {
   WasmEdge::RefType Type = static_cast<WasmEdge::RefType>(RefType);
+  if (Limit.Min > UINT32_MAX || (Limit.HasMax && Limit.Max > UINT32_MAX)) {
+    return nullptr;
+  }
   if (Limit.HasMax) {
     return toTabTypeCxt(
         new WasmEdge::AST::TableType(Type, Limit.Min, Limit.Max));
@@ -1052,10 +1080,14 @@ WASMEDGE_CAPI_EXPORT WasmEdge_Limit
 WasmEdge_TableTypeGetLimit(const WasmEdge_TableTypeContext *Cxt) {
   if (Cxt) {
     const auto &Lim = fromTabTypeCxt(Cxt)->getLimit();
-    return WasmEdge_Limit{
-        .HasMax = Lim.hasMax(), .Min = Lim.getMin(), .Max = Lim.getMax()};
+    return WasmEdge_Limit{.HasMax = Lim.hasMax(),
+                          .Min = Lim.getMin(),
+                          .Max = Lim.getMax(),
+                          .Shared = Lim.isShared(),
+                          .Is64 = Lim.is64()};
   }
-  return WasmEdge_Limit{.HasMax = false, .Min = 0, .Max = 0};
+  return WasmEdge_Limit{
+      .HasMax = false, .Min = 0, .Max = 0, .Shared = false, .Is64 = false};
 }
 
 WASMEDGE_CAPI_EXPORT void
@@ -1069,21 +1101,32 @@ WasmEdge_TableTypeDelete(WasmEdge_TableTypeContext *Cxt) {
 
 WASMEDGE_CAPI_EXPORT WasmEdge_MemoryTypeContext *
 WasmEdge_MemoryTypeCreate(const WasmEdge_Limit Limit) {
-  if (Limit.HasMax) {
-    return toMemTypeCxt(new WasmEdge::AST::MemoryType(Limit.Min, Limit.Max));
-  } else {
-    return toMemTypeCxt(new WasmEdge::AST::MemoryType(Limit.Min));
+  if (Limit.Shared && !Limit.HasMax) {
+    return nullptr;
+  }
+  if (!Limit.Is64 &&
+      (Limit.Min > UINT32_MAX || (Limit.HasMax && Limit.Max > UINT32_MAX))) {
+    return nullptr;
   }
+  WasmEdge::AST::Limit Lim =
+      Limit.HasMax ? WasmEdge::AST::Limit(Limit.Min, Limit.Max, Limit.Shared)
+                   : WasmEdge::AST::Limit(Limit.Min);
+  Lim.set64(Limit.Is64);
+  return toMemTypeCxt(new WasmEdge::AST::MemoryType(Lim));
 }
 
 WASMEDGE_CAPI_EXPORT WasmEdge_Limit
 WasmEdge_MemoryTypeGetLimit(const WasmEdge_MemoryTypeContext *Cxt) {
   if (Cxt) {
     const auto &Lim = fromMemTypeCxt(Cxt)->getLimit();
-    return WasmEdge_Limit{
-        .HasMax = Lim.hasMax(), .Min = Lim.getMin(), .Max = Lim.getMax()};
+    return WasmEdge_Limit{.HasMax = Lim.hasMax(),
+                          .Min = Lim.getMin(),
+                          .Max = Lim.getMax(),
+                          .Shared = Lim.isShared(),
+                          .Is64 = Lim.is64()};
   }
-  return WasmEdge_Limit{.HasMax = false, .Min = 0, .Max = 0};
+  return WasmEdge_Limit{
+      .HasMax = false, .Min = 0, .Max = 0, .Shared = false, .Is64 = false};
 }
 
 WASMEDGE_CAPI_EXPORT void
@@ -1575,6 +1618,52 @@ WASMEDGE_CAPI_EXPORT WasmEdge_Result WasmEdge_ExecutorInvokeRegistered(
       StoreCxt);
 }
 
+WASMEDGE_CAPI_EXPORT WasmEdge_Result WasmEdge_ExecutorInvokeFunction(
+    WasmEdge_ExecutorContext *Cxt, WasmEdge_StoreContext *StoreCxt,
+    const WasmEdge_FunctionInstanceContext *FuncCxt,
+    const WasmEdge_Value *Params, const uint32_t ParamLen,
+    WasmEdge_Value *Returns, const uint32_t ReturnLen) {
+  auto ParamPair = genParamPair(Params, ParamLen);
+  return wrap(
+      [&]() {
+        return Cxt->Exec.invoke(*fromStoreCxt(StoreCxt), *fromFuncCxt(FuncCxt),
+                                ParamPair.first, ParamPair.second);
+      },
+      [&](auto &&Res) { fillWasmEdge_ValueArr(*Res, Returns, ReturnLen); }, Cxt,
+      StoreCxt, FuncCxt);
+}
+
+WASMEDGE_CAPI_EXPORT void WasmEdge_ExecutorStop(WasmEdge_ExecutorContext *Cxt) {
+  if (Cxt) {
+    Cxt->Exec.stop();
+  }
+}
+
+WASMEDGE_CAPI_EXPORT void
+WasmEdge_ExecutorResetStop(WasmEdge_ExecutorContext *Cxt) {
+  if (Cxt) {
+    Cxt->Exec.resetStop();
+  }
+}
+
+WASMEDGE_CAPI_EXPORT uint32_t WasmEdge_ExecutorGetBacktrace(
+    const WasmEdge_ExecutorContext *Cxt, WasmEdge_BacktraceFrame *Frames,
+    const uint32_t Len) {
+  if (Cxt) {
+    return fillBacktrace(Cxt->Exec.getExecutionFailure().Backtrace, Frames,
+                         Len);
+  }
+  return 0;
+}
+
+WASMEDGE_CAPI_EXPORT const void *
+WasmEdge_ExecutorGetHostFailureData(const WasmEdge_ExecutorContext *Cxt) {
+  if (Cxt) {
+    return Cxt->Exec.getExecutionFailure().HostData.get();
+  }
+  return nullptr;
+}
+
 WASMEDGE_CAPI_EXPORT void
 WasmEdge_ExecutorDelete(WasmEdge_ExecutorContext *Cxt) {
   delete Cxt;
@@ -1904,6 +1993,39 @@ WASMEDGE_CAPI_EXPORT void WasmEdge_StoreDelete(WasmEdge_StoreContext *Cxt) {
   delete fromStoreCxt(Cxt);
 }
 
+WASMEDGE_CAPI_EXPORT void
+WasmEdge_StoreSetResourceLimiter(WasmEdge_StoreContext *Cxt,
+                                 WasmEdge_ResourceLimiter_t Limiter, void *Data,
+                                 void (*Finalizer)(void *)) {
+  if (!Cxt) {
+    return;
+  }
+  if (!Limiter) {
+    if (Finalizer) {
+      Finalizer(Data);
+    }
+    fromStoreCxt(Cxt)->setResourceLimiter({});
+    return;
+  }
+  // The data is shared by the copies of the limiter, and finalized when the
+  // last copy is destroyed.
+  std::shared_ptr<void> SharedData(Data, [Finalizer](void *Ptr) {
+    if (Finalizer) {
+      Finalizer(Ptr);
+    }
+  });
+  fromStoreCxt(Cxt)->setResourceLimiter(
+      [Limiter, SharedData = std::move(SharedData)](
+          WasmEdge::ExternalType Type, std::string_view ModName, uint32_t Idx,
+          uint32_t Current, uint32_t Desired) {
+        WasmEdge_String Name{.Length = static_cast<uint32_t>(ModName.length()),
+                             .Buf = ModName.data()};
+        return Limiter(SharedData.get(), Name,
+                       static_cast<WasmEdge_ExternalType>(Type), Idx, Current,
+                       Desired);
+      });
+}
+
 // <<<<<<<< WasmEdge store functions <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<
 
 // >>>>>>>> WasmEdge module instance functions >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>
@@ -2031,6 +2153,110 @@ WasmEdge_ModuleInstanceListGlobal(const WasmEdge_ModuleInstanceContext *Cxt,
   return 0;
 }
 
+WASMEDGE_CAPI_EXPORT uint64_t WasmEdge_ModuleInstanceGetTableSizeInUse(
+    const WasmEdge_ModuleInstanceContext *Cxt) {
+  if (Cxt) {
+    return fromModCxt(Cxt)->getTableSizeInUse();
+  }
+  return 0;
+}
+
+WASMEDGE_CAPI_EXPORT uint64_t WasmEdge_ModuleInstanceGetMemoryPageSizeInUse(
+    const WasmEdge_ModuleInstanceContext *Cxt) {
+  if (Cxt) {
+    return fromModCxt(Cxt)->getMemPageSizeInUse();
+  }
+  return 0;
+}
+
+WASMEDGE_CAPI_EXPORT uint32_t WasmEdge_ModuleInstanceGetDefinedMemoryLength(
+    const WasmEdge_ModuleInstanceContext *Cxt) {
+  if (Cxt) {
+    const auto *ModInst = fromModCxt(Cxt);
+    return ModInst->getMemNum() - ModInst->getMemImportNum();
+  }
+  return 0;
+}
+
+WASMEDGE_CAPI_EXPORT WasmEdge_MemoryInstanceContext *
+WasmEdge_ModuleInstanceGetDefinedMemory(
+    const WasmEdge_ModuleInstanceContext *Cxt, WasmEdge_StoreContext *StoreCxt,
+    const uint32_t Idx) {
+  if (Cxt && StoreCxt) {
+    const auto *ModInst = fromModCxt(Cxt);
+    const uint64_t MemIdx =
+        static_cast<uint64_t>(Idx) + ModInst->getMemImportNum();
+    if (MemIdx <= UINT32_MAX) {
+      if (auto Res = ModInst->getMemory(static_cast<uint32_t>(MemIdx))) {
+        return toMemCxt(*Res);
+      }
+    }
+  }
+  return nullptr;
+}
+
+WASMEDGE_CAPI_EXPORT uint32_t WasmEdge_ModuleInstanceGetDefinedGlobalLength(
+    const WasmEdge_ModuleInstanceContext *Cxt) {
+  if (Cxt) {
+    const auto *ModInst = fromModCxt(Cxt);
+    return ModInst->getGlobalNum() - ModInst->getGlobalImportNum();
+  }
+  return 0;
+}
+
+WASMEDGE_CAPI_EXPORT WasmEdge_GlobalInstanceContext *
+WasmEdge_ModuleInstanceGetDefinedGlobal(
+    const WasmEdge_ModuleInstanceContext *Cxt, WasmEdge_StoreContext *StoreCxt,
+    const uint32_t Idx) {
+  if (Cxt && StoreCxt) {
+    const auto *ModInst = fromModCxt(Cxt);
+    const uint64_t GlobIdx =
+        static_cast<uint64_t>(Idx) + ModInst->getGlobalImportNum();
+    if (GlobIdx <= UINT32_MAX) {
+      if (auto Res = ModInst->getGlobal(static_cast<uint32_t>(GlobIdx))) {
+        return toGlobCxt(*Res);
+      }
+    }
+  }
+  return nullptr;
+}
+
+WASMEDGE_CAPI_EXPORT uint32_t WasmEdge_ModuleInstanceGetDefinedTableLength(
+    const WasmEdge_ModuleInstanceContext *Cxt) {
+  if (Cxt) {
+    const auto *ModInst = fromModCxt(Cxt);
+    return ModInst->getTableNum() - ModInst->getTableImportNum();
+  }
+  return 0;
+}
+
+WASMEDGE_CAPI_EXPORT WasmEdge_TableInstanceContext *
+WasmEdge_ModuleInstanceGetDefinedTable(
+    const WasmEdge_ModuleInstanceContext *Cxt, WasmEdge_StoreContext *StoreCxt,
+    const uint32_t Idx) {
+  if (Cxt && StoreCxt) {
+    const auto *ModInst = fromModCxt(Cxt);
+    const uint64_t TabIdx =
+        static_cast<uint64_t>(Idx) + ModInst->getTableImportNum();
+    if (TabIdx <= UINT32_MAX) {
+      if (auto Res = ModInst->getTable(static_cast<uint32_t>(TabIdx))) {
+        return toTabCxt(*Res);
+      }
+    }
+  }
+  return nullptr;
+}
+
+WASMEDGE_CAPI_EXPORT uint32_t WasmEdge_ModuleInstanceGetDataSegmentSize(
+    const WasmEdge_ModuleInstanceContext *Cxt, const uint32_t Idx) {
+  if (Cxt) {
+    if (auto Res = fromModCxt(Cxt)->getData(Idx)) {
+      return static_cast<uint32_t>((*Res)->getData().size());
+    }
+  }
+  return 0;
+}
+
 // <<<<<<<< WasmEdge module instance functions <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<
 
 // >>>>>>>> WasmEdge function instance functions >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>
@@ -2069,6 +2295,28 @@ WasmEdge_FunctionInstanceGetFunctionType(
   return nullptr;
 }
 
+WASMEDGE_CAPI_EXPORT void *WasmEdge_FunctionInstanceGetData(
+    const WasmEdge_FunctionInstanceContext *Cxt) {
+  if (Cxt && fromFuncCxt(Cxt)->isHostFunction()) {
+    if (auto *HostFunc =
+            dynamic_cast<CAPIHostFunc *>(&fromFuncCxt(Cxt)->getHostFunc())) {
+      return HostFunc->getData();
+    }
+  }
+  return nullptr;
+}
+
+WASMEDGE_CAPI_EXPORT void
+WasmEdge_HostFunctionSetFailureData(void *Data, void (*Finalizer)(void *)) {
+  if (Finalizer) {
+    Executor::Executor::setHostFailureData(
+        std::shared_ptr<void>(Data, Finalizer));
+  } else {
+    Executor::Executor::setHostFailureData(
+        std::shared_ptr<void>(Data, [](void *) {}));
+  }
+}
+
 WASMEDGE_CAPI_EXPORT void
 WasmEdge_FunctionInstanceDelete(WasmEdge_FunctionInstanceContext *Cxt) {
   delete fromFuncCxt(Cxt);
@@ -2163,7 +2411,9 @@ WasmEdge_TableInstanceDelete(WasmEdge_TableInstanceContext *Cxt) {
 
 WASMEDGE_CAPI_EXPORT WasmEdge_MemoryInstanceContext *
 WasmEdge_MemoryInstanceCreate(const WasmEdge_MemoryTypeContext *MemType) {
-  if (MemType) {
+  using WasmEdge::Runtime::Instance::MemoryInstance;
+  if (MemType && fromMemTypeCxt(MemType)->getLimit().getMin() <=
+                     MemoryInstance::kMaxPageCount) {
     return toMemCxt(new WasmEdge::Runtime::Instance::MemoryInstance(
         *fromMemTypeCxt(MemType)));
   }
@@ -2181,7 +2431,7 @@ WasmEdge_MemoryInstanceGetMemoryType(
 
 WASMEDGE_CAPI_EXPORT WasmEdge_Result WasmEdge_MemoryInstanceGetData(
     const WasmEdge_MemoryInstanceContext *Cxt, uint8_t *Data,
-    const uint32_t Offset, const uint32_t Length) {
+    const uint64_t Offset, const uint32_t Length) {
   return wrap([&]() { return fromMemCxt(Cxt)->getBytes(Offset, Length); },
               [&](auto &&Res) { std::copy_n((*Res).begin(), Length, Data); },
               Cxt, Data);
@@ -2189,7 +2439,7 @@ WASMEDGE_CAPI_EXPORT WasmEdge_Result WasmEdge_MemoryInstanceGetData(
 
 WASMEDGE_CAPI_EXPORT WasmEdge_Result WasmEdge_MemoryInstanceSetData(
     WasmEdge_MemoryInstanceContext *Cxt, const uint8_t *Data,
-    const uint32_t Offset, const uint32_t Length) {
+    const uint64_t Offset, const uint32_t Length) {
   return wrap(
       [&]() {
         return fromMemCxt(Cxt)->setBytes(genSpan(Data, Length), Offset, 0,
@@ -2200,7 +2450,7 @@ WASMEDGE_CAPI_EXPORT WasmEdge_Result WasmEdge_MemoryInstanceSetData(
 
 WASMEDGE_CAPI_EXPORT uint8_t *
 WasmEdge_MemoryInstanceGetPointer(WasmEdge_MemoryInstanceContext *Cxt,
-                                  const uint32_t Offset,
+                                  const uint64_t Offset,
                                   const uint32_t Length) {
   if (Cxt) {
     return fromMemCxt(Cxt)->getPointer<uint8_t *>(Offset, Length);
@@ -2209,7 +2459,7 @@ WasmEdge_MemoryInstanceGetPointer(WasmEdge_MemoryInstanceContext *Cxt,
 }
 
 WASMEDGE_CAPI_EXPORT const uint8_t *WasmEdge_MemoryInstanceGetPointerConst(
-    const WasmEdge_MemoryInstanceContext *Cxt, const uint32_t Offset,
+    const WasmEdge_MemoryInstanceContext *Cxt, const uint64_t Offset,
     const uint32_t Length) {
   if (Cxt) {
     return fromMemCxt(Cxt)->getPointer<const uint8_t *>(Offset, Length);
@@ -2217,7 +2467,7 @@ WASMEDGE_CAPI_EXPORT const uint8_t *WasmEdge_MemoryInstanceGetPointerConst(
   return nullptr;
 }
 
-WASMEDGE_CAPI_EXPORT uint32_t
+WASMEDGE_CAPI_EXPORT uint64_t
 WasmEdge_MemoryInstanceGetPageSize(const WasmEdge_MemoryInstanceContext *Cxt) {
   if (Cxt) {
     return fromMemCxt(Cxt)->getPageSize();
@@ -2226,7 +2476,7 @@ WasmEdge_MemoryInstanceGetPageSize(const WasmEdge_MemoryInstanceContext *Cxt) {
 }
 
 WASMEDGE_CAPI_EXPORT WasmEdge_Result WasmEdge_MemoryInstanceGrowPage(
-    WasmEdge_MemoryInstanceContext *Cxt, const uint32_t Page) {
+    WasmEdge_MemoryInstanceContext *Cxt, const uint64_t Page) {
   return wrap(
       [&]() -> WasmEdge::Expect<void> {
         if (fromMemCxt(Cxt)->growPage(Page)) {
@@ -2369,6 +2619,23 @@ WasmEdge_ImportObjectWASIGetExitCode(WasmEdge_ImportObjectContext *Cxt) {
   return WasiMod->getEnv().getExitCode();
 }
 
+WASMEDGE_CAPI_EXPORT WasmEdge_Result WasmEdge_ImportObjectWASIRedirectStdio(
+    WasmEdge_ImportObjectContext *Cxt, const int32_t StdIn,
+    const int32_t StdOut, const int32_t StdErr) {
+  if (!Cxt) {
+    return genWasmEdge_Result(ErrCode::WrongVMWorkflow);
+  }
+  auto *WasiMod =
+      dynamic_cast<WasmEdge::Host::WasiModule *>(fromImpObjCxt(Cxt));
+  if (!WasiMod) {
+    return genWasmEdge_Result(ErrCode::WrongVMWorkflow);
+  }
+  if (!WasiMod->getEnv().redirectStdio(StdIn, StdOut, StdErr)) {
+    return genWasmEdge_Result(ErrCode::RuntimeError);
+  }
+  return genWasmEdge_Result(ErrCode::Success);
+}
+
 WASMEDGE_CAPI_EXPORT WasmEdge_ImportObjectContext *
 WasmEdge_ImportObjectCreateWasmEdgeProcess(const char *const *AllowedCmds,
                                            const uint32_t CmdsLen,
@@ -2447,6 +2714,15 @@ WasmEdge_ImportObjectAddMemory(WasmEdge_ImportObjectContext *Cxt,
   }
 }
 
+WASMEDGE_CAPI_EXPORT void WasmEdge_ImportObjectAddSharedMemory(
+    WasmEdge_ImportObjectContext *Cxt, const WasmEdge_String Name,
+    WasmEdge_MemoryInstanceContext *MemoryCxt) {
+  if (Cxt && MemoryCxt && fromMemCxt(MemoryCxt)->isShared()) {
+    fromImpObjCxt(Cxt)->addSharedMemory(genStrView(Name),
+                                        fromMemCxt(MemoryCxt));
+  }
+}
+
 WASMEDGE_CAPI_EXPORT void
 WasmEdge_ImportObjectAddGlobal(WasmEdge_ImportObjectContext *Cxt,
                                const WasmEdge_String Name,
@@ -2505,6 +2781,23 @@ WASMEDGE_CAPI_EXPORT WasmEdge_Result WasmEdge_AsyncGet(
       [&](auto Res) { fillWasmEdge_ValueArr(*Res, Returns, ReturnLen); }, Cxt);
 }
 
+WASMEDGE_CAPI_EXPORT uint32_t WasmEdge_AsyncGetBacktrace(
+    WasmEdge_Async *Cxt, WasmEdge_BacktraceFrame *Frames, const uint32_t Len) {
+  if (Cxt) {
+    return fillBacktrace(Cxt->Async.getExecutionFailure().Backtrace, Frames,
+                         Len);
+  }
+  return 0;
+}
+
+WASMEDGE_CAPI_EXPORT const void *
+WasmEdge_AsyncGetHostFailureData(WasmEdge_Async *Cxt) {
+  if (Cxt) {
+    return Cxt->Async.getExecutionFailure().HostData.get();
+  }
+  return nullptr;
+}
+
 WASMEDGE_CAPI_EXPORT void WasmEdge_AsyncDelete(WasmEdge_Async *Cxt) {
   delete Cxt;
 }
@@ -2712,6 +3005,20 @@ WASMEDGE_CAPI_EXPORT WasmEdge_Result WasmEdge_VMExecuteRegistered(
       Cxt);
 }
 
+WASMEDGE_CAPI_EXPORT WasmEdge_Result WasmEdge_VMExecuteFunction(
+    WasmEdge_VMContext *Cxt, const WasmEdge_FunctionInstanceContext *FuncCxt,
+    const WasmEdge_Value *Params, const uint32_t ParamLen,
+    WasmEdge_Value *Returns, const uint32_t ReturnLen) {
+  auto ParamPair = genParamPair(Params, ParamLen);
+  return wrap(
+      [&]() {
+        return Cxt->VM.execute(*fromFuncCxt(FuncCxt), ParamPair.first,
+                               ParamPair.second);
+      },
+      [&](auto &&Res) { fillWasmEdge_ValueArr(*Res, Returns, ReturnLen); }, Cxt,
+      FuncCxt);
+}
+
 WASMEDGE_CAPI_EXPORT WasmEdge_Async *
 WasmEdge_VMAsyncExecute(WasmEdge_VMContext *Cxt, const WasmEdge_String FuncName,
                         const WasmEdge_Value *Params, const uint32_t ParamLen) {
@@ -2837,6 +3144,23 @@ WasmEdge_VMGetStatisticsContext(WasmEdge_VMContext *Cxt) {
   return nullptr;
 }
 
+WASMEDGE_CAPI_EXPORT uint32_t
+WasmEdge_VMGetBacktrace(const WasmEdge_VMContext *Cxt,
+                        WasmEdge_BacktraceFrame *Frames, const uint32_t Len) {
+  if (Cxt) {
+    return fillBacktrace(Cxt->VM.getExecutionFailure().Backtrace, Frames, Len);
+  }
+  return 0;
+}
+
+WASMEDGE_CAPI_EXPORT const void *
+WasmEdge_VMGetHostFailureData(const WasmEdge_VMContext *Cxt) {
+  if (Cxt) {
+    return Cxt->VM.getExecutionFailure().HostData.get();
+  }
+  return nullptr;
+}
+
 WASMEDGE_CAPI_EXPORT void WasmEdge_VMDelete(WasmEdge_VMContext *Cxt) {
   delete Cxt;
 }
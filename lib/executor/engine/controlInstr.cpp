@@ -72,7 +72,7 @@ Expect<void> Executor::runBrTableOp(Runtime::StackManager &StackMgr,
 Expect<void> Executor::runReturnOp(Runtime::StackManager &StackMgr,
                                    AST::InstrView::iterator &PC) noexcept {
   // Check stop token
-  if (unlikely(StopToken.exchange(0, std::memory_order_relaxed))) {
+  if (unlikely(consumeStop())) {
     spdlog::error(ErrCode::Interrupted);
     return Unexpect(ErrCode::Interrupted);
   }
@@ -4,6 +4,7 @@
 #include "executor/executor.h"
 
 #include <array>
+#include <atomic>
 #include <cstdint>
 #include <cstring>
 
@@ -21,6 +22,15 @@ Executor::runFunction(Runtime::StoreManager &StoreMgr,
                       Runtime::StackManager &StackMgr,
                       const Runtime::Instance::FunctionInstance &Func,
                       Span<const ValVariant> Params) {
+  // Register the current thread if it is not registered to this executor.
+  if (This != this) {
+    newThread();
+  }
+
+  // Clear the failure of the last execution on the current thread.
+  Failure = ExecutionFailure{};
+  PendingHostData.reset();
+
   // Set start time.
   if (Stat && Conf.getStatisticsConfigure().isTimeMeasuring()) {
     Stat->startRecordWasm();
@@ -309,6 +319,209 @@ Expect<void> Executor::execute(Runtime::StoreManager &StoreMgr,
       return runMemoryFillOp(
           StackMgr, *getMemInstByIdx(StackMgr, Instr.getTargetIndex()), Instr);
 
+    // Atomic Instructions
+    case OpCode::Memory__atomic__notify:
+      return runAtomicNotifyOp(
+          StackMgr, *getMemInstByIdx(StackMgr, Instr.getTargetIndex()), Instr);
+    case OpCode::Memory__atomic__wait32:
+      return runAtomicWaitOp<uint32_t>(
+          StackMgr, *getMemInstByIdx(StackMgr, Instr.getTargetIndex()), Instr);
+    case OpCode::Memory__atomic__wait64:
+      return runAtomicWaitOp<uint64_t>(
+          StackMgr, *getMemInstByIdx(StackMgr, Instr.getTargetIndex()), Instr);
+    case OpCode::Atomic__fence:
+      std::atomic_thread_fence(std::memory_order_seq_cst);
+      return {};
+    case OpCode::I32__atomic__load:
+      return runAtomicLoadOp<uint32_t, uint32_t>(
+          StackMgr, *getMemInstByIdx(StackMgr, Instr.getTargetIndex()), Instr);
+    case OpCode::I64__atomic__load:
+      return runAtomicLoadOp<uint64_t, uint64_t>(
+          StackMgr, *getMemInstByIdx(StackMgr, Instr.getTargetIndex()), Instr);
+    case OpCode::I32__atomic__load8_u:
+      return runAtomicLoadOp<uint32_t, uint8_t>(
+          StackMgr, *getMemInstByIdx(StackMgr, Instr.getTargetIndex()), Instr);
+    case OpCode::I32__atomic__load16_u:
+      return runAtomicLoadOp<uint32_t, uint16_t>(
+          StackMgr, *getMemInstByIdx(StackMgr, Instr.getTargetIndex()), Instr);
+    case OpCode::I64__atomic__load8_u:
+      return runAtomicLoadOp<uint64_t, uint8_t>(
+          StackMgr, *getMemInstByIdx(StackMgr, Instr.getTargetIndex()), Instr);
+    case OpCode::I64__atomic__load16_u:
+      return runAtomicLoadOp<uint64_t, uint16_t>(
+          StackMgr, *getMemInstByIdx(StackMgr, Instr.getTargetIndex()), Instr);
+    case OpCode::I64__atomic__load32_u:
+      return runAtomicLoadOp<uint64_t, uint32_t>(
+          StackMgr, *getMemInstByIdx(StackMgr, Instr.getTargetIndex()), Instr);
+    case OpCode::I32__atomic__store:
+      return runAtomicStoreOp<uint32_t, uint32_t>(
+          StackMgr, *getMemInstByIdx(StackMgr, Instr.getTargetIndex()), Instr);
+    case OpCode::I64__atomic__store:
+      return runAtomicStoreOp<uint64_t, uint64_t>(
+          StackMgr, *getMemInstByIdx(StackMgr, Instr.getTargetIndex()), Instr);
+    case OpCode::I32__atomic__store8:
+      return runAtomicStoreOp<uint32_t, uint8_t>(
+          StackMgr, *getMemInstByIdx(StackMgr, Instr.getTargetIndex()), Instr);
+    case OpCode::I32__atomic__store16:
+      return runAtomicStoreOp<uint32_t, uint16_t>(
+          StackMgr, *getMemInstByIdx(StackMgr, Instr.getTargetIndex()), Instr);
+    case OpCode::I64__atomic__store8:
+      return runAtomicStoreOp<uint64_t, uint8_t>(
+          StackMgr, *getMemInstByIdx(StackMgr, Instr.getTargetIndex()), Instr);
+    case OpCode::I64__atomic__store16:
+      return runAtomicStoreOp<uint64_t, uint16_t>(
+          StackMgr, *getMemInstByIdx(StackMgr, Instr.getTargetIndex()), Instr);
+    case OpCode::I64__atomic__store32:
+      return runAtomicStoreOp<uint64_t, uint32_t>(
+          StackMgr, *getMemInstByIdx(StackMgr, Instr.getTargetIndex()), Instr);
+    case OpCode::I32__atomic__rmw__add:
+      return runAtomicRmwOp<uint32_t, uint32_t, detail::AtomicAdd>(
+          StackMgr, *getMemInstByIdx(StackMgr, Instr.getTargetIndex()), Instr);
+    case OpCode::I64__atomic__rmw__add:
+      return runAtomicRmwOp<uint64_t, uint64_t, detail::AtomicAdd>(
+          StackMgr, *getMemInstByIdx(StackMgr, Instr.getTargetIndex()), Instr);
+    case OpCode::I32__atomic__rmw8__add_u:
+      return runAtomicRmwOp<uint32_t, uint8_t, detail::AtomicAdd>(
+          StackMgr, *getMemInstByIdx(StackMgr, Instr.getTargetIndex()), Instr);
+    case OpCode::I32__atomic__rmw16__add_u:
+      return runAtomicRmwOp<uint32_t, uint16_t, detail::AtomicAdd>(
+          StackMgr, *getMemInstByIdx(StackMgr, Instr.getTargetIndex()), Instr);
+    case OpCode::I64__atomic__rmw8__add_u:
+      return runAtomicRmwOp<uint64_t, uint8_t, detail::AtomicAdd>(
+          StackMgr, *getMemInstByIdx(StackMgr, Instr.getTargetIndex()), Instr);
+    case OpCode::I64__atomic__rmw16__add_u:
+      return runAtomicRmwOp<uint64_t, uint16_t, detail::AtomicAdd>(
+          StackMgr, *getMemInstByIdx(StackMgr, Instr.getTargetIndex()), Instr);
+    case OpCode::I64__atomic__rmw32__add_u:
+      return runAtomicRmwOp<uint64_t, uint32_t, detail::AtomicAdd>(
+          StackMgr, *getMemInstByIdx(StackMgr, Instr.getTargetIndex()), Instr);
+    case OpCode::I32__atomic__rmw__sub:
+      return runAtomicRmwOp<uint32_t, uint32_t, detail::AtomicSub>(
+          StackMgr, *getMemInstByIdx(StackMgr, Instr.getTargetIndex()), Instr);
+    case OpCode::I64__atomic__rmw__sub:
+      return runAtomicRmwOp<uint64_t, uint64_t, detail::AtomicSub>(
+          StackMgr, *getMemInstByIdx(StackMgr, Instr.getTargetIndex()), Instr);
+    case OpCode::I32__atomic__rmw8__sub_u:
+      return runAtomicRmwOp<uint32_t, uint8_t, detail::AtomicSub>(
+          StackMgr, *getMemInstByIdx(StackMgr, Instr.getTargetIndex()), Instr);
+    case OpCode::I32__atomic__rmw16__sub_u:
+      return runAtomicRmwOp<uint32_t, uint16_t, detail::AtomicSub>(
+          StackMgr, *getMemInstByIdx(StackMgr, Instr.getTargetIndex()), Instr);
+    case OpCode::I64__atomic__rmw8__sub_u:
+      return runAtomicRmwOp<uint64_t, uint8_t, detail::AtomicSub>(
+          StackMgr, *getMemInstByIdx(StackMgr, Instr.getTargetIndex()), Instr);
+    case OpCode::I64__atomic__rmw16__sub_u:
+      return runAtomicRmwOp<uint64_t, uint16_t, detail::AtomicSub>(
+          StackMgr, *getMemInstByIdx(StackMgr, Instr.getTargetIndex()), Instr);
+    case OpCode::I64__atomic__rmw32__sub_u:
+      return runAtomicRmwOp<uint64_t, uint32_t, detail::AtomicSub>(
+          StackMgr, *getMemInstByIdx(StackMgr, Instr.getTargetIndex()), Instr);
+    case OpCode::I32__atomic__rmw__and:
+      return runAtomicRmwOp<uint32_t, uint32_t, detail::AtomicAnd>(
+          StackMgr, *getMemInstByIdx(StackMgr, Instr.getTargetIndex()), Instr);
+    case OpCode::I64__atomic__rmw__and:
+      return runAtomicRmwOp<uint64_t, uint64_t, detail::AtomicAnd>(
+          StackMgr, *getMemInstByIdx(StackMgr, Instr.getTargetIndex()), Instr);
+    case OpCode::I32__atomic__rmw8__and_u:
+      return runAtomicRmwOp<uint32_t, uint8_t, detail::AtomicAnd>(
+          StackMgr, *getMemInstByIdx(StackMgr, Instr.getTargetIndex()), Instr);
+    case OpCode::I32__atomic__rmw16__and_u:
+      return runAtomicRmwOp<uint32_t, uint16_t, detail::AtomicAnd>(
+          StackMgr, *getMemInstByIdx(StackMgr, Instr.getTargetIndex()), Instr);
+    case OpCode::I64__atomic__rmw8__and_u:
+      return runAtomicRmwOp<uint64_t, uint8_t, detail::AtomicAnd>(
+          StackMgr, *getMemInstByIdx(StackMgr, Instr.getTargetIndex()), Instr);
+    case OpCode::I64__atomic__rmw16__and_u:
+      return runAtomicRmwOp<uint64_t, uint16_t, detail::AtomicAnd>(
+          StackMgr, *getMemInstByIdx(StackMgr, Instr.getTargetIndex()), Instr);
+    case OpCode::I64__atomic__rmw32__and_u:
+      return runAtomicRmwOp<uint64_t, uint32_t, detail::AtomicAnd>(
+          StackMgr, *getMemInstByIdx(StackMgr, Instr.getTargetIndex()), Instr);
+    case OpCode::I32__atomic__rmw__or:
+      return runAtomicRmwOp<uint32_t, uint32_t, detail::AtomicOr>(
+          StackMgr, *getMemInstByIdx(StackMgr, Instr.getTargetIndex()), Instr);
+    case OpCode::I64__atomic__rmw__or:
+      return runAtomicRmwOp<uint64_t, uint64_t, detail::AtomicOr>(
+          StackMgr, *getMemInstByIdx(StackMgr, Instr.getTargetIndex()), Instr);
+    case OpCode::I32__atomic__rmw8__or_u:
+      return runAtomicRmwOp<uint32_t, uint8_t, detail::AtomicOr>(
+          StackMgr, *getMemInstByIdx(StackMgr, Instr.getTargetIndex()), Instr);
+    case OpCode::I32__atomic__rmw16__or_u:
+      return runAtomicRmwOp<uint32_t, uint16_t, detail::AtomicOr>(
+          StackMgr, *getMemInstByIdx(StackMgr, Instr.getTargetIndex()), Instr);
+    case OpCode::I64__atomic__rmw8__or_u:
+      return runAtomicRmwOp<uint64_t, uint8_t, detail::AtomicOr>(
+          StackMgr, *getMemInstByIdx(StackMgr, Instr.getTargetIndex()), Instr);
+    case OpCode::I64__atomic__rmw16__or_u:
+      return runAtomicRmwOp<uint64_t, uint16_t, detail::AtomicOr>(
+          StackMgr, *getMemInstByIdx(StackMgr, Instr.getTargetIndex()), Instr);
+    case OpCode::I64__atomic__rmw32__or_u:
+      return runAtomicRmwOp<uint64_t, uint32_t, detail::AtomicOr>(
+          StackMgr, *getMemInstByIdx(StackMgr, Instr.getTargetIndex()), Instr);
+    case OpCode::I32__atomic__rmw__xor:
+      return runAtomicRmwOp<uint32_t, uint32_t, detail::AtomicXor>(
+          StackMgr, *getMemInstByIdx(StackMgr, Instr.getTargetIndex()), Instr);
+    case OpCode::I64__atomic__rmw__xor:
+      return runAtomicRmwOp<uint64_t, uint64_t, detail::AtomicXor>(
+          StackMgr, *getMemInstByIdx(StackMgr, Instr.getTargetIndex()), Instr);
+    case OpCode::I32__atomic__rmw8__xor_u:
+      return runAtomicRmwOp<uint32_t, uint8_t, detail::AtomicXor>(
+          StackMgr, *getMemInstByIdx(StackMgr, Instr.getTargetIndex()), Instr);
+    case OpCode::I32__atomic__rmw16__xor_u:
+      return runAtomicRmwOp<uint32_t, uint16_t, detail::AtomicXor>(
+          StackMgr, *getMemInstByIdx(StackMgr, Instr.getTargetIndex()), Instr);
+    case OpCode::I64__atomic__rmw8__xor_u:
+      return runAtomicRmwOp<uint64_t, uint8_t, detail::AtomicXor>(
+          StackMgr, *getMemInstByIdx(StackMgr, Instr.getTargetIndex()), Instr);
+    case OpCode::I64__atomic__rmw16__xor_u:
+      return runAtomicRmwOp<uint64_t, uint16_t, detail::AtomicXor>(
+          StackMgr, *getMemInstByIdx(StackMgr, Instr.getTargetIndex()), Instr);
+    case OpCode::I64__atomic__rmw32__xor_u:
+      return runAtomicRmwOp<uint64_t, uint32_t, detail::AtomicXor>(
+          StackMgr, *getMemInstByIdx(StackMgr, Instr.getTargetIndex()), Instr);
+    case OpCode::I32__atomic__rmw__xchg:
+      return runAtomicRmwOp<uint32_t, uint32_t, detail::AtomicXchg>(
+          StackMgr, *getMemInstByIdx(StackMgr, Instr.getTargetIndex()), Instr);
+    case OpCode::I64__atomic__rmw__xchg:
+      return runAtomicRmwOp<uint64_t, uint64_t, detail::AtomicXchg>(
+          StackMgr, *getMemInstByIdx(StackMgr, Instr.getTargetIndex()), Instr);
+    case OpCode::I32__atomic__rmw8__xchg_u:
+      return runAtomicRmwOp<uint32_t, uint8_t, detail::AtomicXchg>(
+          StackMgr, *getMemInstByIdx(StackMgr, Instr.getTargetIndex()), Instr);
+    case OpCode::I32__atomic__rmw16__xchg_u:
+      return runAtomicRmwOp<uint32_t, uint16_t, detail::AtomicXchg>(
+          StackMgr, *getMemInstByIdx(StackMgr, Instr.getTargetIndex()), Instr);
+    case OpCode::I64__atomic__rmw8__xchg_u:
+      return runAtomicRmwOp<uint64_t, uint8_t, detail::AtomicXchg>(
+          StackMgr, *getMemInstByIdx(StackMgr, Instr.getTargetIndex()), Instr);
+    case OpCode::I64__atomic__rmw16__xchg_u:
+      return runAtomicRmwOp<uint64_t, uint16_t, detail::AtomicXchg>(
+          StackMgr, *getMemInstByIdx(StackMgr, Instr.getTargetIndex()), Instr);
+    case OpCode::I64__atomic__rmw32__xchg_u:
+      return runAtomicRmwOp<uint64_t, uint32_t, detail::AtomicXchg>(
+          StackMgr, *getMemInstByIdx(StackMgr, Instr.getTargetIndex()), Instr);
+    case OpCode::I32__atomic__rmw__cmpxchg:
+      return runAtomicCmpxchgOp<uint32_t, uint32_t>(
+          StackMgr, *getMemInstByIdx(StackMgr, Instr.getTargetIndex()), Instr);
+    case OpCode::I64__atomic__rmw__cmpxchg:
+      return runAtomicCmpxchgOp<uint64_t, uint64_t>(
+          StackMgr, *getMemInstByIdx(StackMgr, Instr.getTargetIndex()), Instr);
+    case OpCode::I32__atomic__rmw8__cmpxchg_u:
+      return runAtomicCmpxchgOp<uint32_t, uint8_t>(
+          StackMgr, *getMemInstByIdx(StackMgr, Instr.getTargetIndex()), Instr);
+    case OpCode::I32__atomic__rmw16__cmpxchg_u:
+      return runAtomicCmpxchgOp<uint32_t, uint16_t>(
+          StackMgr, *getMemInstByIdx(StackMgr, Instr.getTargetIndex()), Instr);
+    case OpCode::I64__atomic__rmw8__cmpxchg_u:
+      return runAtomicCmpxchgOp<uint64_t, uint8_t>(
+          StackMgr, *getMemInstByIdx(StackMgr, Instr.getTargetIndex()), Instr);
+    case OpCode::I64__atomic__rmw16__cmpxchg_u:
+      return runAtomicCmpxchgOp<uint64_t, uint16_t>(
+          StackMgr, *getMemInstByIdx(StackMgr, Instr.getTargetIndex()), Instr);
+    case OpCode::I64__atomic__rmw32__cmpxchg_u:
+      return runAtomicCmpxchgOp<uint64_t, uint32_t>(
+          StackMgr, *getMemInstByIdx(StackMgr, Instr.getTargetIndex()), Instr);
+
     // Const numeric instructions
     case OpCode::I32__const:
     case OpCode::I64__const:
@@ -1625,11 +1838,15 @@ Expect<void> Executor::execute(Runtime::StoreManager &StoreMgr,
           const AST::Instruction &Instr = *PC;
           spdlog::error(
               ErrInfo::InfoInstruction(Instr.getOpCode(), Instr.getOffset()));
+          recordBacktrace(StackMgr, PC);
           return Unexpect(ErrCode::CostLimitExceeded);
         }
       }
     }
     if (auto Res = Dispatch(); !Res) {
+      if (Res.error() != ErrCode::Terminated) {
+        recordBacktrace(StackMgr, PC);
+      }
       return Unexpect(Res);
     }
     PC++;
@@ -9,8 +9,12 @@ namespace Executor {
 Expect<void>
 Executor::runMemorySizeOp(Runtime::StackManager &StackMgr,
                           Runtime::Instance::MemoryInstance &MemInst) {
-  // Push SZ = page size to stack.
-  StackMgr.push(MemInst.getPageSize());
+  // Push SZ = page size to stack in the index type of the memory.
+  if (MemInst.is64()) {
+    StackMgr.push(static_cast<uint64_t>(MemInst.getPageSize()));
+  } else {
+    StackMgr.push(MemInst.getPageSize());
+  }
   return {};
 }
 
@@ -18,14 +22,22 @@ Expect<void>
 Executor::runMemoryGrowOp(Runtime::StackManager &StackMgr,
                           Runtime::Instance::MemoryInstance &MemInst) {
   // Pop N for growing page size.
-  uint32_t &N = StackMgr.getTop().get<uint32_t>();
+  ValVariant &N = StackMgr.getTop();
 
-  // Grow page and push result.
-  const uint32_t CurrPageSize = static_cast<uint32_t>(MemInst.getPageSize());
-  if (MemInst.growPage(N)) {
-    N = CurrPageSize;
+  // Grow page and push result in the index type of the memory.
+  const uint32_t CurrPageSize = MemInst.getPageSize();
+  if (MemInst.is64()) {
+    if (MemInst.growPage(N.get<uint64_t>())) {
+      N.emplace<uint64_t>(CurrPageSize);
+    } else {
+      N.emplace<uint64_t>(static_cast<uint64_t>(-1));
+    }
   } else {
-    N = static_cast<uint32_t>(-1);
+    if (MemInst.growPage(N.get<uint32_t>())) {
+      N.emplace<uint32_t>(CurrPageSize);
+    } else {
+      N.emplace<uint32_t>(static_cast<uint32_t>(-1));
+    }
   }
   return {};
 }
@@ -36,7 +48,7 @@ Expect<void> Executor::runMemoryInitOp(
   // Pop the length, source, and destination from stack.
   uint32_t Len = StackMgr.pop().get<uint32_t>();
   uint32_t Src = StackMgr.pop().get<uint32_t>();
-  uint32_t Dst = StackMgr.pop().get<uint32_t>();
+  uint64_t Dst = getIndexTypeValue(MemInst, StackMgr.pop());
 
   // Replace mem[Dst : Dst + Len] with data[Src : Src + Len].
   if (auto Res = MemInst.setBytes(DataInst.getData(), Dst, Src, Len)) {
@@ -60,10 +72,14 @@ Executor::runMemoryCopyOp(Runtime::StackManager &StackMgr,
                           Runtime::Instance::MemoryInstance &MemInstDst,
                           Runtime::Instance::MemoryInstance &MemInstSrc,
                           const AST::Instruction &Instr) {
-  // Pop the length, source, and destination from stack.
-  uint32_t Len = StackMgr.pop().get<uint32_t>();
-  uint32_t Src = StackMgr.pop().get<uint32_t>();
-  uint32_t Dst = StackMgr.pop().get<uint32_t>();
+  // Pop the length, source, and destination from stack. The length is 64-bit
+  // only if both of the memories are 64-bit.
+  uint64_t Len = StackMgr.pop().get<uint64_t>();
+  if (!MemInstDst.is64() || !MemInstSrc.is64()) {
+    Len = static_cast<uint32_t>(Len);
+  }
+  uint64_t Src = getIndexTypeValue(MemInstSrc, StackMgr.pop());
+  uint64_t Dst = getIndexTypeValue(MemInstDst, StackMgr.pop());
 
   // Replace mem[Dst : Dst + Len] with mem[Src : Src + Len].
   if (auto Data = MemInstSrc.getBytes(Src, Len)) {
@@ -86,9 +102,9 @@ Executor::runMemoryFillOp(Runtime::StackManager &StackMgr,
                           Runtime::Instance::MemoryInstance &MemInst,
                           const AST::Instruction &Instr) {
   // Pop the length, value, and offset from stack.
-  uint32_t Len = StackMgr.pop().get<uint32_t>();
+  uint64_t Len = getIndexTypeValue(MemInst, StackMgr.pop());
   uint8_t Val = static_cast<uint8_t>(StackMgr.pop().get<uint32_t>());
-  uint32_t Off = StackMgr.pop().get<uint32_t>();
+  uint64_t Off = getIndexTypeValue(MemInst, StackMgr.pop());
 
   // Fill data with Val.
   if (auto Res = MemInst.fillBytes(Val, Off, Len)) {
@@ -100,5 +116,22 @@ Executor::runMemoryFillOp(Runtime::StackManager &StackMgr,
   }
 }
 
+Expect<void>
+Executor::runAtomicNotifyOp(Runtime::StackManager &StackMgr,
+                            Runtime::Instance::MemoryInstance &MemInst,
+                            const AST::Instruction &Instr) {
+  const uint32_t Count = StackMgr.pop().get<uint32_t>();
+  ValVariant &Val = StackMgr.getTop();
+  if (auto Res = detail::calcAtomicAddress(
+          MemInst, Instr, getIndexTypeValue(MemInst, Val), sizeof(uint32_t))) {
+    // There are no waiters on the non-shared memory.
+    Val.emplace<uint32_t>(MemInst.isShared() ? MemInst.atomicNotify(*Res, Count)
+                                             : UINT32_C(0));
+  } else {
+    return Unexpect(Res);
+  }
+  return {};
+}
+
 } // namespace Executor
 } // namespace WasmEdge
@@ -13,6 +13,8 @@ thread_local Executor *Executor::This = nullptr;
 thread_local Runtime::StoreManager *Executor::CurrentStore = nullptr;
 thread_local Runtime::StackManager *Executor::CurrentStack = nullptr;
 thread_local Executor::ExecutionContextStruct Executor::ExecutionContext;
+thread_local Executor::ExecutionFailure Executor::Failure;
+thread_local std::shared_ptr<void> Executor::PendingHostData;
 
 template <typename RetT, typename... ArgsT>
 struct Executor::ProxyHelper<Expect<RetT> (Executor::*)(
@@ -52,6 +52,11 @@ Expect<void> Executor::registerModule(Runtime::StoreManager &StoreMgr,
     ModInst->addMemory(Inst);
     ModInst->exportMemory(Mem.first, ModInst->getMemNum() - 1);
   }
+  for (auto &Mem : Obj.getSharedMems()) {
+    auto *Inst = StoreMgr.importHostMemory(*Mem.second);
+    ModInst->addMemory(Inst);
+    ModInst->exportMemory(Mem.first, ModInst->getMemNum() - 1);
+  }
   for (auto &Glob : Obj.getGlobals()) {
     auto *Inst = StoreMgr.importHostGlobal(*Glob.second.get());
     ModInst->addGlobal(Inst);
@@ -21,7 +21,7 @@ Executor::enterFunction(Runtime::StoreManager &StoreMgr,
   // RetIt: the return position when the entered function returns.
 
   // Check if the interruption occurs.
-  if (unlikely(StopToken.exchange(0, std::memory_order_relaxed))) {
+  if (unlikely(consumeStop())) {
     spdlog::error(ErrCode::Interrupted);
     return Unexpect(ErrCode::Interrupted);
   }
@@ -48,11 +48,12 @@ Executor::enterFunction(Runtime::StoreManager &StoreMgr,
     }
 
     // Push frame.
-    StackMgr.pushFrame(nullptr,   // Host function instance don't have module
-                       RetIt,     // Return PC
-                       ArgsN,     // Only args, no locals in stack
-                       RetsN,     // Returns num
-                       IsTailCall // For tail-call
+    StackMgr.pushFrame(nullptr,    // Host function instance don't have module
+                       RetIt,      // Return PC
+                       ArgsN,      // Only args, no locals in stack
+                       RetsN,      // Returns num
+                       IsTailCall, // For tail-call
+                       &Func       // Function instance
     );
 
     // Do the statistics if the statistics turned on.
@@ -84,6 +85,8 @@ Executor::enterFunction(Runtime::StoreManager &StoreMgr,
       if (Ret.error() == ErrCode::ExecutionFailed) {
         spdlog::error(Ret.error());
       }
+      // Keep the data attached by the host function with the failure.
+      Failure.HostData = std::move(PendingHostData);
       return Unexpect(Ret);
     }
 
@@ -104,7 +107,8 @@ Executor::enterFunction(Runtime::StoreManager &StoreMgr,
                        RetIt,            // Return PC
                        ArgsN,            // Only args, no locals in stack
                        RetsN,            // Returns num
-                       IsTailCall        // For tail-call
+                       IsTailCall,       // For tail-call
+                       &Func             // Function instance
     );
 
     // Prepare arguments.
@@ -167,7 +171,8 @@ Executor::enterFunction(Runtime::StoreManager &StoreMgr,
                        RetIt - 1,                  // Return PC
                        ArgsN + Func.getLocalNum(), // Arguments num + local num
                        RetsN,                      // Returns num
-                       IsTailCall                  // For tail-call
+                       IsTailCall,                 // For tail-call
+                       &Func                       // Function instance
     );
 
     // For native function case, the continuation will be the start of the
@@ -176,12 +181,53 @@ Executor::enterFunction(Runtime::StoreManager &StoreMgr,
   }
 }
 
+void Executor::recordBacktrace(const Runtime::StackManager &StackMgr,
+                               AST::InstrView::iterator PC) {
+  std::vector<BacktraceFrame> Frames;
+  auto StackFrames = StackMgr.getFrames();
+  for (size_t I = StackFrames.size(); I > 0; --I) {
+    const auto *Func = StackFrames[I - 1].Func;
+    if (Func == nullptr) {
+      // The dummy frame at the bottom of the stack.
+      break;
+    }
+    BacktraceFrame Frame{"", UINT32_MAX, "", UINT32_MAX};
+    if (const auto *ModInst = Func->getModule()) {
+      Frame.ModuleName = ModInst->getModuleName();
+      for (uint32_t Idx = 0; Idx < ModInst->getFuncNum(); ++Idx) {
+        if (auto Res = ModInst->getFunc(Idx); Res && *Res == Func) {
+          Frame.FuncIdx = Idx;
+          break;
+        }
+      }
+      Frame.FuncName = ModInst->getFuncExports([&](const auto &FuncExp) {
+        for (const auto &[Name, Inst] : FuncExp) {
+          if (Inst == Func) {
+            return std::string(Name);
+          }
+        }
+        return std::string();
+      });
+    }
+    // Only the interpreted functions have the instruction offsets. The PC
+    // stays in the caller frame for the host and compiled functions.
+    if (!Func->isHostFunction() && !Func->isCompiledFunction()) {
+      Frame.Offset = PC->getOffset();
+      // The return PC of the frame is the call instruction in the caller.
+      PC = StackFrames[I - 1].From;
+    }
+    Frames.push_back(std::move(Frame));
+  }
+
+  Failure.Backtrace = std::move(Frames);
+}
+
 Expect<void> Executor::branchToLabel(Runtime::StackManager &StackMgr,
                                      uint32_t EraseBegin, uint32_t EraseEnd,
                                      int32_t PCOffset,
                                      AST::InstrView::iterator &PC) noexcept {
   // Check stop token
-  if (unlikely(StopToken.exchange(0, std::memory_order_relaxed))) {
+  if (unlikely(consumeStop())) {
     spdlog::error(ErrCode::Interrupted);
     return Unexpect(ErrCode::Interrupted);
   }
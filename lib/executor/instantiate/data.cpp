@@ -19,9 +19,12 @@ Expect<void> Executor::instantiate(Runtime::StoreManager &StoreMgr,
   // A frame with module is pushed into stack outside.
   // Instantiate data instances.
   for (const auto &DataSeg : DataSec.getContent()) {
-    uint32_t Offset = 0;
+    uint64_t Offset = 0;
     // Initialize memory if data mode is active.
     if (DataSeg.getMode() == AST::DataSegment::DataMode::Active) {
+      // Memory index should be 0. Checked in validation phase.
+      auto *MemInst = getMemInstByIdx(StackMgr, DataSeg.getIdx());
+      assuming(MemInst);
       // Run initialize expression.
       if (auto Res =
               runExpression(StoreMgr, StackMgr, DataSeg.getExpr().getInstrs());
@@ -30,18 +33,19 @@ Expect<void> Executor::instantiate(Runtime::StoreManager &StoreMgr,
         spdlog::error(ErrInfo::InfoAST(ASTNodeAttr::Seg_Data));
         return Unexpect(Res);
       }
-      Offset = StackMgr.pop().get<uint32_t>();
+      // The offset is in the index type of the memory.
+      if (MemInst->is64()) {
+        Offset = StackMgr.pop().get<uint64_t>();
+      } else {
+        Offset = StackMgr.pop().get<uint32_t>();
+      }
 
       // Check boundary unless ReferenceTypes or BulkMemoryOperations proposal
       // enabled.
       if (!Conf.hasProposal(Proposal::ReferenceTypes) &&
           !Conf.hasProposal(Proposal::BulkMemoryOperations)) {
-        // Memory index should be 0. Checked in validation phase.
-        auto *MemInst = getMemInstByIdx(StackMgr, DataSeg.getIdx());
         // Check data fits.
-        assuming(MemInst);
-        if (!MemInst->checkAccessBound(
-                Offset, static_cast<uint32_t>(DataSeg.getData().size()))) {
+        if (!MemInst->checkAccessBound(Offset, DataSeg.getData().size())) {
           spdlog::error(ErrCode::DataSegDoesNotFit);
           spdlog::error(ErrInfo::InfoAST(ASTNodeAttr::Seg_Data));
           return Unexpect(ErrCode::DataSegDoesNotFit);
@@ -75,12 +79,11 @@ Expect<void> Executor::initMemory(Runtime::StackManager &StackMgr,
 
       auto *DataInst = getDataInstByIdx(StackMgr, Idx);
       assuming(DataInst);
-      const uint32_t Off = DataInst->getOffset();
+      const uint64_t Off = DataInst->getOffset();
 
       // Replace mem[Off : Off + n] with data[0 : n].
-      if (auto Res = MemInst->setBytes(
-              DataInst->getData(), Off, 0,
-              static_cast<uint32_t>(DataInst->getData().size()));
+      if (auto Res = MemInst->setBytes(DataInst->getData(), Off, 0,
+                                       DataInst->getData().size());
           !Res) {
         spdlog::error(ErrInfo::InfoAST(ASTNodeAttr::Seg_Data));
         return Unexpect(Res);
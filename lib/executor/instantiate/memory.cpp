@@ -3,7 +3,11 @@
 
 #include "executor/executor.h"
 
+#include "common/errinfo.h"
+#include "common/log.h"
+
 #include <cstdint>
+#include <string>
 
 namespace WasmEdge {
 namespace Executor {
@@ -17,6 +21,16 @@ Expect<void> Executor::instantiate(Runtime::StoreManager &StoreMgr,
 
   // Iterate and istantiate memory types.
   for (const auto &MemType : MemSec.getContent()) {
+    // The memory64 modules needing more than 4 GiB are not supported.
+    if (MemType.getLimit().getMin() >
+        Runtime::Instance::MemoryInstance::kMaxPageCount) {
+      spdlog::error(ErrCode::InvalidMemPages);
+      spdlog::error(ErrInfo::InfoLimit(MemType.getLimit().hasMax(),
+                                       MemType.getLimit().getMin(),
+                                       MemType.getLimit().getMax()));
+      spdlog::error(ErrInfo::InfoAST(ASTNodeAttr::Type_Memory));
+      return Unexpect(ErrCode::InvalidMemPages);
+    }
     // Insert memory instance to store manager.
     Runtime::Instance::MemoryInstance *MemInst = nullptr;
     if (InsMode == InstantiateMode::Instantiate) {
@@ -26,6 +40,13 @@ Expect<void> Executor::instantiate(Runtime::StoreManager &StoreMgr,
       MemInst = StoreMgr.importMemory(
           MemType, Conf.getRuntimeConfigure().getMaxMemoryPage());
     }
+    // Consult the resource limiter of the store before growing.
+    MemInst->setGrowingCallback(
+        [&StoreMgr, ModName = std::string(ModInst.getModuleName()),
+         Idx = ModInst.getMemNum()](uint32_t Current, uint32_t Desired) {
+          return StoreMgr.checkGrowing(ExternalType::Memory, ModName, Idx,
+                                       Current, Desired);
+        });
     ModInst.addMemory(MemInst);
   }
   return {};
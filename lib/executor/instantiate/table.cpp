@@ -4,6 +4,7 @@
 #include "executor/executor.h"
 
 #include <cstdint>
+#include <string>
 
 namespace WasmEdge {
 namespace Executor {
@@ -21,6 +22,13 @@ Expect<void> Executor::instantiate(Runtime::StoreManager &StoreMgr,
     } else {
       TabInst = StoreMgr.importTable(TabType);
     }
+    // Consult the resource limiter of the store before growing.
+    TabInst->setGrowingCallback(
+        [&StoreMgr, ModName = std::string(ModInst.getModuleName()),
+         Idx = ModInst.getTableNum()](uint32_t Current, uint32_t Desired) {
+          return StoreMgr.checkGrowing(ExternalType::Table, ModName, Idx,
+                                       Current, Desired);
+        });
     ModInst.addTable(TabInst);
   }
   return {};
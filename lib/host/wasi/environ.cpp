@@ -51,24 +51,35 @@ static inline constexpr const __wasi_rights_t kNoInheritingRights =
 
 void Environ::init(Span<const std::string> Dirs, std::string ProgramName,
                    Span<const std::string> Args, Span<const std::string> Envs) {
+  // Drop the descriptors of the previous initialization.
+  FdMap.clear();
   {
     // Open dir for WASI environment.
     std::vector<std::shared_ptr<VINode>> PreopenedDirs;
     PreopenedDirs.reserve(Dirs.size());
-    for (const auto &Dir : Dirs) {
+    for (const auto &Preopen : Dirs) {
+      // The preopen can be followed by the `:readonly` mode, in both the
+      // `dir:readonly` and the `guest:host:readonly` forms.
+      std::string_view Dir = Preopen;
+      bool ReadOnly = false;
+      if (const auto ModePos = Dir.rfind(':');
+          ModePos != std::string_view::npos &&
+          Dir.substr(ModePos + 1) == "readonly"sv) {
+        ReadOnly = true;
+        Dir = Dir.substr(0, ModePos);
+      }
       const auto Pos = Dir.find(':');
-      std::string HostDir =
-          (Pos == std::string::npos) ? Dir : Dir.substr(Pos + 1);
+      std::string HostDir(
+          (Pos == std::string_view::npos) ? Dir : Dir.substr(Pos + 1));
       std::string GuestDir = VINode::canonicalGuest(
-          (Pos == std::string::npos) ? std::string_view(Dir)
-                                     : std::string_view(Dir).substr(0, Pos));
+          (Pos == std::string_view::npos) ? Dir : Dir.substr(0, Pos));
       if (GuestDir.size() == 0) {
         GuestDir = '/';
       }
-      if (auto Res =
-              VINode::bind(FS, kReadRights | kWriteRights | kCreateRights,
-                           kReadRights | kWriteRights | kCreateRights,
-                           std::move(GuestDir), std::move(HostDir));
+      const __wasi_rights_t Rights =
+          ReadOnly ? kReadRights : kReadRights | kWriteRights | kCreateRights;
+      if (auto Res = VINode::bind(FS, Rights, Rights, std::move(GuestDir),
+                                  std::move(HostDir));
           unlikely(!Res)) {
         spdlog::error("Bind guest directory failed:{}", Res.error());
         continue;
@@ -104,6 +115,40 @@ void Environ::init(Span<const std::string> Dirs, std::string ProgramName,
   ExitCode = 0;
 }
 
+WasiExpect<void> Environ::redirectStdio(int StdIn, int StdOut,
+                                        int StdErr) noexcept {
+  auto GetINode = [](int HostFd, INode (*Default)()) -> WasiExpect<INode> {
+    if (HostFd < 0) {
+      return Default();
+    }
+    return INode::fromFd(HostFd);
+  };
+  auto In = GetINode(StdIn, &INode::stdIn);
+  if (unlikely(!In)) {
+    return WasiUnexpect(In);
+  }
+  auto Out = GetINode(StdOut, &INode::stdOut);
+  if (unlikely(!Out)) {
+    return WasiUnexpect(Out);
+  }
+  auto Err = GetINode(StdErr, &INode::stdErr);
+  if (unlikely(!Err)) {
+    return WasiUnexpect(Err);
+  }
+
+  std::unique_lock Lock(FdMutex);
+  FdMap.insert_or_assign(
+      0, std::make_shared<VINode>(FS, std::move(*In), kStdInDefaultRights,
+                                  kNoInheritingRights));
+  FdMap.insert_or_assign(
+      1, std::make_shared<VINode>(FS, std::move(*Out), kStdOutDefaultRights,
+                                  kNoInheritingRights));
+  FdMap.insert_or_assign(
+      2, std::make_shared<VINode>(FS, std::move(*Err), kStdErrDefaultRights,
+                                  kNoInheritingRights));
+  return {};
+}
+
 void Environ::fini() noexcept {
   EnvironVariables.clear();
   Arguments.clear();
@@ -3,6 +3,7 @@
 
 #include "loader/loader.h"
 
+#include <algorithm>
 #include <cstdint>
 #include <utility>
 #include <vector>
@@ -19,7 +20,7 @@ Expect<OpCode> Loader::loadOpCode() {
     return Unexpect(B1);
   }
 
-  if (Payload == 0xFCU || Payload == 0xFDU) {
+  if (Payload == 0xFCU || Payload == 0xFDU || Payload == 0xFEU) {
     // 2-bytes OpCode case.
     if (auto B2 = FMgr.readU32()) {
       Payload <<= 8;
@@ -141,19 +142,45 @@ Expect<void> Loader::loadInstruction(AST::Instruction &Instr) {
     return {};
   };
 
-  auto readMemImmediate = [this, readU32, &Instr]() -> Expect<void> {
+  auto readU64 = [this](uint64_t &Dst) -> Expect<void> {
+    if (auto Res = FMgr.readU64()) {
+      Dst = *Res;
+    } else {
+      return logLoadError(Res.error(), FMgr.getLastOffset(),
+                          ASTNodeAttr::Instruction);
+    }
+    return {};
+  };
+
+  auto readMemImmediate = [this, readU32, readU64, &Instr]() -> Expect<void> {
     Instr.getTargetIndex() = 0;
-    if (auto Res = readU32(Instr.getMemoryAlign()); unlikely(!Res)) {
+    uint32_t Align = 0;
+    if (auto Res = readU32(Align); unlikely(!Res)) {
       return Unexpect(Res);
     }
-    if (auto Res = readU32(Instr.getMemoryOffset()); unlikely(!Res)) {
-      return Unexpect(Res);
+    // The offset is 64-bit for the Memory64 proposal. The offset of the 32-bit
+    // memories will be checked in the validation phase.
+    if (Conf.hasProposal(Proposal::Memory64)) {
+      if (auto Res = readU64(Instr.getMemoryOffset()); unlikely(!Res)) {
+        return Unexpect(Res);
+      }
+    } else {
+      uint32_t Offset = 0;
+      if (auto Res = readU32(Offset); unlikely(!Res)) {
+        return Unexpect(Res);
+      }
+      Instr.getMemoryOffset() = Offset;
     }
-    if (Conf.hasProposal(Proposal::MultiMemories) &&
-        Instr.getMemoryAlign() >= 64) {
-      Instr.getMemoryAlign() -= 64;
-      return readU32(Instr.getTargetIndex());
+    if (Conf.hasProposal(Proposal::MultiMemories) && Align >= 64) {
+      Align -= 64;
+      if (auto Res = readU32(Instr.getTargetIndex()); unlikely(!Res)) {
+        return Unexpect(Res);
+      }
     }
+    // The alignment larger than 31 is invalid and will be rejected in the
+    // validation phase.
+    Instr.getMemoryAlign() =
+        static_cast<uint8_t>(std::min(Align, UINT32_C(255)));
     return {};
   };
 
@@ -360,6 +387,79 @@ Expect<void> Loader::loadInstruction(AST::Instruction &Instr) {
   case OpCode::I64__store32:
     return readMemImmediate();
 
+  // Atomic Memory Instructions.
+  case OpCode::Memory__atomic__notify:
+  case OpCode::Memory__atomic__wait32:
+  case OpCode::Memory__atomic__wait64:
+  case OpCode::I32__atomic__load:
+  case OpCode::I64__atomic__load:
+  case OpCode::I32__atomic__load8_u:
+  case OpCode::I32__atomic__load16_u:
+  case OpCode::I64__atomic__load8_u:
+  case OpCode::I64__atomic__load16_u:
+  case OpCode::I64__atomic__load32_u:
+  case OpCode::I32__atomic__store:
+  case OpCode::I64__atomic__store:
+  case OpCode::I32__atomic__store8:
+  case OpCode::I32__atomic__store16:
+  case OpCode::I64__atomic__store8:
+  case OpCode::I64__atomic__store16:
+  case OpCode::I64__atomic__store32:
+  case OpCode::I32__atomic__rmw__add:
+  case OpCode::I64__atomic__rmw__add:
+  case OpCode::I32__atomic__rmw8__add_u:
+  case OpCode::I32__atomic__rmw16__add_u:
+  case OpCode::I64__atomic__rmw8__add_u:
+  case OpCode::I64__atomic__rmw16__add_u:
+  case OpCode::I64__atomic__rmw32__add_u:
+  case OpCode::I32__atomic__rmw__sub:
+  case OpCode::I64__atomic__rmw__sub:
+  case OpCode::I32__atomic__rmw8__sub_u:
+  case OpCode::I32__atomic__rmw16__sub_u:
+  case OpCode::I64__atomic__rmw8__sub_u:
+  case OpCode::I64__atomic__rmw16__sub_u:
+  case OpCode::I64__atomic__rmw32__sub_u:
+  case OpCode::I32__atomic__rmw__and:
+  case OpCode::I64__atomic__rmw__and:
+  case OpCode::I32__atomic__rmw8__and_u:
+  case OpCode::I32__atomic__rmw16__and_u:
+  case OpCode::I64__atomic__rmw8__and_u:
+  case OpCode::I64__atomic__rmw16__and_u:
+  case OpCode::I64__atomic__rmw32__and_u:
+  case OpCode::I32__atomic__rmw__or:
+  case OpCode::I64__atomic__rmw__or:
+  case OpCode::I32__atomic__rmw8__or_u:
+  case OpCode::I32__atomic__rmw16__or_u:
+  case OpCode::I64__atomic__rmw8__or_u:
+  case OpCode::I64__atomic__rmw16__or_u:
+  case OpCode::I64__atomic__rmw32__or_u:
+  case OpCode::I32__atomic__rmw__xor:
+  case OpCode::I64__atomic__rmw__xor:
+  case OpCode::I32__atomic__rmw8__xor_u:
+  case OpCode::I32__atomic__rmw16__xor_u:
+  case OpCode::I64__atomic__rmw8__xor_u:
+  case OpCode::I64__atomic__rmw16__xor_u:
+  case OpCode::I64__atomic__rmw32__xor_u:
+  case OpCode::I32__atomic__rmw__xchg:
+  case OpCode::I64__atomic__rmw__xchg:
+  case OpCode::I32__atomic__rmw8__xchg_u:
+  case OpCode::I32__atomic__rmw16__xchg_u:
+  case OpCode::I64__atomic__rmw8__xchg_u:
+  case OpCode::I64__atomic__rmw16__xchg_u:
+  case OpCode::I64__atomic__rmw32__xchg_u:
+  case OpCode::I32__atomic__rmw__cmpxchg:
+  case OpCode::I64__atomic__rmw__cmpxchg:
+  case OpCode::I32__atomic__rmw8__cmpxchg_u:
+  case OpCode::I32__atomic__rmw16__cmpxchg_u:
+  case OpCode::I64__atomic__rmw8__cmpxchg_u:
+  case OpCode::I64__atomic__rmw16__cmpxchg_u:
+  case OpCode::I64__atomic__rmw32__cmpxchg_u:
+    return readMemImmediate();
+  case OpCode::Atomic__fence: {
+    uint32_t Dummy;
+    return readCheckZero(Dummy);
+  }
+
   case OpCode::Memory__init:
     if (!HasDataSection) {
       return logLoadError(ErrCode::DataCountRequired, Instr.getOffset(),
@@ -907,6 +1007,13 @@ Expect<void> Loader::checkInstrProposals(OpCode Code, uint64_t Offset) {
       return logNeedProposal(ErrCode::IllegalOpCode, Proposal::TailCall, Offset,
                              ASTNodeAttr::Instruction);
     }
+  } else if (Code >= OpCode::Memory__atomic__notify &&
+             Code <= OpCode::I64__atomic__rmw32__cmpxchg_u) {
+    // These instructions are for Threads proposal.
+    if (!Conf.hasProposal(Proposal::Threads)) {
+      return logNeedProposal(ErrCode::IllegalOpCode, Proposal::Threads, Offset,
+                             ASTNodeAttr::Instruction);
+    }
   }
   return {};
 }
@@ -13,10 +13,32 @@ Expect<void> Loader::loadLimit(AST::Limit &Lim) {
   if (auto Res = FMgr.readByte()) {
     switch (static_cast<AST::Limit::LimitType>(*Res)) {
     case AST::Limit::LimitType::HasMin:
-      Lim.setHasMax(false);
-      break;
     case AST::Limit::LimitType::HasMinMax:
-      Lim.setHasMax(true);
+      break;
+    case AST::Limit::LimitType::SharedNoMax:
+    case AST::Limit::LimitType::Shared:
+      if (unlikely(!Conf.hasProposal(Proposal::Threads))) {
+        return logNeedProposal(ErrCode::IntegerTooLarge, Proposal::Threads,
+                               FMgr.getLastOffset(), ASTNodeAttr::Type_Limit);
+      }
+      break;
+    case AST::Limit::LimitType::I64HasMin:
+    case AST::Limit::LimitType::I64HasMinMax:
+      if (unlikely(!Conf.hasProposal(Proposal::Memory64))) {
+        return logNeedProposal(ErrCode::IntegerTooLarge, Proposal::Memory64,
+                               FMgr.getLastOffset(), ASTNodeAttr::Type_Limit);
+      }
+      break;
+    case AST::Limit::LimitType::I64SharedNoMax:
+    case AST::Limit::LimitType::I64Shared:
+      if (unlikely(!Conf.hasProposal(Proposal::Memory64))) {
+        return logNeedProposal(ErrCode::IntegerTooLarge, Proposal::Memory64,
+                               FMgr.getLastOffset(), ASTNodeAttr::Type_Limit);
+      }
+      if (unlikely(!Conf.hasProposal(Proposal::Threads))) {
+        return logNeedProposal(ErrCode::IntegerTooLarge, Proposal::Threads,
+                               FMgr.getLastOffset(), ASTNodeAttr::Type_Limit);
+      }
       break;
     default:
       if (*Res == 0x80 || *Res == 0x81) {
@@ -28,13 +50,26 @@ Expect<void> Loader::loadLimit(AST::Limit &Lim) {
                             ASTNodeAttr::Type_Limit);
       }
     }
+    Lim.setHasMax(*Res & 0x01U);
+    Lim.setShared(*Res & 0x02U);
+    Lim.set64(*Res & 0x04U);
   } else {
     return logLoadError(Res.error(), FMgr.getLastOffset(),
                         ASTNodeAttr::Type_Limit);
   }
 
-  // Read min and max number.
-  if (auto Res = FMgr.readU32()) {
+  // Read min and max number. The 64-bit limits are for the Memory64 proposal.
+  auto ReadNum = [this, &Lim]() -> Expect<uint64_t> {
+    if (Lim.is64()) {
+      return FMgr.readU64();
+    }
+    if (auto Res = FMgr.readU32()) {
+      return *Res;
+    } else {
+      return Unexpect(Res);
+    }
+  };
+  if (auto Res = ReadNum()) {
     Lim.setMin(*Res);
     Lim.setMax(*Res);
   } else {
@@ -42,7 +77,7 @@ Expect<void> Loader::loadLimit(AST::Limit &Lim) {
                         ASTNodeAttr::Type_Limit);
   }
   if (Lim.hasMax()) {
-    if (auto Res = FMgr.readU32()) {
+    if (auto Res = ReadNum()) {
       Lim.setMax(*Res);
     } else {
       return logLoadError(Res.error(), FMgr.getLastOffset(),
@@ -152,6 +187,11 @@ Expect<void> Loader::loadType(AST::TableType &TabType) {
     spdlog::error(ErrInfo::InfoAST(ASTNodeAttr::Type_Table));
     return Unexpect(Res);
   }
+  // Tables cannot be shared or 64-bit.
+  if (unlikely(TabType.getLimit().isShared() || TabType.getLimit().is64())) {
+    return logLoadError(ErrCode::IntegerTooLarge, FMgr.getLastOffset(),
+                        ASTNodeAttr::Type_Table);
+  }
   return {};
 }
 
@@ -128,6 +128,15 @@ uint8_t *Allocator::resize(uint8_t *Pointer, uint32_t OldPageCount,
 #endif
 }
 
+bool Allocator::resizesInPlace() noexcept {
+#if defined(HAVE_MMAP) && defined(__x86_64__) || defined(__aarch64__) ||       \
+    WASMEDGE_OS_WINDOWS
+  return true;
+#else
+  return false;
+#endif
+}
+
 void Allocator::release(uint8_t *Pointer, uint32_t) noexcept {
 #if defined(HAVE_MMAP) && defined(__x86_64__) || defined(__aarch64__)
   if (Pointer == nullptr) {
@@ -36,7 +36,7 @@ void FormChecker::reset(bool CleanGlobal) {
     Types.clear();
     Funcs.clear();
     Tables.clear();
-    Mems = 0;
+    Mems.clear();
     Globals.clear();
     Datas.clear();
     Elems.clear();
@@ -88,7 +88,9 @@ void FormChecker::addTable(const AST::TableType &Tab) {
   Tables.push_back(Tab.getRefType());
 }
 
-void FormChecker::addMemory(const AST::MemoryType &) { Mems++; }
+void FormChecker::addMemory(const AST::MemoryType &Mem) {
+  Mems.push_back(Mem.getLimit().is64() ? VType::I64 : VType::I32);
+}
 
 void FormChecker::addGlobal(const AST::GlobalType &Glob, const bool IsImport) {
   // Type in global is comfirmed in loading phase.
@@ -241,16 +243,36 @@ Expect<void> FormChecker::checkInstr(const AST::Instruction &Instr) {
     return static_cast<uint32_t>(CtrlStack.size()) - UINT32_C(1) - N;
   };
 
-  // Helper lambda for checking memory index and perform transformation.
-  auto checkMemAndTrans = [this,
-                           &Instr](Span<const VType> Take,
-                                   Span<const VType> Put) -> Expect<void> {
-    if (Instr.getTargetIndex() >= Mems) {
+  // Helper lambda for checking memory index and getting the index type of the
+  // memory.
+  auto checkMemIdx = [this](uint32_t Idx) -> Expect<VType> {
+    if (Idx >= Mems.size()) {
       return logOutOfRange(ErrCode::InvalidMemoryIdx,
-                           ErrInfo::IndexCategory::Memory,
-                           Instr.getTargetIndex(), Mems);
+                           ErrInfo::IndexCategory::Memory, Idx,
+                           static_cast<uint32_t>(Mems.size()));
     }
-    return StackTrans(Take, Put);
+    return Mems[Idx];
+  };
+
+  // Helper lambda for checking memory index and offset in the memory argument.
+  // Returns the operand types with the address in the index type of memory.
+  auto checkMemArg =
+      [&Instr, checkMemIdx](
+          Span<const VType> Take) -> Expect<std::vector<VType>> {
+    auto IdxType = checkMemIdx(Instr.getTargetIndex());
+    if (!IdxType) {
+      return Unexpect(IdxType);
+    }
+    if (*IdxType == VType::I32 &&
+        Instr.getMemoryOffset() > std::numeric_limits<uint32_t>::max()) {
+      spdlog::error(ErrCode::InvalidMemOffset);
+      spdlog::error(ErrInfo::InfoBoundary(Instr.getMemoryOffset()));
+      return Unexpect(ErrCode::InvalidMemOffset);
+    }
+    // The address is the first operand.
+    std::vector<VType> Operands(Take.begin(), Take.end());
+    Operands[0] = *IdxType;
+    return Operands;
   };
 
   // Helper lambda for checking lane index and perform transformation.
@@ -266,14 +288,13 @@ Expect<void> FormChecker::checkInstr(const AST::Instruction &Instr) {
   };
 
   // Helper lambda for checking memory alignment and perform transformation.
-  auto checkAlignAndTrans = [this, checkLaneAndTrans,
+  auto checkAlignAndTrans = [this, checkMemArg, checkLaneAndTrans,
                              &Instr](uint32_t N, Span<const VType> Take,
                                      Span<const VType> Put,
                                      bool CheckLane = false) -> Expect<void> {
-    if (Instr.getTargetIndex() >= Mems) {
-      return logOutOfRange(ErrCode::InvalidMemoryIdx,
-                           ErrInfo::IndexCategory::Memory,
-                           Instr.getTargetIndex(), Mems);
+    auto Operands = checkMemArg(Take);
+    if (!Operands) {
+      return Unexpect(Operands);
     }
     if (Instr.getMemoryAlign() > 31 ||
         (1UL << Instr.getMemoryAlign()) > (N >> 3UL)) {
@@ -284,9 +305,29 @@ Expect<void> FormChecker::checkInstr(const AST::Instruction &Instr) {
       return Unexpect(ErrCode::InvalidAlignment);
     }
     if (CheckLane) {
-      return checkLaneAndTrans(128 / N, Take, Put);
+      return checkLaneAndTrans(128 / N, *Operands, Put);
     }
-    return StackTrans(Take, Put);
+    return StackTrans(*Operands, Put);
+  };
+
+  // Helper lambda for checking atomic memory alignment and perform
+  // transformation.
+  auto checkAtomicAlignAndTrans =
+      [this, checkMemArg, &Instr](uint32_t N, Span<const VType> Take,
+                                  Span<const VType> Put) -> Expect<void> {
+    auto Operands = checkMemArg(Take);
+    if (!Operands) {
+      return Unexpect(Operands);
+    }
+    if (Instr.getMemoryAlign() > 31 ||
+        (1UL << Instr.getMemoryAlign()) != (N >> 3UL)) {
+      // 2 ^ align needs to == N / 8
+      spdlog::error(ErrCode::InvalidAlignment);
+      spdlog::error(ErrInfo::InfoMismatch(static_cast<uint8_t>(N >> 3),
+                                          Instr.getMemoryAlign()));
+      return Unexpect(ErrCode::InvalidAlignment);
+    }
+    return StackTrans(*Operands, Put);
   };
 
   // Helper lambda for checking vtypes matching.
@@ -805,34 +846,168 @@ Expect<void> FormChecker::checkInstr(const AST::Instruction &Instr) {
     return checkAlignAndTrans(16, {VType::I32, VType::I64}, {});
   case OpCode::I64__store32:
     return checkAlignAndTrans(32, {VType::I32, VType::I64}, {});
+  // Atomic Memory Instructions.
+  case OpCode::Memory__atomic__notify:
+    return checkAtomicAlignAndTrans(32, {VType::I32, VType::I32},
+                                    {VType::I32});
+  case OpCode::Memory__atomic__wait32:
+    return checkAtomicAlignAndTrans(32, {VType::I32, VType::I32, VType::I64},
+                                    {VType::I32});
+  case OpCode::Memory__atomic__wait64:
+    return checkAtomicAlignAndTrans(64, {VType::I32, VType::I64, VType::I64},
+                                    {VType::I32});
+  case OpCode::Atomic__fence:
+    return {};
+  case OpCode::I32__atomic__load:
+    return checkAtomicAlignAndTrans(32, {VType::I32}, {VType::I32});
+  case OpCode::I64__atomic__load:
+    return checkAtomicAlignAndTrans(64, {VType::I32}, {VType::I64});
+  case OpCode::I32__atomic__load8_u:
+    return checkAtomicAlignAndTrans(8, {VType::I32}, {VType::I32});
+  case OpCode::I32__atomic__load16_u:
+    return checkAtomicAlignAndTrans(16, {VType::I32}, {VType::I32});
+  case OpCode::I64__atomic__load8_u:
+    return checkAtomicAlignAndTrans(8, {VType::I32}, {VType::I64});
+  case OpCode::I64__atomic__load16_u:
+    return checkAtomicAlignAndTrans(16, {VType::I32}, {VType::I64});
+  case OpCode::I64__atomic__load32_u:
+    return checkAtomicAlignAndTrans(32, {VType::I32}, {VType::I64});
+  case OpCode::I32__atomic__store:
+    return checkAtomicAlignAndTrans(32, {VType::I32, VType::I32}, {});
+  case OpCode::I64__atomic__store:
+    return checkAtomicAlignAndTrans(64, {VType::I32, VType::I64}, {});
+  case OpCode::I32__atomic__store8:
+    return checkAtomicAlignAndTrans(8, {VType::I32, VType::I32}, {});
+  case OpCode::I32__atomic__store16:
+    return checkAtomicAlignAndTrans(16, {VType::I32, VType::I32}, {});
+  case OpCode::I64__atomic__store8:
+    return checkAtomicAlignAndTrans(8, {VType::I32, VType::I64}, {});
+  case OpCode::I64__atomic__store16:
+    return checkAtomicAlignAndTrans(16, {VType::I32, VType::I64}, {});
+  case OpCode::I64__atomic__store32:
+    return checkAtomicAlignAndTrans(32, {VType::I32, VType::I64}, {});
+  case OpCode::I32__atomic__rmw__add:
+  case OpCode::I32__atomic__rmw__sub:
+  case OpCode::I32__atomic__rmw__and:
+  case OpCode::I32__atomic__rmw__or:
+  case OpCode::I32__atomic__rmw__xor:
+  case OpCode::I32__atomic__rmw__xchg:
+    return checkAtomicAlignAndTrans(32, {VType::I32, VType::I32},
+                                    {VType::I32});
+  case OpCode::I64__atomic__rmw__add:
+  case OpCode::I64__atomic__rmw__sub:
+  case OpCode::I64__atomic__rmw__and:
+  case OpCode::I64__atomic__rmw__or:
+  case OpCode::I64__atomic__rmw__xor:
+  case OpCode::I64__atomic__rmw__xchg:
+    return checkAtomicAlignAndTrans(64, {VType::I32, VType::I64},
+                                    {VType::I64});
+  case OpCode::I32__atomic__rmw8__add_u:
+  case OpCode::I32__atomic__rmw8__sub_u:
+  case OpCode::I32__atomic__rmw8__and_u:
+  case OpCode::I32__atomic__rmw8__or_u:
+  case OpCode::I32__atomic__rmw8__xor_u:
+  case OpCode::I32__atomic__rmw8__xchg_u:
+    return checkAtomicAlignAndTrans(8, {VType::I32, VType::I32}, {VType::I32});
+  case OpCode::I32__atomic__rmw16__add_u:
+  case OpCode::I32__atomic__rmw16__sub_u:
+  case OpCode::I32__atomic__rmw16__and_u:
+  case OpCode::I32__atomic__rmw16__or_u:
+  case OpCode::I32__atomic__rmw16__xor_u:
+  case OpCode::I32__atomic__rmw16__xchg_u:
+    return checkAtomicAlignAndTrans(16, {VType::I32, VType::I32},
+                                    {VType::I32});
+  case OpCode::I64__atomic__rmw8__add_u:
+  case OpCode::I64__atomic__rmw8__sub_u:
+  case OpCode::I64__atomic__rmw8__and_u:
+  case OpCode::I64__atomic__rmw8__or_u:
+  case OpCode::I64__atomic__rmw8__xor_u:
+  case OpCode::I64__atomic__rmw8__xchg_u:
+    return checkAtomicAlignAndTrans(8, {VType::I32, VType::I64}, {VType::I64});
+  case OpCode::I64__atomic__rmw16__add_u:
+  case OpCode::I64__atomic__rmw16__sub_u:
+  case OpCode::I64__atomic__rmw16__and_u:
+  case OpCode::I64__atomic__rmw16__or_u:
+  case OpCode::I64__atomic__rmw16__xor_u:
+  case OpCode::I64__atomic__rmw16__xchg_u:
+    return checkAtomicAlignAndTrans(16, {VType::I32, VType::I64},
+                                    {VType::I64});
+  case OpCode::I64__atomic__rmw32__add_u:
+  case OpCode::I64__atomic__rmw32__sub_u:
+  case OpCode::I64__atomic__rmw32__and_u:
+  case OpCode::I64__atomic__rmw32__or_u:
+  case OpCode::I64__atomic__rmw32__xor_u:
+  case OpCode::I64__atomic__rmw32__xchg_u:
+    return checkAtomicAlignAndTrans(32, {VType::I32, VType::I64},
+                                    {VType::I64});
+  case OpCode::I32__atomic__rmw__cmpxchg:
+    return checkAtomicAlignAndTrans(32, {VType::I32, VType::I32, VType::I32},
+                                    {VType::I32});
+  case OpCode::I64__atomic__rmw__cmpxchg:
+    return checkAtomicAlignAndTrans(64, {VType::I32, VType::I64, VType::I64},
+                                    {VType::I64});
+  case OpCode::I32__atomic__rmw8__cmpxchg_u:
+    return checkAtomicAlignAndTrans(8, {VType::I32, VType::I32, VType::I32},
+                                    {VType::I32});
+  case OpCode::I32__atomic__rmw16__cmpxchg_u:
+    return checkAtomicAlignAndTrans(16, {VType::I32, VType::I32, VType::I32},
+                                    {VType::I32});
+  case OpCode::I64__atomic__rmw8__cmpxchg_u:
+    return checkAtomicAlignAndTrans(8, {VType::I32, VType::I64, VType::I64},
+                                    {VType::I64});
+  case OpCode::I64__atomic__rmw16__cmpxchg_u:
+    return checkAtomicAlignAndTrans(16, {VType::I32, VType::I64, VType::I64},
+                                    {VType::I64});
+  case OpCode::I64__atomic__rmw32__cmpxchg_u:
+    return checkAtomicAlignAndTrans(32, {VType::I32, VType::I64, VType::I64},
+                                    {VType::I64});
   case OpCode::Memory__size:
-    return checkMemAndTrans({}, {VType::I32});
+    if (auto IdxType = checkMemIdx(Instr.getTargetIndex())) {
+      return StackTrans({}, {*IdxType});
+    } else {
+      return Unexpect(IdxType);
+    }
   case OpCode::Memory__grow:
-    return checkMemAndTrans({VType::I32}, {VType::I32});
+    if (auto IdxType = checkMemIdx(Instr.getTargetIndex())) {
+      return StackTrans({*IdxType}, {*IdxType});
+    } else {
+      return Unexpect(IdxType);
+    }
   case OpCode::Memory__init:
     // Check the target memory index. Memory index should be checked first.
-    if (Instr.getTargetIndex() >= Mems) {
-      return logOutOfRange(ErrCode::InvalidMemoryIdx,
-                           ErrInfo::IndexCategory::Memory,
-                           Instr.getTargetIndex(), Mems);
-    }
-    // Check the source data index.
-    if (Instr.getSourceIndex() >= Datas.size()) {
-      return logOutOfRange(ErrCode::InvalidDataIdx,
-                           ErrInfo::IndexCategory::Data, Instr.getSourceIndex(),
-                           static_cast<uint32_t>(Datas.size()));
+    if (auto IdxType = checkMemIdx(Instr.getTargetIndex())) {
+      // Check the source data index.
+      if (Instr.getSourceIndex() >= Datas.size()) {
+        return logOutOfRange(ErrCode::InvalidDataIdx,
+                             ErrInfo::IndexCategory::Data,
+                             Instr.getSourceIndex(),
+                             static_cast<uint32_t>(Datas.size()));
+      }
+      return StackTrans({*IdxType, VType::I32, VType::I32}, {});
+    } else {
+      return Unexpect(IdxType);
     }
-    return StackTrans({VType::I32, VType::I32, VType::I32}, {});
   case OpCode::Memory__copy:
     /// Check the source memory index.
-    if (Instr.getSourceIndex() >= Mems) {
-      return logOutOfRange(ErrCode::InvalidMemoryIdx,
-                           ErrInfo::IndexCategory::Memory,
-                           Instr.getSourceIndex(), Mems);
+    if (auto SrcType = checkMemIdx(Instr.getSourceIndex())) {
+      if (auto DstType = checkMemIdx(Instr.getTargetIndex())) {
+        // The length is 64-bit only if both of the memories are 64-bit.
+        const VType LenType =
+            (*SrcType == VType::I64 && *DstType == VType::I64) ? VType::I64
+                                                               : VType::I32;
+        return StackTrans({*DstType, *SrcType, LenType}, {});
+      } else {
+        return Unexpect(DstType);
+      }
+    } else {
+      return Unexpect(SrcType);
     }
-    [[fallthrough]];
   case OpCode::Memory__fill:
-    return checkMemAndTrans({VType::I32, VType::I32, VType::I32}, {});
+    if (auto IdxType = checkMemIdx(Instr.getTargetIndex())) {
+      return StackTrans({*IdxType, VType::I32, *IdxType}, {});
+    } else {
+      return Unexpect(IdxType);
+    }
   case OpCode::Data__drop:
     // Check the target data index.
     if (Instr.getTargetIndex() >= Datas.size()) {
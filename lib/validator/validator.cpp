@@ -105,7 +105,8 @@ Expect<void> Validator::validate(const AST::Module &Mod) {
   }
 
   // Multiple memories is for the MultiMemories proposal.
-  if (Checker.getMemories() > 1 && !Conf.hasProposal(Proposal::MultiMemories)) {
+  if (Checker.getMemories().size() > 1 &&
+      !Conf.hasProposal(Proposal::MultiMemories)) {
     spdlog::error(ErrCode::MultiMemories);
     spdlog::error(ErrInfo::InfoProposal(Proposal::MultiMemories));
     spdlog::error(ErrInfo::InfoAST(ASTNodeAttr::Module));
@@ -143,12 +144,18 @@ Expect<void> Validator::validate(const AST::MemoryType &Mem) {
   if (auto Res = validate(Lim); !Res) {
     return Unexpect(Res);
   }
-  if (Lim.getMin() > LIMIT_MEMORYTYPE ||
-      (Lim.hasMax() && Lim.getMax() > LIMIT_MEMORYTYPE)) {
+  const uint64_t MaxPages =
+      Lim.is64() ? LIMIT_MEMORYTYPE64 : LIMIT_MEMORYTYPE;
+  if (Lim.getMin() > MaxPages || (Lim.hasMax() && Lim.getMax() > MaxPages)) {
     spdlog::error(ErrCode::InvalidMemPages);
     spdlog::error(ErrInfo::InfoLimit(Lim.hasMax(), Lim.getMin(), Lim.getMax()));
     return Unexpect(ErrCode::InvalidMemPages);
   }
+  if (Lim.isShared() && !Lim.hasMax()) {
+    spdlog::error(ErrCode::SharedMemoryNoMax);
+    spdlog::error(ErrInfo::InfoLimit(Lim.hasMax(), Lim.getMin(), Lim.getMax()));
+    return Unexpect(ErrCode::SharedMemoryNoMax);
+  }
   return {};
 }
 
@@ -232,16 +239,19 @@ Expect<void> Validator::validate(const AST::CodeSegment &CodeSeg,
 Expect<void> Validator::validate(const AST::DataSegment &DataSeg) {
   if (DataSeg.getMode() == AST::DataSegment::DataMode::Active) {
     // Check memory index in context.
-    const auto &MemNum = Checker.getMemories();
-    if (DataSeg.getIdx() >= MemNum) {
+    const auto &Mems = Checker.getMemories();
+    if (DataSeg.getIdx() >= Mems.size()) {
       spdlog::error(ErrCode::InvalidMemoryIdx);
-      spdlog::error(ErrInfo::InfoForbidIndex(ErrInfo::IndexCategory::Memory,
-                                             DataSeg.getIdx(), MemNum));
+      spdlog::error(ErrInfo::InfoForbidIndex(
+          ErrInfo::IndexCategory::Memory, DataSeg.getIdx(),
+          static_cast<uint32_t>(Mems.size())));
       return Unexpect(ErrCode::InvalidMemoryIdx);
     }
-    // Check memory initialization is a const expression.
-    if (auto Res =
-            validateConstExpr(DataSeg.getExpr().getInstrs(), {ValType::I32});
+    // Check memory initialization is a const expression in the index type of
+    // the memory.
+    const ValType IdxType =
+        Mems[DataSeg.getIdx()] == VType::I64 ? ValType::I64 : ValType::I32;
+    if (auto Res = validateConstExpr(DataSeg.getExpr().getInstrs(), {IdxType});
         !Res) {
       spdlog::error(ErrInfo::InfoAST(ASTNodeAttr::Expression));
       return Unexpect(Res);
@@ -324,10 +334,11 @@ Expect<void> Validator::validate(const AST::ExportDesc &ExpDesc) {
     }
     return {};
   case ExternalType::Memory:
-    if (Id >= Checker.getMemories()) {
+    if (Id >= Checker.getMemories().size()) {
       spdlog::error(ErrCode::InvalidMemoryIdx);
-      spdlog::error(ErrInfo::InfoForbidIndex(ErrInfo::IndexCategory::Memory, Id,
-                                             Checker.getMemories()));
+      spdlog::error(ErrInfo::InfoForbidIndex(
+          ErrInfo::IndexCategory::Memory, Id,
+          static_cast<uint32_t>(Checker.getMemories().size())));
       return Unexpect(ErrCode::InvalidMemoryIdx);
     }
     return {};
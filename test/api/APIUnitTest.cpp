@@ -11,6 +11,7 @@
 #include <gtest/gtest.h>
 #include <string>
 #include <string_view>
+#include <thread>
 #include <vector>
 
 namespace {
@@ -91,10 +92,13 @@ WasmEdge_Result ExternTerm(void *, WasmEdge_MemoryInstanceContext *,
   return WasmEdge_Result_Terminate;
 }
 
+int32_t ExternFailData = 5678;
+
 WasmEdge_Result ExternFail(void *, WasmEdge_MemoryInstanceContext *,
                            const WasmEdge_Value *, WasmEdge_Value *Out) {
   // {} -> {i32}
   Out[0] = WasmEdge_ValueGenI32(5678);
+  WasmEdge_HostFunctionSetFailureData(&ExternFailData, nullptr);
   return WasmEdge_Result_Fail;
 }
 
@@ -200,6 +204,16 @@ WasmEdge_ImportObjectContext *createExternModule(std::string_view Name,
   return ImpObj;
 }
 
+// Resource limiter which denies the memory growing beyond 2 pages.
+bool MemLimiter(void *Data, const WasmEdge_String,
+                const WasmEdge_ExternalType Type, const uint32_t,
+                const uint32_t, const uint32_t Desired) {
+  (*static_cast<uint32_t *>(Data))++;
+  return Type != WasmEdge_ExternalType_Memory || Desired <= 2;
+}
+
+void LimiterFinalizer(void *Data) { *static_cast<uint32_t *>(Data) = 0; }
+
 // Helper function to load wasm file into AST module.
 WasmEdge_ASTModuleContext *loadModule(const WasmEdge_ConfigureContext *Conf,
                                       const char *Path) {
@@ -519,6 +533,21 @@ TEST(APICoreTest, MemoryType) {
   WasmEdge_MemoryTypeDelete(nullptr);
   WasmEdge_MemoryTypeDelete(MType);
   WasmEdge_MemoryTypeDelete(nullptr);
+  WasmEdge_Limit Lim3 = {.HasMax = true, .Min = 1, .Max = 4, .Shared = true};
+  WasmEdge_Limit Lim4 = {.HasMax = false, .Min = 1, .Max = 1, .Shared = true};
+  MType = WasmEdge_MemoryTypeCreate(Lim3);
+  EXPECT_TRUE(WasmEdge_LimitIsEqual(WasmEdge_MemoryTypeGetLimit(MType), Lim3));
+  EXPECT_FALSE(WasmEdge_LimitIsEqual(WasmEdge_MemoryTypeGetLimit(MType), Lim1));
+  WasmEdge_MemoryTypeDelete(MType);
+  EXPECT_EQ(WasmEdge_MemoryTypeCreate(Lim4), nullptr);
+  WasmEdge_Limit Lim5 = {
+      .HasMax = true, .Min = 1, .Max = UINT64_C(1) << 40, .Is64 = true};
+  WasmEdge_Limit Lim6 = {.HasMax = true, .Min = 1, .Max = UINT64_C(1) << 40};
+  MType = WasmEdge_MemoryTypeCreate(Lim5);
+  EXPECT_TRUE(WasmEdge_LimitIsEqual(WasmEdge_MemoryTypeGetLimit(MType), Lim5));
+  EXPECT_FALSE(WasmEdge_LimitIsEqual(WasmEdge_MemoryTypeGetLimit(MType), Lim6));
+  WasmEdge_MemoryTypeDelete(MType);
+  EXPECT_EQ(WasmEdge_MemoryTypeCreate(Lim6), nullptr);
 }
 
 TEST(APICoreTest, GlobalType) {
@@ -1085,6 +1114,14 @@ TEST(APICoreTest, ExecutorWithStatistics) {
   EXPECT_TRUE(true);
   ExecCxt = WasmEdge_ExecutorCreate(Conf, nullptr);
   EXPECT_NE(ExecCxt, nullptr);
+  WasmEdge_ExecutorStop(ExecCxt);
+  EXPECT_TRUE(true);
+  WasmEdge_ExecutorStop(nullptr);
+  EXPECT_TRUE(true);
+  WasmEdge_ExecutorResetStop(ExecCxt);
+  EXPECT_TRUE(true);
+  WasmEdge_ExecutorResetStop(nullptr);
+  EXPECT_TRUE(true);
   WasmEdge_ExecutorDelete(ExecCxt);
   EXPECT_TRUE(true);
   ExecCxt = WasmEdge_ExecutorCreate(nullptr, Stat);
@@ -1221,6 +1258,28 @@ TEST(APICoreTest, ExecutorWithStatistics) {
   WasmEdge_StringDelete(FuncName);
   WasmEdge_StringDelete(FuncName2);
 
+  // Get backtrace
+  WasmEdge_BacktraceFrame Frames[2];
+  EXPECT_EQ(WasmEdge_ExecutorGetBacktrace(ExecCxt, Frames, 2), 0U);
+  FuncName = WasmEdge_StringCreateByCString("func-call-indirect");
+  P[0] = WasmEdge_ValueGenI32(100);
+  EXPECT_FALSE(WasmEdge_ResultOK(
+      WasmEdge_ExecutorInvoke(ExecCxt, Store, FuncName, P, 1, R, 1)));
+  WasmEdge_StringDelete(FuncName);
+  EXPECT_EQ(WasmEdge_ExecutorGetBacktrace(ExecCxt, nullptr, 0), 1U);
+  EXPECT_EQ(WasmEdge_ExecutorGetBacktrace(ExecCxt, Frames, 2), 1U);
+  EXPECT_EQ(Frames[0].FuncIndex, 12U);
+  EXPECT_EQ(std::string(Frames[0].FuncName.Buf, Frames[0].FuncName.Length),
+            std::string("func-call-indirect"));
+  EXPECT_NE(Frames[0].Offset, UINT32_MAX);
+  WasmEdge_StringDelete(Frames[0].ModuleName);
+  WasmEdge_StringDelete(Frames[0].FuncName);
+  EXPECT_EQ(WasmEdge_ExecutorGetBacktrace(nullptr, Frames, 2), 0U);
+  // The backtrace is recorded on the thread running the execution
+  std::thread([&]() {
+    EXPECT_EQ(WasmEdge_ExecutorGetBacktrace(ExecCxt, nullptr, 0), 0U);
+  }).join();
+
   // Invoke functions call to host functions
   // Get table and set external reference
   uint32_t TestValue;
@@ -1334,18 +1393,55 @@ TEST(APICoreTest, ExecutorWithStatistics) {
   WasmEdge_StringDelete(FuncName);
   WasmEdge_StringDelete(FuncName2);
 
+  // Invoke function instance from the function reference in table
+  TabName = WasmEdge_StringCreateByCString("tab-func");
+  TabCxt = WasmEdge_StoreFindTable(Store, TabName);
+  EXPECT_NE(TabCxt, nullptr);
+  WasmEdge_StringDelete(TabName);
+  WasmEdge_Value Val;
+  EXPECT_TRUE(
+      WasmEdge_ResultOK(WasmEdge_TableInstanceGetData(TabCxt, &Val, 4)));
+  const WasmEdge_FunctionInstanceContext *FuncRefCxt =
+      WasmEdge_ValueGetFuncRef(Val);
+  EXPECT_NE(FuncRefCxt, nullptr);
+  EXPECT_TRUE(WasmEdge_ResultOK(WasmEdge_ExecutorInvokeFunction(
+      ExecCxt, Store, FuncRefCxt, nullptr, 0, R, 1)));
+  EXPECT_EQ(3, WasmEdge_ValueGetI32(R[0]));
+  EXPECT_EQ(WasmEdge_ValType_I32, R[0].Type);
+  EXPECT_TRUE(isErrMatch(WasmEdge_ErrCode_WrongVMWorkflow,
+                         WasmEdge_ExecutorInvokeFunction(
+                             nullptr, Store, FuncRefCxt, nullptr, 0, R, 1)));
+  EXPECT_TRUE(isErrMatch(WasmEdge_ErrCode_WrongVMWorkflow,
+                         WasmEdge_ExecutorInvokeFunction(
+                             ExecCxt, nullptr, FuncRefCxt, nullptr, 0, R, 1)));
+  EXPECT_TRUE(isErrMatch(WasmEdge_ErrCode_WrongVMWorkflow,
+                         WasmEdge_ExecutorInvokeFunction(
+                             ExecCxt, Store, nullptr, nullptr, 0, R, 1)));
+  // Function type mismatch
+  EXPECT_TRUE(isErrMatch(WasmEdge_ErrCode_FuncSigMismatch,
+                         WasmEdge_ExecutorInvokeFunction(
+                             ExecCxt, Store, FuncRefCxt, P, 1, R, 1)));
+
   // Invoke host function to terminate or fail execution
   ModName = WasmEdge_StringCreateByCString("extern");
   FuncName = WasmEdge_StringCreateByCString("func-term");
   WasmEdge_Result Res = WasmEdge_ExecutorInvokeRegistered(
       ExecCxt, Store, ModName, FuncName, nullptr, 0, R, 1);
   EXPECT_TRUE(WasmEdge_ResultOK(Res));
+  EXPECT_EQ(WasmEdge_ExecutorGetHostFailureData(ExecCxt), nullptr);
   WasmEdge_StringDelete(FuncName);
   FuncName = WasmEdge_StringCreateByCString("func-fail");
   Res = WasmEdge_ExecutorInvokeRegistered(ExecCxt, Store, ModName, FuncName,
                                           nullptr, 0, R, 1);
   EXPECT_TRUE(isErrMatch(WasmEdge_ErrCode_ExecutionFailed, Res));
   EXPECT_GT(WasmEdge_ResultGetCode(Res), 0x01U);
+  // Get the data attached by the failed host function
+  EXPECT_EQ(WasmEdge_ExecutorGetHostFailureData(ExecCxt), &ExternFailData);
+  EXPECT_EQ(WasmEdge_ExecutorGetHostFailureData(nullptr), nullptr);
+  // The failure is recorded on the thread running the execution
+  std::thread([&]() {
+    EXPECT_EQ(WasmEdge_ExecutorGetHostFailureData(ExecCxt), nullptr);
+  }).join();
   WasmEdge_StringDelete(FuncName);
   WasmEdge_StringDelete(ModName);
 
@@ -1479,6 +1575,7 @@ TEST(APICoreTest, Store) {
       WasmEdge_StoreFindFunction(Store, Names[7]);
   EXPECT_NE(WasmEdge_FunctionInstanceGetFunctionType(FuncCxt), nullptr);
   EXPECT_EQ(WasmEdge_FunctionInstanceGetFunctionType(nullptr), nullptr);
+  EXPECT_EQ(WasmEdge_FunctionInstanceGetData(nullptr), nullptr);
 
   // Store list function exports registered
   EXPECT_EQ(WasmEdge_StoreListFunctionRegisteredLength(Store, ModName[0]), 11U);
@@ -1593,6 +1690,68 @@ TEST(APICoreTest, Store) {
   EXPECT_EQ(WasmEdge_StoreFindMemory(nullptr, Names[0]), nullptr);
   EXPECT_EQ(WasmEdge_StoreFindMemory(Store, ErrName), nullptr);
 
+  // Store resource limiter
+  {
+    WasmEdge_MemoryInstanceContext *MemCxt =
+        WasmEdge_StoreFindMemory(Store, Names[0]);
+    const WasmEdge_ModuleInstanceContext *ActiveCxt =
+        WasmEdge_StoreGetActiveModule(Store);
+    uint32_t Calls = 0;
+    EXPECT_EQ(WasmEdge_ModuleInstanceGetMemoryPageSizeInUse(ActiveCxt), 1U);
+    EXPECT_EQ(WasmEdge_ModuleInstanceGetMemoryPageSizeInUse(nullptr), 0U);
+    EXPECT_EQ(WasmEdge_ModuleInstanceGetTableSizeInUse(ActiveCxt), 20U);
+    EXPECT_EQ(WasmEdge_ModuleInstanceGetTableSizeInUse(nullptr), 0U);
+    WasmEdge_StoreSetResourceLimiter(nullptr, MemLimiter, &Calls,
+                                     LimiterFinalizer);
+    WasmEdge_StoreSetResourceLimiter(Store, MemLimiter, &Calls,
+                                     LimiterFinalizer);
+    EXPECT_TRUE(WasmEdge_ResultOK(WasmEdge_MemoryInstanceGrowPage(MemCxt, 1)));
+    EXPECT_FALSE(WasmEdge_ResultOK(WasmEdge_MemoryInstanceGrowPage(MemCxt, 1)));
+    EXPECT_EQ(Calls, 2U);
+    EXPECT_EQ(WasmEdge_MemoryInstanceGetPageSize(MemCxt), 2U);
+    EXPECT_EQ(WasmEdge_ModuleInstanceGetMemoryPageSizeInUse(ActiveCxt), 2U);
+    WasmEdge_StoreSetResourceLimiter(Store, nullptr, nullptr, nullptr);
+    EXPECT_EQ(Calls, 0U);
+    EXPECT_TRUE(WasmEdge_ResultOK(WasmEdge_MemoryInstanceGrowPage(MemCxt, 1)));
+    EXPECT_EQ(Calls, 0U);
+  }
+
+  // Module instance get defined memories, globals, and tables
+  {
+    const WasmEdge_ModuleInstanceContext *ActiveCxt =
+        WasmEdge_StoreGetActiveModule(Store);
+    EXPECT_EQ(WasmEdge_ModuleInstanceGetDefinedMemoryLength(ActiveCxt), 1U);
+    EXPECT_EQ(WasmEdge_ModuleInstanceGetDefinedMemoryLength(nullptr), 0U);
+    EXPECT_EQ(WasmEdge_ModuleInstanceGetDefinedMemory(ActiveCxt, Store, 0),
+              WasmEdge_StoreFindMemory(Store, Names[0]));
+    EXPECT_EQ(WasmEdge_ModuleInstanceGetDefinedMemory(ActiveCxt, Store, 1),
+              nullptr);
+    EXPECT_EQ(WasmEdge_ModuleInstanceGetDefinedMemory(ActiveCxt, nullptr, 0),
+              nullptr);
+    EXPECT_EQ(WasmEdge_ModuleInstanceGetDefinedGlobalLength(ActiveCxt), 2U);
+    EXPECT_EQ(WasmEdge_ModuleInstanceGetDefinedGlobalLength(nullptr), 0U);
+    EXPECT_NE(WasmEdge_ModuleInstanceGetDefinedGlobal(ActiveCxt, Store, 1),
+              nullptr);
+    EXPECT_EQ(WasmEdge_ModuleInstanceGetDefinedGlobal(ActiveCxt, Store, 2),
+              nullptr);
+    EXPECT_EQ(WasmEdge_ModuleInstanceGetDefinedGlobal(nullptr, Store, 0),
+              nullptr);
+    WasmEdge_String TabName = WasmEdge_StringCreateByCString("tab-func");
+    EXPECT_EQ(WasmEdge_ModuleInstanceGetDefinedTableLength(ActiveCxt), 2U);
+    EXPECT_EQ(WasmEdge_ModuleInstanceGetDefinedTableLength(nullptr), 0U);
+    EXPECT_EQ(WasmEdge_ModuleInstanceGetDefinedTable(ActiveCxt, Store, 0),
+              WasmEdge_ModuleInstanceFindTable(ActiveCxt, TabName));
+    EXPECT_EQ(WasmEdge_ModuleInstanceGetDefinedTable(ActiveCxt, Store, 2),
+              nullptr);
+    EXPECT_EQ(WasmEdge_ModuleInstanceGetDefinedTable(nullptr, Store, 0),
+              nullptr);
+    WasmEdge_StringDelete(TabName);
+    // The active data segment is dropped after the instantiation
+    EXPECT_EQ(WasmEdge_ModuleInstanceGetDataSegmentSize(ActiveCxt, 0), 0U);
+    EXPECT_EQ(WasmEdge_ModuleInstanceGetDataSegmentSize(ActiveCxt, 1), 0U);
+    EXPECT_EQ(WasmEdge_ModuleInstanceGetDataSegmentSize(nullptr, 0), 0U);
+  }
+
   // Store list memory exports registered
   EXPECT_EQ(WasmEdge_StoreListMemoryRegisteredLength(Store, ModName[0]), 1U);
   EXPECT_EQ(WasmEdge_StoreListMemoryRegisteredLength(Store, ModName[1]), 0U);
@@ -1920,6 +2079,11 @@ TEST(APICoreTest, Instance) {
   EXPECT_NE(MemCxt, nullptr);
   WasmEdge_MemoryInstanceDelete(MemCxt);
   EXPECT_TRUE(true);
+  MemType = WasmEdge_MemoryTypeCreate(WasmEdge_Limit{
+      .HasMax = false, .Min = 65537, .Max = 65537, .Is64 = true});
+  MemCxt = WasmEdge_MemoryInstanceCreate(MemType);
+  WasmEdge_MemoryTypeDelete(MemType);
+  EXPECT_EQ(MemCxt, nullptr);
   MemType = WasmEdge_MemoryTypeCreate(
       WasmEdge_Limit{.HasMax = true, .Min = 1, .Max = 3});
   MemCxt = WasmEdge_MemoryInstanceCreate(MemType);
@@ -2101,6 +2265,7 @@ TEST(APICoreTest, ImportObject) {
   WasmEdge_FunctionInstanceContext *HostFunc = nullptr;
   WasmEdge_TableInstanceContext *HostTable = nullptr;
   WasmEdge_MemoryInstanceContext *HostMemory = nullptr;
+  WasmEdge_MemoryInstanceContext *SharedMemory = nullptr;
   WasmEdge_GlobalInstanceContext *HostGlobal = nullptr;
   enum WasmEdge_ValType Param[2], Result[1];
 
@@ -2130,6 +2295,11 @@ TEST(APICoreTest, ImportObject) {
   EXPECT_NE(HostFunc, nullptr);
   WasmEdge_FunctionInstanceDelete(HostFunc);
   EXPECT_TRUE(true);
+  HostFunc =
+      WasmEdge_FunctionInstanceCreate(HostFType, ExternAdd, &HostFType, 0);
+  EXPECT_EQ(WasmEdge_FunctionInstanceGetData(HostFunc),
+            static_cast<void *>(&HostFType));
+  WasmEdge_FunctionInstanceDelete(HostFunc);
   HostFunc = WasmEdge_FunctionInstanceCreate(HostFType, ExternAdd, nullptr, 0);
   EXPECT_NE(HostFunc, nullptr);
   HostName = WasmEdge_StringCreateByCString("func-add");
@@ -2182,6 +2352,28 @@ TEST(APICoreTest, ImportObject) {
   WasmEdge_MemoryTypeDelete(HostMType);
   WasmEdge_StringDelete(HostName);
 
+  // Add host shared memory "shared-memory"
+  WasmEdge_Limit SharedMemLimit = {
+      .HasMax = true, .Min = 1, .Max = 2, .Shared = true};
+  HostMType = WasmEdge_MemoryTypeCreate(MemLimit);
+  HostMemory = WasmEdge_MemoryInstanceCreate(HostMType);
+  WasmEdge_MemoryTypeDelete(HostMType);
+  HostName = WasmEdge_StringCreateByCString("shared-memory");
+  // The non-shared memory will not be added.
+  WasmEdge_ImportObjectAddSharedMemory(ImpObj, HostName, HostMemory);
+  EXPECT_TRUE(true);
+  WasmEdge_MemoryInstanceDelete(HostMemory);
+  HostMType = WasmEdge_MemoryTypeCreate(SharedMemLimit);
+  SharedMemory = WasmEdge_MemoryInstanceCreate(HostMType);
+  WasmEdge_ImportObjectAddSharedMemory(nullptr, HostName, SharedMemory);
+  EXPECT_TRUE(true);
+  WasmEdge_ImportObjectAddSharedMemory(ImpObj, HostName, nullptr);
+  EXPECT_TRUE(true);
+  WasmEdge_ImportObjectAddSharedMemory(ImpObj, HostName, SharedMemory);
+  EXPECT_TRUE(true);
+  WasmEdge_MemoryTypeDelete(HostMType);
+  WasmEdge_StringDelete(HostName);
+
   // Add host global "global_i32": const 666
   HostGType = WasmEdge_GlobalTypeCreate(WasmEdge_ValType_I32,
                                         WasmEdge_Mutability_Const);
@@ -2198,7 +2390,13 @@ TEST(APICoreTest, ImportObject) {
   WasmEdge_StringDelete(HostName);
 
   EXPECT_EQ(WasmEdge_ImportObjectWASIGetExitCode(ImpObj), EXIT_FAILURE);
+  EXPECT_TRUE(
+      isErrMatch(WasmEdge_ErrCode_WrongVMWorkflow,
+                 WasmEdge_ImportObjectWASIRedirectStdio(ImpObj, -1, -1, -1)));
   WasmEdge_ImportObjectDelete(ImpObj);
+  // The shared memory is not owned by the import object.
+  EXPECT_EQ(WasmEdge_MemoryInstanceGetPageSize(SharedMemory), 1U);
+  WasmEdge_MemoryInstanceDelete(SharedMemory);
 
   // Create WASI.
   ImpObj = WasmEdge_ImportObjectCreateWASI(Args, 2, Envs, 3, Preopens, 5);
@@ -2212,6 +2410,17 @@ TEST(APICoreTest, ImportObject) {
   // Get WASI exit code.
   EXPECT_EQ(WasmEdge_ImportObjectWASIGetExitCode(ImpObj), EXIT_SUCCESS);
   EXPECT_EQ(WasmEdge_ImportObjectWASIGetExitCode(nullptr), EXIT_FAILURE);
+  // Redirect WASI standard streams.
+  EXPECT_TRUE(WasmEdge_ResultOK(
+      WasmEdge_ImportObjectWASIRedirectStdio(ImpObj, 0, 1, 2)));
+  EXPECT_TRUE(WasmEdge_ResultOK(
+      WasmEdge_ImportObjectWASIRedirectStdio(ImpObj, -1, -1, -1)));
+  EXPECT_TRUE(isErrMatch(
+      WasmEdge_ErrCode_RuntimeError,
+      WasmEdge_ImportObjectWASIRedirectStdio(ImpObj, -1, INT32_MAX, -1)));
+  EXPECT_TRUE(
+      isErrMatch(WasmEdge_ErrCode_WrongVMWorkflow,
+                 WasmEdge_ImportObjectWASIRedirectStdio(nullptr, -1, -1, -1)));
   WasmEdge_ImportObjectDelete(ImpObj);
 
   // Initialize WASI in VM.
@@ -2314,6 +2523,10 @@ TEST(APICoreTest, Async) {
   EXPECT_EQ(WasmEdge_ValType_I32, R[0].Type);
   EXPECT_EQ(912, WasmEdge_ValueGetI32(R[1]));
   EXPECT_EQ(WasmEdge_ValType_I32, R[1].Type);
+  EXPECT_EQ(WasmEdge_AsyncGetHostFailureData(Async), nullptr);
+  EXPECT_EQ(WasmEdge_AsyncGetHostFailureData(nullptr), nullptr);
+  EXPECT_EQ(WasmEdge_AsyncGetBacktrace(Async, nullptr, 0), 0U);
+  EXPECT_EQ(WasmEdge_AsyncGetBacktrace(nullptr, nullptr, 0), 0U);
   WasmEdge_AsyncDelete(Async);
   // VM nullptr case
   Async = WasmEdge_VMAsyncRunWasmFromFile(nullptr, TPath, FuncName, P, 2);
@@ -3125,6 +3338,33 @@ TEST(APICoreTest, VM) {
   EXPECT_TRUE(WasmEdge_ResultOK(
       WasmEdge_VMExecuteRegistered(VM, ModName, FuncName, P, 2, nullptr, 1)));
 
+  // VM execute function instance
+  const WasmEdge_FunctionInstanceContext *FuncCxt =
+      WasmEdge_StoreFindFunctionRegistered(WasmEdge_VMGetStoreContext(VM),
+                                           ModName, FuncName);
+  EXPECT_NE(FuncCxt, nullptr);
+  R[0] = WasmEdge_ValueGenI32(0);
+  R[1] = WasmEdge_ValueGenI32(0);
+  EXPECT_TRUE(
+      WasmEdge_ResultOK(WasmEdge_VMExecuteFunction(VM, FuncCxt, P, 2, R, 2)));
+  EXPECT_EQ(246, WasmEdge_ValueGetI32(R[0]));
+  EXPECT_EQ(WasmEdge_ValType_I32, R[0].Type);
+  EXPECT_EQ(912, WasmEdge_ValueGetI32(R[1]));
+  EXPECT_EQ(WasmEdge_ValType_I32, R[1].Type);
+  EXPECT_TRUE(
+      isErrMatch(WasmEdge_ErrCode_WrongVMWorkflow,
+                 WasmEdge_VMExecuteFunction(nullptr, FuncCxt, P, 2, R, 2)));
+  EXPECT_TRUE(
+      isErrMatch(WasmEdge_ErrCode_WrongVMWorkflow,
+                 WasmEdge_VMExecuteFunction(VM, nullptr, P, 2, R, 2)));
+  // Function type mismatch
+  EXPECT_TRUE(
+      isErrMatch(WasmEdge_ErrCode_FuncSigMismatch,
+                 WasmEdge_VMExecuteFunction(VM, FuncCxt, P, 1, R, 2)));
+  // Discard result
+  EXPECT_TRUE(WasmEdge_ResultOK(
+      WasmEdge_VMExecuteFunction(VM, FuncCxt, P, 2, nullptr, 0)));
+
   // VM get function type
   WasmEdge_VMCleanup(VM);
   EXPECT_TRUE(WasmEdge_ResultOK(WasmEdge_VMLoadWasmFromASTModule(VM, Mod)));
@@ -3231,6 +3471,10 @@ TEST(APICoreTest, VM) {
   EXPECT_NE(WasmEdge_VMGetStatisticsContext(VM), nullptr);
   EXPECT_EQ(WasmEdge_VMGetStatisticsContext(nullptr), nullptr);
 
+  // VM get host failure data
+  EXPECT_EQ(WasmEdge_VMGetHostFailureData(VM), nullptr);
+  EXPECT_EQ(WasmEdge_VMGetHostFailureData(nullptr), nullptr);
+
   WasmEdge_ASTModuleDelete(Mod);
   WasmEdge_ImportObjectDelete(ImpObj);
   WasmEdge_StoreDelete(Store);
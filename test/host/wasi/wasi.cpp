@@ -19,6 +19,7 @@
 #include <string>
 #include <string_view>
 #include <thread>
+#include <unistd.h>
 
 using namespace std::literals;
 
@@ -1307,6 +1308,94 @@ TEST(WasiTest, ProcExit) {
   Env.fini();
 }
 
+TEST(WasiTest, RedirectStdio) {
+  WasmEdge::Host::WASI::Environ Env;
+  WasmEdge::Runtime::Instance::MemoryInstance MemInst(
+      WasmEdge::AST::MemoryType(1));
+
+  WasmEdge::Host::WasiFdRead WasiFdRead(Env);
+  WasmEdge::Host::WasiFdWrite WasiFdWrite(Env);
+  std::array<WasmEdge::ValVariant, 1> Errno = {UINT32_C(0)};
+
+  const uint32_t IOVecSize = 1;
+  const uint32_t NPtr = 0;
+  const uint32_t IOVecPtr = NPtr + sizeof(__wasi_size_t);
+  const uint32_t DataPtr = IOVecPtr + sizeof(__wasi_ciovec_t) * IOVecSize;
+
+  // redirect stdout to a pipe
+  {
+    int Fds[2];
+    ASSERT_EQ(pipe(Fds), 0);
+    Env.init({}, "test"s, {}, {});
+    EXPECT_TRUE(Env.redirectStdio(-1, Fds[1], -1));
+    EXPECT_EQ(close(Fds[1]), 0);
+
+    const auto Data = "hello"sv;
+    writeString(MemInst, Data, DataPtr);
+    auto *IOVec = MemInst.getPointer<__wasi_ciovec_t *>(
+        IOVecPtr, sizeof(__wasi_ciovec_t) * IOVecSize);
+    IOVec[0].buf = DataPtr;
+    IOVec[0].buf_len = Data.size();
+    EXPECT_TRUE(WasiFdWrite.run(&MemInst,
+                                std::initializer_list<WasmEdge::ValVariant>{
+                                    INT32_C(1), IOVecPtr, IOVecSize, NPtr},
+                                Errno));
+    EXPECT_EQ(Errno[0].get<int32_t>(), __WASI_ERRNO_SUCCESS);
+    __wasi_size_t NWritten;
+    EXPECT_TRUE(MemInst.loadValue(NWritten, NPtr, sizeof(NWritten)));
+    EXPECT_EQ(NWritten, Data.size());
+
+    // the duplicated descriptor is closed by fini
+    Env.fini();
+    std::array<char, 16> Buffer;
+    EXPECT_EQ(read(Fds[0], Buffer.data(), Buffer.size()), Data.size());
+    EXPECT_EQ(std::string_view(Buffer.data(), Data.size()), Data);
+    EXPECT_EQ(read(Fds[0], Buffer.data(), Buffer.size()), 0);
+    EXPECT_EQ(close(Fds[0]), 0);
+  }
+
+  // redirect stdin to a pipe
+  {
+    int Fds[2];
+    ASSERT_EQ(pipe(Fds), 0);
+    const auto Data = "abc"sv;
+    EXPECT_EQ(write(Fds[1], Data.data(), Data.size()), Data.size());
+    EXPECT_EQ(close(Fds[1]), 0);
+    Env.init({}, "test"s, {}, {});
+    EXPECT_TRUE(Env.redirectStdio(Fds[0], -1, -1));
+    EXPECT_EQ(close(Fds[0]), 0);
+
+    auto *IOVec = MemInst.getPointer<__wasi_iovec_t *>(
+        IOVecPtr, sizeof(__wasi_iovec_t) * IOVecSize);
+    IOVec[0].buf = DataPtr;
+    IOVec[0].buf_len = 16;
+    EXPECT_TRUE(WasiFdRead.run(&MemInst,
+                               std::initializer_list<WasmEdge::ValVariant>{
+                                   INT32_C(0), IOVecPtr, IOVecSize, NPtr},
+                               Errno));
+    EXPECT_EQ(Errno[0].get<int32_t>(), __WASI_ERRNO_SUCCESS);
+    __wasi_size_t NRead;
+    EXPECT_TRUE(MemInst.loadValue(NRead, NPtr, sizeof(NRead)));
+    EXPECT_EQ(NRead, Data.size());
+    EXPECT_EQ(std::string_view(MemInst.getPointer<char *>(DataPtr), NRead),
+              Data);
+    Env.fini();
+  }
+
+  // invalid host descriptor
+  {
+    int Fds[2];
+    ASSERT_EQ(pipe(Fds), 0);
+    EXPECT_EQ(close(Fds[0]), 0);
+    EXPECT_EQ(close(Fds[1]), 0);
+    Env.init({}, "test"s, {}, {});
+    auto Res = Env.redirectStdio(-1, Fds[1], -1);
+    ASSERT_FALSE(Res);
+    EXPECT_EQ(Res.error(), __WASI_ERRNO_BADF);
+    Env.fini();
+  }
+}
+
 TEST(WasiTest, Random) {
   WasmEdge::Host::WASI::Environ Env;
   WasmEdge::Runtime::Instance::MemoryInstance MemInst(
@@ -1471,6 +1560,32 @@ TEST(WasiTest, Directory) {
     EXPECT_EQ(Errno[0].get<int32_t>(), __WASI_ERRNO_SUCCESS);
     Env.fini();
   }
+
+  // create directory in read-only directory, in both the `guest:host:readonly`
+  // and the `dir:readonly` forms
+  for (const auto &Preopen : {"/:.:readonly"s, ".:readonly"s}) {
+    Env.init({Preopen}, "test"s, {}, {});
+    const auto Path = "tmp"sv;
+    const uint32_t PathSize = Path.size();
+    writeString(MemInst, Path, PathPtr);
+    EXPECT_TRUE(WasiPathCreateDirectory.run(
+        &MemInst,
+        std::initializer_list<WasmEdge::ValVariant>{Fd, PathPtr, PathSize},
+        Errno));
+    EXPECT_EQ(Errno[0].get<int32_t>(), __WASI_ERRNO_NOTCAPABLE);
+
+    const uint32_t FilestatPtr = 8;
+    const auto Current = "."sv;
+    writeString(MemInst, Current, PathPtr);
+    EXPECT_TRUE(WasiPathFilestatGet.run(
+        &MemInst,
+        std::initializer_list<WasmEdge::ValVariant>{
+            Fd, static_cast<uint32_t>(__WASI_LOOKUPFLAGS_SYMLINK_FOLLOW),
+            PathPtr, static_cast<uint32_t>(Current.size()), FilestatPtr},
+        Errno));
+    EXPECT_EQ(Errno[0].get<int32_t>(), __WASI_ERRNO_SUCCESS);
+    Env.fini();
+  }
 }
 
 TEST(WasiTest, SymbolicLink) {
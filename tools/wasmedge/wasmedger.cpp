@@ -39,7 +39,8 @@ int main(int Argc, const char *Argv[]) {
           "Binding directories into WASI virtual filesystem. Each directories "
           "can specified as --dir `guest_path:host_path`, where `guest_path` "
           "specifies the path that will correspond to `host_path` for calls "
-          "like `fopen` in the guest."sv),
+          "like `fopen` in the guest. Append `:readonly` to bind the "
+          "directory as read-only."sv),
       PO::MetaVar("PREOPEN_DIRS"sv));
 
   PO::List<std::string> Env(